@@ -0,0 +1,43 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef AUTOWARE__CAN_SIGNAL_UTILS__CAN_SIGNAL_HPP_
+#define AUTOWARE__CAN_SIGNAL_UTILS__CAN_SIGNAL_HPP_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+
+namespace autoware::can_signal_utils
+{
+/**
+ * @brief signal of a CAN frame defined as in a DBC file
+ */
+struct CanSignal
+{
+  uint32_t id{};
+  // the bit numbering of DBC, the LSB for little endian and the MSB for big endian
+  uint32_t start_bit{};
+  uint32_t length{};
+  bool is_little_endian{};
+  bool is_signed{};
+  double scale{1.0};
+  double offset{0.0};
+};
+
+// returns nullopt when the signal does not fit in the data
+std::optional<double> decode_signal(const CanSignal & signal, const uint8_t * data, size_t size);
+}  // namespace autoware::can_signal_utils
+
+#endif  // AUTOWARE__CAN_SIGNAL_UTILS__CAN_SIGNAL_HPP_
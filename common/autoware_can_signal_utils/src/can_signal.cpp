@@ -0,0 +1,61 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/can_signal_utils/can_signal.hpp"
+
+namespace autoware::can_signal_utils
+{
+namespace
+{
+uint64_t get_bit(const uint8_t * data, const uint32_t position)
+{
+  return (data[position / 8] >> (position % 8)) & 0x01;
+}
+}  // namespace
+
+std::optional<double> decode_signal(const CanSignal & signal, const uint8_t * data, size_t size)
+{
+  if (signal.length == 0 || signal.length > 64) {
+    return std::nullopt;
+  }
+
+  uint64_t raw = 0;
+  if (signal.is_little_endian) {
+    if (signal.start_bit + signal.length > size * 8) {
+      return std::nullopt;
+    }
+    for (uint32_t i = 0; i < signal.length; ++i) {
+      raw |= get_bit(data, signal.start_bit + i) << i;
+    }
+  } else {
+    // the bits go from the MSB to the lower bits of the byte, then to the MSB of the next byte
+    uint32_t position = signal.start_bit;
+    for (uint32_t i = 0; i < signal.length; ++i) {
+      if (position >= size * 8) {
+        return std::nullopt;
+      }
+      raw = (raw << 1) | get_bit(data, position);
+      position = position % 8 == 0 ? position + 15 : position - 1;
+    }
+  }
+
+  double value = static_cast<double>(raw);
+  if (signal.is_signed && signal.length < 64 && (raw >> (signal.length - 1)) & 0x01) {
+    value -= static_cast<double>(uint64_t{1} << signal.length);
+  } else if (signal.is_signed && signal.length == 64) {
+    value = static_cast<double>(static_cast<int64_t>(raw));
+  }
+  return value * signal.scale + signal.offset;
+}
+}  // namespace autoware::can_signal_utils
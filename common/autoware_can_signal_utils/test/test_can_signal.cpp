@@ -0,0 +1,59 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/can_signal_utils/can_signal.hpp"
+
+#include <gtest/gtest.h>
+
+using autoware::can_signal_utils::CanSignal;
+using autoware::can_signal_utils::decode_signal;
+
+TEST(CanSignal, LittleEndian)
+{
+  const uint8_t data[] = {0x34, 0x12, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00};
+
+  CanSignal signal{0x100, 0, 16, true, false, 0.1, 0.0};
+  EXPECT_DOUBLE_EQ(*decode_signal(signal, data, sizeof(data)), 0x1234 * 0.1);
+
+  // 4 bits from the bit 20
+  signal = CanSignal{0x100, 20, 4, true, false, 1.0, 0.0};
+  EXPECT_DOUBLE_EQ(*decode_signal(signal, data, sizeof(data)), 0x0F);
+
+  // out of the data
+  signal = CanSignal{0x100, 60, 8, true, false, 1.0, 0.0};
+  EXPECT_FALSE(decode_signal(signal, data, sizeof(data)));
+}
+
+TEST(CanSignal, BigEndian)
+{
+  const uint8_t data[] = {0x12, 0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00};
+
+  // the MSB of the first byte is the bit 7 in the numbering of DBC
+  const CanSignal signal{0x100, 7, 16, false, false, 1.0, 0.0};
+  EXPECT_DOUBLE_EQ(*decode_signal(signal, data, sizeof(data)), 0x1234);
+
+  const CanSignal nibble{0x100, 3, 8, false, false, 1.0, 0.0};
+  EXPECT_DOUBLE_EQ(*decode_signal(nibble, data, sizeof(data)), 0x23);
+}
+
+TEST(CanSignal, SignedAndOffset)
+{
+  const uint8_t data[] = {0x18, 0xFC};  // -1000
+
+  const CanSignal current{0x100, 0, 16, true, true, 0.1, 0.0};
+  EXPECT_DOUBLE_EQ(*decode_signal(current, data, sizeof(data)), -100.0);
+
+  const CanSignal temperature{0x100, 0, 8, true, false, 1.0, -40.0};
+  EXPECT_DOUBLE_EQ(*decode_signal(temperature, data, sizeof(data)), 24.0 - 40.0);
+}
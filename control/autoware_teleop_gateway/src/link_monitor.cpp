@@ -0,0 +1,89 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "link_monitor.hpp"
+
+namespace autoware::teleop_gateway
+{
+bool LinkMonitor::on_packet(
+  const uint32_t sequence, const double sent_time, const double receive_time)
+{
+  // a packet from the future is rejected before it is taken as the last one, otherwise it would
+  // block the following packets as replays
+  const double latency = receive_time - sent_time;
+  if (latency < -param_.clock_tolerance) {
+    ++num_future_dated_;
+    return false;
+  }
+
+  // a packet which is not newer than the last one is a replay or reordered
+  if (last_sent_time_ && sent_time <= *last_sent_time_) {
+    ++num_replayed_;
+    return false;
+  }
+
+  // the sequence restarts when the operator station is restarted
+  const uint32_t num_lost =
+    last_sequence_ && sequence > *last_sequence_ ? sequence - *last_sequence_ - 1 : 0;
+  last_sequence_ = sequence;
+  last_sent_time_ = sent_time;
+
+  samples_.push_back(Sample{latency, num_lost});
+  while (samples_.size() > param_.window_size) {
+    samples_.pop_front();
+  }
+
+  if (latency > param_.max_latency) {
+    ++num_stale_;
+    return false;
+  }
+  last_forwarded_time_ = receive_time;
+  return true;
+}
+
+LinkState LinkMonitor::get_state(const double now) const
+{
+  if (!last_forwarded_time_ || now - *last_forwarded_time_ > param_.timeout) {
+    return LinkState::LOST;
+  }
+  const auto latency = mean_latency();
+  const bool is_slow = latency && *latency > param_.degraded_latency;
+  if (is_slow || loss_ratio() > param_.degraded_loss_ratio) {
+    return LinkState::DEGRADED;
+  }
+  return LinkState::OK;
+}
+
+std::optional<double> LinkMonitor::mean_latency() const
+{
+  if (samples_.empty()) {
+    return std::nullopt;
+  }
+  double sum = 0.0;
+  for (const auto & sample : samples_) {
+    sum += sample.latency;
+  }
+  return sum / static_cast<double>(samples_.size());
+}
+
+double LinkMonitor::loss_ratio() const
+{
+  double num_lost = 0.0;
+  for (const auto & sample : samples_) {
+    num_lost += sample.num_lost;
+  }
+  const double num_sent = num_lost + static_cast<double>(samples_.size());
+  return num_sent > 0.0 ? num_lost / num_sent : 0.0;
+}
+}  // namespace autoware::teleop_gateway
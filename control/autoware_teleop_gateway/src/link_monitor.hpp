@@ -0,0 +1,80 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef LINK_MONITOR_HPP_
+#define LINK_MONITOR_HPP_
+
+#include <cstddef>
+#include <cstdint>
+#include <deque>
+#include <optional>
+
+namespace autoware::teleop_gateway
+{
+struct LinkParam
+{
+  // [s] a command older than this is not forwarded
+  double max_latency{};
+  // [s] a command sent later than the receive time by more than this is not forwarded
+  double clock_tolerance{};
+  // [s] the link is degraded when the mean latency exceeds this
+  double degraded_latency{};
+  // the link is degraded when the ratio of the lost packets exceeds this
+  double degraded_loss_ratio{};
+  // [s] the link is lost when no command is forwarded for this
+  double timeout{};
+  // number of the recent packets for the latency and the loss ratio
+  size_t window_size{};
+};
+
+enum class LinkState { OK, DEGRADED, LOST };
+
+/**
+ * @brief checks the freshness of each command and the quality of the link
+ */
+class LinkMonitor
+{
+public:
+  explicit LinkMonitor(const LinkParam & param) : param_(param) {}
+
+  // returns true when the command can be forwarded, the times are in seconds
+  bool on_packet(uint32_t sequence, double sent_time, double receive_time);
+
+  [[nodiscard]] LinkState get_state(double now) const;
+  // [s] nullopt before any packet is received
+  [[nodiscard]] std::optional<double> mean_latency() const;
+  [[nodiscard]] double loss_ratio() const;
+  [[nodiscard]] size_t num_replayed() const { return num_replayed_; }
+  [[nodiscard]] size_t num_stale() const { return num_stale_; }
+  [[nodiscard]] size_t num_future_dated() const { return num_future_dated_; }
+
+private:
+  struct Sample
+  {
+    double latency;
+    uint32_t num_lost;
+  };
+
+  LinkParam param_;
+  std::optional<uint32_t> last_sequence_;
+  std::optional<double> last_sent_time_;
+  std::optional<double> last_forwarded_time_;
+  std::deque<Sample> samples_;
+  size_t num_replayed_{0};
+  size_t num_stale_{0};
+  size_t num_future_dated_{0};
+};
+}  // namespace autoware::teleop_gateway
+
+#endif  // LINK_MONITOR_HPP_
@@ -0,0 +1,227 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "teleop_gateway_node.hpp"
+
+#include <arpa/inet.h>
+#include <sys/socket.h>
+#include <unistd.h>
+
+#include <array>
+#include <cerrno>
+#include <cstring>
+#include <fstream>
+#include <iterator>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::teleop_gateway
+{
+namespace
+{
+// the shortest key recommended for HMAC-SHA256 in RFC 2104
+constexpr size_t min_key_size = 32;
+// larger than the packet to detect the oversized datagrams
+constexpr size_t max_datagram_size = 256;
+}  // namespace
+
+TeleopGateway::TeleopGateway(const rclcpp::NodeOptions & node_options)
+: Node("teleop_gateway", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.receive_rate = declare_parameter<double>("receive_rate");
+  node_param_.fallback_brake = declare_parameter<double>("fallback_brake");
+
+  LinkParam link_param;
+  link_param.max_latency = declare_parameter<double>("link.max_latency");
+  link_param.clock_tolerance = declare_parameter<double>("link.clock_tolerance");
+  link_param.degraded_latency = declare_parameter<double>("link.degraded_latency");
+  link_param.degraded_loss_ratio = declare_parameter<double>("link.degraded_loss_ratio");
+  link_param.timeout = declare_parameter<double>("link.timeout");
+  link_param.window_size = static_cast<size_t>(declare_parameter<int>("link.window_size"));
+
+  const auto key_file = declare_parameter<std::string>("key_file");
+  std::ifstream ifs(key_file, std::ios::binary);
+  if (!ifs) {
+    throw std::runtime_error("failed to open the key file: " + key_file);
+  }
+  key_.assign(std::istreambuf_iterator<char>(ifs), std::istreambuf_iterator<char>());
+  if (key_.size() < min_key_size) {
+    throw std::invalid_argument(
+      "the key must be " + std::to_string(min_key_size) + " bytes or longer: " + key_file);
+  }
+
+  const auto local_port = declare_parameter<int>("local_port");
+
+  // Socket
+  socket_fd_ = socket(AF_INET, SOCK_DGRAM, 0);
+  if (socket_fd_ < 0) {
+    throw std::runtime_error(std::string("failed to create a socket: ") + std::strerror(errno));
+  }
+  sockaddr_in local_address{};
+  local_address.sin_family = AF_INET;
+  local_address.sin_addr.s_addr = htonl(INADDR_ANY);
+  local_address.sin_port = htons(static_cast<uint16_t>(local_port));
+  if (bind(socket_fd_, reinterpret_cast<sockaddr *>(&local_address), sizeof(local_address)) < 0) {
+    const std::string error = std::strerror(errno);
+    // the destructor is not called when the constructor throws
+    close(socket_fd_);
+    socket_fd_ = -1;
+    throw std::runtime_error("failed to bind port " + std::to_string(local_port) + ": " + error);
+  }
+
+  // Publisher
+  pub_control_cmd_ = create_publisher<ControlCommandStamped>("output/control_cmd", rclcpp::QoS{1});
+  pub_gear_cmd_ = create_publisher<GearShiftStamped>("output/gear_cmd", rclcpp::QoS{1});
+  pub_turn_signal_cmd_ =
+    create_publisher<TurnSignalStamped>("output/turn_signal_cmd", rclcpp::QoS{1});
+  pub_heartbeat_ = create_publisher<Heartbeat>("output/heartbeat", rclcpp::QoS{1});
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.receive_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&TeleopGateway::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("teleop_gateway");
+  updater_.add("teleop_link", this, &TeleopGateway::check_link_state);
+
+  // State
+  link_monitor_ = std::make_unique<LinkMonitor>(link_param);
+}
+
+TeleopGateway::~TeleopGateway()
+{
+  if (socket_fd_ >= 0) {
+    close(socket_fd_);
+  }
+}
+
+void TeleopGateway::on_timer()
+{
+  if (const auto command = receive()) {
+    publish_command(*command);
+    last_command_ = *command;
+  }
+
+  // the heartbeat stops when the link is lost, which stops the vehicle in the command gate
+  if (link_monitor_->get_state(system_clock_.now().seconds()) == LinkState::LOST) {
+    publish_fallback_command();
+    return;
+  }
+  Heartbeat heartbeat;
+  heartbeat.stamp = now();
+  pub_heartbeat_->publish(heartbeat);
+}
+
+std::optional<TeleopCommand> TeleopGateway::receive()
+{
+  // only the latest command in this cycle is forwarded
+  std::optional<TeleopCommand> latest;
+  std::array<uint8_t, max_datagram_size> buffer{};
+  while (true) {
+    const auto size = recv(socket_fd_, buffer.data(), buffer.size(), MSG_DONTWAIT);
+    if (size < 0) {
+      if (errno != EAGAIN && errno != EWOULDBLOCK) {
+        RCLCPP_WARN_THROTTLE(
+          get_logger(), *get_clock(), 5000, "failed to receive from the operator station: %s",
+          std::strerror(errno));
+      }
+      return latest;
+    }
+
+    const auto command = decode_packet(buffer.data(), static_cast<size_t>(size), key_);
+    if (!command) {
+      ++num_invalid_packets_;
+      RCLCPP_WARN_THROTTLE(get_logger(), *get_clock(), 5000, "ignore an invalid packet");
+      continue;
+    }
+    const double sent_time = static_cast<double>(command->sent_time) * 1e-9;
+    if (link_monitor_->on_packet(command->sequence, sent_time, system_clock_.now().seconds())) {
+      latest = command;
+    }
+  }
+}
+
+void TeleopGateway::publish_command(const TeleopCommand & command)
+{
+  const auto stamp = now();
+
+  ControlCommandStamped control_cmd;
+  control_cmd.stamp = stamp;
+  control_cmd.control.steering_angle = command.steering_angle;
+  control_cmd.control.steering_angle_velocity = command.steering_angle_velocity;
+  control_cmd.control.throttle = command.throttle;
+  control_cmd.control.brake = command.brake;
+  pub_control_cmd_->publish(control_cmd);
+
+  GearShiftStamped gear_cmd;
+  gear_cmd.stamp = stamp;
+  gear_cmd.gear_shift.data = command.gear_shift;
+  pub_gear_cmd_->publish(gear_cmd);
+
+  TurnSignalStamped turn_signal_cmd;
+  turn_signal_cmd.stamp = stamp;
+  turn_signal_cmd.turn_signal.data = command.turn_signal;
+  pub_turn_signal_cmd_->publish(turn_signal_cmd);
+}
+
+void TeleopGateway::publish_fallback_command()
+{
+  // nothing is sent before the operator station is connected
+  if (!last_command_) {
+    return;
+  }
+
+  // the steering is kept, and the vehicle brakes until the MRM takes over
+  ControlCommandStamped control_cmd;
+  control_cmd.stamp = now();
+  control_cmd.control.steering_angle = last_command_->steering_angle;
+  control_cmd.control.steering_angle_velocity = 0.0;
+  control_cmd.control.throttle = 0.0;
+  control_cmd.control.brake = node_param_.fallback_brake;
+  pub_control_cmd_->publish(control_cmd);
+}
+
+void TeleopGateway::check_link_state(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  if (const auto latency = link_monitor_->mean_latency()) {
+    stat.addf("mean_latency", "%.3f [s]", *latency);
+  }
+  stat.addf("loss_ratio", "%.3f", link_monitor_->loss_ratio());
+  stat.add("num_stale_packets", link_monitor_->num_stale());
+  stat.add("num_replayed_packets", link_monitor_->num_replayed());
+  stat.add("num_future_dated_packets", link_monitor_->num_future_dated());
+  stat.add("num_invalid_packets", num_invalid_packets_);
+
+  // ERROR triggers the MRM through the diagnostic graph
+  switch (link_monitor_->get_state(system_clock_.now().seconds())) {
+    case LinkState::OK:
+      stat.summary(DiagnosticStatus::OK, "OK");
+      break;
+    case LinkState::DEGRADED:
+      stat.summary(DiagnosticStatus::WARN, "the link is degraded");
+      break;
+    case LinkState::LOST:
+      stat.summary(DiagnosticStatus::ERROR, "the link is lost");
+      break;
+  }
+}
+}  // namespace autoware::teleop_gateway
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::teleop_gateway::TeleopGateway)
@@ -0,0 +1,89 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef TELEOP_GATEWAY_NODE_HPP_
+#define TELEOP_GATEWAY_NODE_HPP_
+
+#include "link_monitor.hpp"
+#include "teleop_packet.hpp"
+
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <tier4_external_api_msgs/msg/control_command_stamped.hpp>
+#include <tier4_external_api_msgs/msg/gear_shift_stamped.hpp>
+#include <tier4_external_api_msgs/msg/heartbeat.hpp>
+#include <tier4_external_api_msgs/msg/turn_signal_stamped.hpp>
+
+#include <cstdint>
+#include <memory>
+#include <optional>
+#include <vector>
+
+namespace autoware::teleop_gateway
+{
+using tier4_external_api_msgs::msg::ControlCommandStamped;
+using tier4_external_api_msgs::msg::GearShiftStamped;
+using tier4_external_api_msgs::msg::Heartbeat;
+using tier4_external_api_msgs::msg::TurnSignalStamped;
+
+struct NodeParam
+{
+  double receive_rate{};
+  double fallback_brake{};
+};
+
+class TeleopGateway : public rclcpp::Node
+{
+public:
+  explicit TeleopGateway(const rclcpp::NodeOptions & node_options);
+  ~TeleopGateway() override;
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+  std::vector<uint8_t> key_;
+
+  // Publisher
+  rclcpp::Publisher<ControlCommandStamped>::SharedPtr pub_control_cmd_;
+  rclcpp::Publisher<GearShiftStamped>::SharedPtr pub_gear_cmd_;
+  rclcpp::Publisher<TurnSignalStamped>::SharedPtr pub_turn_signal_cmd_;
+  rclcpp::Publisher<Heartbeat>::SharedPtr pub_heartbeat_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_link_state(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  int socket_fd_{-1};
+  // the latency is measured with the system clock, which is synchronized with the operator station
+  rclcpp::Clock system_clock_{RCL_SYSTEM_TIME};
+  std::unique_ptr<LinkMonitor> link_monitor_;
+  std::optional<TeleopCommand> last_command_;
+  size_t num_invalid_packets_{0};
+
+  // Function
+  std::optional<TeleopCommand> receive();
+  void publish_command(const TeleopCommand & command);
+  void publish_fallback_command();
+};
+}  // namespace autoware::teleop_gateway
+
+#endif  // TELEOP_GATEWAY_NODE_HPP_
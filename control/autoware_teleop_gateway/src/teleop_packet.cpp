@@ -0,0 +1,122 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "teleop_packet.hpp"
+
+#include <openssl/crypto.h>
+#include <openssl/evp.h>
+#include <openssl/hmac.h>
+
+#include <array>
+#include <cstring>
+#include <type_traits>
+#include <vector>
+
+namespace autoware::teleop_gateway
+{
+namespace
+{
+template <typename T>
+void write(std::vector<uint8_t> & data, const T value)
+{
+  static_assert(std::is_integral_v<T>);
+  for (size_t i = 0; i < sizeof(T); ++i) {
+    data.push_back(static_cast<uint8_t>(static_cast<uint64_t>(value) >> (8 * i)));
+  }
+}
+
+void write_float(std::vector<uint8_t> & data, const float value)
+{
+  uint32_t bits = 0;
+  std::memcpy(&bits, &value, sizeof(bits));
+  write(data, bits);
+}
+
+template <typename T>
+T read(const uint8_t * data, size_t & offset)
+{
+  static_assert(std::is_integral_v<T>);
+  uint64_t value = 0;
+  for (size_t i = 0; i < sizeof(T); ++i) {
+    value |= static_cast<uint64_t>(data[offset + i]) << (8 * i);
+  }
+  offset += sizeof(T);
+  return static_cast<T>(value);
+}
+
+float read_float(const uint8_t * data, size_t & offset)
+{
+  const auto bits = read<uint32_t>(data, offset);
+  float value = 0.0f;
+  std::memcpy(&value, &bits, sizeof(value));
+  return value;
+}
+
+std::array<uint8_t, mac_size> calc_mac(
+  const uint8_t * data, const size_t size, const std::vector<uint8_t> & key)
+{
+  std::array<uint8_t, mac_size> mac{};
+  unsigned int mac_length = 0;
+  HMAC(
+    EVP_sha256(), key.data(), static_cast<int>(key.size()), data, size, mac.data(), &mac_length);
+  return mac;
+}
+}  // namespace
+
+std::vector<uint8_t> encode_packet(const TeleopCommand & command, const std::vector<uint8_t> & key)
+{
+  std::vector<uint8_t> data;
+  data.reserve(packet_size);
+  write(data, packet_version);
+  write(data, command.sequence);
+  write(data, command.sent_time);
+  write_float(data, command.steering_angle);
+  write_float(data, command.steering_angle_velocity);
+  write_float(data, command.throttle);
+  write_float(data, command.brake);
+  write(data, command.gear_shift);
+  write(data, command.turn_signal);
+
+  const auto mac = calc_mac(data.data(), data.size(), key);
+  data.insert(data.end(), mac.begin(), mac.end());
+  return data;
+}
+
+std::optional<TeleopCommand> decode_packet(
+  const uint8_t * data, const size_t size, const std::vector<uint8_t> & key)
+{
+  if (size != packet_size || data[0] != packet_version) {
+    return std::nullopt;
+  }
+
+  // the comparison takes a constant time not to leak the MAC
+  const size_t body_size = packet_size - mac_size;
+  const auto mac = calc_mac(data, body_size, key);
+  if (CRYPTO_memcmp(mac.data(), data + body_size, mac_size) != 0) {
+    return std::nullopt;
+  }
+
+  size_t offset = 1;
+  TeleopCommand command;
+  command.sequence = read<uint32_t>(data, offset);
+  command.sent_time = read<int64_t>(data, offset);
+  command.steering_angle = read_float(data, offset);
+  command.steering_angle_velocity = read_float(data, offset);
+  command.throttle = read_float(data, offset);
+  command.brake = read_float(data, offset);
+  command.gear_shift = read<uint8_t>(data, offset);
+  command.turn_signal = read<uint8_t>(data, offset);
+  return command;
+}
+}  // namespace autoware::teleop_gateway
@@ -0,0 +1,56 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef TELEOP_PACKET_HPP_
+#define TELEOP_PACKET_HPP_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+namespace autoware::teleop_gateway
+{
+constexpr uint8_t packet_version = 1;
+constexpr size_t mac_size = 32;
+constexpr size_t packet_size = 31 + mac_size;
+
+/**
+ * @brief command sent from the operator station
+ */
+struct TeleopCommand
+{
+  // increases by one for each packet to count the lost packets
+  uint32_t sequence{};
+  // the time when the operator station sent the packet, in nanoseconds since the UNIX epoch,
+  // which must increase to reject the replayed packets
+  int64_t sent_time{};
+  float steering_angle{};
+  float steering_angle_velocity{};
+  float throttle{};
+  float brake{};
+  // the values of tier4_external_api_msgs::msg::GearShift and TurnSignal
+  uint8_t gear_shift{};
+  uint8_t turn_signal{};
+};
+
+// The packet is little endian and authenticated with HMAC-SHA256 of the shared key.
+std::vector<uint8_t> encode_packet(const TeleopCommand & command, const std::vector<uint8_t> & key);
+
+// returns nullopt when the size, the version or the MAC is invalid
+std::optional<TeleopCommand> decode_packet(
+  const uint8_t * data, size_t size, const std::vector<uint8_t> & key);
+}  // namespace autoware::teleop_gateway
+
+#endif  // TELEOP_PACKET_HPP_
@@ -0,0 +1,164 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "link_monitor.hpp"
+#include "teleop_packet.hpp"
+
+#include <gtest/gtest.h>
+
+#include <vector>
+
+using autoware::teleop_gateway::LinkMonitor;
+using autoware::teleop_gateway::LinkParam;
+using autoware::teleop_gateway::LinkState;
+using autoware::teleop_gateway::TeleopCommand;
+
+namespace
+{
+const std::vector<uint8_t> key = {0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF};
+
+TeleopCommand create_command()
+{
+  TeleopCommand command;
+  command.sequence = 42;
+  command.sent_time = 1700000000123456789;
+  command.steering_angle = 0.25f;
+  command.steering_angle_velocity = -0.5f;
+  command.throttle = 0.1f;
+  command.brake = 0.0f;
+  command.gear_shift = 4;
+  command.turn_signal = 2;
+  return command;
+}
+
+LinkParam create_param()
+{
+  LinkParam param;
+  param.max_latency = 0.3;
+  param.clock_tolerance = 0.05;
+  param.degraded_latency = 0.1;
+  param.degraded_loss_ratio = 0.2;
+  param.timeout = 0.5;
+  param.window_size = 10;
+  return param;
+}
+}  // namespace
+
+TEST(TeleopPacket, EncodeDecode)
+{
+  using autoware::teleop_gateway::decode_packet;
+  using autoware::teleop_gateway::encode_packet;
+  const auto command = create_command();
+  const auto packet = encode_packet(command, key);
+  ASSERT_EQ(packet.size(), autoware::teleop_gateway::packet_size);
+
+  const auto decoded = decode_packet(packet.data(), packet.size(), key);
+  ASSERT_TRUE(decoded.has_value());
+  EXPECT_EQ(decoded->sequence, command.sequence);
+  EXPECT_EQ(decoded->sent_time, command.sent_time);
+  EXPECT_FLOAT_EQ(decoded->steering_angle, command.steering_angle);
+  EXPECT_FLOAT_EQ(decoded->steering_angle_velocity, command.steering_angle_velocity);
+  EXPECT_FLOAT_EQ(decoded->throttle, command.throttle);
+  EXPECT_FLOAT_EQ(decoded->brake, command.brake);
+  EXPECT_EQ(decoded->gear_shift, command.gear_shift);
+  EXPECT_EQ(decoded->turn_signal, command.turn_signal);
+}
+
+TEST(TeleopPacket, RejectInvalidPacket)
+{
+  using autoware::teleop_gateway::decode_packet;
+  using autoware::teleop_gateway::encode_packet;
+  auto packet = encode_packet(create_command(), key);
+
+  // wrong key
+  const std::vector<uint8_t> other_key = {0x00};
+  EXPECT_FALSE(decode_packet(packet.data(), packet.size(), other_key).has_value());
+
+  // wrong size
+  EXPECT_FALSE(decode_packet(packet.data(), packet.size() - 1, key).has_value());
+
+  // tampered throttle
+  packet.at(21) ^= 0x01;
+  EXPECT_FALSE(decode_packet(packet.data(), packet.size(), key).has_value());
+}
+
+TEST(LinkMonitor, ForwardFreshCommand)
+{
+  LinkMonitor monitor(create_param());
+  EXPECT_EQ(monitor.get_state(0.0), LinkState::LOST);
+
+  EXPECT_TRUE(monitor.on_packet(0, 10.00, 10.05));
+  EXPECT_EQ(monitor.get_state(10.1), LinkState::OK);
+
+  // stale command
+  EXPECT_FALSE(monitor.on_packet(1, 10.10, 10.50));
+  EXPECT_EQ(monitor.num_stale(), 1u);
+
+  // no command is forwarded for the timeout
+  EXPECT_EQ(monitor.get_state(10.6), LinkState::LOST);
+}
+
+TEST(LinkMonitor, RejectReplay)
+{
+  LinkMonitor monitor(create_param());
+  EXPECT_TRUE(monitor.on_packet(0, 10.00, 10.01));
+  EXPECT_FALSE(monitor.on_packet(0, 10.00, 10.02));
+  EXPECT_EQ(monitor.num_replayed(), 1u);
+
+  // the sequence restarts with the newer time
+  EXPECT_TRUE(monitor.on_packet(0, 10.10, 10.11));
+}
+
+TEST(LinkMonitor, RejectFutureDated)
+{
+  LinkMonitor monitor(create_param());
+
+  // a small offset of the clocks is tolerated
+  EXPECT_TRUE(monitor.on_packet(0, 10.02, 10.00));
+
+  // a command dated in the future is rejected
+  EXPECT_FALSE(monitor.on_packet(1, 20.00, 10.02));
+  EXPECT_EQ(monitor.num_future_dated(), 1u);
+  EXPECT_EQ(monitor.num_replayed(), 0u);
+
+  // the following commands are not blocked as replays
+  EXPECT_TRUE(monitor.on_packet(2, 10.04, 10.04));
+  EXPECT_EQ(monitor.num_replayed(), 0u);
+}
+
+TEST(LinkMonitor, Degraded)
+{
+  LinkMonitor monitor(create_param());
+
+  // one of four packets is lost
+  double t = 10.0;
+  for (uint32_t sequence = 0; sequence < 40; ++sequence) {
+    t += 0.02;
+    if (sequence % 4 != 3) {
+      monitor.on_packet(sequence, t, t + 0.01);
+    }
+  }
+  EXPECT_NEAR(monitor.loss_ratio(), 0.25, 0.05);
+  EXPECT_EQ(monitor.get_state(t), LinkState::DEGRADED);
+
+  // high latency
+  LinkMonitor slow_monitor(create_param());
+  for (uint32_t sequence = 0; sequence < 10; ++sequence) {
+    t += 0.02;
+    slow_monitor.on_packet(sequence, t, t + 0.2);
+  }
+  ASSERT_TRUE(slow_monitor.mean_latency().has_value());
+  EXPECT_NEAR(*slow_monitor.mean_latency(), 0.2, 1e-6);
+  EXPECT_EQ(slow_monitor.get_state(t + 0.2), LinkState::DEGRADED);
+}
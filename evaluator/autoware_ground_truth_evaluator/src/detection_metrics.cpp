@@ -0,0 +1,178 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "detection_metrics.hpp"
+
+#include <algorithm>
+#include <cmath>
+#include <tuple>
+#include <utility>
+
+namespace autoware::ground_truth_evaluator
+{
+namespace
+{
+double calc_distance(const EvaluationObject & a, const EvaluationObject & b)
+{
+  return std::hypot(a.x - b.x, a.y - b.y);
+}
+}  // namespace
+
+std::optional<double> calc_average_precision(
+  const std::vector<EvaluationFrame> & frames, const std::string & label,
+  const double distance_threshold)
+{
+  // the index of the frame and the estimation
+  std::vector<std::pair<size_t, const EvaluationObject *>> estimations;
+  size_t ground_truth_count = 0;
+  for (size_t i = 0; i < frames.size(); ++i) {
+    for (const auto & estimation : frames.at(i).estimations) {
+      if (estimation.label == label) {
+        estimations.emplace_back(i, &estimation);
+      }
+    }
+    ground_truth_count += std::count_if(
+      frames.at(i).ground_truths.begin(), frames.at(i).ground_truths.end(),
+      [&label](const EvaluationObject & object) { return object.label == label; });
+  }
+  if (ground_truth_count == 0) {
+    return std::nullopt;
+  }
+  std::stable_sort(estimations.begin(), estimations.end(), [](const auto & a, const auto & b) {
+    return a.second->score > b.second->score;
+  });
+
+  // match the estimations in the descending order of the score to the nearest ground truth
+  std::vector<std::vector<bool>> is_matched(frames.size());
+  for (size_t i = 0; i < frames.size(); ++i) {
+    is_matched.at(i).resize(frames.at(i).ground_truths.size(), false);
+  }
+  std::vector<double> precisions;
+  std::vector<double> recalls;
+  size_t true_positive_count = 0;
+  for (size_t k = 0; k < estimations.size(); ++k) {
+    const auto & [frame_index, estimation] = estimations.at(k);
+    const auto & ground_truths = frames.at(frame_index).ground_truths;
+    std::optional<size_t> nearest;
+    double nearest_distance = distance_threshold;
+    for (size_t j = 0; j < ground_truths.size(); ++j) {
+      const double distance = calc_distance(*estimation, ground_truths.at(j));
+      if (
+        ground_truths.at(j).label == label && !is_matched.at(frame_index).at(j) &&
+        distance <= nearest_distance) {
+        nearest = j;
+        nearest_distance = distance;
+      }
+    }
+    if (nearest) {
+      is_matched.at(frame_index).at(*nearest) = true;
+      ++true_positive_count;
+    }
+    precisions.push_back(static_cast<double>(true_positive_count) / static_cast<double>(k + 1));
+    recalls.push_back(
+      static_cast<double>(true_positive_count) / static_cast<double>(ground_truth_count));
+  }
+
+  // the area under the precision envelope over the recall
+  double average_precision = 0.0;
+  double max_precision = 0.0;
+  for (size_t k = precisions.size(); 0 < k; --k) {
+    max_precision = std::max(max_precision, precisions.at(k - 1));
+    const double previous_recall = k == 1 ? 0.0 : recalls.at(k - 2);
+    average_precision += (recalls.at(k - 1) - previous_recall) * max_precision;
+  }
+  return average_precision;
+}
+
+MotaAccumulator::MotaAccumulator(const double distance_threshold)
+: distance_threshold_(distance_threshold)
+{
+}
+
+void MotaAccumulator::add_frame(const EvaluationFrame & frame)
+{
+  const auto & estimations = frame.estimations;
+  const auto & ground_truths = frame.ground_truths;
+  std::vector<bool> is_estimation_matched(estimations.size(), false);
+  std::vector<std::optional<size_t>> matched_estimations(ground_truths.size());
+
+  // keep the matches of the previous frame while they are within the threshold
+  for (size_t j = 0; j < ground_truths.size(); ++j) {
+    const auto previous = matches_.find(ground_truths.at(j).id);
+    if (previous == matches_.end()) {
+      continue;
+    }
+    for (size_t i = 0; i < estimations.size(); ++i) {
+      if (
+        !is_estimation_matched.at(i) && estimations.at(i).id == previous->second &&
+        calc_distance(estimations.at(i), ground_truths.at(j)) <= distance_threshold_) {
+        is_estimation_matched.at(i) = true;
+        matched_estimations.at(j) = i;
+        break;
+      }
+    }
+  }
+
+  // match the rest greedily in the ascending order of the distance
+  std::vector<std::tuple<double, size_t, size_t>> candidates;
+  for (size_t j = 0; j < ground_truths.size(); ++j) {
+    if (matched_estimations.at(j)) {
+      continue;
+    }
+    for (size_t i = 0; i < estimations.size(); ++i) {
+      const double distance = calc_distance(estimations.at(i), ground_truths.at(j));
+      if (!is_estimation_matched.at(i) && distance <= distance_threshold_) {
+        candidates.emplace_back(distance, j, i);
+      }
+    }
+  }
+  std::sort(candidates.begin(), candidates.end());
+  for (const auto & [distance, j, i] : candidates) {
+    if (matched_estimations.at(j) || is_estimation_matched.at(i)) {
+      continue;
+    }
+    is_estimation_matched.at(i) = true;
+    matched_estimations.at(j) = i;
+  }
+
+  for (size_t j = 0; j < ground_truths.size(); ++j) {
+    ++result_.ground_truth_count;
+    if (!matched_estimations.at(j)) {
+      ++result_.false_negative_count;
+      continue;
+    }
+    const auto & id = estimations.at(*matched_estimations.at(j)).id;
+    const auto previous = matches_.find(ground_truths.at(j).id);
+    if (previous != matches_.end() && previous->second != id) {
+      ++result_.id_switch_count;
+    }
+    matches_[ground_truths.at(j).id] = id;
+  }
+  result_.false_positive_count += static_cast<size_t>(
+    std::count(is_estimation_matched.begin(), is_estimation_matched.end(), false));
+}
+
+std::optional<MotaResult> MotaAccumulator::result() const
+{
+  if (result_.ground_truth_count == 0) {
+    return std::nullopt;
+  }
+  auto result = result_;
+  const auto error_count =
+    result.false_negative_count + result.false_positive_count + result.id_switch_count;
+  result.mota =
+    1.0 - static_cast<double>(error_count) / static_cast<double>(result.ground_truth_count);
+  return result;
+}
+}  // namespace autoware::ground_truth_evaluator
@@ -0,0 +1,80 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef DETECTION_METRICS_HPP_
+#define DETECTION_METRICS_HPP_
+
+#include <cstddef>
+#include <optional>
+#include <string>
+#include <unordered_map>
+#include <vector>
+
+namespace autoware::ground_truth_evaluator
+{
+// an object on the ground plane of the common frame
+struct EvaluationObject
+{
+  std::string label;
+  double x{};
+  double y{};
+  // the confidence of a detection, unused for the ground truth
+  double score{};
+  // the track ID of a tracked object and the ground truth, unused for a detection
+  std::string id;
+};
+
+struct EvaluationFrame
+{
+  std::vector<EvaluationObject> estimations;
+  std::vector<EvaluationObject> ground_truths;
+};
+
+// Calculates the average precision of the label, matching the center distance within the
+// threshold in the same way as nuScenes. nullopt when there is no ground truth of the label.
+std::optional<double> calc_average_precision(
+  const std::vector<EvaluationFrame> & frames, const std::string & label,
+  double distance_threshold);
+
+struct MotaResult
+{
+  size_t ground_truth_count{};
+  size_t false_negative_count{};
+  size_t false_positive_count{};
+  size_t id_switch_count{};
+  double mota{};
+};
+
+// Accumulates the CLEAR MOT metrics frame by frame. The estimations and the ground truths are
+// matched regardless of the labels, and the previous matches are kept while they are within the
+// threshold.
+class MotaAccumulator
+{
+public:
+  explicit MotaAccumulator(double distance_threshold);
+
+  void add_frame(const EvaluationFrame & frame);
+
+  // nullopt when no ground truth has been added
+  std::optional<MotaResult> result() const;
+
+private:
+  double distance_threshold_;
+  MotaResult result_{};
+  // the last estimation ID matched to each ground truth ID
+  std::unordered_map<std::string, std::string> matches_;
+};
+}  // namespace autoware::ground_truth_evaluator
+
+#endif  // DETECTION_METRICS_HPP_
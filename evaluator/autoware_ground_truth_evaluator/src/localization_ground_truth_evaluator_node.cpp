@@ -0,0 +1,147 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "localization_ground_truth_evaluator_node.hpp"
+
+#include "report.hpp"
+
+#include <tf2/utils.h>
+
+#include <algorithm>
+#include <exception>
+#include <string>
+#include <vector>
+
+namespace autoware::ground_truth_evaluator
+{
+namespace
+{
+TrajectoryPoint to_trajectory_point(
+  const builtin_interfaces::msg::Time & stamp, const geometry_msgs::msg::Pose & pose)
+{
+  TrajectoryPoint point;
+  point.time = rclcpp::Time(stamp).seconds();
+  point.x = pose.position.x;
+  point.y = pose.position.y;
+  point.z = pose.position.z;
+  point.yaw = tf2::getYaw(pose.orientation);
+  return point;
+}
+}  // namespace
+
+LocalizationGroundTruthEvaluator::LocalizationGroundTruthEvaluator(
+  const rclcpp::NodeOptions & node_options)
+: Node("localization_ground_truth_evaluator", node_options)
+{
+  // Parameter
+  node_param_.output_directory = declare_parameter<std::string>("output_directory");
+  node_param_.ground_truth_max_gap = declare_parameter<double>("ground_truth_max_gap");
+  node_param_.rpe_segment_lengths =
+    declare_parameter<std::vector<double>>("rpe.segment_lengths");
+  node_param_.max_ate_rmse = declare_parameter<double>("criteria.max_ate_rmse");
+  node_param_.max_rpe_translation_error =
+    declare_parameter<double>("criteria.max_rpe_translation_error");
+  if (node_param_.output_directory.empty()) {
+    RCLCPP_WARN(get_logger(), "output_directory is not specified, the report will NOT be saved.");
+  }
+
+  // Subscriber
+  sub_odometry_ = create_subscription<Odometry>(
+    "input/odometry", rclcpp::QoS{100}, [this](const Odometry::ConstSharedPtr msg) {
+      estimated_trajectory_.push_back(to_trajectory_point(msg->header.stamp, msg->pose.pose));
+    });
+  sub_ground_truth_ = create_subscription<PoseWithCovarianceStamped>(
+    "input/ground_truth", rclcpp::QoS{100},
+    [this](const PoseWithCovarianceStamped::ConstSharedPtr msg) {
+      ground_truth_trajectory_.push_back(to_trajectory_point(msg->header.stamp, msg->pose.pose));
+    });
+}
+
+LocalizationGroundTruthEvaluator::~LocalizationGroundTruthEvaluator()
+{
+  if (node_param_.output_directory.empty()) {
+    return;
+  }
+  try {
+    save_report();
+  } catch (const std::exception & e) {
+    RCLCPP_ERROR(get_logger(), "%s", e.what());
+  }
+}
+
+void LocalizationGroundTruthEvaluator::save_report()
+{
+  const auto by_time = [](const TrajectoryPoint & a, const TrajectoryPoint & b) {
+    return a.time < b.time;
+  };
+  std::sort(estimated_trajectory_.begin(), estimated_trajectory_.end(), by_time);
+  std::sort(ground_truth_trajectory_.begin(), ground_truth_trajectory_.end(), by_time);
+
+  // the ground truth is interpolated at the time of each estimation
+  std::vector<PosePair> pairs;
+  for (const auto & estimated : estimated_trajectory_) {
+    const auto ground_truth =
+      interpolate(ground_truth_trajectory_, estimated.time, node_param_.ground_truth_max_gap);
+    if (ground_truth) {
+      pairs.push_back({estimated, *ground_truth});
+    }
+  }
+
+  nlohmann::json report;
+  report["estimation_count"] = estimated_trajectory_.size();
+  report["ground_truth_count"] = ground_truth_trajectory_.size();
+  report["pair_count"] = pairs.size();
+  report["duration"] =
+    pairs.empty() ? 0.0 : pairs.back().estimated.time - pairs.front().estimated.time;
+
+  bool passed = !pairs.empty();
+  const auto ate = calc_absolute_trajectory_error(pairs);
+  if (ate) {
+    report["ate"]["rmse"] = ate->rmse;
+    report["ate"]["mean"] = ate->mean;
+    report["ate"]["median"] = ate->median;
+    report["ate"]["max"] = ate->max;
+    report["ate"]["yaw_rmse"] = ate->yaw_rmse;
+    passed = passed && ate->rmse <= node_param_.max_ate_rmse;
+  }
+
+  report["rpe"] = nlohmann::json::array();
+  for (const auto segment_length : node_param_.rpe_segment_lengths) {
+    const auto rpe = calc_relative_pose_error(pairs, segment_length);
+    if (!rpe) {
+      continue;
+    }
+    nlohmann::json segment;
+    segment["segment_length"] = rpe->segment_length;
+    segment["count"] = rpe->count;
+    segment["translation_error"] = rpe->translation_error;
+    segment["rotation_error"] = rpe->rotation_error;
+    report["rpe"].push_back(segment);
+    passed = passed && rpe->translation_error <= node_param_.max_rpe_translation_error;
+  }
+
+  report["criteria"]["max_ate_rmse"] = node_param_.max_ate_rmse;
+  report["criteria"]["max_rpe_translation_error"] = node_param_.max_rpe_translation_error;
+  report["passed"] = passed;
+
+  write_report(
+    node_param_.output_directory, "localization_report", "Localization Evaluation", report);
+  RCLCPP_INFO(
+    get_logger(), "the report is saved in %s, passed: %s", node_param_.output_directory.c_str(),
+    passed ? "true" : "false");
+}
+}  // namespace autoware::ground_truth_evaluator
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::ground_truth_evaluator::LocalizationGroundTruthEvaluator)
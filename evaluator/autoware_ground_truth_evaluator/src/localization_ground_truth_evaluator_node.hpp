@@ -0,0 +1,65 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef LOCALIZATION_GROUND_TRUTH_EVALUATOR_NODE_HPP_
+#define LOCALIZATION_GROUND_TRUTH_EVALUATOR_NODE_HPP_
+
+#include "trajectory_metrics.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <geometry_msgs/msg/pose_with_covariance_stamped.hpp>
+#include <nav_msgs/msg/odometry.hpp>
+
+#include <string>
+#include <vector>
+
+namespace autoware::ground_truth_evaluator
+{
+using geometry_msgs::msg::PoseWithCovarianceStamped;
+using nav_msgs::msg::Odometry;
+
+struct LocalizationNodeParam
+{
+  std::string output_directory;
+  double ground_truth_max_gap{};
+  std::vector<double> rpe_segment_lengths;
+  double max_ate_rmse{};
+  double max_rpe_translation_error{};
+};
+
+class LocalizationGroundTruthEvaluator : public rclcpp::Node
+{
+public:
+  explicit LocalizationGroundTruthEvaluator(const rclcpp::NodeOptions & node_options);
+  ~LocalizationGroundTruthEvaluator() override;
+
+private:
+  // Parameter
+  LocalizationNodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<Odometry>::SharedPtr sub_odometry_;
+  rclcpp::Subscription<PoseWithCovarianceStamped>::SharedPtr sub_ground_truth_;
+
+  // State
+  std::vector<TrajectoryPoint> estimated_trajectory_;
+  std::vector<TrajectoryPoint> ground_truth_trajectory_;
+
+  // Function
+  void save_report();
+};
+}  // namespace autoware::ground_truth_evaluator
+
+#endif  // LOCALIZATION_GROUND_TRUTH_EVALUATOR_NODE_HPP_
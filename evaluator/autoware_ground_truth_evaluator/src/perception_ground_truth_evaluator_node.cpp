@@ -0,0 +1,207 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "perception_ground_truth_evaluator_node.hpp"
+
+#include "report.hpp"
+
+#include <autoware/object_recognition_utils/object_classification.hpp>
+#include <autoware/universe_utils/ros/uuid_helper.hpp>
+
+#include <geometry_msgs/msg/point_stamped.hpp>
+
+#include <tf2_geometry_msgs/tf2_geometry_msgs.hpp>
+
+#include <algorithm>
+#include <cstdlib>
+#include <exception>
+#include <set>
+#include <sstream>
+#include <string>
+#include <vector>
+
+namespace autoware::ground_truth_evaluator
+{
+PerceptionGroundTruthEvaluator::PerceptionGroundTruthEvaluator(
+  const rclcpp::NodeOptions & node_options)
+: Node("perception_ground_truth_evaluator", node_options)
+{
+  // Parameter
+  node_param_.output_directory = declare_parameter<std::string>("output_directory");
+  node_param_.target_frame = declare_parameter<std::string>("target_frame");
+  node_param_.stamp_tolerance = declare_parameter<double>("stamp_tolerance");
+  node_param_.map_distance_thresholds =
+    declare_parameter<std::vector<double>>("map.distance_thresholds");
+  node_param_.mota_distance_threshold = declare_parameter<double>("mota.distance_threshold");
+  node_param_.min_map = declare_parameter<double>("criteria.min_map");
+  node_param_.min_mota = declare_parameter<double>("criteria.min_mota");
+  if (node_param_.output_directory.empty()) {
+    RCLCPP_WARN(get_logger(), "output_directory is not specified, the report will NOT be saved.");
+  }
+
+  tf_buffer_ = std::make_unique<tf2_ros::Buffer>(get_clock());
+  tf_listener_ = std::make_unique<tf2_ros::TransformListener>(*tf_buffer_);
+
+  // Subscriber
+  sub_objects_ = create_subscription<DetectedObjects>(
+    "input/objects", rclcpp::QoS{10}, [this](const DetectedObjects::ConstSharedPtr msg) {
+      add_objects(*msg, false, detections_);
+    });
+  sub_tracked_objects_ = create_subscription<TrackedObjects>(
+    "input/tracked_objects", rclcpp::QoS{10}, [this](const TrackedObjects::ConstSharedPtr msg) {
+      add_objects(*msg, true, tracks_);
+    });
+  sub_ground_truth_objects_ = create_subscription<TrackedObjects>(
+    "input/ground_truth_objects", rclcpp::QoS{10},
+    [this](const TrackedObjects::ConstSharedPtr msg) { add_objects(*msg, true, ground_truths_); });
+}
+
+PerceptionGroundTruthEvaluator::~PerceptionGroundTruthEvaluator()
+{
+  if (node_param_.output_directory.empty()) {
+    return;
+  }
+  try {
+    save_report();
+  } catch (const std::exception & e) {
+    RCLCPP_ERROR(get_logger(), "%s", e.what());
+  }
+}
+
+template <class ObjectsT>
+void PerceptionGroundTruthEvaluator::add_objects(
+  const ObjectsT & objects, const bool has_id, ObjectsHistory & history)
+{
+  geometry_msgs::msg::TransformStamped transform;
+  try {
+    transform = tf_buffer_->lookupTransform(
+      node_param_.target_frame, objects.header.frame_id, objects.header.stamp,
+      rclcpp::Duration::from_seconds(0.1));
+  } catch (const tf2::TransformException & e) {
+    RCLCPP_WARN_THROTTLE(get_logger(), *get_clock(), 1000, "%s", e.what());
+    return;
+  }
+
+  auto & evaluation_objects = history[rclcpp::Time(objects.header.stamp).nanoseconds()];
+  evaluation_objects.clear();
+  for (const auto & object : objects.objects) {
+    geometry_msgs::msg::PointStamped position;
+    position.header = objects.header;
+    position.point = object.kinematics.pose_with_covariance.pose.position;
+    tf2::doTransform(position, position, transform);
+
+    EvaluationObject evaluation_object;
+    evaluation_object.label = autoware::object_recognition_utils::convertLabelToString(
+      autoware::object_recognition_utils::getHighestProbLabel(object.classification));
+    evaluation_object.x = position.point.x;
+    evaluation_object.y = position.point.y;
+    evaluation_object.score = object.existence_probability;
+    if (has_id) {
+      evaluation_object.id = autoware::universe_utils::toHexString(object.object_id);
+    }
+    evaluation_objects.push_back(evaluation_object);
+  }
+}
+
+std::vector<EvaluationFrame> PerceptionGroundTruthEvaluator::create_frames(
+  const ObjectsHistory & estimations) const
+{
+  // a frame of the ground truth without the estimations counts as all missed
+  const auto tolerance = static_cast<int64_t>(node_param_.stamp_tolerance * 1e9);
+  std::vector<EvaluationFrame> frames;
+  for (const auto & [stamp, ground_truths] : ground_truths_) {
+    EvaluationFrame frame;
+    frame.ground_truths = ground_truths;
+    const auto next = estimations.lower_bound(stamp - tolerance);
+    if (next != estimations.end() && std::llabs(next->first - stamp) <= tolerance) {
+      frame.estimations = next->second;
+    }
+    frames.push_back(frame);
+  }
+  return frames;
+}
+
+void PerceptionGroundTruthEvaluator::save_report()
+{
+  nlohmann::json report;
+  report["ground_truth_frame_count"] = ground_truths_.size();
+  report["detection_frame_count"] = detections_.size();
+  report["tracking_frame_count"] = tracks_.size();
+  bool passed = !ground_truths_.empty();
+
+  // the detection is evaluated only when it is received
+  if (!detections_.empty()) {
+    const auto frames = create_frames(detections_);
+    std::set<std::string> labels;
+    for (const auto & frame : frames) {
+      for (const auto & ground_truth : frame.ground_truths) {
+        labels.insert(ground_truth.label);
+      }
+    }
+
+    report["classes"] = nlohmann::json::array();
+    double ap_sum = 0.0;
+    for (const auto & label : labels) {
+      nlohmann::json label_report;
+      label_report["label"] = label;
+      double label_ap_sum = 0.0;
+      for (const auto threshold : node_param_.map_distance_thresholds) {
+        const double ap = calc_average_precision(frames, label, threshold).value_or(0.0);
+        std::ostringstream key;
+        key << "ap@" << threshold;
+        label_report[key.str()] = ap;
+        label_ap_sum += ap;
+      }
+      const double label_ap =
+        label_ap_sum / static_cast<double>(node_param_.map_distance_thresholds.size());
+      label_report["ap"] = label_ap;
+      report["classes"].push_back(label_report);
+      ap_sum += label_ap;
+    }
+    const double map = labels.empty() ? 0.0 : ap_sum / static_cast<double>(labels.size());
+    report["map"] = map;
+    passed = passed && node_param_.min_map <= map;
+  }
+
+  // the tracking is evaluated only when it is received
+  if (!tracks_.empty()) {
+    MotaAccumulator accumulator(node_param_.mota_distance_threshold);
+    for (const auto & frame : create_frames(tracks_)) {
+      accumulator.add_frame(frame);
+    }
+    const auto result = accumulator.result();
+    if (result) {
+      report["mota"]["mota"] = result->mota;
+      report["mota"]["ground_truth_count"] = result->ground_truth_count;
+      report["mota"]["false_negative_count"] = result->false_negative_count;
+      report["mota"]["false_positive_count"] = result->false_positive_count;
+      report["mota"]["id_switch_count"] = result->id_switch_count;
+      passed = passed && node_param_.min_mota <= result->mota;
+    }
+  }
+
+  report["criteria"]["min_map"] = node_param_.min_map;
+  report["criteria"]["min_mota"] = node_param_.min_mota;
+  report["passed"] = passed;
+
+  write_report(
+    node_param_.output_directory, "perception_report", "Perception Evaluation", report);
+  RCLCPP_INFO(
+    get_logger(), "the report is saved in %s, passed: %s", node_param_.output_directory.c_str(),
+    passed ? "true" : "false");
+}
+}  // namespace autoware::ground_truth_evaluator
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::ground_truth_evaluator::PerceptionGroundTruthEvaluator)
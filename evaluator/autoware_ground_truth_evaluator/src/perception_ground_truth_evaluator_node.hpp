@@ -0,0 +1,84 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef PERCEPTION_GROUND_TRUTH_EVALUATOR_NODE_HPP_
+#define PERCEPTION_GROUND_TRUTH_EVALUATOR_NODE_HPP_
+
+#include "detection_metrics.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_perception_msgs/msg/detected_objects.hpp>
+#include <autoware_perception_msgs/msg/tracked_objects.hpp>
+
+#include <tf2_ros/buffer.h>
+#include <tf2_ros/transform_listener.h>
+
+#include <cstdint>
+#include <map>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::ground_truth_evaluator
+{
+using autoware_perception_msgs::msg::DetectedObjects;
+using autoware_perception_msgs::msg::TrackedObjects;
+
+struct PerceptionNodeParam
+{
+  std::string output_directory;
+  std::string target_frame;
+  double stamp_tolerance{};
+  std::vector<double> map_distance_thresholds;
+  double mota_distance_threshold{};
+  double min_map{};
+  double min_mota{};
+};
+
+// the objects of the frames in the target frame, keyed by the stamp in nanoseconds
+using ObjectsHistory = std::map<int64_t, std::vector<EvaluationObject>>;
+
+class PerceptionGroundTruthEvaluator : public rclcpp::Node
+{
+public:
+  explicit PerceptionGroundTruthEvaluator(const rclcpp::NodeOptions & node_options);
+  ~PerceptionGroundTruthEvaluator() override;
+
+private:
+  // Parameter
+  PerceptionNodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<DetectedObjects>::SharedPtr sub_objects_;
+  rclcpp::Subscription<TrackedObjects>::SharedPtr sub_tracked_objects_;
+  rclcpp::Subscription<TrackedObjects>::SharedPtr sub_ground_truth_objects_;
+  std::unique_ptr<tf2_ros::Buffer> tf_buffer_;
+  std::unique_ptr<tf2_ros::TransformListener> tf_listener_;
+
+  // State
+  ObjectsHistory detections_;
+  ObjectsHistory tracks_;
+  ObjectsHistory ground_truths_;
+
+  // Function
+  template <class ObjectsT>
+  void add_objects(const ObjectsT & objects, bool has_id, ObjectsHistory & history);
+  std::vector<EvaluationFrame> create_frames(const ObjectsHistory & estimations) const;
+  void save_report();
+};
+}  // namespace autoware::ground_truth_evaluator
+
+#endif  // PERCEPTION_GROUND_TRUTH_EVALUATOR_NODE_HPP_
@@ -0,0 +1,136 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "report.hpp"
+
+#include <filesystem>
+#include <fstream>
+#include <set>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+
+namespace autoware::ground_truth_evaluator
+{
+namespace
+{
+std::string escape(const std::string & text)
+{
+  std::string escaped;
+  for (const auto c : text) {
+    switch (c) {
+      case '&':
+        escaped += "&amp;";
+        break;
+      case '<':
+        escaped += "&lt;";
+        break;
+      case '>':
+        escaped += "&gt;";
+        break;
+      case '"':
+        escaped += "&quot;";
+        break;
+      default:
+        escaped += c;
+    }
+  }
+  return escaped;
+}
+
+void render(const nlohmann::json & value, std::ostringstream & html)
+{
+  if (value.is_object()) {
+    html << "<table>";
+    for (const auto & [key, child] : value.items()) {
+      html << "<tr><th>" << escape(key) << "</th><td>";
+      render(child, html);
+      html << "</td></tr>";
+    }
+    html << "</table>";
+    return;
+  }
+
+  if (value.is_array() && !value.empty() && value.front().is_object()) {
+    std::set<std::string> keys;
+    for (const auto & element : value) {
+      for (const auto & [key, child] : element.items()) {
+        keys.insert(key);
+      }
+    }
+    html << "<table><tr>";
+    for (const auto & key : keys) {
+      html << "<th>" << escape(key) << "</th>";
+    }
+    html << "</tr>";
+    for (const auto & element : value) {
+      html << "<tr>";
+      for (const auto & key : keys) {
+        html << "<td>";
+        if (element.is_object() && element.contains(key)) {
+          render(element.at(key), html);
+        }
+        html << "</td>";
+      }
+      html << "</tr>";
+    }
+    html << "</table>";
+    return;
+  }
+
+  if (value.is_boolean()) {
+    html << (value.get<bool>() ? "<span class=\"pass\">true</span>"
+                               : "<span class=\"fail\">false</span>");
+    return;
+  }
+  if (value.is_string()) {
+    html << escape(value.get<std::string>());
+    return;
+  }
+  html << escape(value.dump());
+}
+}  // namespace
+
+std::string to_html(const std::string & title, const nlohmann::json & report)
+{
+  std::ostringstream html;
+  html << "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>" << escape(title)
+       << "</title>\n<style>\n"
+       << "body { font-family: sans-serif; }\n"
+       << "table { border-collapse: collapse; margin: 4px 0; }\n"
+       << "th, td { border: 1px solid #999; padding: 2px 8px; text-align: left; }\n"
+       << ".pass { color: green; font-weight: bold; }\n"
+       << ".fail { color: red; font-weight: bold; }\n"
+       << "</style>\n</head>\n<body>\n<h1>" << escape(title) << "</h1>\n";
+  render(report, html);
+  html << "\n</body>\n</html>\n";
+  return html.str();
+}
+
+void write_report(
+  const std::string & directory, const std::string & name, const std::string & title,
+  const nlohmann::json & report)
+{
+  std::filesystem::create_directories(directory);
+  const auto path = std::filesystem::path(directory) / name;
+
+  std::ofstream json_file(path.string() + ".json");
+  json_file << report.dump(2) << std::endl;
+  std::ofstream html_file(path.string() + ".html");
+  html_file << to_html(title, report);
+  if (!json_file || !html_file) {
+    throw std::runtime_error("failed to write the report: " + path.string());
+  }
+}
+}  // namespace autoware::ground_truth_evaluator
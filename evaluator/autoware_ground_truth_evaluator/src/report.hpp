@@ -0,0 +1,35 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef REPORT_HPP_
+#define REPORT_HPP_
+
+#include <nlohmann/json.hpp>
+
+#include <string>
+
+namespace autoware::ground_truth_evaluator
+{
+// Renders the report as a standalone HTML page, the objects as tables and the arrays of objects
+// as tables with a row per element.
+std::string to_html(const std::string & title, const nlohmann::json & report);
+
+// Writes `<name>.json` and `<name>.html` into the directory, which is created if not exists.
+// Throws std::runtime_error when the files cannot be written.
+void write_report(
+  const std::string & directory, const std::string & name, const std::string & title,
+  const nlohmann::json & report);
+}  // namespace autoware::ground_truth_evaluator
+
+#endif  // REPORT_HPP_
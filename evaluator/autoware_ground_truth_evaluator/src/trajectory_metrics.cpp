@@ -0,0 +1,157 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "trajectory_metrics.hpp"
+
+#include <algorithm>
+#include <cmath>
+#include <iterator>
+
+namespace autoware::ground_truth_evaluator
+{
+namespace
+{
+double normalize_angle(const double angle)
+{
+  return std::atan2(std::sin(angle), std::cos(angle));
+}
+
+// the pose of b in the coordinate of a
+TrajectoryPoint relative_pose(const TrajectoryPoint & a, const TrajectoryPoint & b)
+{
+  const double dx = b.x - a.x;
+  const double dy = b.y - a.y;
+  TrajectoryPoint relative;
+  relative.x = std::cos(a.yaw) * dx + std::sin(a.yaw) * dy;
+  relative.y = -std::sin(a.yaw) * dx + std::cos(a.yaw) * dy;
+  relative.yaw = normalize_angle(b.yaw - a.yaw);
+  return relative;
+}
+}  // namespace
+
+std::optional<TrajectoryPoint> interpolate(
+  const std::vector<TrajectoryPoint> & trajectory, const double time, const double max_gap)
+{
+  const auto next = std::lower_bound(
+    trajectory.begin(), trajectory.end(), time,
+    [](const TrajectoryPoint & point, const double t) { return point.time < t; });
+  if (next == trajectory.end()) {
+    return std::nullopt;
+  }
+  if (next->time == time) {
+    return *next;
+  }
+  if (next == trajectory.begin()) {
+    return std::nullopt;
+  }
+
+  const auto & p0 = *std::prev(next);
+  const auto & p1 = *next;
+  if (max_gap < p1.time - p0.time) {
+    return std::nullopt;
+  }
+  const double ratio = (time - p0.time) / (p1.time - p0.time);
+  TrajectoryPoint point;
+  point.time = time;
+  point.x = p0.x + (p1.x - p0.x) * ratio;
+  point.y = p0.y + (p1.y - p0.y) * ratio;
+  point.z = p0.z + (p1.z - p0.z) * ratio;
+  point.yaw = normalize_angle(p0.yaw + normalize_angle(p1.yaw - p0.yaw) * ratio);
+  return point;
+}
+
+std::optional<AbsoluteTrajectoryError> calc_absolute_trajectory_error(
+  const std::vector<PosePair> & pairs)
+{
+  if (pairs.empty()) {
+    return std::nullopt;
+  }
+
+  std::vector<double> errors;
+  double squared_sum = 0.0;
+  double yaw_squared_sum = 0.0;
+  for (const auto & pair : pairs) {
+    const double error = std::hypot(
+      pair.estimated.x - pair.ground_truth.x, pair.estimated.y - pair.ground_truth.y,
+      pair.estimated.z - pair.ground_truth.z);
+    const double yaw_error = normalize_angle(pair.estimated.yaw - pair.ground_truth.yaw);
+    errors.push_back(error);
+    squared_sum += error * error;
+    yaw_squared_sum += yaw_error * yaw_error;
+  }
+
+  AbsoluteTrajectoryError ate;
+  ate.count = errors.size();
+  ate.rmse = std::sqrt(squared_sum / static_cast<double>(ate.count));
+  ate.yaw_rmse = std::sqrt(yaw_squared_sum / static_cast<double>(ate.count));
+  double sum = 0.0;
+  for (const auto error : errors) {
+    sum += error;
+  }
+  ate.mean = sum / static_cast<double>(ate.count);
+  std::sort(errors.begin(), errors.end());
+  const size_t half = errors.size() / 2;
+  ate.median = errors.size() % 2 == 0 ? (errors.at(half - 1) + errors.at(half)) / 2.0
+                                      : errors.at(half);
+  ate.max = errors.back();
+  return ate;
+}
+
+std::optional<RelativePoseError> calc_relative_pose_error(
+  const std::vector<PosePair> & pairs, const double segment_length)
+{
+  if (pairs.empty() || segment_length <= 0.0) {
+    return std::nullopt;
+  }
+
+  std::vector<double> distances{0.0};
+  for (size_t i = 1; i < pairs.size(); ++i) {
+    const auto & p0 = pairs.at(i - 1).ground_truth;
+    const auto & p1 = pairs.at(i).ground_truth;
+    distances.push_back(distances.back() + std::hypot(p1.x - p0.x, p1.y - p0.y));
+  }
+
+  RelativePoseError rpe;
+  rpe.segment_length = segment_length;
+  double translation_sum = 0.0;
+  double rotation_sum = 0.0;
+  size_t last = 0;
+  for (size_t first = 0; first < pairs.size(); ++first) {
+    // the first pair which has travelled the segment length from the start
+    last = std::max(last, first);
+    while (last < pairs.size() && distances.at(last) - distances.at(first) < segment_length) {
+      ++last;
+    }
+    if (last == pairs.size()) {
+      break;
+    }
+
+    const auto relative_gt =
+      relative_pose(pairs.at(first).ground_truth, pairs.at(last).ground_truth);
+    const auto relative_est = relative_pose(pairs.at(first).estimated, pairs.at(last).estimated);
+    const auto error = relative_pose(relative_est, relative_gt);
+    const double length = distances.at(last) - distances.at(first);
+    translation_sum += std::hypot(error.x, error.y) / length;
+    rotation_sum += std::abs(error.yaw) / length;
+    ++rpe.count;
+  }
+  if (rpe.count == 0) {
+    return std::nullopt;
+  }
+
+  rpe.translation_error = translation_sum / static_cast<double>(rpe.count) * 100.0;
+  rpe.rotation_error = rotation_sum / static_cast<double>(rpe.count) * 180.0 / M_PI;
+  return rpe;
+}
+}  // namespace autoware::ground_truth_evaluator
@@ -0,0 +1,75 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef TRAJECTORY_METRICS_HPP_
+#define TRAJECTORY_METRICS_HPP_
+
+#include <optional>
+#include <vector>
+
+namespace autoware::ground_truth_evaluator
+{
+struct TrajectoryPoint
+{
+  double time{};
+  double x{};
+  double y{};
+  double z{};
+  double yaw{};
+};
+
+// an estimated pose and the ground truth at the same time
+struct PosePair
+{
+  TrajectoryPoint estimated;
+  TrajectoryPoint ground_truth;
+};
+
+// Interpolates the trajectory sorted by the time linearly.
+// nullopt when the time is out of the trajectory or the neighbors are more than max_gap apart.
+std::optional<TrajectoryPoint> interpolate(
+  const std::vector<TrajectoryPoint> & trajectory, double time, double max_gap);
+
+struct AbsoluteTrajectoryError
+{
+  size_t count{};
+  double rmse{};
+  double mean{};
+  double median{};
+  double max{};
+  double yaw_rmse{};
+};
+
+// The error of the position [m] and the yaw [rad] at each pair, without alignment since both of
+// them are expected in the map frame.
+std::optional<AbsoluteTrajectoryError> calc_absolute_trajectory_error(
+  const std::vector<PosePair> & pairs);
+
+struct RelativePoseError
+{
+  double segment_length{};
+  size_t count{};
+  // the translational error divided by the segment length [%]
+  double translation_error{};
+  // the rotational error divided by the segment length [deg/m]
+  double rotation_error{};
+};
+
+// The drift in 2D over the segments of the length travelled along the ground truth, in the same
+// way as the odometry benchmark of KITTI.
+std::optional<RelativePoseError> calc_relative_pose_error(
+  const std::vector<PosePair> & pairs, double segment_length);
+}  // namespace autoware::ground_truth_evaluator
+
+#endif  // TRAJECTORY_METRICS_HPP_
@@ -0,0 +1,89 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "detection_metrics.hpp"
+
+#include <gtest/gtest.h>
+
+#include <string>
+#include <vector>
+
+using autoware::ground_truth_evaluator::EvaluationFrame;
+using autoware::ground_truth_evaluator::EvaluationObject;
+
+namespace
+{
+EvaluationObject create_object(
+  const std::string & label, const double x, const double score, const std::string & id = "")
+{
+  EvaluationObject object;
+  object.label = label;
+  object.x = x;
+  object.score = score;
+  object.id = id;
+  return object;
+}
+}  // namespace
+
+TEST(DetectionMetrics, AveragePrecision)
+{
+  using autoware::ground_truth_evaluator::calc_average_precision;
+
+  EvaluationFrame frame;
+  frame.ground_truths = {create_object("car", 0.0, 0.0), create_object("car", 10.0, 0.0)};
+  // a false positive of the highest score, a true positive and a detection of the other label
+  frame.estimations = {
+    create_object("car", 20.0, 0.9), create_object("car", 0.5, 0.8),
+    create_object("pedestrian", 10.0, 0.7)};
+
+  // precision 0 at recall 0, then 1/2 at recall 1/2
+  const auto ap = calc_average_precision({frame}, "car", 1.0);
+  ASSERT_TRUE(ap.has_value());
+  EXPECT_DOUBLE_EQ(*ap, 0.25);
+
+  const auto pedestrian_ap = calc_average_precision({frame}, "pedestrian", 1.0);
+  EXPECT_FALSE(pedestrian_ap.has_value());
+
+  // a detection is not matched twice
+  frame.estimations = {create_object("car", 0.0, 0.9), create_object("car", 0.1, 0.8)};
+  frame.ground_truths = {create_object("car", 0.0, 0.0)};
+  const auto duplicated_ap = calc_average_precision({frame}, "car", 1.0);
+  ASSERT_TRUE(duplicated_ap.has_value());
+  EXPECT_DOUBLE_EQ(*duplicated_ap, 1.0);
+}
+
+TEST(DetectionMetrics, Mota)
+{
+  using autoware::ground_truth_evaluator::MotaAccumulator;
+
+  MotaAccumulator accumulator(1.0);
+  EXPECT_FALSE(accumulator.result().has_value());
+
+  EvaluationFrame frame;
+  frame.ground_truths = {create_object("car", 0.0, 0.0, "a"), create_object("car", 10.0, 0.0, "b")};
+  frame.estimations = {create_object("car", 0.2, 0.0, "1"), create_object("car", 30.0, 0.0, "2")};
+  accumulator.add_frame(frame);
+
+  // the track of "a" switches from "1" to "3"
+  frame.estimations = {create_object("car", 0.2, 0.0, "3"), create_object("car", 10.0, 0.0, "4")};
+  accumulator.add_frame(frame);
+
+  const auto result = accumulator.result();
+  ASSERT_TRUE(result.has_value());
+  EXPECT_EQ(result->ground_truth_count, 4u);
+  EXPECT_EQ(result->false_negative_count, 1u);
+  EXPECT_EQ(result->false_positive_count, 1u);
+  EXPECT_EQ(result->id_switch_count, 1u);
+  EXPECT_DOUBLE_EQ(result->mota, 1.0 - 3.0 / 4.0);
+}
@@ -0,0 +1,35 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "report.hpp"
+
+#include <gtest/gtest.h>
+
+#include <string>
+
+TEST(Report, ToHtml)
+{
+  using autoware::ground_truth_evaluator::to_html;
+
+  nlohmann::json report;
+  report["passed"] = false;
+  report["ate"] = {{"rmse", 0.5}};
+  report["classes"] = nlohmann::json::array({{{"label", "<car>"}, {"ap", 0.8}}});
+
+  const auto html = to_html("report", report);
+  EXPECT_NE(html.find("<th>rmse</th><td>0.5</td>"), std::string::npos);
+  EXPECT_NE(html.find("<span class=\"fail\">false</span>"), std::string::npos);
+  EXPECT_NE(html.find("<tr><th>ap</th><th>label</th></tr>"), std::string::npos);
+  EXPECT_NE(html.find("&lt;car&gt;"), std::string::npos);
+}
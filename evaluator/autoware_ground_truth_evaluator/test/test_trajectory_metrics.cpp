@@ -0,0 +1,94 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "trajectory_metrics.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+#include <vector>
+
+using autoware::ground_truth_evaluator::PosePair;
+using autoware::ground_truth_evaluator::TrajectoryPoint;
+
+namespace
+{
+TrajectoryPoint create_point(const double time, const double x, const double y, const double yaw)
+{
+  TrajectoryPoint point;
+  point.time = time;
+  point.x = x;
+  point.y = y;
+  point.yaw = yaw;
+  return point;
+}
+}  // namespace
+
+TEST(TrajectoryMetrics, Interpolate)
+{
+  using autoware::ground_truth_evaluator::interpolate;
+  const std::vector<TrajectoryPoint> trajectory{
+    create_point(0.0, 0.0, 0.0, M_PI - 0.1), create_point(1.0, 2.0, 4.0, -M_PI + 0.1),
+    create_point(3.0, 2.0, 4.0, 0.0)};
+
+  const auto point = interpolate(trajectory, 0.5, 1.0);
+  ASSERT_TRUE(point.has_value());
+  EXPECT_DOUBLE_EQ(point->x, 1.0);
+  EXPECT_DOUBLE_EQ(point->y, 2.0);
+  // interpolated across the discontinuity of the angle
+  EXPECT_NEAR(std::abs(point->yaw), M_PI, 1e-9);
+
+  EXPECT_TRUE(interpolate(trajectory, 1.0, 1.0).has_value());
+  EXPECT_FALSE(interpolate(trajectory, 2.0, 1.0).has_value());
+  EXPECT_FALSE(interpolate(trajectory, -0.1, 1.0).has_value());
+  EXPECT_FALSE(interpolate(trajectory, 3.1, 1.0).has_value());
+}
+
+TEST(TrajectoryMetrics, AbsoluteTrajectoryError)
+{
+  using autoware::ground_truth_evaluator::calc_absolute_trajectory_error;
+  EXPECT_FALSE(calc_absolute_trajectory_error({}).has_value());
+
+  std::vector<PosePair> pairs;
+  for (const double error : {0.0, 1.0, 1.0, 2.0}) {
+    pairs.push_back({create_point(0.0, error, 0.0, 0.0), create_point(0.0, 0.0, 0.0, 0.0)});
+  }
+  const auto ate = calc_absolute_trajectory_error(pairs);
+  ASSERT_TRUE(ate.has_value());
+  EXPECT_EQ(ate->count, 4u);
+  EXPECT_DOUBLE_EQ(ate->rmse, std::sqrt(6.0 / 4.0));
+  EXPECT_DOUBLE_EQ(ate->mean, 1.0);
+  EXPECT_DOUBLE_EQ(ate->median, 1.0);
+  EXPECT_DOUBLE_EQ(ate->max, 2.0);
+  EXPECT_DOUBLE_EQ(ate->yaw_rmse, 0.0);
+}
+
+TEST(TrajectoryMetrics, RelativePoseError)
+{
+  using autoware::ground_truth_evaluator::calc_relative_pose_error;
+
+  // the estimation drifts 1 % forward, with a constant offset which does not affect the RPE
+  std::vector<PosePair> pairs;
+  for (int i = 0; i <= 100; ++i) {
+    const double x = static_cast<double>(i);
+    pairs.push_back({create_point(x, x * 1.01 + 5.0, 3.0, 0.0), create_point(x, x, 0.0, 0.0)});
+  }
+  const auto rpe = calc_relative_pose_error(pairs, 10.0);
+  ASSERT_TRUE(rpe.has_value());
+  EXPECT_EQ(rpe->count, 91u);
+  EXPECT_NEAR(rpe->translation_error, 1.0, 1e-9);
+  EXPECT_NEAR(rpe->rotation_error, 0.0, 1e-9);
+
+  EXPECT_FALSE(calc_relative_pose_error(pairs, 200.0).has_value());
+}
@@ -0,0 +1,159 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "trajectory_comparator_node.hpp"
+
+#include <cmath>
+#include <string>
+#include <vector>
+
+namespace autoware::trajectory_comparator
+{
+namespace
+{
+std::string join(const std::vector<std::string> & names)
+{
+  std::string joined;
+  for (const auto & name : names) {
+    joined += (joined.empty() ? "" : ", ") + name;
+  }
+  return joined;
+}
+}  // namespace
+
+TrajectoryComparatorNode::TrajectoryComparatorNode(const rclcpp::NodeOptions & node_options)
+: Node("trajectory_comparator", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.comparison_length = declare_parameter<double>("comparison_length");
+  node_param_.max_time_difference = declare_parameter<double>("max_time_difference");
+  node_param_.threshold.lateral_deviation =
+    declare_parameter<double>("threshold.lateral_deviation");
+  node_param_.threshold.yaw_deviation = declare_parameter<double>("threshold.yaw_deviation");
+  node_param_.threshold.velocity_difference =
+    declare_parameter<double>("threshold.velocity_difference");
+  node_param_.threshold.stop_distance_difference =
+    declare_parameter<double>("threshold.stop_distance_difference");
+
+  // Subscriber
+  sub_reference_ = create_subscription<Trajectory>(
+    "~/input/reference_trajectory", 1,
+    std::bind(&TrajectoryComparatorNode::on_reference, this, std::placeholders::_1));
+  sub_candidate_ = create_subscription<Trajectory>(
+    "~/input/candidate_trajectory", 1,
+    std::bind(&TrajectoryComparatorNode::on_candidate, this, std::placeholders::_1));
+
+  // Publisher
+  pub_metrics_ = create_publisher<MetricArrayMsg>("~/metrics", 1);
+
+  // Diagnostic Updater
+  updater_.setHardwareID("trajectory_comparator");
+  updater_.add("trajectory_divergence", this, &TrajectoryComparatorNode::check_divergence);
+}
+
+void TrajectoryComparatorNode::on_reference(const Trajectory::ConstSharedPtr msg)
+{
+  reference_ = msg;
+}
+
+void TrajectoryComparatorNode::on_candidate(const Trajectory::ConstSharedPtr msg)
+{
+  if (!reference_) {
+    RCLCPP_WARN_THROTTLE(get_logger(), *get_clock(), 5000, "waiting for the reference trajectory");
+    return;
+  }
+
+  // the trajectories planned from different inputs are not comparable
+  const double time_difference =
+    std::abs((rclcpp::Time(msg->header.stamp) - rclcpp::Time(reference_->header.stamp)).seconds());
+  if (time_difference > node_param_.max_time_difference) {
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 5000,
+      "the stamps of the trajectories differ by %.3f [s], which is more than %.3f [s]",
+      time_difference, node_param_.max_time_difference);
+    return;
+  }
+
+  divergence_ = calc_divergence(*reference_, *msg, node_param_.comparison_length);
+  if (!divergence_) {
+    return;
+  }
+
+  ++num_comparisons_;
+  exceeded_metrics_ = find_exceeded_metrics(*divergence_, node_param_.threshold);
+  if (!exceeded_metrics_.empty()) {
+    ++num_divergences_;
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 1000, "the candidate trajectory diverges: %s",
+      join(exceeded_metrics_).c_str());
+  }
+
+  publish_metrics(msg->header.stamp);
+  updater_.force_update();
+}
+
+void TrajectoryComparatorNode::publish_metrics(const builtin_interfaces::msg::Time & stamp)
+{
+  MetricArrayMsg metrics_msg;
+  metrics_msg.stamp = stamp;
+
+  const auto add_metric = [&](const std::string & name, const double value) {
+    MetricMsg metric_msg;
+    metric_msg.name = "trajectory_divergence/" + name;
+    metric_msg.value = std::to_string(value);
+    metrics_msg.metric_array.push_back(metric_msg);
+  };
+  add_metric("lateral_deviation/max", divergence_->max_lateral_deviation);
+  add_metric("lateral_deviation/mean", divergence_->mean_lateral_deviation);
+  add_metric("yaw_deviation/max", divergence_->max_yaw_deviation);
+  add_metric("velocity_difference/max", divergence_->max_velocity_difference);
+  if (divergence_->reference_stop_distance && divergence_->candidate_stop_distance) {
+    add_metric(
+      "stop_distance_difference",
+      *divergence_->candidate_stop_distance - *divergence_->reference_stop_distance);
+  }
+  add_metric(
+    "divergence_ratio",
+    static_cast<double>(num_divergences_) / static_cast<double>(num_comparisons_));
+
+  pub_metrics_->publish(metrics_msg);
+}
+
+void TrajectoryComparatorNode::check_divergence(
+  diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  stat.add("num_comparisons", num_comparisons_);
+  stat.add("num_divergences", num_divergences_);
+
+  if (!divergence_) {
+    stat.summary(DiagnosticStatus::OK, "no comparison");
+    return;
+  }
+
+  stat.add("max_lateral_deviation", divergence_->max_lateral_deviation);
+  stat.add("max_yaw_deviation", divergence_->max_yaw_deviation);
+  stat.add("max_velocity_difference", divergence_->max_velocity_difference);
+
+  if (exceeded_metrics_.empty()) {
+    stat.summary(DiagnosticStatus::OK, "OK");
+  } else {
+    stat.summary(DiagnosticStatus::WARN, "diverged: " + join(exceeded_metrics_));
+  }
+}
+}  // namespace autoware::trajectory_comparator
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::trajectory_comparator::TrajectoryComparatorNode)
@@ -0,0 +1,78 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef TRAJECTORY_COMPARATOR_NODE_HPP_
+#define TRAJECTORY_COMPARATOR_NODE_HPP_
+
+#include "trajectory_divergence.hpp"
+
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_planning_msgs/msg/trajectory.hpp>
+#include <tier4_metric_msgs/msg/metric_array.hpp>
+
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::trajectory_comparator
+{
+using MetricMsg = tier4_metric_msgs::msg::Metric;
+using MetricArrayMsg = tier4_metric_msgs::msg::MetricArray;
+
+struct NodeParam
+{
+  double comparison_length{};
+  double max_time_difference{};
+  DivergenceThreshold threshold{};
+};
+
+class TrajectoryComparatorNode : public rclcpp::Node
+{
+public:
+  explicit TrajectoryComparatorNode(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<Trajectory>::SharedPtr sub_reference_;
+  rclcpp::Subscription<Trajectory>::SharedPtr sub_candidate_;
+
+  void on_reference(const Trajectory::ConstSharedPtr msg);
+  void on_candidate(const Trajectory::ConstSharedPtr msg);
+
+  // Publisher
+  rclcpp::Publisher<MetricArrayMsg>::SharedPtr pub_metrics_;
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_divergence(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  Trajectory::ConstSharedPtr reference_;
+  std::optional<TrajectoryDivergence> divergence_;
+  std::vector<std::string> exceeded_metrics_;
+  size_t num_comparisons_{0};
+  size_t num_divergences_{0};
+
+  // Function
+  void publish_metrics(const builtin_interfaces::msg::Time & stamp);
+};
+}  // namespace autoware::trajectory_comparator
+
+#endif  // TRAJECTORY_COMPARATOR_NODE_HPP_
@@ -0,0 +1,133 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "trajectory_divergence.hpp"
+
+#include <autoware/motion_utils/trajectory/interpolation.hpp>
+#include <autoware/motion_utils/trajectory/trajectory.hpp>
+#include <autoware/universe_utils/geometry/geometry.hpp>
+#include <autoware/universe_utils/math/normalization.hpp>
+
+#include <tf2/utils.h>
+
+#include <algorithm>
+#include <cmath>
+
+namespace autoware::trajectory_comparator
+{
+namespace
+{
+std::optional<double> calc_stop_distance(const Trajectory & trajectory)
+{
+  const auto stop_index = autoware::motion_utils::searchZeroVelocityIndex(trajectory.points);
+  if (!stop_index) {
+    return std::nullopt;
+  }
+  return autoware::motion_utils::calcSignedArcLength(trajectory.points, 0, *stop_index);
+}
+}  // namespace
+
+std::optional<TrajectoryDivergence> calc_divergence(
+  const Trajectory & reference, const Trajectory & candidate, const double comparison_length)
+{
+  if (reference.points.size() < 2 || candidate.points.size() < 2) {
+    return std::nullopt;
+  }
+
+  TrajectoryDivergence divergence;
+  const auto & reference_front = reference.points.front().pose.position;
+  const double reference_length = autoware::motion_utils::calcArcLength(reference.points);
+
+  double sum_lateral_deviation = 0.0;
+  double arc_length = 0.0;
+  for (size_t i = 0; i < candidate.points.size(); ++i) {
+    const auto & point = candidate.points.at(i);
+    if (i > 0) {
+      arc_length += autoware::universe_utils::calcDistance2d(candidate.points.at(i - 1), point);
+    }
+    if (arc_length > comparison_length) {
+      break;
+    }
+
+    const double longitudinal = autoware::motion_utils::calcSignedArcLength(
+      reference.points, reference_front, point.pose.position);
+    if (longitudinal < 0.0 || reference_length < longitudinal) {
+      continue;
+    }
+
+    const double lateral_deviation =
+      std::abs(autoware::motion_utils::calcLateralOffset(reference.points, point.pose.position));
+    const auto interpolated = autoware::motion_utils::calcInterpolatedPoint(reference, point.pose);
+    const double yaw_deviation = std::abs(autoware::universe_utils::normalizeRadian(
+      tf2::getYaw(point.pose.orientation) - tf2::getYaw(interpolated.pose.orientation)));
+    const double velocity_difference =
+      std::abs(point.longitudinal_velocity_mps - interpolated.longitudinal_velocity_mps);
+
+    divergence.max_lateral_deviation =
+      std::max(divergence.max_lateral_deviation, lateral_deviation);
+    divergence.max_yaw_deviation = std::max(divergence.max_yaw_deviation, yaw_deviation);
+    divergence.max_velocity_difference =
+      std::max(divergence.max_velocity_difference, velocity_difference);
+    sum_lateral_deviation += lateral_deviation;
+    ++divergence.num_compared_points;
+  }
+
+  if (divergence.num_compared_points == 0) {
+    return std::nullopt;
+  }
+  divergence.mean_lateral_deviation =
+    sum_lateral_deviation / static_cast<double>(divergence.num_compared_points);
+
+  // a stop point beyond the comparison length is kept while the other one is within it, so that
+  // the stop points near the boundary are compared directly
+  auto reference_stop = calc_stop_distance(reference);
+  auto candidate_stop = calc_stop_distance(candidate);
+  const auto is_beyond = [&](const std::optional<double> & stop) {
+    return !stop || *stop > comparison_length;
+  };
+  if (is_beyond(reference_stop) && is_beyond(candidate_stop)) {
+    reference_stop.reset();
+    candidate_stop.reset();
+  }
+  divergence.reference_stop_distance = reference_stop;
+  divergence.candidate_stop_distance = candidate_stop;
+  return divergence;
+}
+
+std::vector<std::string> find_exceeded_metrics(
+  const TrajectoryDivergence & divergence, const DivergenceThreshold & threshold)
+{
+  std::vector<std::string> exceeded;
+  if (divergence.max_lateral_deviation > threshold.lateral_deviation) {
+    exceeded.emplace_back("lateral_deviation");
+  }
+  if (divergence.max_yaw_deviation > threshold.yaw_deviation) {
+    exceeded.emplace_back("yaw_deviation");
+  }
+  if (divergence.max_velocity_difference > threshold.velocity_difference) {
+    exceeded.emplace_back("velocity_difference");
+  }
+
+  // a stop point which exists in only one of the trajectories is also a divergence
+  const auto & reference_stop = divergence.reference_stop_distance;
+  const auto & candidate_stop = divergence.candidate_stop_distance;
+  if (
+    reference_stop.has_value() != candidate_stop.has_value() ||
+    (reference_stop && std::abs(*reference_stop - *candidate_stop) >
+                         threshold.stop_distance_difference)) {
+    exceeded.emplace_back("stop_distance_difference");
+  }
+  return exceeded;
+}
+}  // namespace autoware::trajectory_comparator
@@ -0,0 +1,63 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef TRAJECTORY_DIVERGENCE_HPP_
+#define TRAJECTORY_DIVERGENCE_HPP_
+
+#include <autoware_planning_msgs/msg/trajectory.hpp>
+
+#include <cstddef>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::trajectory_comparator
+{
+using autoware_planning_msgs::msg::Trajectory;
+
+struct TrajectoryDivergence
+{
+  double max_lateral_deviation{};
+  double mean_lateral_deviation{};
+  double max_yaw_deviation{};
+  double max_velocity_difference{};
+  // arc length from the first point to the first point of zero velocity, empty when neither
+  // trajectory stops within the comparison length
+  std::optional<double> reference_stop_distance{};
+  std::optional<double> candidate_stop_distance{};
+  std::size_t num_compared_points{};
+};
+
+struct DivergenceThreshold
+{
+  double lateral_deviation{};
+  double yaw_deviation{};
+  double velocity_difference{};
+  double stop_distance_difference{};
+};
+
+/**
+ * @brief compares the points of the candidate within comparison_length from its first point with
+ *        the reference, the points beyond the ends of the reference are not compared
+ * @return nullopt when either trajectory has less than two points or no point is compared
+ */
+std::optional<TrajectoryDivergence> calc_divergence(
+  const Trajectory & reference, const Trajectory & candidate, double comparison_length);
+
+// returns the names of the metrics which exceed the threshold
+std::vector<std::string> find_exceeded_metrics(
+  const TrajectoryDivergence & divergence, const DivergenceThreshold & threshold);
+}  // namespace autoware::trajectory_comparator
+
+#endif  // TRAJECTORY_DIVERGENCE_HPP_
@@ -0,0 +1,155 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "trajectory_divergence.hpp"
+
+#include <autoware/universe_utils/geometry/geometry.hpp>
+
+#include <gtest/gtest.h>
+
+#include <algorithm>
+#include <string>
+#include <vector>
+
+using autoware::trajectory_comparator::calc_divergence;
+using autoware::trajectory_comparator::DivergenceThreshold;
+using autoware::trajectory_comparator::find_exceeded_metrics;
+using autoware::trajectory_comparator::Trajectory;
+using autoware_planning_msgs::msg::TrajectoryPoint;
+
+namespace
+{
+// straight trajectory along the x axis with 1 m interval
+Trajectory create_trajectory(
+  const size_t num_points, const double y, const double velocity, const double x_offset = 0.0)
+{
+  Trajectory trajectory;
+  for (size_t i = 0; i < num_points; ++i) {
+    TrajectoryPoint point;
+    point.pose.position = autoware::universe_utils::createPoint(
+      x_offset + static_cast<double>(i), y, 0.0);
+    point.pose.orientation = autoware::universe_utils::createQuaternionFromYaw(0.0);
+    point.longitudinal_velocity_mps = static_cast<float>(velocity);
+    trajectory.points.push_back(point);
+  }
+  return trajectory;
+}
+
+DivergenceThreshold create_threshold()
+{
+  return {0.5, 0.2, 1.0, 2.0};
+}
+}  // namespace
+
+TEST(TrajectoryDivergence, TooShort)
+{
+  const auto reference = create_trajectory(10, 0.0, 5.0);
+  EXPECT_FALSE(calc_divergence(reference, create_trajectory(1, 0.0, 5.0), 100.0));
+  EXPECT_FALSE(calc_divergence(create_trajectory(1, 0.0, 5.0), reference, 100.0));
+}
+
+TEST(TrajectoryDivergence, Identical)
+{
+  const auto trajectory = create_trajectory(10, 0.0, 5.0);
+  const auto divergence = calc_divergence(trajectory, trajectory, 100.0);
+  ASSERT_TRUE(divergence);
+  EXPECT_EQ(divergence->num_compared_points, 10u);
+  EXPECT_NEAR(divergence->max_lateral_deviation, 0.0, 1e-6);
+  EXPECT_NEAR(divergence->max_velocity_difference, 0.0, 1e-6);
+  EXPECT_TRUE(find_exceeded_metrics(*divergence, create_threshold()).empty());
+}
+
+TEST(TrajectoryDivergence, LateralAndVelocity)
+{
+  const auto reference = create_trajectory(10, 0.0, 5.0);
+  const auto candidate = create_trajectory(10, 1.0, 3.0);
+  const auto divergence = calc_divergence(reference, candidate, 100.0);
+  ASSERT_TRUE(divergence);
+  EXPECT_NEAR(divergence->max_lateral_deviation, 1.0, 1e-6);
+  EXPECT_NEAR(divergence->mean_lateral_deviation, 1.0, 1e-6);
+  EXPECT_NEAR(divergence->max_velocity_difference, 2.0, 1e-6);
+
+  const auto exceeded = find_exceeded_metrics(*divergence, create_threshold());
+  EXPECT_EQ(exceeded, (std::vector<std::string>{"lateral_deviation", "velocity_difference"}));
+}
+
+TEST(TrajectoryDivergence, ComparisonRange)
+{
+  // the points beyond the reference and beyond the comparison length are ignored
+  const auto reference = create_trajectory(5, 0.0, 5.0);
+  const auto candidate = create_trajectory(20, 0.0, 5.0, 2.0);
+  const auto divergence = calc_divergence(reference, candidate, 10.0);
+  ASSERT_TRUE(divergence);
+  EXPECT_EQ(divergence->num_compared_points, 3u);
+
+  // no point overlaps with the reference
+  EXPECT_FALSE(calc_divergence(reference, create_trajectory(5, 0.0, 5.0, 10.0), 10.0));
+}
+
+TEST(TrajectoryDivergence, StopPoint)
+{
+  auto reference = create_trajectory(10, 0.0, 5.0);
+  auto candidate = reference;
+  reference.points.at(5).longitudinal_velocity_mps = 0.0;
+
+  // the stop point exists only in the reference
+  auto divergence = calc_divergence(reference, candidate, 100.0);
+  ASSERT_TRUE(divergence);
+  EXPECT_NEAR(*divergence->reference_stop_distance, 5.0, 1e-6);
+  EXPECT_FALSE(divergence->candidate_stop_distance);
+  auto exceeded = find_exceeded_metrics(*divergence, create_threshold());
+  EXPECT_NE(
+    std::find(exceeded.begin(), exceeded.end(), "stop_distance_difference"), exceeded.end());
+
+  // within the threshold
+  candidate.points.at(6).longitudinal_velocity_mps = 0.0;
+  divergence = calc_divergence(reference, candidate, 100.0);
+  ASSERT_TRUE(divergence);
+  exceeded = find_exceeded_metrics(*divergence, create_threshold());
+  EXPECT_EQ(
+    std::find(exceeded.begin(), exceeded.end(), "stop_distance_difference"), exceeded.end());
+}
+
+TEST(TrajectoryDivergence, StopPointNearComparisonLength)
+{
+  auto reference = create_trajectory(60, 0.0, 5.0);
+  auto candidate = reference;
+  reference.points.at(49).longitudinal_velocity_mps = 0.0;
+  candidate.points.at(50).longitudinal_velocity_mps = 0.0;
+
+  // the stop points across the boundary are compared directly
+  auto divergence = calc_divergence(reference, candidate, 49.5);
+  ASSERT_TRUE(divergence);
+  ASSERT_TRUE(divergence->reference_stop_distance);
+  ASSERT_TRUE(divergence->candidate_stop_distance);
+  EXPECT_NEAR(*divergence->candidate_stop_distance, 50.0, 1e-6);
+  auto exceeded = find_exceeded_metrics(*divergence, create_threshold());
+  EXPECT_EQ(
+    std::find(exceeded.begin(), exceeded.end(), "stop_distance_difference"), exceeded.end());
+
+  // both stop points beyond the comparison length are ignored
+  divergence = calc_divergence(reference, candidate, 40.0);
+  ASSERT_TRUE(divergence);
+  EXPECT_FALSE(divergence->reference_stop_distance);
+  EXPECT_FALSE(divergence->candidate_stop_distance);
+
+  // a stop point within the comparison length without the counterpart is still a divergence
+  candidate.points.at(50).longitudinal_velocity_mps = 5.0;
+  divergence = calc_divergence(reference, candidate, 49.5);
+  ASSERT_TRUE(divergence);
+  EXPECT_FALSE(divergence->candidate_stop_distance);
+  exceeded = find_exceeded_metrics(*divergence, create_threshold());
+  EXPECT_NE(
+    std::find(exceeded.begin(), exceeded.end(), "stop_distance_difference"), exceeded.end());
+}
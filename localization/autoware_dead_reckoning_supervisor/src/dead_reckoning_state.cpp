@@ -0,0 +1,43 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "dead_reckoning_state.hpp"
+
+#include <cmath>
+
+namespace autoware::dead_reckoning_supervisor
+{
+DeadReckoningState::DeadReckoningState(const DeadReckoningParam & param) : param_(param)
+{
+}
+
+Mode DeadReckoningState::update(const bool has_pose_source, const double speed, const double dt)
+{
+  if (has_pose_source) {
+    mode_ = Mode::NORMAL;
+    elapsed_time_ = 0.0;
+    travelled_distance_ = 0.0;
+    return mode_;
+  }
+
+  // the time and the distance are counted from the last correction by a pose source
+  elapsed_time_ += dt;
+  travelled_distance_ += std::abs(speed) * dt;
+
+  const bool is_expired =
+    param_.max_duration < elapsed_time_ || param_.max_distance < travelled_distance_;
+  mode_ = is_expired ? Mode::EXPIRED : Mode::DEAD_RECKONING;
+  return mode_;
+}
+}  // namespace autoware::dead_reckoning_supervisor
@@ -0,0 +1,57 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef DEAD_RECKONING_STATE_HPP_
+#define DEAD_RECKONING_STATE_HPP_
+
+namespace autoware::dead_reckoning_supervisor
+{
+enum class Mode {
+  // NDT or GNSS corrects the EKF
+  NORMAL,
+  // the EKF predicts with the twist only, within the limits
+  DEAD_RECKONING,
+  // the dead reckoning has exceeded the limits
+  EXPIRED,
+};
+
+struct DeadReckoningParam
+{
+  double max_duration{};
+  double max_distance{};
+};
+
+class DeadReckoningState
+{
+public:
+  explicit DeadReckoningState(const DeadReckoningParam & param);
+
+  // Advances the state by dt with the availability of the pose sources and the ego speed.
+  // The dead reckoning starts when no pose source is available, and it is reset when any of them
+  // is available again.
+  Mode update(bool has_pose_source, double speed, double dt);
+
+  Mode mode() const { return mode_; }
+  double elapsed_time() const { return elapsed_time_; }
+  double travelled_distance() const { return travelled_distance_; }
+
+private:
+  DeadReckoningParam param_;
+  Mode mode_{Mode::NORMAL};
+  double elapsed_time_{};
+  double travelled_distance_{};
+};
+}  // namespace autoware::dead_reckoning_supervisor
+
+#endif  // DEAD_RECKONING_STATE_HPP_
@@ -0,0 +1,133 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "dead_reckoning_supervisor_node.hpp"
+
+#include <algorithm>
+#include <cmath>
+
+namespace autoware::dead_reckoning_supervisor
+{
+DeadReckoningSupervisor::DeadReckoningSupervisor(const rclcpp::NodeOptions & node_options)
+: Node("dead_reckoning_supervisor", node_options), updater_(this), state_(DeadReckoningParam{})
+{
+  // Parameter
+  node_param_.update_rate = declare_parameter<double>("update_rate");
+  node_param_.ndt_timeout = declare_parameter<double>("ndt_timeout");
+  node_param_.use_gnss_pose = declare_parameter<bool>("use_gnss_pose");
+  node_param_.gnss_timeout = declare_parameter<double>("gnss_timeout");
+  node_param_.gnss_max_position_stddev = declare_parameter<double>("gnss_max_position_stddev");
+  node_param_.twist_timeout = declare_parameter<double>("twist_timeout");
+  node_param_.dead_reckoning.max_duration = declare_parameter<double>("max_duration");
+  node_param_.dead_reckoning.max_distance = declare_parameter<double>("max_distance");
+  state_ = DeadReckoningState(node_param_.dead_reckoning);
+
+  // Subscriber
+  // ndt_scan_matcher publishes the pose only when the matching has converged
+  sub_ndt_pose_ = create_subscription<PoseWithCovarianceStamped>(
+    "input/ndt_pose_with_covariance", rclcpp::QoS{1},
+    [this](const PoseWithCovarianceStamped::ConstSharedPtr) { ndt_received_time_ = now(); });
+  // the GNSS pose corrects the EKF only when it is fused, which is not the default of Autoware
+  if (node_param_.use_gnss_pose) {
+    sub_gnss_pose_ = create_subscription<PoseWithCovarianceStamped>(
+      "input/gnss_pose_with_covariance", rclcpp::QoS{1},
+      std::bind(&DeadReckoningSupervisor::on_gnss_pose, this, std::placeholders::_1));
+  }
+  sub_twist_ = create_subscription<TwistWithCovarianceStamped>(
+    "input/twist_with_covariance", rclcpp::QoS{1},
+    [this](const TwistWithCovarianceStamped::ConstSharedPtr msg) {
+      speed_ = msg->twist.twist.linear.x;
+      twist_received_time_ = now();
+    });
+
+  // Publisher
+  pub_is_dead_reckoning_ =
+    create_publisher<BoolStamped>("output/is_dead_reckoning", rclcpp::QoS{1});
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.update_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&DeadReckoningSupervisor::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("dead_reckoning_supervisor");
+  updater_.add("dead_reckoning", this, &DeadReckoningSupervisor::check_dead_reckoning);
+}
+
+void DeadReckoningSupervisor::on_gnss_pose(const PoseWithCovarianceStamped::ConstSharedPtr msg)
+{
+  // a float or single-point fix is too inaccurate to correct the EKF
+  const auto & covariance = msg->pose.covariance;
+  const double position_stddev = std::sqrt(std::max(covariance[0 * 6 + 0], covariance[1 * 6 + 1]));
+  if (position_stddev <= node_param_.gnss_max_position_stddev) {
+    gnss_received_time_ = now();
+  }
+}
+
+void DeadReckoningSupervisor::on_timer()
+{
+  const auto current_time = now();
+  const double dt = last_update_time_ ? (current_time - *last_update_time_).seconds() : 0.0;
+  last_update_time_ = current_time;
+
+  is_ndt_available_ = is_fresh(ndt_received_time_, current_time, node_param_.ndt_timeout);
+  is_gnss_available_ = is_fresh(gnss_received_time_, current_time, node_param_.gnss_timeout);
+  is_twist_available_ = is_fresh(twist_received_time_, current_time, node_param_.twist_timeout);
+
+  state_.update(is_ndt_available_ || is_gnss_available_, is_twist_available_ ? speed_ : 0.0, dt);
+
+  BoolStamped is_dead_reckoning;
+  is_dead_reckoning.stamp = current_time;
+  is_dead_reckoning.data = state_.mode() != Mode::NORMAL;
+  pub_is_dead_reckoning_->publish(is_dead_reckoning);
+}
+
+void DeadReckoningSupervisor::check_dead_reckoning(
+  diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  stat.add("is_ndt_available", is_ndt_available_);
+  if (node_param_.use_gnss_pose) {
+    stat.add("is_gnss_available", is_gnss_available_);
+  }
+  stat.addf("elapsed_time", "%.2f [s]", state_.elapsed_time());
+  stat.addf("travelled_distance", "%.2f [m]", state_.travelled_distance());
+
+  if (state_.mode() == Mode::NORMAL) {
+    stat.summary(DiagnosticStatus::OK, "OK");
+    return;
+  }
+  // the EKF cannot predict the pose without the twist
+  if (!is_twist_available_) {
+    stat.summary(DiagnosticStatus::ERROR, "dead reckoning without the twist");
+    return;
+  }
+  if (state_.mode() == Mode::EXPIRED) {
+    stat.summary(DiagnosticStatus::ERROR, "dead reckoning has exceeded the limits");
+    return;
+  }
+  stat.summary(DiagnosticStatus::WARN, "dead reckoning");
+}
+
+bool DeadReckoningSupervisor::is_fresh(
+  const std::optional<rclcpp::Time> & received_time, const rclcpp::Time & current_time,
+  const double timeout)
+{
+  return received_time && (current_time - *received_time).seconds() <= timeout;
+}
+}  // namespace autoware::dead_reckoning_supervisor
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::dead_reckoning_supervisor::DeadReckoningSupervisor)
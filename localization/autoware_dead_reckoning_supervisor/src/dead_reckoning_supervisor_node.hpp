@@ -0,0 +1,93 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef DEAD_RECKONING_SUPERVISOR_NODE_HPP_
+#define DEAD_RECKONING_SUPERVISOR_NODE_HPP_
+
+#include "dead_reckoning_state.hpp"
+
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_internal_debug_msgs/msg/bool_stamped.hpp>
+#include <geometry_msgs/msg/pose_with_covariance_stamped.hpp>
+#include <geometry_msgs/msg/twist_with_covariance_stamped.hpp>
+
+#include <optional>
+
+namespace autoware::dead_reckoning_supervisor
+{
+using autoware_internal_debug_msgs::msg::BoolStamped;
+using geometry_msgs::msg::PoseWithCovarianceStamped;
+using geometry_msgs::msg::TwistWithCovarianceStamped;
+
+struct NodeParam
+{
+  double update_rate{};
+  double ndt_timeout{};
+  bool use_gnss_pose{};
+  double gnss_timeout{};
+  double gnss_max_position_stddev{};
+  double twist_timeout{};
+  DeadReckoningParam dead_reckoning{};
+};
+
+class DeadReckoningSupervisor : public rclcpp::Node
+{
+public:
+  explicit DeadReckoningSupervisor(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<PoseWithCovarianceStamped>::SharedPtr sub_ndt_pose_;
+  rclcpp::Subscription<PoseWithCovarianceStamped>::SharedPtr sub_gnss_pose_;
+  rclcpp::Subscription<TwistWithCovarianceStamped>::SharedPtr sub_twist_;
+
+  void on_gnss_pose(const PoseWithCovarianceStamped::ConstSharedPtr msg);
+
+  // Publisher
+  rclcpp::Publisher<BoolStamped>::SharedPtr pub_is_dead_reckoning_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_dead_reckoning(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  DeadReckoningState state_;
+  std::optional<rclcpp::Time> ndt_received_time_;
+  std::optional<rclcpp::Time> gnss_received_time_;
+  std::optional<rclcpp::Time> twist_received_time_;
+  std::optional<rclcpp::Time> last_update_time_;
+  double speed_{};
+  bool is_ndt_available_{};
+  bool is_gnss_available_{};
+  bool is_twist_available_{};
+
+  // Function
+  static bool is_fresh(
+    const std::optional<rclcpp::Time> & received_time, const rclcpp::Time & current_time,
+    double timeout);
+};
+}  // namespace autoware::dead_reckoning_supervisor
+
+#endif  // DEAD_RECKONING_SUPERVISOR_NODE_HPP_
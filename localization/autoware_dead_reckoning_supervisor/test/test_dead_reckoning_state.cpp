@@ -0,0 +1,69 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "dead_reckoning_state.hpp"
+
+#include <gtest/gtest.h>
+
+using autoware::dead_reckoning_supervisor::DeadReckoningParam;
+using autoware::dead_reckoning_supervisor::DeadReckoningState;
+using autoware::dead_reckoning_supervisor::Mode;
+
+namespace
+{
+DeadReckoningParam create_param()
+{
+  DeadReckoningParam param;
+  param.max_duration = 10.0;
+  param.max_distance = 50.0;
+  return param;
+}
+}  // namespace
+
+TEST(DeadReckoningState, StartAndRecover)
+{
+  DeadReckoningState state(create_param());
+  EXPECT_EQ(state.update(true, 5.0, 0.1), Mode::NORMAL);
+
+  EXPECT_EQ(state.update(false, 2.0, 1.0), Mode::DEAD_RECKONING);
+  EXPECT_EQ(state.update(false, -2.0, 1.0), Mode::DEAD_RECKONING);
+  EXPECT_DOUBLE_EQ(state.elapsed_time(), 2.0);
+  EXPECT_DOUBLE_EQ(state.travelled_distance(), 4.0);
+
+  EXPECT_EQ(state.update(true, 2.0, 1.0), Mode::NORMAL);
+  EXPECT_DOUBLE_EQ(state.elapsed_time(), 0.0);
+  EXPECT_DOUBLE_EQ(state.travelled_distance(), 0.0);
+}
+
+TEST(DeadReckoningState, ExpireByDuration)
+{
+  DeadReckoningState state(create_param());
+  for (int i = 0; i < 10; ++i) {
+    EXPECT_EQ(state.update(false, 0.0, 1.0), Mode::DEAD_RECKONING);
+  }
+  EXPECT_EQ(state.update(false, 0.0, 1.0), Mode::EXPIRED);
+
+  // a pose source resets the limits
+  EXPECT_EQ(state.update(true, 0.0, 1.0), Mode::NORMAL);
+  EXPECT_EQ(state.update(false, 0.0, 1.0), Mode::DEAD_RECKONING);
+}
+
+TEST(DeadReckoningState, ExpireByDistance)
+{
+  DeadReckoningState state(create_param());
+  EXPECT_EQ(state.update(false, 20.0, 2.0), Mode::DEAD_RECKONING);
+  EXPECT_EQ(state.update(false, 20.0, 1.0), Mode::EXPIRED);
+  // stays expired even when the ego stops
+  EXPECT_EQ(state.update(false, 0.0, 1.0), Mode::EXPIRED);
+}
@@ -29,6 +29,9 @@ diagnostic_msgs::msg::DiagnosticStatus check_set_initialpose(const bool is_set_i
 diagnostic_msgs::msg::DiagnosticStatus check_measurement_updated(
   const std::string & measurement_type, const size_t no_update_count,
   const size_t no_update_count_threshold_warn, const size_t no_update_count_threshold_error);
+diagnostic_msgs::msg::DiagnosticStatus check_pose_measurement_updated(
+  const size_t no_update_count, const size_t no_update_count_threshold_warn,
+  const size_t no_update_count_threshold_error, const bool is_dead_reckoning);
 diagnostic_msgs::msg::DiagnosticStatus check_measurement_queue_size(
   const std::string & measurement_type, const size_t queue_size);
 diagnostic_msgs::msg::DiagnosticStatus check_measurement_delay_gate(
@@ -25,6 +25,7 @@
 #include <autoware/universe_utils/system/stop_watch.hpp>
 #include <rclcpp/rclcpp.hpp>
 
+#include <autoware_internal_debug_msgs/msg/bool_stamped.hpp>
 #include <autoware_internal_debug_msgs/msg/float64_multi_array_stamped.hpp>
 #include <autoware_internal_debug_msgs/msg/float64_stamped.hpp>
 #include <diagnostic_msgs/msg/diagnostic_array.hpp>
@@ -95,6 +96,12 @@ private:
   //!< @brief measurement twist with covariance subscriber
   rclcpp::Subscription<geometry_msgs::msg::TwistWithCovarianceStamped>::SharedPtr
     sub_twist_with_cov_;
+  //!< @brief measurement heading with covariance subscriber
+  rclcpp::Subscription<geometry_msgs::msg::PoseWithCovarianceStamped>::SharedPtr
+    sub_heading_with_cov_;
+  //!< @brief dead reckoning state subscriber
+  rclcpp::Subscription<autoware_internal_debug_msgs::msg::BoolStamped>::SharedPtr
+    sub_is_dead_reckoning_;
   //!< @brief time for ekf calculation callback
   rclcpp::TimerBase::SharedPtr timer_control_;
   //!< @brief last predict time
@@ -121,12 +128,16 @@ private:
 
   bool is_activated_;
   bool is_set_initialpose_;
+  //!< @brief whether dead_reckoning_supervisor allows the dead reckoning
+  bool is_dead_reckoning_{false};
 
   EKFDiagnosticInfo pose_diag_info_;
   EKFDiagnosticInfo twist_diag_info_;
+  EKFDiagnosticInfo heading_diag_info_;
 
   AgedObjectQueue<geometry_msgs::msg::PoseWithCovarianceStamped::SharedPtr> pose_queue_;
   AgedObjectQueue<geometry_msgs::msg::TwistWithCovarianceStamped::SharedPtr> twist_queue_;
+  AgedObjectQueue<geometry_msgs::msg::PoseWithCovarianceStamped::SharedPtr> heading_queue_;
 
   /**
    * @brief computes update & prediction of EKF for each ekf_dt_[s] time
@@ -144,6 +155,12 @@ private:
   void callback_twist_with_covariance(
     geometry_msgs::msg::TwistWithCovarianceStamped::SharedPtr msg);
 
+  /**
+   * @brief set heading with covariance measurement, of which only the yaw is used
+   */
+  void callback_heading_with_covariance(
+    geometry_msgs::msg::PoseWithCovarianceStamped::SharedPtr msg);
+
   /**
    * @brief set initial_pose to current EKF pose
    */
@@ -125,6 +125,9 @@ public:
   bool measurement_update_twist(
     const TwistWithCovariance & twist, const rclcpp::Time & t_curr,
     EKFDiagnosticInfo & twist_diag_info);
+  bool measurement_update_heading(
+    const PoseWithCovariance & heading, const rclcpp::Time & t_curr,
+    EKFDiagnosticInfo & heading_diag_info);
   geometry_msgs::msg::PoseWithCovarianceStamped compensate_rph_with_delay(
     const PoseWithCovariance & pose, tf2::Vector3 last_angular_velocity, const double delay_time);
 
@@ -42,6 +42,11 @@ public:
       node->declare_parameter<double>("twist_measurement.twist_additional_delay")),
     twist_gate_dist(node->declare_parameter<double>("twist_measurement.twist_gate_dist")),
     twist_smoothing_steps(node->declare_parameter<int>("twist_measurement.twist_smoothing_steps")),
+    enable_heading_measurement(
+      node->declare_parameter<bool>("heading_measurement.enable_heading_measurement")),
+    heading_additional_delay(
+      node->declare_parameter<double>("heading_measurement.heading_additional_delay")),
+    heading_gate_dist(node->declare_parameter<double>("heading_measurement.heading_gate_dist")),
     proc_stddev_vx_c(node->declare_parameter<double>("process_noise.proc_stddev_vx_c")),
     proc_stddev_wz_c(node->declare_parameter<double>("process_noise.proc_stddev_wz_c")),
     proc_stddev_yaw_c(node->declare_parameter<double>("process_noise.proc_stddev_yaw_c")),
@@ -59,6 +64,10 @@ public:
       node->declare_parameter<int>("diagnostics.twist_no_update_count_threshold_warn")),
     twist_no_update_count_threshold_error(
       node->declare_parameter<int>("diagnostics.twist_no_update_count_threshold_error")),
+    heading_no_update_count_threshold_warn(
+      node->declare_parameter<int>("diagnostics.heading_no_update_count_threshold_warn")),
+    heading_no_update_count_threshold_error(
+      node->declare_parameter<int>("diagnostics.heading_no_update_count_threshold_error")),
     ellipse_scale(node->declare_parameter<double>("diagnostics.ellipse_scale")),
     error_ellipse_size(node->declare_parameter<double>("diagnostics.error_ellipse_size")),
     warn_ellipse_size(node->declare_parameter<double>("diagnostics.warn_ellipse_size")),
@@ -84,6 +93,9 @@ public:
   const double twist_additional_delay;
   const double twist_gate_dist;
   const size_t twist_smoothing_steps;
+  const bool enable_heading_measurement;
+  const double heading_additional_delay;
+  const double heading_gate_dist;
   const double proc_stddev_vx_c;   //!< @brief  vx process noise
   const double proc_stddev_wz_c;   //!< @brief  wz process noise
   const double proc_stddev_yaw_c;  //!< @brief  yaw process noise
@@ -94,6 +106,8 @@ public:
   const size_t pose_no_update_count_threshold_error;
   const size_t twist_no_update_count_threshold_warn;
   const size_t twist_no_update_count_threshold_error;
+  const size_t heading_no_update_count_threshold_warn;
+  const size_t heading_no_update_count_threshold_error;
   double ellipse_scale;
   double error_ellipse_size;
   double warn_ellipse_size;
@@ -22,10 +22,13 @@ namespace autoware::ekf_localizer
 
 Eigen::Matrix<double, 3, 6> pose_measurement_matrix();
 Eigen::Matrix<double, 2, 6> twist_measurement_matrix();
+Eigen::Matrix<double, 1, 6> heading_measurement_matrix();
 Eigen::Matrix3d pose_measurement_covariance(
   const std::array<double, 36ul> & covariance, const size_t smoothing_step);
 Eigen::Matrix2d twist_measurement_covariance(
   const std::array<double, 36ul> & covariance, const size_t smoothing_step);
+Eigen::Matrix<double, 1, 1> heading_measurement_covariance(
+  const std::array<double, 36ul> & covariance, const size_t smoothing_step);
 
 }  // namespace autoware::ekf_localizer
 
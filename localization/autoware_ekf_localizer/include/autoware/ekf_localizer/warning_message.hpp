@@ -24,8 +24,11 @@ std::string pose_delay_step_warning_message(
   const double delay_time, const double delay_time_threshold);
 std::string twist_delay_step_warning_message(
   const double delay_time, const double delay_time_threshold);
+std::string heading_delay_step_warning_message(
+  const double delay_time, const double delay_time_threshold);
 std::string pose_delay_time_warning_message(const double delay_time);
 std::string twist_delay_time_warning_message(const double delay_time);
+std::string heading_delay_time_warning_message(const double delay_time);
 std::string mahalanobis_warning_message(const double distance, const double max_distance);
 
 }  // namespace autoware::ekf_localizer
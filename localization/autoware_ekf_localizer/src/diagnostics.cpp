@@ -91,6 +91,27 @@ diagnostic_msgs::msg::DiagnosticStatus check_measurement_updated(
   return stat;
 }
 
+diagnostic_msgs::msg::DiagnosticStatus check_pose_measurement_updated(
+  const size_t no_update_count, const size_t no_update_count_threshold_warn,
+  const size_t no_update_count_threshold_error, const bool is_dead_reckoning)
+{
+  diagnostic_msgs::msg::DiagnosticStatus stat = check_measurement_updated(
+    "pose", no_update_count, no_update_count_threshold_warn, no_update_count_threshold_error);
+
+  diagnostic_msgs::msg::KeyValue key_value;
+  key_value.key = "is_dead_reckoning";
+  key_value.value = is_dead_reckoning ? "True" : "False";
+  stat.values.push_back(key_value);
+
+  // the dead reckoning is limited by dead_reckoning_supervisor, which reports ERROR by itself
+  if (is_dead_reckoning && stat.level == diagnostic_msgs::msg::DiagnosticStatus::ERROR) {
+    stat.level = diagnostic_msgs::msg::DiagnosticStatus::WARN;
+    stat.message = "[WARN]pose is not updated in the dead reckoning";
+  }
+
+  return stat;
+}
+
 diagnostic_msgs::msg::DiagnosticStatus check_measurement_queue_size(
   const std::string & measurement_type, const size_t queue_size)
 {
@@ -53,7 +53,8 @@ EKFLocalizer::EKFLocalizer(const rclcpp::NodeOptions & node_options)
   params_(this),
   ekf_dt_(params_.ekf_dt),
   pose_queue_(params_.pose_smoothing_steps),
-  twist_queue_(params_.twist_smoothing_steps)
+  twist_queue_(params_.twist_smoothing_steps),
+  heading_queue_(1)
 {
   is_activated_ = false;
   is_set_initialpose_ = false;
@@ -86,6 +87,16 @@ EKFLocalizer::EKFLocalizer(const rclcpp::NodeOptions & node_options)
   sub_twist_with_cov_ = create_subscription<geometry_msgs::msg::TwistWithCovarianceStamped>(
     "in_twist_with_covariance", 1,
     std::bind(&EKFLocalizer::callback_twist_with_covariance, this, _1));
+  if (params_.enable_heading_measurement) {
+    sub_heading_with_cov_ = create_subscription<geometry_msgs::msg::PoseWithCovarianceStamped>(
+      "in_heading_with_covariance", 1,
+      std::bind(&EKFLocalizer::callback_heading_with_covariance, this, _1));
+  }
+  sub_is_dead_reckoning_ = create_subscription<autoware_internal_debug_msgs::msg::BoolStamped>(
+    "in_is_dead_reckoning", 1,
+    [this](const autoware_internal_debug_msgs::msg::BoolStamped::ConstSharedPtr msg) {
+      is_dead_reckoning_ = msg->data;
+    });
   service_trigger_node_ = create_service<std_srvs::srv::SetBool>(
     "trigger_node_srv",
     std::bind(
@@ -223,6 +234,37 @@ void EKFLocalizer::timer_callback()
   }
   twist_diag_info_.no_update_count = twist_is_updated ? 0 : (twist_diag_info_.no_update_count + 1);
 
+  /* heading measurement update */
+  heading_diag_info_.queue_size = heading_queue_.size();
+  heading_diag_info_.is_passed_delay_gate = true;
+  heading_diag_info_.delay_time = 0.0;
+  heading_diag_info_.delay_time_threshold = 0.0;
+  heading_diag_info_.is_passed_mahalanobis_gate = true;
+  heading_diag_info_.mahalanobis_distance = 0.0;
+
+  bool heading_is_updated = false;
+
+  if (!heading_queue_.empty()) {
+    DEBUG_INFO(get_logger(), "------------------------- start Heading -------------------------");
+    stop_watch_.tic();
+
+    // save the initial size because the queue size can change in the loop
+    const size_t n = heading_queue_.size();
+    for (size_t i = 0; i < n; ++i) {
+      const auto heading = heading_queue_.pop_increment_age();
+      bool is_updated =
+        ekf_module_->measurement_update_heading(*heading, current_time, heading_diag_info_);
+      if (is_updated) {
+        heading_is_updated = true;
+      }
+    }
+    DEBUG_INFO(
+      get_logger(), "[EKF] measurement_update_heading calc time = %f [ms]", stop_watch_.toc());
+    DEBUG_INFO(get_logger(), "------------------------- end Heading -------------------------\n");
+  }
+  heading_diag_info_.no_update_count =
+    heading_is_updated ? 0 : (heading_diag_info_.no_update_count + 1);
+
   const geometry_msgs::msg::PoseStamped current_ekf_pose =
     ekf_module_->get_current_pose(current_time, false);
   const geometry_msgs::msg::PoseStamped current_biased_ekf_pose =
@@ -309,6 +351,21 @@ void EKFLocalizer::callback_twist_with_covariance(
   publish_callback_return_diagnostics("twist", msg->header.stamp);
 }
 
+/*
+ * callback_heading_with_covariance
+ */
+void EKFLocalizer::callback_heading_with_covariance(
+  geometry_msgs::msg::PoseWithCovarianceStamped::SharedPtr msg)
+{
+  if (!is_activated_ && !is_set_initialpose_) {
+    return;
+  }
+
+  heading_queue_.push(msg);
+
+  publish_callback_return_diagnostics("heading", msg->header.stamp);
+}
+
 /*
  * publish_estimate_result
  */
@@ -374,9 +431,9 @@ void EKFLocalizer::publish_diagnostics(
   diag_status_array.push_back(check_set_initialpose(is_set_initialpose_));
 
   if (is_activated_ && is_set_initialpose_) {
-    diag_status_array.push_back(check_measurement_updated(
-      "pose", pose_diag_info_.no_update_count, params_.pose_no_update_count_threshold_warn,
-      params_.pose_no_update_count_threshold_error));
+    diag_status_array.push_back(check_pose_measurement_updated(
+      pose_diag_info_.no_update_count, params_.pose_no_update_count_threshold_warn,
+      params_.pose_no_update_count_threshold_error, is_dead_reckoning_));
     diag_status_array.push_back(check_measurement_queue_size("pose", pose_diag_info_.queue_size));
     diag_status_array.push_back(check_measurement_delay_gate(
       "pose", pose_diag_info_.is_passed_delay_gate, pose_diag_info_.delay_time,
@@ -396,6 +453,21 @@ void EKFLocalizer::publish_diagnostics(
       "twist", twist_diag_info_.is_passed_mahalanobis_gate, twist_diag_info_.mahalanobis_distance,
       params_.twist_gate_dist));
 
+    if (params_.enable_heading_measurement) {
+      diag_status_array.push_back(check_measurement_updated(
+        "heading", heading_diag_info_.no_update_count,
+        params_.heading_no_update_count_threshold_warn,
+        params_.heading_no_update_count_threshold_error));
+      diag_status_array.push_back(
+        check_measurement_queue_size("heading", heading_diag_info_.queue_size));
+      diag_status_array.push_back(check_measurement_delay_gate(
+        "heading", heading_diag_info_.is_passed_delay_gate, heading_diag_info_.delay_time,
+        heading_diag_info_.delay_time_threshold));
+      diag_status_array.push_back(check_measurement_mahalanobis_gate(
+        "heading", heading_diag_info_.is_passed_mahalanobis_gate,
+        heading_diag_info_.mahalanobis_distance, params_.heading_gate_dist));
+    }
+
     geometry_msgs::msg::PoseWithCovariance pose_cov;
     pose_cov.pose = current_ekf_pose.pose;
     pose_cov.covariance = ekf_module_->get_current_pose_covariance();
@@ -449,6 +521,7 @@ void EKFLocalizer::service_trigger_node(
   if (req->data) {
     pose_queue_.clear();
     twist_queue_.clear();
+    heading_queue_.clear();
     is_activated_ = true;
   } else {
     is_activated_ = false;
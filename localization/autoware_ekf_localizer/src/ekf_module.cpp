@@ -322,6 +322,92 @@ bool EKFModule::measurement_update_pose(
   return true;
 }
 
+bool EKFModule::measurement_update_heading(
+  const PoseWithCovariance & heading, const rclcpp::Time & t_curr,
+  EKFDiagnosticInfo & heading_diag_info)
+{
+  if (heading.header.frame_id != params_.pose_frame_id) {
+    warning_->warn_throttle(
+      fmt::format(
+        "heading frame_id is %s, but pose_frame is set as %s. They must be same.",
+        heading.header.frame_id.c_str(), params_.pose_frame_id.c_str()),
+      2000);
+  }
+  const Eigen::MatrixXd x_curr = kalman_filter_.getLatestX();
+  DEBUG_PRINT_MAT(x_curr.transpose());
+
+  constexpr int dim_y = 1;  // yaw
+
+  /* Calculate delay step */
+  double delay_time = (t_curr - heading.header.stamp).seconds() + params_.heading_additional_delay;
+  if (delay_time < 0.0) {
+    warning_->warn_throttle(heading_delay_time_warning_message(delay_time), 1000);
+  }
+  delay_time = std::max(delay_time, 0.0);
+
+  const size_t delay_step = find_closest_delay_time_index(delay_time);
+
+  heading_diag_info.delay_time = std::max(delay_time, heading_diag_info.delay_time);
+  heading_diag_info.delay_time_threshold = accumulated_delay_times_.back();
+  if (delay_step >= params_.extend_state_step) {
+    heading_diag_info.is_passed_delay_gate = false;
+    warning_->warn_throttle(
+      heading_delay_step_warning_message(
+        heading_diag_info.delay_time, heading_diag_info.delay_time_threshold),
+      2000);
+    return false;
+  }
+
+  /* The yaw is offset in the same way as the pose measurement. */
+  double yaw = tf2::getYaw(heading.pose.pose.orientation);
+  const double ekf_yaw = kalman_filter_.getXelement(delay_step * dim_x_ + IDX::YAW);
+  const double yaw_error = normalize_yaw(yaw - ekf_yaw);
+  yaw = yaw_error + ekf_yaw;
+
+  /* Set measurement matrix */
+  Eigen::MatrixXd y(dim_y, 1);
+  y << yaw;
+
+  if (has_nan(y) || has_inf(y)) {
+    warning_->warn(
+      "[EKF] heading measurement matrix includes NaN of Inf. ignore update. check heading "
+      "message.");
+    return false;
+  }
+
+  /* Gate */
+  Eigen::VectorXd y_ekf(dim_y);
+  y_ekf << ekf_yaw;
+  const Eigen::MatrixXd p_curr = kalman_filter_.getLatestP();
+  const Eigen::MatrixXd p_y = p_curr.block(IDX::YAW, IDX::YAW, dim_y, dim_y);
+
+  const double distance = mahalanobis(y_ekf, y, p_y);
+  heading_diag_info.mahalanobis_distance =
+    std::max(distance, heading_diag_info.mahalanobis_distance);
+  if (distance > params_.heading_gate_dist) {
+    heading_diag_info.is_passed_mahalanobis_gate = false;
+    warning_->warn_throttle(mahalanobis_warning_message(distance, params_.heading_gate_dist), 2000);
+    warning_->warn_throttle("Ignore the measurement data.", 2000);
+    return false;
+  }
+
+  DEBUG_PRINT_MAT(y.transpose());
+  DEBUG_PRINT_MAT(y_ekf.transpose());
+  DEBUG_PRINT_MAT((y - y_ekf).transpose());
+
+  const Eigen::Matrix<double, 1, 6> c = heading_measurement_matrix();
+  const Eigen::Matrix<double, 1, 1> r = heading_measurement_covariance(heading.pose.covariance, 1);
+
+  kalman_filter_.updateWithDelay(y, c, r, static_cast<int>(delay_step));
+
+  // debug
+  const Eigen::MatrixXd x_result = kalman_filter_.getLatestX();
+  DEBUG_PRINT_MAT(x_result.transpose());
+  DEBUG_PRINT_MAT((x_result - x_curr).transpose());
+
+  return true;
+}
+
 geometry_msgs::msg::PoseWithCovarianceStamped EKFModule::compensate_rph_with_delay(
   const PoseWithCovariance & pose, tf2::Vector3 last_angular_velocity, const double delay_time)
 {
@@ -37,6 +37,13 @@ Eigen::Matrix<double, 2, 6> twist_measurement_matrix()
   return c;
 }
 
+Eigen::Matrix<double, 1, 6> heading_measurement_matrix()
+{
+  Eigen::Matrix<double, 1, 6> c = Eigen::Matrix<double, 1, 6>::Zero();
+  c(0, IDX::YAW) = 1.0;  // for yaw
+  return c;
+}
+
 Eigen::Matrix3d pose_measurement_covariance(
   const std::array<double, 36ul> & covariance, const size_t smoothing_step)
 {
@@ -58,4 +65,13 @@ Eigen::Matrix2d twist_measurement_covariance(
   return r * static_cast<double>(smoothing_step);
 }
 
+Eigen::Matrix<double, 1, 1> heading_measurement_covariance(
+  const std::array<double, 36ul> & covariance, const size_t smoothing_step)
+{
+  Eigen::Matrix<double, 1, 1> r;
+  using COV_IDX = autoware::universe_utils::xyzrpy_covariance_index::XYZRPY_COV_IDX;
+  r << covariance.at(COV_IDX::YAW_YAW);
+  return r * static_cast<double>(smoothing_step);
+}
+
 }  // namespace autoware::ekf_localizer
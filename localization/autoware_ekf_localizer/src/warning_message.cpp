@@ -39,6 +39,15 @@ std::string twist_delay_step_warning_message(
   return fmt::format(s, delay_time, delay_time_threshold);
 }
 
+std::string heading_delay_step_warning_message(
+  const double delay_time, const double delay_time_threshold)
+{
+  const std::string s =
+    "Heading delay exceeds the compensation limit, ignored. "
+    "delay: {:.3f}[s], limit: {:.3f}[s]";
+  return fmt::format(s, delay_time, delay_time_threshold);
+}
+
 std::string pose_delay_time_warning_message(const double delay_time)
 {
   const std::string s = "Pose time stamp is inappropriate, set delay to 0[s]. delay = {:.3f}";
@@ -51,6 +60,12 @@ std::string twist_delay_time_warning_message(const double delay_time)
   return fmt::format(s, delay_time);
 }
 
+std::string heading_delay_time_warning_message(const double delay_time)
+{
+  const std::string s = "Heading time stamp is inappropriate, set delay to 0[s]. delay = {:.3f}";
+  return fmt::format(s, delay_time);
+}
+
 std::string mahalanobis_warning_message(const double distance, const double max_distance)
 {
   const std::string s = "The Mahalanobis distance {:.4f} is over the limit {:.4f}.";
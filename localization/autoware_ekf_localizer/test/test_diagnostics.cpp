@@ -93,6 +93,36 @@ TEST(TestEkfDiagnostics, check_measurement_updated)
   EXPECT_EQ(stat.level, diagnostic_msgs::msg::DiagnosticStatus::ERROR);
 }
 
+TEST(TestEkfDiagnostics, check_pose_measurement_updated)
+{
+  diagnostic_msgs::msg::DiagnosticStatus stat;
+
+  // the default parameters at predict_frequency 50 Hz
+  const size_t no_update_count_threshold_warn = 50;
+  const size_t no_update_count_threshold_error = 100;
+
+  // from 2 s to 10 s without the pose, which is within max_duration of the dead reckoning
+  for (size_t no_update_count = 100; no_update_count <= 500; no_update_count += 50) {
+    stat = check_pose_measurement_updated(
+      no_update_count, no_update_count_threshold_warn, no_update_count_threshold_error, true);
+    EXPECT_EQ(stat.level, diagnostic_msgs::msg::DiagnosticStatus::WARN);
+
+    stat = check_pose_measurement_updated(
+      no_update_count, no_update_count_threshold_warn, no_update_count_threshold_error, false);
+    EXPECT_EQ(stat.level, diagnostic_msgs::msg::DiagnosticStatus::ERROR);
+  }
+
+  size_t no_update_count = 0;
+  stat = check_pose_measurement_updated(
+    no_update_count, no_update_count_threshold_warn, no_update_count_threshold_error, true);
+  EXPECT_EQ(stat.level, diagnostic_msgs::msg::DiagnosticStatus::OK);
+
+  no_update_count = 50;
+  stat = check_pose_measurement_updated(
+    no_update_count, no_update_count_threshold_warn, no_update_count_threshold_error, false);
+  EXPECT_EQ(stat.level, diagnostic_msgs::msg::DiagnosticStatus::WARN);
+}
+
 TEST(TestEkfDiagnostics, check_measurement_queue_size)
 {
   diagnostic_msgs::msg::DiagnosticStatus stat;
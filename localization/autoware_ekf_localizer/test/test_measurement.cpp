@@ -35,6 +35,14 @@ TEST(Measurement, twist_measurement_matrix)
   EXPECT_EQ((m - expected).norm(), 0);
 }
 
+TEST(Measurement, heading_measurement_matrix)
+{
+  const Eigen::Matrix<double, 1, 6> m = heading_measurement_matrix();
+  Eigen::Matrix<double, 1, 6> expected;
+  expected << 0, 0, 1, 0, 0, 0;
+  EXPECT_EQ((m - expected).norm(), 0);
+}
+
 TEST(Measurement, pose_measurement_covariance)
 {
   {
@@ -83,4 +91,14 @@ TEST(Measurement, twist_measurement_covariance)
   }
 }
 
+TEST(Measurement, heading_measurement_covariance)
+{
+  std::array<double, 36> covariance{};
+  covariance.fill(1);
+  covariance[35] = 3;
+
+  const Eigen::Matrix<double, 1, 1> m = heading_measurement_covariance(covariance, 2);
+  EXPECT_EQ(m(0, 0), 6.);
+}
+
 }  // namespace autoware::ekf_localizer
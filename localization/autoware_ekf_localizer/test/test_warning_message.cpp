@@ -37,6 +37,14 @@ TEST(twist_delay_step_warning_message, SmokeTest)
     "delay: 10.000[s], limit: 6.000[s]");
 }
 
+TEST(heading_delay_step_warning_message, SmokeTest)
+{
+  EXPECT_STREQ(
+    heading_delay_step_warning_message(8.0, 5.0).c_str(),
+    "Heading delay exceeds the compensation limit, ignored. "
+    "delay: 8.000[s], limit: 5.000[s]");
+}
+
 TEST(pose_delay_time_warning_message, SmokeTest)
 {
   EXPECT_STREQ(
@@ -57,6 +65,13 @@ TEST(twist_delay_time_warning_message, SmokeTest)
     "Twist time stamp is inappropriate, set delay to 0[s]. delay = -0.400");
 }
 
+TEST(heading_delay_time_warning_message, SmokeTest)
+{
+  EXPECT_STREQ(
+    heading_delay_time_warning_message(-1.0).c_str(),
+    "Heading time stamp is inappropriate, set delay to 0[s]. delay = -1.000");
+}
+
 TEST(mahalanobis_warning_message, SmokeTest)
 {
   EXPECT_STREQ(
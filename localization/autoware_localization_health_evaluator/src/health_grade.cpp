@@ -0,0 +1,122 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "health_grade.hpp"
+
+#include <algorithm>
+#include <cmath>
+
+namespace autoware::localization_health_evaluator
+{
+std::string to_string(const Grade grade)
+{
+  switch (grade) {
+    case Grade::GOOD:
+      return "good";
+    case Grade::DEGRADED:
+      return "degraded";
+    case Grade::POOR:
+      return "poor";
+  }
+  return "unknown";
+}
+
+Grade grade_lower_is_worse(const double value, const GradeThreshold & threshold)
+{
+  if (value < threshold.error) {
+    return Grade::POOR;
+  }
+  if (value < threshold.warn) {
+    return Grade::DEGRADED;
+  }
+  return Grade::GOOD;
+}
+
+Grade grade_higher_is_worse(const double value, const GradeThreshold & threshold)
+{
+  if (threshold.error < value) {
+    return Grade::POOR;
+  }
+  if (threshold.warn < value) {
+    return Grade::DEGRADED;
+  }
+  return Grade::GOOD;
+}
+
+std::optional<double> calc_normalized_innovation_squared(
+  const double dx, const double dy, const std::array<double, 36> & measured_covariance,
+  const std::array<double, 36> & estimated_covariance)
+{
+  const double s_xx = measured_covariance[0 * 6 + 0] + estimated_covariance[0 * 6 + 0];
+  const double s_xy = measured_covariance[0 * 6 + 1] + estimated_covariance[0 * 6 + 1];
+  const double s_yy = measured_covariance[1 * 6 + 1] + estimated_covariance[1 * 6 + 1];
+  const double det = s_xx * s_yy - s_xy * s_xy;
+  if (det <= 0.0) {
+    return std::nullopt;
+  }
+  return (s_yy * dx * dx - 2.0 * s_xy * dx * dy + s_xx * dy * dy) / det;
+}
+
+MovingAverage::MovingAverage(const size_t size) : size_(size)
+{
+}
+
+void MovingAverage::add(const double value)
+{
+  values_.push_back(value);
+  while (size_ < values_.size()) {
+    values_.pop_front();
+  }
+}
+
+std::optional<double> MovingAverage::mean() const
+{
+  if (values_.empty()) {
+    return std::nullopt;
+  }
+  double sum = 0.0;
+  for (const auto value : values_) {
+    sum += value;
+  }
+  return sum / static_cast<double>(values_.size());
+}
+
+GradeHysteresis::GradeHysteresis(const HysteresisParam & param) : param_(param)
+{
+}
+
+Grade GradeHysteresis::update(const Grade grade, const double time)
+{
+  if (grade == grade_) {
+    candidate_.reset();
+    return grade_;
+  }
+  const bool is_worse = grade_ < grade;
+  if (!candidate_ || (grade_ < *candidate_) != is_worse) {
+    candidate_ = grade;
+    candidate_since_ = time;
+  } else {
+    // the timer keeps running while the grade stays on the same side, and the candidate is the
+    // grade closest to the current one, which has been sustained since then
+    candidate_ = is_worse ? std::min(*candidate_, grade) : std::max(*candidate_, grade);
+  }
+
+  const double hold_time = is_worse ? param_.degrade_time : param_.recover_time;
+  if (hold_time <= time - candidate_since_) {
+    grade_ = *candidate_;
+    candidate_.reset();
+  }
+  return grade_;
+}
+}  // namespace autoware::localization_health_evaluator
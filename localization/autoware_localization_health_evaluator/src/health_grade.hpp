@@ -0,0 +1,90 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef HEALTH_GRADE_HPP_
+#define HEALTH_GRADE_HPP_
+
+#include <array>
+#include <cstddef>
+#include <deque>
+#include <optional>
+#include <string>
+
+namespace autoware::localization_health_evaluator
+{
+// in the order of the severity, which is the same as the diagnostic level
+enum class Grade { GOOD = 0, DEGRADED = 1, POOR = 2 };
+
+std::string to_string(Grade grade);
+
+struct GradeThreshold
+{
+  double warn{};
+  double error{};
+};
+
+// for the metrics which are better when larger, e.g. the NDT scores
+Grade grade_lower_is_worse(double value, const GradeThreshold & threshold);
+
+// for the metrics which are better when smaller, e.g. the covariance and the innovation
+Grade grade_higher_is_worse(double value, const GradeThreshold & threshold);
+
+// Calculates the normalized innovation squared (NIS) of the horizontal position between the
+// measured pose and the estimated pose, with the sum of their covariances in the row-major 6x6.
+// nullopt when the covariance is singular.
+std::optional<double> calc_normalized_innovation_squared(
+  double dx, double dy, const std::array<double, 36> & measured_covariance,
+  const std::array<double, 36> & estimated_covariance);
+
+// the mean of the last `size` values
+class MovingAverage
+{
+public:
+  explicit MovingAverage(size_t size);
+  void add(double value);
+  std::optional<double> mean() const;
+  void clear() { values_.clear(); }
+
+private:
+  size_t size_;
+  std::deque<double> values_;
+};
+
+struct HysteresisParam
+{
+  // the grade gets worse when the worse grade continues for this time [s]
+  double degrade_time{};
+  // the grade gets better when the better grade continues for this time [s]
+  double recover_time{};
+};
+
+class GradeHysteresis
+{
+public:
+  explicit GradeHysteresis(const HysteresisParam & param);
+
+  // Updates with the instantaneous grade at the time [s], and returns the filtered grade, which
+  // moves to the grade sustained on the worse or the better side for the hold time.
+  Grade update(Grade grade, double time);
+  Grade grade() const { return grade_; }
+
+private:
+  HysteresisParam param_;
+  Grade grade_{Grade::GOOD};
+  std::optional<Grade> candidate_;
+  double candidate_since_{};
+};
+}  // namespace autoware::localization_health_evaluator
+
+#endif  // HEALTH_GRADE_HPP_
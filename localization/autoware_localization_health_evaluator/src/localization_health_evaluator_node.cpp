@@ -0,0 +1,201 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "localization_health_evaluator_node.hpp"
+
+#include <algorithm>
+#include <cmath>
+#include <string>
+
+namespace autoware::localization_health_evaluator
+{
+namespace
+{
+GradeThreshold declare_threshold(rclcpp::Node & node, const std::string & prefix)
+{
+  GradeThreshold threshold;
+  threshold.warn = node.declare_parameter<double>(prefix + ".warn");
+  threshold.error = node.declare_parameter<double>(prefix + ".error");
+  return threshold;
+}
+}  // namespace
+
+LocalizationHealthEvaluator::LocalizationHealthEvaluator(const rclcpp::NodeOptions & node_options)
+: Node("localization_health_evaluator", node_options),
+  updater_(this),
+  innovation_average_(1),
+  hysteresis_(HysteresisParam{})
+{
+  // Parameter
+  node_param_.update_rate = declare_parameter<double>("update_rate");
+  node_param_.metric_timeout = declare_parameter<double>("metric_timeout");
+  node_param_.transform_probability = declare_threshold(*this, "transform_probability");
+  node_param_.nearest_voxel_transformation_likelihood =
+    declare_threshold(*this, "nearest_voxel_transformation_likelihood");
+  node_param_.innovation_window_size =
+    static_cast<size_t>(declare_parameter<int>("innovation.window_size"));
+  node_param_.innovation_max_time_difference =
+    declare_parameter<double>("innovation.max_time_difference");
+  node_param_.innovation = declare_threshold(*this, "innovation");
+  node_param_.covariance_trace = declare_threshold(*this, "covariance_trace");
+  node_param_.hysteresis.degrade_time = declare_parameter<double>("hysteresis.degrade_time");
+  node_param_.hysteresis.recover_time = declare_parameter<double>("hysteresis.recover_time");
+  innovation_average_ = MovingAverage(node_param_.innovation_window_size);
+  hysteresis_ = GradeHysteresis(node_param_.hysteresis);
+
+  // Subscriber
+  sub_transform_probability_ = create_subscription<Float32Stamped>(
+    "input/transform_probability", rclcpp::QoS{1},
+    [this](const Float32Stamped::ConstSharedPtr msg) {
+      transform_probability_ = Metric{msg->data, now()};
+    });
+  sub_nearest_voxel_transformation_likelihood_ = create_subscription<Float32Stamped>(
+    "input/nearest_voxel_transformation_likelihood", rclcpp::QoS{1},
+    [this](const Float32Stamped::ConstSharedPtr msg) {
+      nearest_voxel_transformation_likelihood_ = Metric{msg->data, now()};
+    });
+  sub_ndt_pose_ = create_subscription<PoseWithCovarianceStamped>(
+    "input/ndt_pose_with_covariance", rclcpp::QoS{1},
+    std::bind(&LocalizationHealthEvaluator::on_ndt_pose, this, std::placeholders::_1));
+  sub_kinematic_state_ = create_subscription<Odometry>(
+    "input/kinematic_state", rclcpp::QoS{1},
+    std::bind(&LocalizationHealthEvaluator::on_kinematic_state, this, std::placeholders::_1));
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.update_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&LocalizationHealthEvaluator::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("localization_health_evaluator");
+  updater_.add(
+    "localization_health", this, &LocalizationHealthEvaluator::check_localization_health);
+}
+
+void LocalizationHealthEvaluator::on_ndt_pose(const PoseWithCovarianceStamped::ConstSharedPtr msg)
+{
+  // the NDT pose is delayed by the matching, so it is compared with the estimation at its stamp
+  const rclcpp::Time stamp(msg->header.stamp);
+  const auto nearest = std::min_element(
+    kinematic_states_.begin(), kinematic_states_.end(),
+    [&stamp](const Odometry::ConstSharedPtr & a, const Odometry::ConstSharedPtr & b) {
+      return std::abs((rclcpp::Time(a->header.stamp) - stamp).seconds()) <
+             std::abs((rclcpp::Time(b->header.stamp) - stamp).seconds());
+    });
+  if (nearest == kinematic_states_.end()) {
+    return;
+  }
+  if (
+    node_param_.innovation_max_time_difference <
+    std::abs((rclcpp::Time((*nearest)->header.stamp) - stamp).seconds())) {
+    return;
+  }
+
+  const auto & estimated = (*nearest)->pose;
+
+  const auto nis = calc_normalized_innovation_squared(
+    msg->pose.pose.position.x - estimated.pose.position.x,
+    msg->pose.pose.position.y - estimated.pose.position.y, msg->pose.covariance,
+    estimated.covariance);
+  if (!nis) {
+    return;
+  }
+  innovation_average_.add(*nis);
+  innovation_ = Metric{*innovation_average_.mean(), now()};
+}
+
+void LocalizationHealthEvaluator::on_kinematic_state(const Odometry::ConstSharedPtr msg)
+{
+  const auto & covariance = msg->pose.covariance;
+  covariance_trace_ = Metric{covariance[0 * 6 + 0] + covariance[1 * 6 + 1], now()};
+
+  // keep the estimations for the delay of the NDT pose
+  kinematic_states_.push_back(msg);
+  const rclcpp::Time stamp(msg->header.stamp);
+  while (node_param_.metric_timeout <
+         (stamp - rclcpp::Time(kinematic_states_.front()->header.stamp)).seconds()) {
+    kinematic_states_.pop_front();
+  }
+}
+
+void LocalizationHealthEvaluator::on_timer()
+{
+  // the metrics which are not received recently are not evaluated, e.g. NDT is not used
+  Grade grade = Grade::GOOD;
+  if (is_fresh(transform_probability_)) {
+    grade = std::max(
+      grade,
+      grade_lower_is_worse(transform_probability_->value, node_param_.transform_probability));
+  }
+  if (is_fresh(nearest_voxel_transformation_likelihood_)) {
+    grade = std::max(
+      grade, grade_lower_is_worse(
+               nearest_voxel_transformation_likelihood_->value,
+               node_param_.nearest_voxel_transformation_likelihood));
+  }
+  if (is_fresh(innovation_)) {
+    grade = std::max(grade, grade_higher_is_worse(innovation_->value, node_param_.innovation));
+  } else {
+    innovation_average_.clear();
+  }
+  if (is_fresh(covariance_trace_)) {
+    grade = std::max(
+      grade, grade_higher_is_worse(covariance_trace_->value, node_param_.covariance_trace));
+  }
+
+  instant_grade_ = grade;
+  hysteresis_.update(grade, now().seconds());
+}
+
+void LocalizationHealthEvaluator::check_localization_health(
+  diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  const auto add_metric = [this, &stat](const std::string & key, const std::optional<Metric> & m) {
+    if (is_fresh(m)) {
+      stat.addf(key, "%.3f", m->value);
+    } else {
+      stat.add(key, "not received");
+    }
+  };
+  add_metric("transform_probability", transform_probability_);
+  add_metric("nearest_voxel_transformation_likelihood", nearest_voxel_transformation_likelihood_);
+  add_metric("innovation", innovation_);
+  add_metric("covariance_trace", covariance_trace_);
+  stat.add("instant_grade", to_string(instant_grade_));
+  stat.add("grade", to_string(hysteresis_.grade()));
+
+  switch (hysteresis_.grade()) {
+    case Grade::GOOD:
+      stat.summary(DiagnosticStatus::OK, "OK");
+      return;
+    case Grade::DEGRADED:
+      stat.summary(DiagnosticStatus::WARN, "localization is degraded");
+      return;
+    case Grade::POOR:
+      stat.summary(DiagnosticStatus::ERROR, "localization is poor");
+      return;
+  }
+}
+
+bool LocalizationHealthEvaluator::is_fresh(const std::optional<Metric> & metric) const
+{
+  return metric && (now() - metric->received_time).seconds() <= node_param_.metric_timeout;
+}
+}  // namespace autoware::localization_health_evaluator
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(
+  autoware::localization_health_evaluator::LocalizationHealthEvaluator)
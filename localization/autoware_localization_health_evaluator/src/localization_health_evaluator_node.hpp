@@ -0,0 +1,99 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef LOCALIZATION_HEALTH_EVALUATOR_NODE_HPP_
+#define LOCALIZATION_HEALTH_EVALUATOR_NODE_HPP_
+
+#include "health_grade.hpp"
+
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_internal_debug_msgs/msg/float32_stamped.hpp>
+#include <geometry_msgs/msg/pose_with_covariance_stamped.hpp>
+#include <nav_msgs/msg/odometry.hpp>
+
+#include <deque>
+#include <optional>
+
+namespace autoware::localization_health_evaluator
+{
+using autoware_internal_debug_msgs::msg::Float32Stamped;
+using geometry_msgs::msg::PoseWithCovarianceStamped;
+using nav_msgs::msg::Odometry;
+
+struct NodeParam
+{
+  double update_rate{};
+  double metric_timeout{};
+  GradeThreshold transform_probability{};
+  GradeThreshold nearest_voxel_transformation_likelihood{};
+  size_t innovation_window_size{};
+  double innovation_max_time_difference{};
+  GradeThreshold innovation{};
+  GradeThreshold covariance_trace{};
+  HysteresisParam hysteresis{};
+};
+
+// the latest value of a metric and the time when it is received
+struct Metric
+{
+  double value{};
+  rclcpp::Time received_time;
+};
+
+class LocalizationHealthEvaluator : public rclcpp::Node
+{
+public:
+  explicit LocalizationHealthEvaluator(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<Float32Stamped>::SharedPtr sub_transform_probability_;
+  rclcpp::Subscription<Float32Stamped>::SharedPtr sub_nearest_voxel_transformation_likelihood_;
+  rclcpp::Subscription<PoseWithCovarianceStamped>::SharedPtr sub_ndt_pose_;
+  rclcpp::Subscription<Odometry>::SharedPtr sub_kinematic_state_;
+
+  void on_ndt_pose(const PoseWithCovarianceStamped::ConstSharedPtr msg);
+  void on_kinematic_state(const Odometry::ConstSharedPtr msg);
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_localization_health(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  std::optional<Metric> transform_probability_;
+  std::optional<Metric> nearest_voxel_transformation_likelihood_;
+  std::optional<Metric> innovation_;
+  std::optional<Metric> covariance_trace_;
+  std::deque<Odometry::ConstSharedPtr> kinematic_states_;
+  MovingAverage innovation_average_;
+  GradeHysteresis hysteresis_;
+  Grade instant_grade_{Grade::GOOD};
+
+  // Function
+  bool is_fresh(const std::optional<Metric> & metric) const;
+};
+}  // namespace autoware::localization_health_evaluator
+
+#endif  // LOCALIZATION_HEALTH_EVALUATOR_NODE_HPP_
@@ -0,0 +1,97 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "health_grade.hpp"
+
+#include <gtest/gtest.h>
+
+#include <array>
+
+using autoware::localization_health_evaluator::Grade;
+using autoware::localization_health_evaluator::GradeThreshold;
+
+TEST(HealthGrade, GradeThreshold)
+{
+  using autoware::localization_health_evaluator::grade_higher_is_worse;
+  using autoware::localization_health_evaluator::grade_lower_is_worse;
+
+  const GradeThreshold score{3.0, 2.0};
+  EXPECT_EQ(grade_lower_is_worse(3.5, score), Grade::GOOD);
+  EXPECT_EQ(grade_lower_is_worse(2.5, score), Grade::DEGRADED);
+  EXPECT_EQ(grade_lower_is_worse(1.5, score), Grade::POOR);
+
+  const GradeThreshold covariance{0.2, 0.5};
+  EXPECT_EQ(grade_higher_is_worse(0.1, covariance), Grade::GOOD);
+  EXPECT_EQ(grade_higher_is_worse(0.3, covariance), Grade::DEGRADED);
+  EXPECT_EQ(grade_higher_is_worse(0.6, covariance), Grade::POOR);
+}
+
+TEST(HealthGrade, NormalizedInnovationSquared)
+{
+  using autoware::localization_health_evaluator::calc_normalized_innovation_squared;
+
+  std::array<double, 36> measured{};
+  measured[0 * 6 + 0] = 0.5;
+  measured[1 * 6 + 1] = 1.0;
+  std::array<double, 36> estimated{};
+  estimated[0 * 6 + 0] = 0.5;
+  estimated[1 * 6 + 1] = 1.0;
+
+  // S = diag(1, 2)
+  const auto nis = calc_normalized_innovation_squared(1.0, 2.0, measured, estimated);
+  ASSERT_TRUE(nis.has_value());
+  EXPECT_DOUBLE_EQ(*nis, 1.0 + 2.0);
+
+  EXPECT_FALSE(calc_normalized_innovation_squared(1.0, 2.0, {}, {}).has_value());
+}
+
+TEST(HealthGrade, MovingAverage)
+{
+  autoware::localization_health_evaluator::MovingAverage average(2);
+  EXPECT_FALSE(average.mean().has_value());
+  average.add(1.0);
+  average.add(3.0);
+  average.add(5.0);
+  ASSERT_TRUE(average.mean().has_value());
+  EXPECT_DOUBLE_EQ(*average.mean(), 4.0);
+}
+
+TEST(HealthGrade, Hysteresis)
+{
+  using autoware::localization_health_evaluator::GradeHysteresis;
+  using autoware::localization_health_evaluator::HysteresisParam;
+
+  GradeHysteresis hysteresis(HysteresisParam{0.5, 2.0});
+  EXPECT_EQ(hysteresis.update(Grade::POOR, 0.0), Grade::GOOD);
+  EXPECT_EQ(hysteresis.update(Grade::POOR, 0.4), Grade::GOOD);
+  EXPECT_EQ(hysteresis.update(Grade::POOR, 0.5), Grade::POOR);
+
+  // a short recovery is ignored
+  EXPECT_EQ(hysteresis.update(Grade::GOOD, 1.0), Grade::POOR);
+  EXPECT_EQ(hysteresis.update(Grade::POOR, 2.0), Grade::POOR);
+  EXPECT_EQ(hysteresis.update(Grade::GOOD, 3.0), Grade::POOR);
+  EXPECT_EQ(hysteresis.update(Grade::GOOD, 4.9), Grade::POOR);
+  EXPECT_EQ(hysteresis.update(Grade::GOOD, 5.0), Grade::GOOD);
+
+  // a grade alternating between degraded and poor is degraded after the degrade time
+  for (int i = 0; i < 5; ++i) {
+    const auto grade = i % 2 == 0 ? Grade::DEGRADED : Grade::POOR;
+    EXPECT_EQ(hysteresis.update(grade, 6.0 + 0.1 * i), Grade::GOOD);
+  }
+  EXPECT_EQ(hysteresis.update(Grade::POOR, 6.5), Grade::DEGRADED);
+  // and it is not poor until poor continues
+  EXPECT_EQ(hysteresis.update(Grade::DEGRADED, 6.6), Grade::DEGRADED);
+  EXPECT_EQ(hysteresis.update(Grade::POOR, 6.7), Grade::DEGRADED);
+  EXPECT_EQ(hysteresis.update(Grade::POOR, 7.2), Grade::POOR);
+}
@@ -22,6 +22,7 @@
 
 #include <autoware_map_msgs/msg/lanelet_map_bin.hpp>
 #include <autoware_map_msgs/msg/map_projector_info.hpp>
+#include <std_srvs/srv/trigger.hpp>
 
 #include <lanelet2_projection/UTM.h>
 
@@ -49,10 +50,16 @@ private:
   using MapProjectorInfo = autoware::component_interface_specs_universe::map::MapProjectorInfo;
 
   void on_map_projector_info(const MapProjectorInfo::Message::ConstSharedPtr msg);
+  void on_reload(
+    const std_srvs::srv::Trigger::Request::SharedPtr req,
+    const std_srvs::srv::Trigger::Response::SharedPtr res);
+  bool publish_map(const MapProjectorInfo::Message & projector_info);
 
   autoware::component_interface_utils::Subscription<MapProjectorInfo>::SharedPtr
     sub_map_projector_info_;
   rclcpp::Publisher<autoware_map_msgs::msg::LaneletMapBin>::SharedPtr pub_map_bin_;
+  rclcpp::Service<std_srvs::srv::Trigger>::SharedPtr srv_reload_;
+  MapProjectorInfo::Message::ConstSharedPtr projector_info_;
 };
 }  // namespace autoware::map_loader
 
@@ -72,10 +72,42 @@ Lanelet2MapLoaderNode::Lanelet2MapLoaderNode(const rclcpp::NodeOptions & options
   declare_parameter<std::string>("lanelet2_map_path");
   declare_parameter<double>("center_line_resolution");
   declare_parameter<bool>("use_waypoints");
+
+  // service
+  srv_reload_ = create_service<std_srvs::srv::Trigger>(
+    "service/reload",
+    std::bind(
+      &Lanelet2MapLoaderNode::on_reload, this, std::placeholders::_1, std::placeholders::_2));
 }
 
 void Lanelet2MapLoaderNode::on_map_projector_info(
   const MapProjectorInfo::Message::ConstSharedPtr msg)
+{
+  projector_info_ = msg;
+  publish_map(*msg);
+}
+
+void Lanelet2MapLoaderNode::on_reload(
+  const std_srvs::srv::Trigger::Request::SharedPtr,
+  const std_srvs::srv::Trigger::Response::SharedPtr res)
+{
+  if (!projector_info_) {
+    res->success = false;
+    res->message = "map projector info is not received";
+    return;
+  }
+
+  // the map file may be replaced with an unsupported one, which must not stop the node
+  try {
+    res->success = publish_map(*projector_info_);
+  } catch (const std::exception & e) {
+    RCLCPP_ERROR(get_logger(), "Failed to reload lanelet2_map: %s", e.what());
+    res->success = false;
+  }
+  res->message = res->success ? "reloaded" : "failed to reload lanelet2_map";
+}
+
+bool Lanelet2MapLoaderNode::publish_map(const MapProjectorInfo::Message & projector_info)
 {
   const auto allow_unsupported_version = get_parameter("allow_unsupported_version").as_bool();
   const auto lanelet2_filename = get_parameter("lanelet2_map_path").as_string();
@@ -83,10 +115,10 @@ void Lanelet2MapLoaderNode::on_map_projector_info(
   const auto use_waypoints = get_parameter("use_waypoints").as_bool();
 
   // load map from file
-  const auto map = load_map(lanelet2_filename, *msg);
+  const auto map = load_map(lanelet2_filename, projector_info);
   if (!map) {
     RCLCPP_ERROR(get_logger(), "Failed to load lanelet2_map. Not published.");
-    return;
+    return false;
   }
 
   std::string format_version{"null"}, map_version{""};
@@ -129,10 +161,13 @@ void Lanelet2MapLoaderNode::on_map_projector_info(
   const auto map_bin_msg = create_map_bin_msg(map, lanelet2_filename, now());
 
   // create publisher and publish
-  pub_map_bin_ =
-    create_publisher<LaneletMapBin>("output/lanelet2_map", rclcpp::QoS{1}.transient_local());
+  if (!pub_map_bin_) {
+    pub_map_bin_ =
+      create_publisher<LaneletMapBin>("output/lanelet2_map", rclcpp::QoS{1}.transient_local());
+  }
   pub_map_bin_->publish(map_bin_msg);
   RCLCPP_INFO(get_logger(), "Succeeded to load lanelet2_map. Map is published.");
+  return true;
 }
 
 lanelet::LaneletMapPtr Lanelet2MapLoaderNode::load_map(
@@ -0,0 +1,84 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "http_client.hpp"
+
+#include <curl/curl.h>
+
+#include <cstdio>
+#include <filesystem>
+#include <fstream>
+#include <memory>
+#include <stdexcept>
+#include <string>
+
+namespace autoware::map_update_client
+{
+namespace
+{
+size_t write_to_string(char * data, size_t size, size_t count, void * user_data)
+{
+  static_cast<std::string *>(user_data)->append(data, size * count);
+  return size * count;
+}
+
+size_t write_to_stream(char * data, size_t size, size_t count, void * user_data)
+{
+  auto & ofs = *static_cast<std::ofstream *>(user_data);
+  ofs.write(data, static_cast<std::streamsize>(size * count));
+  return ofs ? size * count : 0;
+}
+
+template <typename T>
+void perform(
+  const std::string & url, const double timeout, curl_write_callback callback, T * user_data)
+{
+  const std::unique_ptr<CURL, decltype(&curl_easy_cleanup)> curl(
+    curl_easy_init(), curl_easy_cleanup);
+  if (!curl) {
+    throw std::runtime_error("failed to initialize curl");
+  }
+
+  curl_easy_setopt(curl.get(), CURLOPT_URL, url.c_str());
+  curl_easy_setopt(curl.get(), CURLOPT_FOLLOWLOCATION, 1L);
+  curl_easy_setopt(curl.get(), CURLOPT_FAILONERROR, 1L);
+  curl_easy_setopt(curl.get(), CURLOPT_NOSIGNAL, 1L);
+  curl_easy_setopt(curl.get(), CURLOPT_TIMEOUT_MS, static_cast<long>(timeout * 1000.0));
+  curl_easy_setopt(curl.get(), CURLOPT_WRITEFUNCTION, callback);
+  curl_easy_setopt(curl.get(), CURLOPT_WRITEDATA, user_data);
+
+  const auto result = curl_easy_perform(curl.get());
+  if (result != CURLE_OK) {
+    throw std::runtime_error(url + ": " + curl_easy_strerror(result));
+  }
+}
+}  // namespace
+
+std::string http_get(const std::string & url, const double timeout)
+{
+  std::string body;
+  perform(url, timeout, write_to_string, &body);
+  return body;
+}
+
+void http_download(const std::string & url, const std::string & file_path, const double timeout)
+{
+  std::filesystem::create_directories(std::filesystem::path(file_path).parent_path());
+  std::ofstream ofs(file_path, std::ios::binary | std::ios::trunc);
+  if (!ofs) {
+    throw std::runtime_error("failed to open " + file_path);
+  }
+  perform(url, timeout, write_to_stream, &ofs);
+}
+}  // namespace autoware::map_update_client
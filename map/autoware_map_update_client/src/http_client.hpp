@@ -0,0 +1,27 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef HTTP_CLIENT_HPP_
+#define HTTP_CLIENT_HPP_
+
+#include <string>
+
+namespace autoware::map_update_client
+{
+// Both functions throw std::runtime_error on a transfer failure or an HTTP error status.
+std::string http_get(const std::string & url, double timeout);
+void http_download(const std::string & url, const std::string & file_path, double timeout);
+}  // namespace autoware::map_update_client
+
+#endif  // HTTP_CLIENT_HPP_
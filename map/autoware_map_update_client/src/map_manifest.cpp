@@ -0,0 +1,194 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "map_manifest.hpp"
+
+#include <nlohmann/json.hpp>
+#include <openssl/evp.h>
+
+#include <array>
+#include <filesystem>
+#include <fstream>
+#include <iomanip>
+#include <map>
+#include <memory>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::map_update_client
+{
+namespace fs = std::filesystem;
+
+namespace
+{
+constexpr char lanelet2_map_file[] = "lanelet2_map.osm";
+
+bool is_hex_digest(const std::string & digest)
+{
+  return digest.size() == 64 &&
+         digest.find_first_not_of("0123456789abcdef") == std::string::npos;
+}
+}  // namespace
+
+MapManifest parse_manifest(const std::string & json)
+{
+  MapManifest manifest;
+  try {
+    const auto j = nlohmann::json::parse(json);
+    manifest.version = j.at("version").get<std::string>();
+    for (const auto & file : j.at("files")) {
+      MapFile map_file;
+      map_file.path = file.at("path").get<std::string>();
+      map_file.sha256 = file.at("sha256").get<std::string>();
+      map_file.size = file.at("size").get<uint64_t>();
+      manifest.files.push_back(map_file);
+    }
+  } catch (const nlohmann::json::exception & e) {
+    throw std::invalid_argument(std::string("malformed manifest: ") + e.what());
+  }
+
+  if (manifest.version.empty()) {
+    throw std::invalid_argument("empty version in the manifest");
+  }
+  for (const auto & file : manifest.files) {
+    if (!is_safe_path(file.path)) {
+      throw std::invalid_argument("unsafe path in the manifest: " + file.path);
+    }
+    if (!is_hex_digest(file.sha256)) {
+      throw std::invalid_argument("invalid sha256 of " + file.path);
+    }
+  }
+  return manifest;
+}
+
+std::string dump_manifest(const MapManifest & manifest)
+{
+  nlohmann::json j;
+  j["version"] = manifest.version;
+  j["files"] = nlohmann::json::array();
+  for (const auto & file : manifest.files) {
+    j["files"].push_back({{"path", file.path}, {"sha256", file.sha256}, {"size", file.size}});
+  }
+  return j.dump(2);
+}
+
+MapManifest load_manifest(const std::string & file_path)
+{
+  std::ifstream ifs(file_path);
+  if (!ifs) {
+    throw std::invalid_argument("failed to open " + file_path);
+  }
+  std::stringstream ss;
+  ss << ifs.rdbuf();
+  return parse_manifest(ss.str());
+}
+
+bool is_safe_path(const std::string & path)
+{
+  const fs::path p(path);
+  if (path.empty() || p.is_absolute() || p.has_root_name()) {
+    return false;
+  }
+  for (const auto & element : p) {
+    if (element == "..") {
+      return false;
+    }
+  }
+  return true;
+}
+
+std::string calc_sha256(const std::string & file_path)
+{
+  std::ifstream ifs(file_path, std::ios::binary);
+  if (!ifs) {
+    throw std::runtime_error("failed to open " + file_path);
+  }
+
+  const std::unique_ptr<EVP_MD_CTX, decltype(&EVP_MD_CTX_free)> context(
+    EVP_MD_CTX_new(), EVP_MD_CTX_free);
+  if (!context || !EVP_DigestInit_ex(context.get(), EVP_sha256(), nullptr)) {
+    throw std::runtime_error("failed to initialize sha256");
+  }
+
+  std::array<char, 65536> buffer{};
+  while (ifs.read(buffer.data(), buffer.size()) || ifs.gcount() > 0) {
+    EVP_DigestUpdate(context.get(), buffer.data(), static_cast<size_t>(ifs.gcount()));
+  }
+
+  std::array<unsigned char, EVP_MAX_MD_SIZE> digest{};
+  unsigned int digest_size = 0;
+  EVP_DigestFinal_ex(context.get(), digest.data(), &digest_size);
+
+  std::ostringstream ss;
+  for (unsigned int i = 0; i < digest_size; ++i) {
+    ss << std::hex << std::setw(2) << std::setfill('0') << static_cast<int>(digest.at(i));
+  }
+  return ss.str();
+}
+
+std::vector<std::string> verify_files(const std::string & directory, const MapManifest & manifest)
+{
+  std::vector<std::string> errors;
+  for (const auto & file : manifest.files) {
+    const auto file_path = (fs::path(directory) / file.path).string();
+    if (!fs::is_regular_file(file_path)) {
+      errors.push_back("missing " + file.path);
+      continue;
+    }
+    if (fs::file_size(file_path) != file.size) {
+      errors.push_back("size mismatch of " + file.path);
+      continue;
+    }
+    if (calc_sha256(file_path) != file.sha256) {
+      errors.push_back("sha256 mismatch of " + file.path);
+    }
+  }
+  return errors;
+}
+
+bool requires_restart(const MapManifest & current, const MapManifest & next)
+{
+  const auto to_digests = [](const MapManifest & manifest) {
+    std::map<std::string, std::string> digests;
+    for (const auto & file : manifest.files) {
+      if (file.path != lanelet2_map_file) {
+        digests.emplace(file.path, file.sha256);
+      }
+    }
+    return digests;
+  };
+  return to_digests(current) != to_digests(next);
+}
+
+std::vector<MapFile> find_unchanged_files(const MapManifest & current, const MapManifest & next)
+{
+  std::map<std::string, const MapFile *> current_files;
+  for (const auto & file : current.files) {
+    current_files.emplace(file.path, &file);
+  }
+
+  std::vector<MapFile> unchanged_files;
+  for (const auto & file : next.files) {
+    const auto itr = current_files.find(file.path);
+    if (
+      itr != current_files.end() && itr->second->sha256 == file.sha256 &&
+      itr->second->size == file.size) {
+      unchanged_files.push_back(file);
+    }
+  }
+  return unchanged_files;
+}
+}  // namespace autoware::map_update_client
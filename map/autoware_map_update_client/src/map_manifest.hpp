@@ -0,0 +1,58 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef MAP_MANIFEST_HPP_
+#define MAP_MANIFEST_HPP_
+
+#include <cstdint>
+#include <string>
+#include <vector>
+
+namespace autoware::map_update_client
+{
+struct MapFile
+{
+  std::string path;  // relative to the directory of the version
+  std::string sha256;
+  uint64_t size{};
+};
+
+struct MapManifest
+{
+  std::string version;
+  std::vector<MapFile> files;
+};
+
+// throws std::invalid_argument when the manifest is malformed or includes an unsafe path
+MapManifest parse_manifest(const std::string & json);
+std::string dump_manifest(const MapManifest & manifest);
+MapManifest load_manifest(const std::string & file_path);
+
+// a path which never goes out of the directory of the version
+bool is_safe_path(const std::string & path);
+
+// the lowercase hex digest, throws std::runtime_error when the file cannot be read
+std::string calc_sha256(const std::string & file_path);
+
+// returns the error messages, which is empty when all the files match the manifest
+std::vector<std::string> verify_files(const std::string & directory, const MapManifest & manifest);
+
+// the lanelet2 map is the only map which can be reloaded without restarting the loaders
+bool requires_restart(const MapManifest & current, const MapManifest & next);
+
+// the files of the next version which are the same as the current version, e.g. unchanged tiles
+std::vector<MapFile> find_unchanged_files(const MapManifest & current, const MapManifest & next);
+}  // namespace autoware::map_update_client
+
+#endif  // MAP_MANIFEST_HPP_
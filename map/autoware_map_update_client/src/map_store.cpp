@@ -0,0 +1,128 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "map_store.hpp"
+
+#include <algorithm>
+#include <cctype>
+#include <filesystem>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::map_update_client
+{
+namespace fs = std::filesystem;
+
+namespace
+{
+constexpr char current_link_name[] = "current";
+constexpr char staging_prefix[] = ".staging_";
+
+bool is_valid_version(const std::string & version)
+{
+  // the version is a name of a directory
+  if (version.empty() || version == "." || version == ".." || version == current_link_name) {
+    return false;
+  }
+  return std::all_of(version.begin(), version.end(), [](const char c) {
+    return std::isalnum(static_cast<unsigned char>(c)) || c == '.' || c == '_' || c == '-';
+  });
+}
+
+void check_version(const std::string & version)
+{
+  if (!is_valid_version(version) || version.front() == '.') {
+    throw std::invalid_argument("invalid map version: " + version);
+  }
+}
+}  // namespace
+
+MapStore::MapStore(const std::string & map_root) : map_root_(map_root)
+{
+  fs::create_directories(map_root_);
+}
+
+std::optional<std::string> MapStore::get_current_version() const
+{
+  const auto link = fs::path(map_root_) / current_link_name;
+  if (!fs::is_symlink(link)) {
+    return std::nullopt;
+  }
+  return fs::read_symlink(link).filename().string();
+}
+
+bool MapStore::has_version(const std::string & version) const
+{
+  return is_valid_version(version) && fs::is_directory(get_version_directory(version));
+}
+
+std::string MapStore::get_version_directory(const std::string & version) const
+{
+  return (fs::path(map_root_) / version).string();
+}
+
+std::string MapStore::create_staging_directory(const std::string & version) const
+{
+  check_version(version);
+  const auto directory = fs::path(map_root_) / (staging_prefix + version);
+  fs::remove_all(directory);
+  fs::create_directories(directory);
+  return directory.string();
+}
+
+void MapStore::commit(const std::string & version) const
+{
+  check_version(version);
+  const auto directory = fs::path(get_version_directory(version));
+  fs::remove_all(directory);
+  fs::rename(fs::path(map_root_) / (staging_prefix + version), directory);
+}
+
+void MapStore::activate(const std::string & version) const
+{
+  if (!has_version(version)) {
+    throw std::invalid_argument("map version not found: " + version);
+  }
+
+  // the rename of a symbolic link replaces the old one atomically
+  const auto link = fs::path(map_root_) / current_link_name;
+  const auto temporary_link = fs::path(map_root_) / (std::string(current_link_name) + ".tmp");
+  fs::remove(temporary_link);
+  fs::create_directory_symlink(version, temporary_link);
+  fs::rename(temporary_link, link);
+}
+
+void MapStore::remove_old_versions(const size_t keep) const
+{
+  const auto current_version = get_current_version();
+  std::vector<fs::directory_entry> versions;
+  for (const auto & entry : fs::directory_iterator(map_root_)) {
+    const auto name = entry.path().filename().string();
+    if (
+      entry.is_symlink() || !entry.is_directory() || name.front() == '.' ||
+      name == current_version) {
+      continue;
+    }
+    versions.push_back(entry);
+  }
+
+  std::sort(versions.begin(), versions.end(), [](const auto & a, const auto & b) {
+    return a.last_write_time() > b.last_write_time();
+  });
+  for (size_t i = keep; i < versions.size(); ++i) {
+    fs::remove_all(versions.at(i).path());
+  }
+}
+}  // namespace autoware::map_update_client
@@ -0,0 +1,53 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef MAP_STORE_HPP_
+#define MAP_STORE_HPP_
+
+#include <cstddef>
+#include <optional>
+#include <string>
+
+namespace autoware::map_update_client
+{
+/**
+ * @brief versions of the map under a root directory
+ *
+ * Each version is stored in `<map_root>/<version>`, and `<map_root>/current` is the symbolic link
+ * to the active version, which is given to the map loaders as the map path.
+ */
+class MapStore
+{
+public:
+  explicit MapStore(const std::string & map_root);
+
+  std::optional<std::string> get_current_version() const;
+  bool has_version(const std::string & version) const;
+  std::string get_version_directory(const std::string & version) const;
+
+  // creates an empty directory to download the version, which is invisible to the loaders
+  std::string create_staging_directory(const std::string & version) const;
+  // moves the staging directory of the version to its version directory
+  void commit(const std::string & version) const;
+  // switches the current version atomically
+  void activate(const std::string & version) const;
+  // removes the versions except for the current one and the `keep` newest ones
+  void remove_old_versions(size_t keep) const;
+
+private:
+  std::string map_root_;
+};
+}  // namespace autoware::map_update_client
+
+#endif  // MAP_STORE_HPP_
@@ -0,0 +1,278 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "map_update_client_node.hpp"
+
+#include "http_client.hpp"
+#include "map_manifest.hpp"
+#include "map_validator.hpp"
+
+#include <curl/curl.h>
+
+#include <chrono>
+#include <filesystem>
+#include <fstream>
+#include <memory>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::map_update_client
+{
+namespace
+{
+constexpr char manifest_file[] = "manifest.json";
+
+std::string join(const std::vector<std::string> & messages)
+{
+  std::string joined;
+  for (const auto & message : messages) {
+    joined += (joined.empty() ? "" : ", ") + message;
+  }
+  return joined;
+}
+}  // namespace
+
+MapUpdateClient::MapUpdateClient(const rclcpp::NodeOptions & node_options)
+: Node("map_update_client", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.update_rate = declare_parameter<double>("update_rate");
+  node_param_.server_url = declare_parameter<std::string>("server_url");
+  node_param_.area = declare_parameter<std::string>("area");
+  node_param_.map_root = declare_parameter<std::string>("map_root");
+  node_param_.check_interval = declare_parameter<double>("check_interval");
+  node_param_.download_timeout = declare_parameter<double>("download_timeout");
+  node_param_.keep_versions = static_cast<int>(declare_parameter<int>("keep_versions"));
+
+  curl_global_init(CURL_GLOBAL_DEFAULT);
+  store_ = std::make_unique<MapStore>(node_param_.map_root);
+
+  // Subscriber
+  const auto adaptor = autoware::component_interface_utils::NodeAdaptor(this);
+  adaptor.init_sub(sub_route_state_, [this](const RouteState::Message::ConstSharedPtr msg) {
+    route_state_ = msg->state;
+  });
+
+  // Service
+  srv_check_ = create_service<Trigger>(
+    "service/check",
+    std::bind(&MapUpdateClient::on_check, this, std::placeholders::_1, std::placeholders::_2));
+
+  // Client
+  cli_reload_lanelet2_map_ = create_client<Trigger>("service/reload_lanelet2_map");
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.update_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&MapUpdateClient::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("map_update_client");
+  updater_.add("map_update", this, &MapUpdateClient::check_map_update);
+}
+
+MapUpdateClient::~MapUpdateClient()
+{
+  // the download must be finished before the cleanup of curl
+  if (update_.valid()) {
+    update_.wait();
+  }
+  curl_global_cleanup();
+}
+
+void MapUpdateClient::on_check(
+  const Trigger::Request::SharedPtr, const Trigger::Response::SharedPtr res)
+{
+  if (update_.valid()) {
+    res->success = false;
+    res->message = "the update is in progress";
+    return;
+  }
+  start_update();
+  res->success = true;
+  res->message = "started to check the update";
+}
+
+void MapUpdateClient::on_timer()
+{
+  const auto current_time = now();
+
+  if (!update_.valid() && !pending_version_) {
+    const bool is_check_time =
+      !last_check_time_ ||
+      (current_time - *last_check_time_).seconds() >= node_param_.check_interval;
+    if (is_check_time) {
+      start_update();
+    }
+  }
+
+  if (
+    update_.valid() &&
+    update_.wait_for(std::chrono::seconds(0)) == std::future_status::ready) {
+    try {
+      pending_version_ = update_.get();
+      error_message_.clear();
+    } catch (const std::exception & e) {
+      error_message_ = e.what();
+      RCLCPP_WARN(get_logger(), "Failed to update the map: %s", e.what());
+    }
+  }
+
+  if (pending_version_ && is_between_missions()) {
+    try {
+      swap_map(*pending_version_);
+    } catch (const std::exception & e) {
+      error_message_ = e.what();
+      RCLCPP_ERROR(get_logger(), "Failed to swap the map: %s", e.what());
+    }
+    pending_version_ = std::nullopt;
+  }
+}
+
+void MapUpdateClient::start_update()
+{
+  last_check_time_ = now();
+  update_ = std::async(std::launch::async, [this]() { return fetch_update(); });
+}
+
+std::optional<std::string> MapUpdateClient::fetch_update() const
+{
+  const auto area_url = node_param_.server_url + "/" + node_param_.area;
+  const auto manifest = parse_manifest(
+    http_get(area_url + "/" + manifest_file, node_param_.download_timeout));
+
+  if (store_->get_current_version() == manifest.version) {
+    return std::nullopt;
+  }
+  // a version in the store has already been validated
+  if (store_->has_version(manifest.version)) {
+    return manifest.version;
+  }
+
+  // the unchanged files, e.g. the pointcloud tiles out of the updated region, are taken from the
+  // current version instead of being downloaded again
+  const auto directory = store_->create_staging_directory(manifest.version);
+  std::set<std::string> reused_paths;
+  if (const auto current_version = store_->get_current_version()) {
+    const auto current_directory = store_->get_version_directory(*current_version);
+    const auto current_manifest =
+      load_manifest((std::filesystem::path(current_directory) / manifest_file).string());
+    for (const auto & file : find_unchanged_files(current_manifest, manifest)) {
+      const auto source = std::filesystem::path(current_directory) / file.path;
+      const auto destination = std::filesystem::path(directory) / file.path;
+      std::filesystem::create_directories(destination.parent_path());
+      // the files of a version are never modified, so they can be shared by the hard link
+      std::error_code ec;
+      std::filesystem::create_hard_link(source, destination, ec);
+      if (ec) {
+        std::filesystem::copy_file(source, destination);
+      }
+      reused_paths.insert(file.path);
+    }
+  }
+  for (const auto & file : manifest.files) {
+    if (reused_paths.count(file.path) != 0) {
+      continue;
+    }
+    http_download(
+      area_url + "/" + manifest.version + "/" + file.path,
+      (std::filesystem::path(directory) / file.path).string(), node_param_.download_timeout);
+  }
+  RCLCPP_INFO(
+    get_logger(), "Downloaded %zu files and reused %zu files for the map version %s",
+    manifest.files.size() - reused_paths.size(), reused_paths.size(), manifest.version.c_str());
+
+  auto errors = verify_files(directory, manifest);
+  if (errors.empty()) {
+    errors = validate_map_directory(directory);
+  }
+  if (!errors.empty()) {
+    throw std::runtime_error("invalid map " + manifest.version + ": " + join(errors));
+  }
+
+  std::ofstream(std::filesystem::path(directory) / manifest_file) << dump_manifest(manifest);
+  store_->commit(manifest.version);
+  return manifest.version;
+}
+
+bool MapUpdateClient::is_between_missions() const
+{
+  return route_state_ == RouteState::Message::UNSET ||
+         route_state_ == RouteState::Message::ARRIVED;
+}
+
+void MapUpdateClient::swap_map(const std::string & version)
+{
+  const auto current_version = store_->get_current_version();
+  const auto next_manifest = load_manifest(
+    (std::filesystem::path(store_->get_version_directory(version)) / manifest_file).string());
+
+  // the loaders have loaded nothing from the store when there is no current version
+  bool requires_restart_loaders = true;
+  if (current_version) {
+    const auto current_manifest = load_manifest(
+      (std::filesystem::path(store_->get_version_directory(*current_version)) / manifest_file)
+        .string());
+    requires_restart_loaders = requires_restart(current_manifest, next_manifest);
+  }
+
+  store_->activate(version);
+  store_->remove_old_versions(static_cast<size_t>(node_param_.keep_versions));
+  RCLCPP_INFO(get_logger(), "Activated the map version %s", version.c_str());
+
+  if (requires_restart_loaders) {
+    is_restart_required_ = true;
+    return;
+  }
+  if (!cli_reload_lanelet2_map_->service_is_ready()) {
+    error_message_ = "lanelet2 map loader is not ready to reload";
+    is_restart_required_ = true;
+    return;
+  }
+  cli_reload_lanelet2_map_->async_send_request(
+    std::make_shared<Trigger::Request>(), [this](rclcpp::Client<Trigger>::SharedFuture future) {
+      const auto response = future.get();
+      if (!response->success) {
+        error_message_ = "failed to reload lanelet2 map: " + response->message;
+        is_restart_required_ = true;
+      }
+    });
+}
+
+void MapUpdateClient::check_map_update(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  const auto current_version = store_->get_current_version();
+  stat.add("current_version", current_version.value_or("none"));
+  stat.add("pending_version", pending_version_.value_or("none"));
+
+  if (!error_message_.empty()) {
+    stat.summary(DiagnosticStatus::WARN, error_message_);
+  } else if (is_restart_required_) {
+    stat.summary(DiagnosticStatus::WARN, "restart required to load the new map");
+  } else if (pending_version_) {
+    stat.summary(DiagnosticStatus::OK, "waiting for the end of the mission");
+  } else if (update_.valid()) {
+    stat.summary(DiagnosticStatus::OK, "checking the update");
+  } else {
+    stat.summary(DiagnosticStatus::OK, "up to date");
+  }
+}
+}  // namespace autoware::map_update_client
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::map_update_client::MapUpdateClient)
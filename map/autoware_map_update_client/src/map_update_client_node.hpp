@@ -0,0 +1,98 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef MAP_UPDATE_CLIENT_NODE_HPP_
+#define MAP_UPDATE_CLIENT_NODE_HPP_
+
+#include "map_store.hpp"
+
+#include <autoware/adapi_specs/routing.hpp>
+#include <autoware/component_interface_utils/rclcpp.hpp>
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <std_srvs/srv/trigger.hpp>
+
+#include <future>
+#include <memory>
+#include <optional>
+#include <string>
+
+namespace autoware::map_update_client
+{
+using std_srvs::srv::Trigger;
+
+struct NodeParam
+{
+  double update_rate{};
+  std::string server_url{};
+  std::string area{};
+  std::string map_root{};
+  double check_interval{};
+  double download_timeout{};
+  int keep_versions{};
+};
+
+class MapUpdateClient : public rclcpp::Node
+{
+public:
+  explicit MapUpdateClient(const rclcpp::NodeOptions & node_options);
+  ~MapUpdateClient() override;
+
+private:
+  using RouteState = autoware::adapi_specs::routing::RouteState;
+
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  autoware::component_interface_utils::Subscription<RouteState>::SharedPtr sub_route_state_;
+
+  // Service
+  rclcpp::Service<Trigger>::SharedPtr srv_check_;
+
+  void on_check(const Trigger::Request::SharedPtr req, const Trigger::Response::SharedPtr res);
+
+  // Client
+  rclcpp::Client<Trigger>::SharedPtr cli_reload_lanelet2_map_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_map_update(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  std::unique_ptr<MapStore> store_;
+  RouteState::Message::_state_type route_state_{RouteState::Message::UNKNOWN};
+  // the version to be activated, which is empty when the current version is the latest
+  std::future<std::optional<std::string>> update_;
+  std::optional<std::string> pending_version_;
+  std::optional<rclcpp::Time> last_check_time_;
+  std::string error_message_;
+  bool is_restart_required_{};
+
+  // Function
+  void start_update();
+  std::optional<std::string> fetch_update() const;
+  bool is_between_missions() const;
+  void swap_map(const std::string & version);
+};
+}  // namespace autoware::map_update_client
+
+#endif  // MAP_UPDATE_CLIENT_NODE_HPP_
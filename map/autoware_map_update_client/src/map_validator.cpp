@@ -0,0 +1,120 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "map_validator.hpp"
+
+#include <autoware_lanelet2_extension/io/autoware_osm_parser.hpp>
+
+#include <yaml-cpp/yaml.h>
+
+#include <cctype>
+#include <cstdint>
+#include <filesystem>
+#include <string>
+#include <vector>
+
+namespace autoware::map_update_client
+{
+namespace fs = std::filesystem;
+
+namespace
+{
+void validate_lanelet2_map(const fs::path & directory, std::vector<std::string> & errors)
+{
+  const auto file_path = directory / "lanelet2_map.osm";
+  if (!fs::is_regular_file(file_path)) {
+    errors.emplace_back("missing lanelet2_map.osm");
+    return;
+  }
+
+  std::string format_version{"null"}, map_version{""};
+  lanelet::io_handlers::AutowareOsmParser::parseVersions(
+    file_path.string(), &format_version, &map_version);
+  if (format_version == "null" || format_version.empty() || !isdigit(format_version[0])) {
+    errors.emplace_back("lanelet2_map.osm has no valid format_version");
+    return;
+  }
+  const auto major_version = lanelet::io_handlers::parseMajorVersion(format_version);
+  if (
+    !major_version ||
+    major_version.value() > static_cast<uint64_t>(lanelet::autoware::version)) {
+    errors.emplace_back("unsupported format_version of lanelet2_map.osm: " + format_version);
+  }
+}
+
+void validate_pointcloud_map(const fs::path & directory, std::vector<std::string> & errors)
+{
+  // the pointcloud map is either a single file or a directory of divided files
+  const auto pcd_file_path = directory / "pointcloud_map.pcd";
+  const auto pcd_directory = directory / "pointcloud_map";
+  if (!fs::is_regular_file(pcd_file_path) && !fs::is_directory(pcd_directory)) {
+    errors.emplace_back("missing pointcloud_map.pcd or pointcloud_map/");
+    return;
+  }
+
+  const auto metadata_path = directory / "pointcloud_map_metadata.yaml";
+  if (!fs::is_regular_file(metadata_path)) {
+    return;
+  }
+
+  try {
+    const auto metadata = YAML::LoadFile(metadata_path.string());
+    if (!metadata["x_resolution"] || !metadata["y_resolution"]) {
+      errors.emplace_back("pointcloud_map_metadata.yaml has no x_resolution or y_resolution");
+    }
+    for (const auto & node : metadata) {
+      const auto key = node.first.as<std::string>();
+      if (key == "x_resolution" || key == "y_resolution") {
+        continue;
+      }
+      if (node.second.as<std::vector<int>>().size() != 2) {
+        errors.emplace_back("pointcloud_map_metadata.yaml has an invalid origin of " + key);
+      }
+      if (!fs::is_regular_file(pcd_directory / key)) {
+        errors.emplace_back("missing pointcloud_map/" + key + " listed in the metadata");
+      }
+    }
+  } catch (const YAML::Exception & e) {
+    errors.emplace_back(std::string("malformed pointcloud_map_metadata.yaml: ") + e.what());
+  }
+}
+
+void validate_map_projector_info(const fs::path & directory, std::vector<std::string> & errors)
+{
+  // the projector info is optional, and it is read from the lanelet2 map when missing
+  const auto file_path = directory / "map_projector_info.yaml";
+  if (!fs::is_regular_file(file_path)) {
+    return;
+  }
+
+  try {
+    const auto info = YAML::LoadFile(file_path.string());
+    if (!info["projector_type"]) {
+      errors.emplace_back("map_projector_info.yaml has no projector_type");
+    }
+  } catch (const YAML::Exception & e) {
+    errors.emplace_back(std::string("malformed map_projector_info.yaml: ") + e.what());
+  }
+}
+}  // namespace
+
+std::vector<std::string> validate_map_directory(const std::string & directory)
+{
+  std::vector<std::string> errors;
+  validate_lanelet2_map(directory, errors);
+  validate_pointcloud_map(directory, errors);
+  validate_map_projector_info(directory, errors);
+  return errors;
+}
+}  // namespace autoware::map_update_client
@@ -0,0 +1,30 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef MAP_VALIDATOR_HPP_
+#define MAP_VALIDATOR_HPP_
+
+#include <string>
+#include <vector>
+
+namespace autoware::map_update_client
+{
+/**
+ * @brief checks that a map directory can be loaded by the map loaders
+ * @return the error messages, which is empty when the directory is valid
+ */
+std::vector<std::string> validate_map_directory(const std::string & directory);
+}  // namespace autoware::map_update_client
+
+#endif  // MAP_VALIDATOR_HPP_
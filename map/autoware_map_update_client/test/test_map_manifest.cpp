@@ -0,0 +1,146 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "map_manifest.hpp"
+
+#include <gtest/gtest.h>
+
+#include <filesystem>
+#include <fstream>
+#include <string>
+
+using autoware::map_update_client::is_safe_path;
+using autoware::map_update_client::MapFile;
+using autoware::map_update_client::MapManifest;
+using autoware::map_update_client::parse_manifest;
+
+namespace fs = std::filesystem;
+
+namespace
+{
+// sha256 of "abc"
+constexpr char abc_sha256[] = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+MapManifest create_manifest(const std::string & lanelet2_sha256, const std::string & pcd_sha256)
+{
+  MapManifest manifest;
+  manifest.version = "v1";
+  manifest.files.push_back(MapFile{"lanelet2_map.osm", lanelet2_sha256, 3});
+  manifest.files.push_back(MapFile{"pointcloud_map/0_0.pcd", pcd_sha256, 3});
+  return manifest;
+}
+}  // namespace
+
+TEST(MapManifest, parse_manifest)
+{
+  const auto manifest = parse_manifest(
+    std::string(R"({"version": "2025.01", "files": [{"path": "lanelet2_map.osm", "sha256": ")") +
+    abc_sha256 + R"(", "size": 3}]})");
+  EXPECT_EQ(manifest.version, "2025.01");
+  ASSERT_EQ(manifest.files.size(), 1u);
+  EXPECT_EQ(manifest.files.front().path, "lanelet2_map.osm");
+  EXPECT_EQ(manifest.files.front().sha256, abc_sha256);
+  EXPECT_EQ(manifest.files.front().size, 3u);
+
+  using autoware::map_update_client::dump_manifest;
+  const auto dumped = parse_manifest(dump_manifest(manifest));
+  EXPECT_EQ(dumped.version, manifest.version);
+  EXPECT_EQ(dumped.files.size(), 1u);
+}
+
+TEST(MapManifest, parse_invalid_manifest)
+{
+  EXPECT_THROW(parse_manifest("{"), std::invalid_argument);
+  EXPECT_THROW(parse_manifest(R"({"files": []})"), std::invalid_argument);
+  EXPECT_THROW(parse_manifest(R"({"version": "", "files": []})"), std::invalid_argument);
+  EXPECT_THROW(
+    parse_manifest(std::string(R"({"version": "v1", "files": [{"path": "../a", "sha256": ")") +
+                   abc_sha256 + R"(", "size": 3}]})"),
+    std::invalid_argument);
+  EXPECT_THROW(
+    parse_manifest(R"({"version": "v1", "files": [{"path": "a", "sha256": "abc", "size": 3}]})"),
+    std::invalid_argument);
+}
+
+TEST(MapManifest, is_safe_path)
+{
+  EXPECT_TRUE(is_safe_path("lanelet2_map.osm"));
+  EXPECT_TRUE(is_safe_path("pointcloud_map/0_0.pcd"));
+  EXPECT_FALSE(is_safe_path(""));
+  EXPECT_FALSE(is_safe_path("/etc/passwd"));
+  EXPECT_FALSE(is_safe_path("../lanelet2_map.osm"));
+  EXPECT_FALSE(is_safe_path("pointcloud_map/../../a.pcd"));
+}
+
+TEST(MapManifest, verify_files)
+{
+  const auto directory = fs::temp_directory_path() / "test_map_manifest";
+  fs::remove_all(directory);
+  fs::create_directories(directory / "pointcloud_map");
+  std::ofstream(directory / "lanelet2_map.osm") << "abc";
+  std::ofstream(directory / "pointcloud_map" / "0_0.pcd") << "abd";
+
+  using autoware::map_update_client::calc_sha256;
+  using autoware::map_update_client::verify_files;
+  EXPECT_EQ(calc_sha256((directory / "lanelet2_map.osm").string()), abc_sha256);
+
+  // the content of the pointcloud map is different
+  EXPECT_EQ(verify_files(directory.string(), create_manifest(abc_sha256, abc_sha256)).size(), 1u);
+
+  std::ofstream(directory / "pointcloud_map" / "0_0.pcd") << "abc";
+  EXPECT_TRUE(verify_files(directory.string(), create_manifest(abc_sha256, abc_sha256)).empty());
+
+  fs::remove(directory / "lanelet2_map.osm");
+  EXPECT_EQ(verify_files(directory.string(), create_manifest(abc_sha256, abc_sha256)).size(), 1u);
+
+  fs::remove_all(directory);
+}
+
+TEST(MapManifest, requires_restart)
+{
+  using autoware::map_update_client::requires_restart;
+  const std::string other_sha256(64, '0');
+
+  // only the lanelet2 map is changed
+  EXPECT_FALSE(requires_restart(
+    create_manifest(abc_sha256, abc_sha256), create_manifest(other_sha256, abc_sha256)));
+  // the pointcloud map is changed
+  EXPECT_TRUE(requires_restart(
+    create_manifest(abc_sha256, abc_sha256), create_manifest(abc_sha256, other_sha256)));
+  // a file is added
+  auto manifest = create_manifest(abc_sha256, abc_sha256);
+  manifest.files.push_back(MapFile{"map_projector_info.yaml", abc_sha256, 3});
+  EXPECT_TRUE(requires_restart(create_manifest(abc_sha256, abc_sha256), manifest));
+}
+
+TEST(MapManifest, find_unchanged_files)
+{
+  using autoware::map_update_client::find_unchanged_files;
+  const std::string other_sha256(64, '0');
+
+  // only the pointcloud map is unchanged
+  const auto unchanged_files = find_unchanged_files(
+    create_manifest(abc_sha256, abc_sha256), create_manifest(other_sha256, abc_sha256));
+  ASSERT_EQ(unchanged_files.size(), 1u);
+  EXPECT_EQ(unchanged_files.front().path, "pointcloud_map/0_0.pcd");
+
+  // the size is also compared
+  auto manifest = create_manifest(abc_sha256, abc_sha256);
+  manifest.files.at(0).size = 4;
+  EXPECT_EQ(find_unchanged_files(create_manifest(abc_sha256, abc_sha256), manifest).size(), 1u);
+
+  // a new file is not unchanged
+  manifest.files.push_back(MapFile{"map_projector_info.yaml", abc_sha256, 3});
+  EXPECT_EQ(find_unchanged_files(create_manifest(abc_sha256, abc_sha256), manifest).size(), 1u);
+}
@@ -0,0 +1,115 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "map_store.hpp"
+
+#include <gtest/gtest.h>
+
+#include <filesystem>
+#include <fstream>
+#include <string>
+
+using autoware::map_update_client::MapStore;
+
+namespace fs = std::filesystem;
+
+namespace
+{
+class MapStoreTest : public ::testing::Test
+{
+protected:
+  void SetUp() override
+  {
+    map_root_ = fs::temp_directory_path() / "test_map_update_client";
+    fs::remove_all(map_root_);
+  }
+
+  void TearDown() override { fs::remove_all(map_root_); }
+
+  void add_version(const MapStore & store, const std::string & version)
+  {
+    const auto directory = store.create_staging_directory(version);
+    std::ofstream(fs::path(directory) / "lanelet2_map.osm") << version;
+    store.commit(version);
+  }
+
+  fs::path map_root_;
+};
+}  // namespace
+
+TEST_F(MapStoreTest, empty)
+{
+  const MapStore store(map_root_.string());
+  EXPECT_FALSE(store.get_current_version());
+  EXPECT_FALSE(store.has_version("v1"));
+}
+
+TEST_F(MapStoreTest, commit)
+{
+  const MapStore store(map_root_.string());
+  const auto directory = store.create_staging_directory("v1");
+  EXPECT_FALSE(store.has_version("v1"));
+
+  std::ofstream(fs::path(directory) / "lanelet2_map.osm") << "v1";
+  store.commit("v1");
+  EXPECT_TRUE(store.has_version("v1"));
+  EXPECT_TRUE(
+    fs::is_regular_file(fs::path(store.get_version_directory("v1")) / "lanelet2_map.osm"));
+  EXPECT_FALSE(fs::exists(directory));
+}
+
+TEST_F(MapStoreTest, activate)
+{
+  const MapStore store(map_root_.string());
+  add_version(store, "v1");
+  add_version(store, "v2");
+
+  store.activate("v1");
+  EXPECT_EQ(store.get_current_version(), "v1");
+  store.activate("v2");
+  EXPECT_EQ(store.get_current_version(), "v2");
+
+  std::ifstream ifs(map_root_ / "current" / "lanelet2_map.osm");
+  std::string content;
+  ifs >> content;
+  EXPECT_EQ(content, "v2");
+
+  EXPECT_THROW(store.activate("v3"), std::invalid_argument);
+  EXPECT_EQ(store.get_current_version(), "v2");
+}
+
+TEST_F(MapStoreTest, invalid_version)
+{
+  const MapStore store(map_root_.string());
+  EXPECT_THROW(store.create_staging_directory(""), std::invalid_argument);
+  EXPECT_THROW(store.create_staging_directory(".."), std::invalid_argument);
+  EXPECT_THROW(store.create_staging_directory("current"), std::invalid_argument);
+  EXPECT_THROW(store.create_staging_directory("a/b"), std::invalid_argument);
+}
+
+TEST_F(MapStoreTest, remove_old_versions)
+{
+  const MapStore store(map_root_.string());
+  add_version(store, "v1");
+  add_version(store, "v2");
+  add_version(store, "v3");
+  fs::last_write_time(store.get_version_directory("v2"), fs::file_time_type::clock::now());
+  store.activate("v1");
+
+  store.remove_old_versions(1);
+  EXPECT_TRUE(store.has_version("v1"));
+  EXPECT_TRUE(store.has_version("v2"));
+  EXPECT_FALSE(store.has_version("v3"));
+  EXPECT_EQ(store.get_current_version(), "v1");
+}
@@ -0,0 +1,68 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "near_field_belt.hpp"
+
+#include <algorithm>
+#include <cmath>
+
+namespace autoware::near_field_guard
+{
+double calc_clearance(const Footprint & footprint, const EchoPoint & point)
+{
+  const double dx = std::max({footprint.rear - point.x, 0.0, point.x - footprint.front});
+  const double dy = std::max({footprint.right - point.y, 0.0, point.y - footprint.left});
+  return std::hypot(dx, dy);
+}
+
+std::optional<double> calc_min_clearance(
+  const Footprint & footprint, const std::vector<EchoPoint> & points,
+  const TravelDirection direction)
+{
+  std::optional<double> min_clearance;
+  for (const auto & point : points) {
+    if (direction == TravelDirection::FORWARD && point.x < footprint.rear) {
+      continue;
+    }
+    if (direction == TravelDirection::BACKWARD && point.x > footprint.front) {
+      continue;
+    }
+    const double clearance = calc_clearance(footprint, point);
+    if (!min_clearance || clearance < *min_clearance) {
+      min_clearance = clearance;
+    }
+  }
+  return min_clearance;
+}
+
+GuardLevel decide_level(
+  const std::optional<double> & min_clearance, const GuardLevel current_level,
+  const BeltParam & param)
+{
+  if (!min_clearance) {
+    return GuardLevel::CLEAR;
+  }
+
+  // the hysteresis is applied to the current level and the levels below it
+  const double stop_margin = current_level == GuardLevel::STOP ? param.hysteresis_distance : 0.0;
+  const double creep_margin = current_level != GuardLevel::CLEAR ? param.hysteresis_distance : 0.0;
+  if (*min_clearance < param.stop_distance + stop_margin) {
+    return GuardLevel::STOP;
+  }
+  if (*min_clearance < param.creep_distance + creep_margin) {
+    return GuardLevel::CREEP;
+  }
+  return GuardLevel::CLEAR;
+}
+}  // namespace autoware::near_field_guard
@@ -0,0 +1,63 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef NEAR_FIELD_BELT_HPP_
+#define NEAR_FIELD_BELT_HPP_
+
+#include <optional>
+#include <vector>
+
+namespace autoware::near_field_guard
+{
+// offsets of the edges of the vehicle from base_link, where rear and right are negative
+struct Footprint
+{
+  double front{};
+  double rear{};
+  double left{};
+  double right{};
+};
+
+// point of an echo in base_link
+struct EchoPoint
+{
+  double x{};
+  double y{};
+};
+
+struct BeltParam
+{
+  double stop_distance{};
+  double creep_distance{};
+  // added to the distances to release the current level
+  double hysteresis_distance{};
+};
+
+enum class TravelDirection { FORWARD, BACKWARD };
+
+enum class GuardLevel { CLEAR = 0, CREEP = 1, STOP = 2 };
+
+// returns 0 when the point is inside the footprint
+double calc_clearance(const Footprint & footprint, const EchoPoint & point);
+
+// The echoes behind the rear end for FORWARD and ahead of the front end for BACKWARD are ignored,
+// since the ego moves away from them. Returns nullopt when no echo is left.
+std::optional<double> calc_min_clearance(
+  const Footprint & footprint, const std::vector<EchoPoint> & points, TravelDirection direction);
+
+GuardLevel decide_level(
+  const std::optional<double> & min_clearance, GuardLevel current_level, const BeltParam & param);
+}  // namespace autoware::near_field_guard
+
+#endif  // NEAR_FIELD_BELT_HPP_
@@ -0,0 +1,275 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "near_field_guard_node.hpp"
+
+#include <autoware_vehicle_info_utils/vehicle_info_utils.hpp>
+
+#include <algorithm>
+#include <cmath>
+#include <limits>
+#include <string>
+#include <vector>
+
+namespace autoware::near_field_guard
+{
+namespace
+{
+std::string join(const std::vector<std::string> & names)
+{
+  std::string joined;
+  for (const auto & name : names) {
+    joined += (joined.empty() ? "" : ", ") + name;
+  }
+  return joined;
+}
+
+const char * to_string(const GuardLevel level)
+{
+  switch (level) {
+    case GuardLevel::STOP:
+      return "STOP";
+    case GuardLevel::CREEP:
+      return "CREEP";
+    default:
+      return "CLEAR";
+  }
+}
+}  // namespace
+
+NearFieldGuard::NearFieldGuard(const rclcpp::NodeOptions & node_options)
+: Node("near_field_guard", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.update_rate = declare_parameter<double>("update_rate");
+  node_param_.sensor_frames = declare_parameter<std::vector<std::string>>("sensor_frames");
+  node_param_.range_timeout = declare_parameter<double>("range_timeout");
+  node_param_.belt.stop_distance = declare_parameter<double>("stop_distance");
+  node_param_.belt.creep_distance = declare_parameter<double>("creep_distance");
+  node_param_.belt.hysteresis_distance = declare_parameter<double>("hysteresis_distance");
+  node_param_.state_clear_time = declare_parameter<double>("state_clear_time");
+  node_param_.creep_speed = declare_parameter<double>("creep_speed");
+  node_param_.parking_scenario_only = declare_parameter<bool>("activation.parking_scenario_only");
+  node_param_.activation_max_speed = declare_parameter<double>("activation.max_speed");
+  node_param_.min_acceleration = declare_parameter<double>("velocity_limit.min_acceleration");
+  node_param_.max_jerk = declare_parameter<double>("velocity_limit.max_jerk");
+  node_param_.min_jerk = declare_parameter<double>("velocity_limit.min_jerk");
+  node_param_.data_timeout = declare_parameter<double>("data_timeout");
+
+  const auto vehicle_info = autoware::vehicle_info_utils::VehicleInfoUtils(*this).getVehicleInfo();
+  footprint_.front = vehicle_info.max_longitudinal_offset_m;
+  footprint_.rear = vehicle_info.min_longitudinal_offset_m;
+  footprint_.left = vehicle_info.max_lateral_offset_m;
+  footprint_.right = vehicle_info.min_lateral_offset_m;
+
+  // the sensors are fixed on the vehicle
+  managed_tf_buffer_ =
+    std::make_unique<autoware::universe_utils::ManagedTransformBuffer>(this, true);
+
+  // Subscriber
+  sub_range_ = create_subscription<Range>(
+    "input/range", rclcpp::SensorDataQoS(),
+    [this](const Range::ConstSharedPtr msg) { ranges_[msg->header.frame_id] = msg; });
+  sub_kinematic_state_ = create_subscription<Odometry>(
+    "input/kinematic_state", rclcpp::QoS{1},
+    [this](const Odometry::ConstSharedPtr msg) { kinematic_state_ = msg; });
+  sub_gear_status_ = create_subscription<GearReport>(
+    "input/gear_status", rclcpp::QoS{1},
+    [this](const GearReport::ConstSharedPtr msg) { gear_status_ = msg; });
+  sub_scenario_ = create_subscription<Scenario>(
+    "input/scenario", rclcpp::QoS{1},
+    [this](const Scenario::ConstSharedPtr msg) { scenario_ = msg; });
+
+  // Publisher
+  pub_velocity_limit_ = create_publisher<VelocityLimit>(
+    "output/velocity_limit", rclcpp::QoS{1}.transient_local());
+  pub_velocity_limit_clear_command_ = create_publisher<VelocityLimitClearCommand>(
+    "output/velocity_limit_clear_command", rclcpp::QoS{1}.transient_local());
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.update_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&NearFieldGuard::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("near_field_guard");
+  updater_.add("near_field", this, &NearFieldGuard::check_near_field);
+}
+
+void NearFieldGuard::on_timer()
+{
+  if (
+    !kinematic_state_ ||
+    (now() - kinematic_state_->header.stamp).seconds() > node_param_.data_timeout) {
+    return;
+  }
+
+  // the guard is kept active until the limit is released
+  is_active_ = level_ != GuardLevel::CLEAR || is_activated();
+  const auto points = get_echo_points();
+  if (!is_active_) {
+    min_clearance_.reset();
+    update_level(GuardLevel::CLEAR);
+    return;
+  }
+
+  // the ego does not move while the near field is not monitored
+  if (!lost_sensors_.empty()) {
+    min_clearance_.reset();
+    update_level(GuardLevel::STOP);
+    return;
+  }
+
+  const bool is_reverse =
+    gear_status_ &&
+    (gear_status_->report == GearReport::REVERSE || gear_status_->report == GearReport::REVERSE_2);
+  const auto direction = is_reverse ? TravelDirection::BACKWARD : TravelDirection::FORWARD;
+  min_clearance_ = calc_min_clearance(footprint_, points, direction);
+  update_level(decide_level(min_clearance_, level_, node_param_.belt));
+}
+
+bool NearFieldGuard::is_activated() const
+{
+  // the ultrasonic sensors are too short-ranged to protect the ego at a higher speed
+  if (std::abs(kinematic_state_->twist.twist.linear.x) > node_param_.activation_max_speed) {
+    return false;
+  }
+  if (!node_param_.parking_scenario_only) {
+    return true;
+  }
+  if (!scenario_) {
+    return false;
+  }
+  const auto & scenarios = scenario_->activating_scenarios;
+  return std::any_of(scenarios.begin(), scenarios.end(), [](const auto & scenario) {
+    return scenario == Scenario::PARKING;
+  });
+}
+
+std::vector<EchoPoint> NearFieldGuard::get_echo_points()
+{
+  const auto current_time = now();
+  std::vector<EchoPoint> points;
+  lost_sensors_.clear();
+  for (const auto & frame : node_param_.sensor_frames) {
+    const auto it = ranges_.find(frame);
+    if (
+      it == ranges_.end() ||
+      (current_time - it->second->header.stamp).seconds() > node_param_.range_timeout) {
+      lost_sensors_.push_back(frame);
+      continue;
+    }
+
+    // +inf is no echo, and -inf is an object closer than min_range which is placed at min_range
+    const auto & range = *it->second;
+    if (std::isnan(range.range) || range.range == std::numeric_limits<float>::infinity()) {
+      continue;
+    }
+    const float distance = std::isinf(range.range) ? range.min_range : range.range;
+
+    Eigen::Matrix4f transform;
+    if (!managed_tf_buffer_->getTransform("base_link", frame, transform)) {
+      lost_sensors_.push_back(frame);
+      continue;
+    }
+
+    // the object can be anywhere in the cone, so the center and the both edges are checked
+    const float half_fov = range.field_of_view / 2.0f;
+    for (const float angle : {-half_fov, 0.0f, half_fov}) {
+      const Eigen::Vector4f point(
+        distance * std::cos(angle), distance * std::sin(angle), 0.0f, 1.0f);
+      const Eigen::Vector4f transformed = transform * point;
+      points.push_back(EchoPoint{transformed.x(), transformed.y()});
+    }
+  }
+  return points;
+}
+
+void NearFieldGuard::update_level(const GuardLevel level)
+{
+  if (level >= level_) {
+    release_start_time_.reset();
+    if (level != level_) {
+      level_ = level;
+      publish_velocity_limit(level_);
+    }
+    return;
+  }
+
+  // a lower level is applied after state_clear_time to prevent chattering
+  const auto current_time = now();
+  if (!release_start_time_) {
+    release_start_time_ = current_time;
+  }
+  if ((current_time - *release_start_time_).seconds() < node_param_.state_clear_time) {
+    return;
+  }
+  release_start_time_.reset();
+  level_ = level;
+  publish_velocity_limit(level_);
+}
+
+void NearFieldGuard::publish_velocity_limit(const GuardLevel level)
+{
+  if (level == GuardLevel::CLEAR) {
+    VelocityLimitClearCommand clear_command;
+    clear_command.stamp = now();
+    clear_command.command = true;
+    clear_command.sender = "near_field_guard";
+    pub_velocity_limit_clear_command_->publish(clear_command);
+    RCLCPP_INFO(get_logger(), "reset velocity limit");
+    return;
+  }
+
+  VelocityLimit velocity_limit;
+  velocity_limit.stamp = now();
+  velocity_limit.max_velocity =
+    level == GuardLevel::STOP ? 0.0f : static_cast<float>(node_param_.creep_speed);
+  velocity_limit.use_constraints = true;
+  velocity_limit.constraints.min_acceleration = static_cast<float>(node_param_.min_acceleration);
+  velocity_limit.constraints.max_jerk = static_cast<float>(node_param_.max_jerk);
+  velocity_limit.constraints.min_jerk = static_cast<float>(node_param_.min_jerk);
+  velocity_limit.sender = "near_field_guard";
+  pub_velocity_limit_->publish(velocity_limit);
+  RCLCPP_INFO(get_logger(), "set velocity limit: %.2f [m/s]", velocity_limit.max_velocity);
+}
+
+void NearFieldGuard::check_near_field(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  if (!kinematic_state_) {
+    stat.summary(DiagnosticStatus::STALE, "no ego state");
+    return;
+  }
+
+  stat.add("is_active", is_active_);
+  stat.add("level", to_string(level_));
+  if (min_clearance_) {
+    stat.addf("min_clearance", "%.2f [m]", *min_clearance_);
+  }
+
+  // the lost sensors matter only while the guard is active
+  if (!lost_sensors_.empty()) {
+    stat.summary(
+      is_active_ ? DiagnosticStatus::ERROR : DiagnosticStatus::WARN,
+      "no range from " + join(lost_sensors_));
+    return;
+  }
+  stat.summary(DiagnosticStatus::OK, "OK");
+}
+}  // namespace autoware::near_field_guard
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::near_field_guard::NearFieldGuard)
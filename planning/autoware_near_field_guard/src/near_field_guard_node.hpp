@@ -0,0 +1,113 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef NEAR_FIELD_GUARD_NODE_HPP_
+#define NEAR_FIELD_GUARD_NODE_HPP_
+
+#include "near_field_belt.hpp"
+
+#include <autoware/universe_utils/ros/managed_transform_buffer.hpp>
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_vehicle_msgs/msg/gear_report.hpp>
+#include <nav_msgs/msg/odometry.hpp>
+#include <sensor_msgs/msg/range.hpp>
+#include <tier4_planning_msgs/msg/scenario.hpp>
+#include <tier4_planning_msgs/msg/velocity_limit.hpp>
+#include <tier4_planning_msgs/msg/velocity_limit_clear_command.hpp>
+
+#include <memory>
+#include <optional>
+#include <string>
+#include <unordered_map>
+#include <vector>
+
+namespace autoware::near_field_guard
+{
+using autoware_vehicle_msgs::msg::GearReport;
+using nav_msgs::msg::Odometry;
+using sensor_msgs::msg::Range;
+using tier4_planning_msgs::msg::Scenario;
+using tier4_planning_msgs::msg::VelocityLimit;
+using tier4_planning_msgs::msg::VelocityLimitClearCommand;
+
+struct NodeParam
+{
+  double update_rate{};
+  std::vector<std::string> sensor_frames;
+  double range_timeout{};
+  BeltParam belt{};
+  double state_clear_time{};
+  double creep_speed{};
+  bool parking_scenario_only{};
+  double activation_max_speed{};
+  double min_acceleration{};
+  double max_jerk{};
+  double min_jerk{};
+  double data_timeout{};
+};
+
+class NearFieldGuard : public rclcpp::Node
+{
+public:
+  explicit NearFieldGuard(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+  Footprint footprint_{};
+
+  // Subscriber
+  rclcpp::Subscription<Range>::SharedPtr sub_range_;
+  rclcpp::Subscription<Odometry>::SharedPtr sub_kinematic_state_;
+  rclcpp::Subscription<GearReport>::SharedPtr sub_gear_status_;
+  rclcpp::Subscription<Scenario>::SharedPtr sub_scenario_;
+
+  // Publisher
+  rclcpp::Publisher<VelocityLimit>::SharedPtr pub_velocity_limit_;
+  rclcpp::Publisher<VelocityLimitClearCommand>::SharedPtr pub_velocity_limit_clear_command_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_near_field(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  std::unique_ptr<autoware::universe_utils::ManagedTransformBuffer> managed_tf_buffer_;
+  std::unordered_map<std::string, Range::ConstSharedPtr> ranges_;
+  Odometry::ConstSharedPtr kinematic_state_;
+  GearReport::ConstSharedPtr gear_status_;
+  Scenario::ConstSharedPtr scenario_;
+  bool is_active_{false};
+  GuardLevel level_{GuardLevel::CLEAR};
+  std::optional<double> min_clearance_;
+  std::vector<std::string> lost_sensors_;
+  // the time since when a lower level than the current one is decided
+  std::optional<rclcpp::Time> release_start_time_;
+
+  // Function
+  bool is_activated() const;
+  std::vector<EchoPoint> get_echo_points();
+  void update_level(GuardLevel level);
+  void publish_velocity_limit(GuardLevel level);
+};
+}  // namespace autoware::near_field_guard
+
+#endif  // NEAR_FIELD_GUARD_NODE_HPP_
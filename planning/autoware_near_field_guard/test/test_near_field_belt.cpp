@@ -0,0 +1,95 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "near_field_belt.hpp"
+
+#include <gtest/gtest.h>
+
+#include <vector>
+
+using autoware::near_field_guard::BeltParam;
+using autoware::near_field_guard::EchoPoint;
+using autoware::near_field_guard::Footprint;
+using autoware::near_field_guard::GuardLevel;
+using autoware::near_field_guard::TravelDirection;
+
+namespace
+{
+Footprint create_footprint()
+{
+  Footprint footprint;
+  footprint.front = 3.5;
+  footprint.rear = -1.0;
+  footprint.left = 0.9;
+  footprint.right = -0.9;
+  return footprint;
+}
+
+BeltParam create_param()
+{
+  BeltParam param;
+  param.stop_distance = 0.3;
+  param.creep_distance = 1.0;
+  param.hysteresis_distance = 0.1;
+  return param;
+}
+}  // namespace
+
+TEST(NearFieldBelt, CalcClearance)
+{
+  using autoware::near_field_guard::calc_clearance;
+  const auto footprint = create_footprint();
+  EXPECT_DOUBLE_EQ(calc_clearance(footprint, EchoPoint{4.0, 0.0}), 0.5);
+  EXPECT_DOUBLE_EQ(calc_clearance(footprint, EchoPoint{-1.5, 0.5}), 0.5);
+  EXPECT_DOUBLE_EQ(calc_clearance(footprint, EchoPoint{1.0, -1.2}), 0.3);
+  EXPECT_DOUBLE_EQ(calc_clearance(footprint, EchoPoint{6.5, 4.9}), 5.0);
+  EXPECT_DOUBLE_EQ(calc_clearance(footprint, EchoPoint{1.0, 0.0}), 0.0);
+}
+
+TEST(NearFieldBelt, CalcMinClearance)
+{
+  using autoware::near_field_guard::calc_min_clearance;
+  const auto footprint = create_footprint();
+  const std::vector<EchoPoint> points{{4.5, 0.0}, {-1.2, 0.0}, {0.0, 1.5}};
+
+  const auto forward = calc_min_clearance(footprint, points, TravelDirection::FORWARD);
+  ASSERT_TRUE(forward.has_value());
+  EXPECT_DOUBLE_EQ(*forward, 0.6);
+
+  const auto backward = calc_min_clearance(footprint, points, TravelDirection::BACKWARD);
+  ASSERT_TRUE(backward.has_value());
+  EXPECT_DOUBLE_EQ(*backward, 0.2);
+
+  const std::vector<EchoPoint> behind{{-1.2, 0.0}};
+  EXPECT_FALSE(calc_min_clearance(footprint, behind, TravelDirection::FORWARD).has_value());
+}
+
+TEST(NearFieldBelt, DecideLevel)
+{
+  using autoware::near_field_guard::decide_level;
+  const auto param = create_param();
+
+  EXPECT_EQ(decide_level(std::nullopt, GuardLevel::STOP, param), GuardLevel::CLEAR);
+  EXPECT_EQ(decide_level(0.2, GuardLevel::CLEAR, param), GuardLevel::STOP);
+  EXPECT_EQ(decide_level(0.5, GuardLevel::CLEAR, param), GuardLevel::CREEP);
+  EXPECT_EQ(decide_level(1.5, GuardLevel::CLEAR, param), GuardLevel::CLEAR);
+
+  // hysteresis
+  EXPECT_EQ(decide_level(0.35, GuardLevel::CREEP, param), GuardLevel::CREEP);
+  EXPECT_EQ(decide_level(0.35, GuardLevel::STOP, param), GuardLevel::STOP);
+  EXPECT_EQ(decide_level(0.45, GuardLevel::STOP, param), GuardLevel::CREEP);
+  EXPECT_EQ(decide_level(1.05, GuardLevel::CLEAR, param), GuardLevel::CLEAR);
+  EXPECT_EQ(decide_level(1.05, GuardLevel::CREEP, param), GuardLevel::CREEP);
+  EXPECT_EQ(decide_level(1.05, GuardLevel::STOP, param), GuardLevel::CREEP);
+}
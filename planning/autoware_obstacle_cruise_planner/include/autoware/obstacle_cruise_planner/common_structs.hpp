@@ -43,6 +43,7 @@ struct PlannerData
   double ego_vel;
   double ego_acc;
   bool is_driving_forward;
+  double friction_ratio{1.0};  // ratio of the friction coefficient to the nominal one
 };
 
 struct PoseWithStamp
@@ -122,7 +122,8 @@ private:
     const Obstacle & obstacle, const double precise_lat_dist);
   PlannerData createPlannerData(
     const Odometry & odometry, const AccelWithCovarianceStamped & acc,
-    const std::vector<TrajectoryPoint> & traj_points) const;
+    const std::vector<TrajectoryPoint> & traj_points,
+    const Float64Stamped::ConstSharedPtr friction_coefficient_ptr) const;
 
   void checkConsistency(
     const rclcpp::Time & current_time, const PredictedObjects & predicted_objects,
@@ -148,6 +149,10 @@ private:
   double additional_safe_distance_margin_on_curve_;
   double min_safe_distance_margin_on_curve_;
   bool suppress_sudden_obstacle_stop_;
+  bool enable_friction_derating_;
+  double nominal_friction_coefficient_;
+  double min_friction_ratio_;
+  double friction_coefficient_timeout_;
 
   std::vector<int> inside_stop_obstacle_types_;
   std::vector<int> outside_stop_obstacle_types_;
@@ -181,6 +186,8 @@ private:
     this, "~/input/pointcloud"};
   autoware::universe_utils::InterProcessPollingSubscriber<AccelWithCovarianceStamped> acc_sub_{
     this, "~/input/acceleration"};
+  autoware::universe_utils::InterProcessPollingSubscriber<Float64Stamped>
+    friction_coefficient_sub_{this, "~/input/friction_coefficient"};
   autoware::objects_of_interest_marker_interface::ObjectsOfInterestMarkerInterface
     objects_of_interest_marker_interface_{this, "obstacle_cruise_planner"};
 
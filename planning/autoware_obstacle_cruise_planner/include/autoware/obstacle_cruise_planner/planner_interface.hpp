@@ -169,11 +169,13 @@ protected:
     const PlannerData & planner_data, const geometry_msgs::msg::Point & collision_point);
 
   double calcRSSDistance(
-    const double ego_vel, const double obstacle_vel, const double margin = 0.0) const
+    const double ego_vel, const double obstacle_vel, const double margin = 0.0,
+    const double friction_ratio = 1.0) const
   {
     const auto & i = longitudinal_info_;
+    const double min_ego_accel_for_rss = i.min_ego_accel_for_rss * friction_ratio;
     const double rss_dist_with_margin =
-      ego_vel * i.idling_time + std::pow(ego_vel, 2) * 0.5 / std::abs(i.min_ego_accel_for_rss) -
+      ego_vel * i.idling_time + std::pow(ego_vel, 2) * 0.5 / std::abs(min_ego_accel_for_rss) -
       std::pow(obstacle_vel, 2) * 0.5 / std::abs(i.min_object_accel_for_rss) + margin;
     return rss_dist_with_margin;
   }
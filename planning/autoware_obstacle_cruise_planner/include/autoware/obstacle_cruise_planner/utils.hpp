@@ -38,6 +38,10 @@ PoseWithStamp getCurrentObjectPose(
 
 std::vector<StopObstacle> getClosestStopObstacles(const std::vector<StopObstacle> & stop_obstacles);
 
+double calcFrictionRatio(
+  const double friction_coefficient, const double nominal_friction_coefficient,
+  const double min_ratio);
+
 template <class T>
 size_t getIndexWithLongitudinalOffset(
   const T & points, const double longitudinal_offset, std::optional<size_t> start_idx)
@@ -569,6 +569,11 @@ ObstacleCruisePlannerNode::ObstacleCruisePlannerNode(const rclcpp::NodeOptions &
       declare_parameter<double>("common.stop_on_curve.min_safe_distance_margin");
     suppress_sudden_obstacle_stop_ =
       declare_parameter<bool>("common.suppress_sudden_obstacle_stop");
+    enable_friction_derating_ = declare_parameter<bool>("common.friction_derating.enable");
+    nominal_friction_coefficient_ =
+      declare_parameter<double>("common.friction_derating.nominal_friction_coefficient");
+    min_friction_ratio_ = declare_parameter<double>("common.friction_derating.min_ratio");
+    friction_coefficient_timeout_ = declare_parameter<double>("common.friction_derating.timeout");
     planner_ptr_->setParam(
       enable_debug_info_, enable_calculation_time_info_, use_pointcloud_, min_behavior_stop_margin_,
       enable_approaching_on_curve_, additional_safe_distance_margin_on_curve_,
@@ -632,6 +637,16 @@ rcl_interfaces::msg::SetParametersResult ObstacleCruisePlannerNode::onParam(
   autoware::universe_utils::updateParam<bool>(
     parameters, "common.enable_slow_down_planning", enable_slow_down_planning_);
 
+  autoware::universe_utils::updateParam<bool>(
+    parameters, "common.friction_derating.enable", enable_friction_derating_);
+  autoware::universe_utils::updateParam<double>(
+    parameters, "common.friction_derating.nominal_friction_coefficient",
+    nominal_friction_coefficient_);
+  autoware::universe_utils::updateParam<double>(
+    parameters, "common.friction_derating.min_ratio", min_friction_ratio_);
+  autoware::universe_utils::updateParam<double>(
+    parameters, "common.friction_derating.timeout", friction_coefficient_timeout_);
+
   behavior_determination_param_.onParam(parameters);
 
   rcl_interfaces::msg::SetParametersResult result;
@@ -646,6 +661,7 @@ void ObstacleCruisePlannerNode::onTrajectory(const Trajectory::ConstSharedPtr ms
   const auto objects_ptr = objects_sub_.takeData();
   const auto pointcloud_ptr = use_pointcloud_ ? pointcloud_sub_.takeData() : nullptr;
   const auto acc_ptr = acc_sub_.takeData();
+  const auto friction_coefficient_ptr = friction_coefficient_sub_.takeData();
   const bool can_detect_obstacles = objects_ptr || pointcloud_ptr;
   if (!ego_odom_ptr || !can_detect_obstacles || !acc_ptr) {
     return;
@@ -701,7 +717,8 @@ void ObstacleCruisePlannerNode::onTrajectory(const Trajectory::ConstSharedPtr ms
   }();
 
   // 3. Create data for planning
-  const auto planner_data = createPlannerData(ego_odom, acc, traj_points);
+  const auto planner_data =
+    createPlannerData(ego_odom, acc, traj_points, friction_coefficient_ptr);
 
   // 4. Stop planning
   const auto stop_traj_points = planner_ptr_->generateStopTrajectory(planner_data, stop_obstacles);
@@ -2079,7 +2096,8 @@ double ObstacleCruisePlannerNode::calcCollisionTimeMargin(
 
 PlannerData ObstacleCruisePlannerNode::createPlannerData(
   const Odometry & odometry, const AccelWithCovarianceStamped & acc,
-  const std::vector<TrajectoryPoint> & traj_points) const
+  const std::vector<TrajectoryPoint> & traj_points,
+  const Float64Stamped::ConstSharedPtr friction_coefficient_ptr) const
 {
   PlannerData planner_data;
   planner_data.current_time = now();
@@ -2088,6 +2106,16 @@ PlannerData ObstacleCruisePlannerNode::createPlannerData(
   planner_data.ego_vel = odometry.twist.twist.linear.x;
   planner_data.ego_acc = acc.accel.accel.linear.x;
   planner_data.is_driving_forward = is_driving_forward_;
+
+  // NOTE: The braking deceleration of the ego for RSS is derated on a slippery road, and the
+  //       stale friction coefficient is ignored since the road condition estimator may be down.
+  if (
+    enable_friction_derating_ && friction_coefficient_ptr &&
+    (planner_data.current_time - rclcpp::Time(friction_coefficient_ptr->stamp)).seconds() <
+      friction_coefficient_timeout_) {
+    planner_data.friction_ratio = obstacle_cruise_utils::calcFrictionRatio(
+      friction_coefficient_ptr->data, nominal_friction_coefficient_, min_friction_ratio_);
+  }
   return planner_data;
 }
 
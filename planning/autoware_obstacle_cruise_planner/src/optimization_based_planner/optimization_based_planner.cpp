@@ -439,7 +439,8 @@ std::optional<SBoundaries> OptimizationBasedPlanner::getSBoundaries(
 
     // Step3 search nearest obstacle to follow for rviz marker
     const double obj_vel = std::abs(obj.velocity);
-    const double rss_dist = calcRSSDistance(planner_data.ego_vel, obj_vel);
+    const double rss_dist =
+      calcRSSDistance(planner_data.ego_vel, obj_vel, 0.0, planner_data.friction_ratio);
 
     const auto & safe_distance_margin = longitudinal_info_.safe_distance_margin;
     const double ego_obj_length = autoware::motion_utils::calcSignedArcLength(
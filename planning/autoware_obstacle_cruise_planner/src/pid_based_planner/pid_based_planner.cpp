@@ -209,7 +209,8 @@ std::optional<PIDBasedPlanner::CruiseObstacleInfo> PIDBasedPlanner::calcObstacle
 
     // calculate distance between ego and obstacle based on RSS
     const double target_dist_to_obstacle = calcRSSDistance(
-      planner_data.ego_vel, obstacle.velocity, longitudinal_info_.safe_distance_margin);
+      planner_data.ego_vel, obstacle.velocity, longitudinal_info_.safe_distance_margin,
+      planner_data.friction_ratio);
 
     // calculate error distance and normalized one
     const double error_cruise_dist = dist_to_obstacle - target_dist_to_obstacle;
@@ -17,6 +17,8 @@
 #include "autoware/object_recognition_utils/predicted_path_utils.hpp"
 #include "autoware/universe_utils/ros/marker_helper.hpp"
 
+#include <algorithm>
+#include <cmath>
 #include <limits>
 #include <string>
 #include <vector>
@@ -118,4 +120,14 @@ std::vector<StopObstacle> getClosestStopObstacles(const std::vector<StopObstacle
   return candidates;
 }
 
+double calcFrictionRatio(
+  const double friction_coefficient, const double nominal_friction_coefficient,
+  const double min_ratio)
+{
+  if (!std::isfinite(friction_coefficient) || nominal_friction_coefficient <= 0.0) {
+    return 1.0;
+  }
+  return std::clamp(friction_coefficient / nominal_friction_coefficient, min_ratio, 1.0);
+}
+
 }  // namespace obstacle_cruise_utils
@@ -21,6 +21,7 @@
 
 #include <gtest/gtest.h>
 
+#include <cmath>
 #include <string>
 #include <vector>
 
@@ -74,3 +75,15 @@ TEST(ObstacleCruisePlannerUtilsTest, getClosestStopObstacles)
   stop_obstacles.emplace_back(generate_stop_obstacle(ObjectClassification::BUS, 10.0));
   EXPECT_EQ(3, obstacle_cruise_utils::getClosestStopObstacles(stop_obstacles).size());
 }
+
+TEST(ObstacleCruisePlannerUtilsTest, calcFrictionRatio)
+{
+  using obstacle_cruise_utils::calcFrictionRatio;
+
+  EXPECT_DOUBLE_EQ(1.0, calcFrictionRatio(0.8, 0.8, 0.3));
+  EXPECT_DOUBLE_EQ(1.0, calcFrictionRatio(1.0, 0.8, 0.3));
+  EXPECT_DOUBLE_EQ(0.625, calcFrictionRatio(0.5, 0.8, 0.3));
+  EXPECT_DOUBLE_EQ(0.3, calcFrictionRatio(0.1, 0.8, 0.3));
+  EXPECT_DOUBLE_EQ(1.0, calcFrictionRatio(std::nan(""), 0.8, 0.3));
+  EXPECT_DOUBLE_EQ(1.0, calcFrictionRatio(0.5, 0.0, 0.3));
+}
@@ -0,0 +1,304 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "cuda_pointcloud_preprocessor.hpp"
+
+#include "point_operations.hpp"
+
+#include <autoware/cuda_utils/cuda_check_error.hpp>
+#include <cuda_blackboard/cuda_unique_ptr.hpp>
+
+#include <thrust/binary_search.h>
+#include <thrust/copy.h>
+#include <thrust/execution_policy.h>
+#include <thrust/functional.h>
+#include <thrust/iterator/transform_iterator.h>
+#include <thrust/reduce.h>
+#include <thrust/sort.h>
+#include <thrust/transform.h>
+
+#include <cmath>
+#include <memory>
+
+namespace autoware::cuda_pointcloud_preprocessor
+{
+namespace
+{
+constexpr int threads_per_block = 256;
+
+constexpr int voxel_index_bits = 21;
+constexpr std::int64_t voxel_index_offset = 1 << (voxel_index_bits - 1);
+constexpr std::uint64_t voxel_index_mask = (1ULL << voxel_index_bits) - 1;
+
+int num_blocks(const std::size_t num_points)
+{
+  return static_cast<int>((num_points + threads_per_block - 1) / threads_per_block);
+}
+
+__global__ void transform_kernel(
+  const InputPointType * input, const std::size_t num_points, const TransformStruct transform,
+  const TwistSegment * segments, const int num_segments, OutputPointType * output,
+  std::uint8_t * masks)
+{
+  const std::size_t i = blockIdx.x * blockDim.x + threadIdx.x;
+  if (i >= num_points) {
+    return;
+  }
+
+  output[i] = transform_point(input[i], transform, segments, num_segments);
+  masks[i] = is_finite(output[i]) ? 1 : 0;
+}
+
+__global__ void crop_box_kernel(
+  const OutputPointType * points, const std::size_t num_points,
+  const CropBoxParameters * crop_boxes, const int num_crop_boxes, std::uint8_t * masks)
+{
+  const std::size_t i = blockIdx.x * blockDim.x + threadIdx.x;
+  if (i >= num_points || masks[i] == 0) {
+    return;
+  }
+
+  for (int j = 0; j < num_crop_boxes; ++j) {
+    if (is_inside(crop_boxes[j], points[i])) {
+      masks[i] = 0;
+      return;
+    }
+  }
+}
+
+__global__ void ground_kernel(
+  const OutputPointType * points, const std::size_t num_points, const std::uint32_t * cell_indices,
+  const float * cell_min_z, const GroundSegmentationParameters parameters, std::uint8_t * masks)
+{
+  const std::size_t i = blockIdx.x * blockDim.x + threadIdx.x;
+  if (i >= num_points) {
+    return;
+  }
+
+  masks[i] = is_ground(points[i].z, cell_min_z[cell_indices[i]], parameters) ? 0 : 1;
+}
+
+struct IsKept
+{
+  __host__ __device__ bool operator()(const std::uint8_t mask) const { return mask != 0; }
+};
+
+struct CellKey
+{
+  GroundSegmentationParameters parameters;
+
+  __host__ __device__ std::uint64_t operator()(const OutputPointType & p) const
+  {
+    return to_cell_key(p, parameters);
+  }
+};
+
+struct PointZ
+{
+  __host__ __device__ float operator()(const OutputPointType & p) const { return p.z; }
+};
+
+struct VoxelKey
+{
+  VoxelSize voxel_size;
+
+  __host__ __device__ std::uint64_t to_index(const float value, const float size) const
+  {
+    const auto index = static_cast<std::int64_t>(floorf(value / size)) + voxel_index_offset;
+    return static_cast<std::uint64_t>(index) & voxel_index_mask;
+  }
+
+  __host__ __device__ std::uint64_t operator()(const OutputPointType & p) const
+  {
+    return (to_index(p.x, voxel_size.x) << (2 * voxel_index_bits)) |
+           (to_index(p.y, voxel_size.y) << voxel_index_bits) | to_index(p.z, voxel_size.z);
+  }
+};
+
+struct ToAccumulator
+{
+  __host__ __device__ VoxelAccumulator operator()(const OutputPointType & p) const
+  {
+    return {p.x, p.y, p.z, 1, p};
+  }
+};
+
+struct Accumulate
+{
+  __host__ __device__ VoxelAccumulator
+  operator()(const VoxelAccumulator & a, const VoxelAccumulator & b) const
+  {
+    return {a.x + b.x, a.y + b.y, a.z + b.z, a.count + b.count, a.representative};
+  }
+};
+
+struct ToCentroid
+{
+  __host__ __device__ OutputPointType operator()(const VoxelAccumulator & a) const
+  {
+    auto p = a.representative;
+    const float inv_count = 1.0f / static_cast<float>(a.count);
+    p.x = a.x * inv_count;
+    p.y = a.y * inv_count;
+    p.z = a.z * inv_count;
+    return p;
+  }
+};
+}  // namespace
+
+CudaPointcloudPreprocessor::CudaPointcloudPreprocessor()
+: stream_(autoware::cuda_utils::makeCudaStream(cudaStreamNonBlocking))
+{
+}
+
+// defined here so that the device vectors are destroyed by the CUDA compiler
+CudaPointcloudPreprocessor::~CudaPointcloudPreprocessor() = default;
+
+void CudaPointcloudPreprocessor::set_crop_boxes(const std::vector<CropBoxParameters> & crop_boxes)
+{
+  crop_boxes_.assign(crop_boxes.begin(), crop_boxes.end());
+}
+
+std::unique_ptr<cuda_blackboard::CudaPointCloud2> CudaPointcloudPreprocessor::process(
+  const InputPointType * input, const std::size_t num_points,
+  const TransformStruct & sensor_to_base, const std::vector<TwistSegment> & segments)
+{
+  auto output = std::make_unique<cuda_blackboard::CudaPointCloud2>();
+  output->height = 1;
+  output->width = 0;
+  output->is_bigendian = false;
+  output->is_dense = true;
+  output->point_step = sizeof(OutputPointType);
+  output->row_step = 0;
+  if (num_points == 0) {
+    return output;
+  }
+  const auto policy = thrust::cuda::par.on(*stream_);
+
+  input_points_.resize(num_points);
+  transformed_points_.resize(num_points);
+  masks_.resize(num_points);
+  filtered_points_.resize(num_points);
+  swap_points_.resize(num_points);
+  segments_.assign(segments.begin(), segments.end());
+
+  CHECK_CUDA_ERROR(cudaMemcpyAsync(
+    thrust::raw_pointer_cast(input_points_.data()), input, num_points * sizeof(InputPointType),
+    cudaMemcpyHostToDevice, *stream_));
+
+  transform_kernel<<<num_blocks(num_points), threads_per_block, 0, *stream_>>>(
+    thrust::raw_pointer_cast(input_points_.data()), num_points, sensor_to_base,
+    thrust::raw_pointer_cast(segments_.data()), static_cast<int>(segments_.size()),
+    thrust::raw_pointer_cast(transformed_points_.data()),
+    thrust::raw_pointer_cast(masks_.data()));
+  CHECK_CUDA_ERROR(cudaGetLastError());
+
+  if (!crop_boxes_.empty()) {
+    crop_box_kernel<<<num_blocks(num_points), threads_per_block, 0, *stream_>>>(
+      thrust::raw_pointer_cast(transformed_points_.data()), num_points,
+      thrust::raw_pointer_cast(crop_boxes_.data()), static_cast<int>(crop_boxes_.size()),
+      thrust::raw_pointer_cast(masks_.data()));
+    CHECK_CUDA_ERROR(cudaGetLastError());
+  }
+
+  const auto filtered_end = thrust::copy_if(
+    policy, transformed_points_.begin(), transformed_points_.begin() + num_points, masks_.begin(),
+    filtered_points_.begin(), IsKept{});
+  auto num_output = static_cast<std::size_t>(filtered_end - filtered_points_.begin());
+
+  if (ground_segmentation_ && num_output > 0) {
+    num_output = remove_ground(num_output);
+  }
+  const bool use_voxel_grid = voxel_size_.x > 0.0f && voxel_size_.y > 0.0f && voxel_size_.z > 0.0f;
+  if (use_voxel_grid && num_output > 0) {
+    num_output = downsample(num_output);
+  }
+
+  // the points stay on the device for the next node
+  output->width = static_cast<std::uint32_t>(num_output);
+  output->row_step = output->width * output->point_step;
+  output->data = cuda_blackboard::make_unique<std::uint8_t[]>(num_output * sizeof(OutputPointType));
+  if (num_output > 0) {
+    CHECK_CUDA_ERROR(cudaMemcpyAsync(
+      output->data.get(), thrust::raw_pointer_cast(filtered_points_.data()),
+      num_output * sizeof(OutputPointType), cudaMemcpyDeviceToDevice, *stream_));
+  }
+  CHECK_CUDA_ERROR(cudaStreamSynchronize(*stream_));
+  return output;
+}
+
+std::size_t CudaPointcloudPreprocessor::remove_ground(const std::size_t num_points)
+{
+  const auto policy = thrust::cuda::par.on(*stream_);
+  const auto points_begin = filtered_points_.begin();
+  const auto points_end = filtered_points_.begin() + num_points;
+
+  cell_keys_.resize(num_points);
+  unique_cells_.resize(num_points);
+  cell_min_z_.resize(num_points);
+  cell_indices_.resize(num_points);
+
+  // the lowest point of each cell
+  const auto keys_begin = cell_keys_.begin();
+  const auto keys_end = cell_keys_.begin() + num_points;
+  thrust::transform(policy, points_begin, points_end, keys_begin, CellKey{*ground_segmentation_});
+  thrust::sort_by_key(policy, keys_begin, keys_end, points_begin);
+  const auto reduced_end = thrust::reduce_by_key(
+    policy, keys_begin, keys_end, thrust::make_transform_iterator(points_begin, PointZ{}),
+    unique_cells_.begin(), cell_min_z_.begin(), thrust::equal_to<std::uint64_t>{},
+    thrust::minimum<float>{});
+  thrust::lower_bound(
+    policy, unique_cells_.begin(), reduced_end.first, keys_begin, keys_end,
+    cell_indices_.begin());
+
+  ground_kernel<<<num_blocks(num_points), threads_per_block, 0, *stream_>>>(
+    thrust::raw_pointer_cast(filtered_points_.data()), num_points,
+    thrust::raw_pointer_cast(cell_indices_.data()), thrust::raw_pointer_cast(cell_min_z_.data()),
+    *ground_segmentation_, thrust::raw_pointer_cast(masks_.data()));
+  CHECK_CUDA_ERROR(cudaGetLastError());
+
+  const auto non_ground_end = thrust::copy_if(
+    policy, points_begin, points_end, masks_.begin(), swap_points_.begin(), IsKept{});
+  filtered_points_.swap(swap_points_);
+  return static_cast<std::size_t>(non_ground_end - swap_points_.begin());
+}
+
+std::size_t CudaPointcloudPreprocessor::downsample(const std::size_t num_points)
+{
+  const auto policy = thrust::cuda::par.on(*stream_);
+  const auto points_begin = filtered_points_.begin();
+  const auto points_end = filtered_points_.begin() + num_points;
+
+  voxel_keys_.resize(num_points);
+  unique_keys_.resize(num_points);
+  accumulators_.resize(num_points);
+
+  const auto keys_begin = voxel_keys_.begin();
+  const auto keys_end = voxel_keys_.begin() + num_points;
+  thrust::transform(policy, points_begin, points_end, keys_begin, VoxelKey{voxel_size_});
+  thrust::sort_by_key(policy, keys_begin, keys_end, points_begin);
+
+  const auto reduced_end = thrust::reduce_by_key(
+    policy, keys_begin, keys_end, thrust::make_transform_iterator(points_begin, ToAccumulator{}),
+    unique_keys_.begin(), accumulators_.begin(), thrust::equal_to<std::uint64_t>{},
+    Accumulate{});
+  const auto num_voxels = static_cast<std::size_t>(reduced_end.first - unique_keys_.begin());
+
+  thrust::transform(
+    policy, accumulators_.begin(), accumulators_.begin() + num_voxels, swap_points_.begin(),
+    ToCentroid{});
+  filtered_points_.swap(swap_points_);
+  return num_voxels;
+}
+}  // namespace autoware::cuda_pointcloud_preprocessor
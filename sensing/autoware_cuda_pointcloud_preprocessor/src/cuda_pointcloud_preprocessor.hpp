@@ -0,0 +1,107 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef CUDA_POINTCLOUD_PREPROCESSOR_HPP_
+#define CUDA_POINTCLOUD_PREPROCESSOR_HPP_
+
+#include "point_types.hpp"
+#include "twist_integration.hpp"
+
+#include <autoware/cuda_utils/stream_unique_ptr.hpp>
+#include <cuda_blackboard/cuda_pointcloud2.hpp>
+
+#include <thrust/device_vector.h>
+
+#include <cstddef>
+#include <cstdint>
+#include <memory>
+#include <optional>
+#include <vector>
+
+namespace autoware::cuda_pointcloud_preprocessor
+{
+struct VoxelSize
+{
+  float x;
+  float y;
+  float z;
+};
+
+struct VoxelAccumulator
+{
+  float x;
+  float y;
+  float z;
+  std::uint32_t count;
+  OutputPointType representative;
+};
+
+/**
+ * @brief runs the transform, the distortion correction, the crop boxes, the ground segmentation
+ *        and the voxel grid downsampling on the GPU, copying the points only at the input
+ */
+class CudaPointcloudPreprocessor
+{
+public:
+  CudaPointcloudPreprocessor();
+  ~CudaPointcloudPreprocessor();
+
+  // the points inside any box are removed, the boxes are in the base frame
+  void set_crop_boxes(const std::vector<CropBoxParameters> & crop_boxes);
+  // the ground points are removed, which is disabled when nullopt
+  void set_ground_segmentation(const std::optional<GroundSegmentationParameters> & parameters)
+  {
+    ground_segmentation_ = parameters;
+  }
+  // the downsampling is disabled when any size is not positive
+  void set_voxel_size(const VoxelSize & voxel_size) { voxel_size_ = voxel_size; }
+
+  /**
+   * @param input points in the sensor frame on the host
+   * @param sensor_to_base transform from the sensor frame to the base frame
+   * @param segments motion of the base frame, the distortion is not corrected when empty
+   * @return points in the base frame at the stamp of the input, whose data is on the device.
+   *         The header and the fields are left to the caller.
+   */
+  std::unique_ptr<cuda_blackboard::CudaPointCloud2> process(
+    const InputPointType * input, std::size_t num_points, const TransformStruct & sensor_to_base,
+    const std::vector<TwistSegment> & segments);
+
+private:
+  std::size_t remove_ground(std::size_t num_points);
+  std::size_t downsample(std::size_t num_points);
+
+  autoware::cuda_utils::StreamUniquePtr stream_;
+  VoxelSize voxel_size_{0.0f, 0.0f, 0.0f};
+  std::optional<GroundSegmentationParameters> ground_segmentation_;
+
+  // the buffers are reused across the frames, and they are reallocated only when they grow
+  thrust::device_vector<CropBoxParameters> crop_boxes_;
+  thrust::device_vector<TwistSegment> segments_;
+  thrust::device_vector<InputPointType> input_points_;
+  thrust::device_vector<OutputPointType> transformed_points_;
+  thrust::device_vector<std::uint8_t> masks_;
+  thrust::device_vector<OutputPointType> filtered_points_;
+  thrust::device_vector<OutputPointType> swap_points_;
+  thrust::device_vector<std::uint64_t> cell_keys_;
+  thrust::device_vector<std::uint64_t> unique_cells_;
+  thrust::device_vector<float> cell_min_z_;
+  thrust::device_vector<std::uint32_t> cell_indices_;
+  thrust::device_vector<std::uint64_t> voxel_keys_;
+  thrust::device_vector<std::uint64_t> unique_keys_;
+  thrust::device_vector<VoxelAccumulator> accumulators_;
+};
+}  // namespace autoware::cuda_pointcloud_preprocessor
+
+#endif  // CUDA_POINTCLOUD_PREPROCESSOR_HPP_
@@ -0,0 +1,284 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "cuda_pointcloud_preprocessor_node.hpp"
+
+#include <Eigen/Core>
+
+#include <sensor_msgs/msg/point_field.hpp>
+
+#include <algorithm>
+#include <cstddef>
+#include <memory>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace autoware::cuda_pointcloud_preprocessor
+{
+namespace
+{
+// bounds the queues while no point cloud is received
+constexpr size_t max_queue_size = 1000;
+
+std::int64_t to_nanoseconds(const builtin_interfaces::msg::Time & stamp)
+{
+  return rclcpp::Time(stamp).nanoseconds();
+}
+
+template <typename T>
+void push_sample(std::deque<T> & queue, const T & sample)
+{
+  queue.push_back(sample);
+  while (max_queue_size < queue.size()) {
+    queue.pop_front();
+  }
+}
+
+// removes the samples before the latest one at the stamp, which are no longer used
+template <typename T>
+void trim_queue(std::deque<T> & queue, const std::int64_t stamp_ns)
+{
+  while (2 <= queue.size() && queue[1].stamp_ns <= stamp_ns) {
+    queue.pop_front();
+  }
+}
+
+bool has_field(
+  const sensor_msgs::msg::PointCloud2 & msg, const std::string & name, const uint32_t offset,
+  const uint8_t datatype)
+{
+  return std::any_of(msg.fields.begin(), msg.fields.end(), [&](const auto & field) {
+    return field.name == name && field.offset == offset && field.datatype == datatype;
+  });
+}
+
+// checks that the memory layout is the one of PointXYZIRCAEDT
+bool is_input_layout(const sensor_msgs::msg::PointCloud2 & msg)
+{
+  using sensor_msgs::msg::PointField;
+  return msg.point_step == sizeof(InputPointType) &&
+         has_field(msg, "x", offsetof(InputPointType, x), PointField::FLOAT32) &&
+         has_field(msg, "y", offsetof(InputPointType, y), PointField::FLOAT32) &&
+         has_field(msg, "z", offsetof(InputPointType, z), PointField::FLOAT32) &&
+         has_field(msg, "intensity", offsetof(InputPointType, intensity), PointField::UINT8) &&
+         has_field(msg, "return_type", offsetof(InputPointType, return_type), PointField::UINT8) &&
+         has_field(msg, "channel", offsetof(InputPointType, channel), PointField::UINT16) &&
+         has_field(msg, "time_stamp", offsetof(InputPointType, time_stamp), PointField::UINT32);
+}
+
+sensor_msgs::msg::PointField create_field(
+  const std::string & name, const uint32_t offset, const uint8_t datatype)
+{
+  sensor_msgs::msg::PointField field;
+  field.name = name;
+  field.offset = offset;
+  field.datatype = datatype;
+  field.count = 1;
+  return field;
+}
+}  // namespace
+
+CudaPointcloudPreprocessorNode::CudaPointcloudPreprocessorNode(
+  const rclcpp::NodeOptions & node_options)
+: Node("cuda_pointcloud_preprocessor", node_options)
+{
+  // Parameter
+  node_param_.base_frame = declare_parameter<std::string>("base_frame");
+  node_param_.use_imu = declare_parameter<bool>("use_imu");
+  {
+    const auto min_x = declare_parameter<std::vector<double>>("crop_box.min_x");
+    const auto min_y = declare_parameter<std::vector<double>>("crop_box.min_y");
+    const auto min_z = declare_parameter<std::vector<double>>("crop_box.min_z");
+    const auto max_x = declare_parameter<std::vector<double>>("crop_box.max_x");
+    const auto max_y = declare_parameter<std::vector<double>>("crop_box.max_y");
+    const auto max_z = declare_parameter<std::vector<double>>("crop_box.max_z");
+    const auto num_boxes = min_x.size();
+    for (const auto * v : {&min_y, &min_z, &max_x, &max_y, &max_z}) {
+      if (v->size() != num_boxes) {
+        throw std::invalid_argument("all the crop_box parameters must have the same length");
+      }
+    }
+    for (size_t i = 0; i < num_boxes; ++i) {
+      node_param_.crop_boxes.push_back(
+        {static_cast<float>(min_x[i]), static_cast<float>(min_y[i]), static_cast<float>(min_z[i]),
+         static_cast<float>(max_x[i]), static_cast<float>(max_y[i]),
+         static_cast<float>(max_z[i])});
+    }
+  }
+  if (declare_parameter<bool>("ground_segmentation.enable")) {
+    node_param_.ground_segmentation = GroundSegmentationParameters{
+      static_cast<float>(declare_parameter<double>("ground_segmentation.grid_size")),
+      static_cast<float>(declare_parameter<double>("ground_segmentation.height_threshold")),
+      static_cast<float>(declare_parameter<double>("ground_segmentation.max_ground_z"))};
+  }
+  node_param_.voxel_size.x = static_cast<float>(declare_parameter<double>("voxel_size.x"));
+  node_param_.voxel_size.y = static_cast<float>(declare_parameter<double>("voxel_size.y"));
+  node_param_.voxel_size.z = static_cast<float>(declare_parameter<double>("voxel_size.z"));
+  const auto has_static_tf_only = declare_parameter<bool>("has_static_tf_only");
+
+  // Subscriber
+  sub_pointcloud_ = create_subscription<sensor_msgs::msg::PointCloud2>(
+    "~/input/pointcloud", rclcpp::SensorDataQoS(),
+    std::bind(&CudaPointcloudPreprocessorNode::on_pointcloud, this, std::placeholders::_1));
+  sub_twist_ = create_subscription<geometry_msgs::msg::TwistWithCovarianceStamped>(
+    "~/input/twist", 10,
+    std::bind(&CudaPointcloudPreprocessorNode::on_twist, this, std::placeholders::_1));
+  if (node_param_.use_imu) {
+    sub_imu_ = create_subscription<sensor_msgs::msg::Imu>(
+      "~/input/imu", 10,
+      std::bind(&CudaPointcloudPreprocessorNode::on_imu, this, std::placeholders::_1));
+  }
+
+  // Publisher
+  // the subscribers of cuda_blackboard receive the device buffer, and the others receive a copy
+  pub_pointcloud_ =
+    std::make_unique<cuda_blackboard::CudaBlackboardPublisher<cuda_blackboard::CudaPointCloud2>>(
+      *this, "~/output/pointcloud");
+
+  // State
+  managed_tf_buffer_ =
+    std::make_unique<autoware::universe_utils::ManagedTransformBuffer>(this, has_static_tf_only);
+  preprocessor_ = std::make_unique<CudaPointcloudPreprocessor>();
+  preprocessor_->set_crop_boxes(node_param_.crop_boxes);
+  preprocessor_->set_ground_segmentation(node_param_.ground_segmentation);
+  preprocessor_->set_voxel_size(node_param_.voxel_size);
+}
+
+void CudaPointcloudPreprocessorNode::on_twist(
+  const geometry_msgs::msg::TwistWithCovarianceStamped::ConstSharedPtr msg)
+{
+  const auto & twist = msg->twist.twist;
+  const TwistSample sample{
+    to_nanoseconds(msg->header.stamp), static_cast<float>(twist.linear.x),
+    static_cast<float>(twist.angular.z)};
+  push_sample(twist_queue_, sample);
+}
+
+void CudaPointcloudPreprocessorNode::on_imu(const sensor_msgs::msg::Imu::ConstSharedPtr msg)
+{
+  const auto transform = get_transform(msg->header.frame_id);
+  if (!transform) {
+    return;
+  }
+
+  // the yaw rate in the base frame is the z element of the rotated angular velocity
+  const auto & m = transform->m;
+  const auto & w = msg->angular_velocity;
+  const double yaw_rate = m[8] * w.x + m[9] * w.y + m[10] * w.z;
+  const YawRateSample sample{to_nanoseconds(msg->header.stamp), static_cast<float>(yaw_rate)};
+  push_sample(yaw_rate_queue_, sample);
+}
+
+void CudaPointcloudPreprocessorNode::on_pointcloud(
+  const sensor_msgs::msg::PointCloud2::ConstSharedPtr msg)
+{
+  if (!is_input_layout(*msg)) {
+    RCLCPP_ERROR_THROTTLE(
+      get_logger(), *get_clock(), 5000, "the input point cloud must be PointXYZIRCAEDT");
+    return;
+  }
+  const auto num_points = static_cast<size_t>(msg->width) * msg->height;
+  if (
+    msg->row_step != static_cast<size_t>(msg->width) * msg->point_step ||
+    msg->data.size() < num_points * msg->point_step) {
+    RCLCPP_ERROR_THROTTLE(
+      get_logger(), *get_clock(), 5000,
+      "the size of the input point cloud is inconsistent with its width, height and steps");
+    return;
+  }
+  const auto sensor_to_base = get_transform(msg->header.frame_id);
+  if (!sensor_to_base) {
+    return;
+  }
+
+  const auto * input = reinterpret_cast<const InputPointType *>(msg->data.data());
+  const auto cloud_stamp_ns = to_nanoseconds(msg->header.stamp);
+  trim_queue(twist_queue_, cloud_stamp_ns);
+  if (!twist_queue_.empty()) {
+    trim_queue(yaw_rate_queue_, twist_queue_.front().stamp_ns);
+  }
+
+  uint32_t max_time_stamp = 0;
+  for (size_t i = 0; i < num_points; ++i) {
+    max_time_stamp = std::max(max_time_stamp, input[i].time_stamp);
+  }
+  const auto segments = create_twist_segments(
+    get_twist_samples(), cloud_stamp_ns, static_cast<float>(max_time_stamp) * 1e-9f);
+  if (segments.empty()) {
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 5000, "no twist is received, the distortion is not corrected");
+  }
+
+  auto output = preprocessor_->process(input, num_points, *sensor_to_base, segments);
+  output->header.stamp = msg->header.stamp;
+  output->header.frame_id = node_param_.base_frame;
+  {
+    using sensor_msgs::msg::PointField;
+    output->fields = {
+      create_field("x", offsetof(OutputPointType, x), PointField::FLOAT32),
+      create_field("y", offsetof(OutputPointType, y), PointField::FLOAT32),
+      create_field("z", offsetof(OutputPointType, z), PointField::FLOAT32),
+      create_field("intensity", offsetof(OutputPointType, intensity), PointField::UINT8),
+      create_field("return_type", offsetof(OutputPointType, return_type), PointField::UINT8),
+      create_field("channel", offsetof(OutputPointType, channel), PointField::UINT16)};
+  }
+  pub_pointcloud_->publish(std::move(output));
+}
+
+std::optional<TransformStruct> CudaPointcloudPreprocessorNode::get_transform(
+  const std::string & source_frame)
+{
+  Eigen::Matrix4f eigen_transform;
+  if (!managed_tf_buffer_->getTransform(node_param_.base_frame, source_frame, eigen_transform)) {
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 5000, "failed to get the transform from %s to %s",
+      source_frame.c_str(), node_param_.base_frame.c_str());
+    return std::nullopt;
+  }
+
+  TransformStruct transform{};
+  for (int row = 0; row < 3; ++row) {
+    for (int col = 0; col < 4; ++col) {
+      transform.m[row * 4 + col] = eigen_transform(row, col);
+    }
+  }
+  return transform;
+}
+
+std::vector<TwistSample> CudaPointcloudPreprocessorNode::get_twist_samples() const
+{
+  std::vector<TwistSample> samples(twist_queue_.begin(), twist_queue_.end());
+  if (!node_param_.use_imu || yaw_rate_queue_.empty()) {
+    return samples;
+  }
+
+  // the yaw rate of the latest IMU sample before each twist replaces the one of the twist
+  for (auto & sample : samples) {
+    auto it = std::upper_bound(
+      yaw_rate_queue_.begin(), yaw_rate_queue_.end(), sample.stamp_ns,
+      [](const std::int64_t stamp, const YawRateSample & s) { return stamp < s.stamp_ns; });
+    if (it != yaw_rate_queue_.begin()) {
+      --it;
+    }
+    sample.yaw_rate = it->yaw_rate;
+  }
+  return samples;
+}
+}  // namespace autoware::cuda_pointcloud_preprocessor
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(
+  autoware::cuda_pointcloud_preprocessor::CudaPointcloudPreprocessorNode)
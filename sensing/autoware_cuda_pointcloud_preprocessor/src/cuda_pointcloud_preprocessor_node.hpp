@@ -0,0 +1,87 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef CUDA_POINTCLOUD_PREPROCESSOR_NODE_HPP_
+#define CUDA_POINTCLOUD_PREPROCESSOR_NODE_HPP_
+
+#include "cuda_pointcloud_preprocessor.hpp"
+#include "twist_integration.hpp"
+
+#include <autoware/universe_utils/ros/managed_transform_buffer.hpp>
+#include <cuda_blackboard/cuda_blackboard_publisher.hpp>
+#include <cuda_blackboard/cuda_pointcloud2.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <geometry_msgs/msg/twist_with_covariance_stamped.hpp>
+#include <sensor_msgs/msg/imu.hpp>
+#include <sensor_msgs/msg/point_cloud2.hpp>
+
+#include <deque>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::cuda_pointcloud_preprocessor
+{
+struct NodeParam
+{
+  std::string base_frame{};
+  bool use_imu{};
+  std::vector<CropBoxParameters> crop_boxes{};
+  std::optional<GroundSegmentationParameters> ground_segmentation{};
+  VoxelSize voxel_size{};
+};
+
+class CudaPointcloudPreprocessorNode : public rclcpp::Node
+{
+public:
+  explicit CudaPointcloudPreprocessorNode(const rclcpp::NodeOptions & node_options);
+
+private:
+  struct YawRateSample
+  {
+    std::int64_t stamp_ns;
+    float yaw_rate;
+  };
+
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<sensor_msgs::msg::PointCloud2>::SharedPtr sub_pointcloud_;
+  rclcpp::Subscription<geometry_msgs::msg::TwistWithCovarianceStamped>::SharedPtr sub_twist_;
+  rclcpp::Subscription<sensor_msgs::msg::Imu>::SharedPtr sub_imu_;
+
+  void on_pointcloud(const sensor_msgs::msg::PointCloud2::ConstSharedPtr msg);
+  void on_twist(const geometry_msgs::msg::TwistWithCovarianceStamped::ConstSharedPtr msg);
+  void on_imu(const sensor_msgs::msg::Imu::ConstSharedPtr msg);
+
+  // Publisher
+  std::unique_ptr<cuda_blackboard::CudaBlackboardPublisher<cuda_blackboard::CudaPointCloud2>>
+    pub_pointcloud_;
+
+  // State
+  std::unique_ptr<autoware::universe_utils::ManagedTransformBuffer> managed_tf_buffer_;
+  std::unique_ptr<CudaPointcloudPreprocessor> preprocessor_;
+  std::deque<TwistSample> twist_queue_;
+  std::deque<YawRateSample> yaw_rate_queue_;
+
+  // Function
+  std::optional<TransformStruct> get_transform(const std::string & source_frame);
+  std::vector<TwistSample> get_twist_samples() const;
+};
+}  // namespace autoware::cuda_pointcloud_preprocessor
+
+#endif  // CUDA_POINTCLOUD_PREPROCESSOR_NODE_HPP_
@@ -0,0 +1,83 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef POINT_OPERATIONS_HPP_
+#define POINT_OPERATIONS_HPP_
+
+#include "point_types.hpp"
+#include "twist_integration.hpp"
+
+#include <cmath>
+#include <cstdint>
+
+// the operations on each point, which are shared by the kernels and the unit tests on the CPU
+namespace autoware::cuda_pointcloud_preprocessor
+{
+/**
+ * @brief transforms the point into the base frame, and moves it from the base frame at its own
+ *        time into the one at the stamp of the cloud when any segment is given
+ */
+CUDA_HOST_DEVICE inline OutputPointType transform_point(
+  const InputPointType & p, const TransformStruct & transform, const TwistSegment * segments,
+  const int num_segments)
+{
+  const auto & m = transform.m;
+  float x = m[0] * p.x + m[1] * p.y + m[2] * p.z + m[3];
+  float y = m[4] * p.x + m[5] * p.y + m[6] * p.z + m[7];
+  const float z = m[8] * p.x + m[9] * p.y + m[10] * p.z + m[11];
+
+  if (num_segments > 0) {
+    const auto pose = pose_at(segments, num_segments, static_cast<float>(p.time_stamp) * 1e-9f);
+    const float cos_yaw = std::cos(pose.yaw);
+    const float sin_yaw = std::sin(pose.yaw);
+    const float corrected_x = cos_yaw * x - sin_yaw * y + pose.x;
+    const float corrected_y = sin_yaw * x + cos_yaw * y + pose.y;
+    x = corrected_x;
+    y = corrected_y;
+  }
+  return {x, y, z, p.intensity, p.return_type, p.channel};
+}
+
+CUDA_HOST_DEVICE inline bool is_finite(const OutputPointType & p)
+{
+  return std::isfinite(p.x) && std::isfinite(p.y) && std::isfinite(p.z);
+}
+
+CUDA_HOST_DEVICE inline bool is_inside(const CropBoxParameters & b, const OutputPointType & p)
+{
+  return b.min_x <= p.x && p.x <= b.max_x && b.min_y <= p.y && p.y <= b.max_y && b.min_z <= p.z &&
+         p.z <= b.max_z;
+}
+
+CUDA_HOST_DEVICE inline std::uint32_t to_cell_index(const float value, const float grid_size)
+{
+  return static_cast<std::uint32_t>(static_cast<std::int32_t>(std::floor(value / grid_size)));
+}
+
+// 2D index of the cell of the ground segmentation, 32 bits for each of x and y
+CUDA_HOST_DEVICE inline std::uint64_t to_cell_key(
+  const OutputPointType & p, const GroundSegmentationParameters & parameters)
+{
+  return (static_cast<std::uint64_t>(to_cell_index(p.x, parameters.grid_size)) << 32) |
+         to_cell_index(p.y, parameters.grid_size);
+}
+
+CUDA_HOST_DEVICE inline bool is_ground(
+  const float z, const float cell_min_z, const GroundSegmentationParameters & parameters)
+{
+  return cell_min_z <= parameters.max_ground_z && z <= cell_min_z + parameters.height_threshold;
+}
+}  // namespace autoware::cuda_pointcloud_preprocessor
+
+#endif  // POINT_OPERATIONS_HPP_
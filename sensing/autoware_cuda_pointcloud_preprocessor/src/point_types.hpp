@@ -0,0 +1,79 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef POINT_TYPES_HPP_
+#define POINT_TYPES_HPP_
+
+#include <cstdint>
+
+namespace autoware::cuda_pointcloud_preprocessor
+{
+// the memory layout of autoware::point_types::PointXYZIRCAEDT
+struct InputPointType
+{
+  float x;
+  float y;
+  float z;
+  std::uint8_t intensity;
+  std::uint8_t return_type;
+  std::uint16_t channel;
+  float azimuth;
+  float elevation;
+  float distance;
+  // nanoseconds since the stamp of the point cloud
+  std::uint32_t time_stamp;
+};
+
+// the memory layout of autoware::point_types::PointXYZIRC
+struct OutputPointType
+{
+  float x;
+  float y;
+  float z;
+  std::uint8_t intensity;
+  std::uint8_t return_type;
+  std::uint16_t channel;
+};
+
+static_assert(sizeof(InputPointType) == 32, "InputPointType must match PointXYZIRCAEDT");
+static_assert(sizeof(OutputPointType) == 16, "OutputPointType must match PointXYZIRC");
+
+struct CropBoxParameters
+{
+  float min_x;
+  float min_y;
+  float min_z;
+  float max_x;
+  float max_y;
+  float max_z;
+};
+
+struct GroundSegmentationParameters
+{
+  // [m] size of the square cells, in each of which the lowest point is regarded as the ground
+  float grid_size;
+  // [m] the points up to this height above the lowest point of the cell are the ground
+  float height_threshold;
+  // [m] the cell has no ground when the lowest point is higher than this in the base frame
+  float max_ground_z;
+};
+
+// row-major 3x4 matrix of a rigid transform
+struct TransformStruct
+{
+  float m[12];
+};
+}  // namespace autoware::cuda_pointcloud_preprocessor
+
+#endif  // POINT_TYPES_HPP_
@@ -0,0 +1,54 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "twist_integration.hpp"
+
+#include <algorithm>
+
+namespace autoware::cuda_pointcloud_preprocessor
+{
+std::vector<TwistSegment> create_twist_segments(
+  const std::vector<TwistSample> & samples, const std::int64_t cloud_stamp_ns,
+  const float duration)
+{
+  std::vector<TwistSegment> segments;
+  if (samples.empty()) {
+    return segments;
+  }
+
+  // the latest sample before the point cloud, or the first one when all of them are later
+  auto it = std::upper_bound(
+    samples.begin(), samples.end(), cloud_stamp_ns,
+    [](const std::int64_t stamp, const TwistSample & sample) { return stamp < sample.stamp_ns; });
+  if (it != samples.begin()) {
+    --it;
+  }
+  segments.push_back({0.0f, 0.0f, 0.0f, 0.0f, it->velocity, it->yaw_rate});
+
+  for (++it; it != samples.end(); ++it) {
+    const float start_time = static_cast<float>(it->stamp_ns - cloud_stamp_ns) * 1e-9f;
+    if (start_time > duration) {
+      break;
+    }
+    if (start_time <= segments.back().start_time) {
+      segments.back().velocity = it->velocity;
+      segments.back().yaw_rate = it->yaw_rate;
+      continue;
+    }
+    const auto pose = integrate_segment(segments.back(), start_time);
+    segments.push_back({start_time, pose.x, pose.y, pose.yaw, it->velocity, it->yaw_rate});
+  }
+  return segments;
+}
+}  // namespace autoware::cuda_pointcloud_preprocessor
@@ -0,0 +1,100 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef TWIST_INTEGRATION_HPP_
+#define TWIST_INTEGRATION_HPP_
+
+#include <cmath>
+#include <cstdint>
+#include <vector>
+
+#ifdef __CUDACC__
+#define CUDA_HOST_DEVICE __host__ __device__
+#else
+#define CUDA_HOST_DEVICE
+#endif
+
+namespace autoware::cuda_pointcloud_preprocessor
+{
+struct TwistSample
+{
+  std::int64_t stamp_ns;
+  float velocity;
+  float yaw_rate;
+};
+
+/**
+ * @brief constant twist from start_time until the start of the next segment, the pose is the
+ *        one of base_link at start_time relative to base_link at the stamp of the point cloud
+ */
+struct TwistSegment
+{
+  float start_time;
+  float x;
+  float y;
+  float yaw;
+  float velocity;
+  float yaw_rate;
+};
+
+struct Pose2D
+{
+  float x;
+  float y;
+  float yaw;
+};
+
+CUDA_HOST_DEVICE inline Pose2D integrate_segment(const TwistSegment & segment, const float time)
+{
+  const float dt = time - segment.start_time;
+  const float yaw = segment.yaw + segment.yaw_rate * dt;
+  if (std::fabs(segment.yaw_rate) < 1e-6f) {
+    return {
+      segment.x + segment.velocity * dt * std::cos(segment.yaw),
+      segment.y + segment.velocity * dt * std::sin(segment.yaw), yaw};
+  }
+  // exact integration along the arc
+  const float r = segment.velocity / segment.yaw_rate;
+  return {
+    segment.x + r * (std::sin(yaw) - std::sin(segment.yaw)),
+    segment.y - r * (std::cos(yaw) - std::cos(segment.yaw)), yaw};
+}
+
+// the segments are sorted by start_time and the first one starts at 0
+CUDA_HOST_DEVICE inline Pose2D pose_at(
+  const TwistSegment * segments, const int num_segments, const float time)
+{
+  int low = 0;
+  int high = num_segments - 1;
+  while (low < high) {
+    const int mid = (low + high + 1) / 2;
+    if (segments[mid].start_time <= time) {
+      low = mid;
+    } else {
+      high = mid - 1;
+    }
+  }
+  return integrate_segment(segments[low], time);
+}
+
+/**
+ * @brief builds the segments which cover [0, duration] seconds since cloud_stamp_ns
+ * @param samples twist samples sorted by the stamp
+ * @return empty when there is no sample
+ */
+std::vector<TwistSegment> create_twist_segments(
+  const std::vector<TwistSample> & samples, std::int64_t cloud_stamp_ns, float duration);
+}  // namespace autoware::cuda_pointcloud_preprocessor
+
+#endif  // TWIST_INTEGRATION_HPP_
@@ -0,0 +1,133 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "point_operations.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+#include <limits>
+#include <vector>
+
+using autoware::cuda_pointcloud_preprocessor::create_twist_segments;
+using autoware::cuda_pointcloud_preprocessor::CropBoxParameters;
+using autoware::cuda_pointcloud_preprocessor::GroundSegmentationParameters;
+using autoware::cuda_pointcloud_preprocessor::InputPointType;
+using autoware::cuda_pointcloud_preprocessor::OutputPointType;
+using autoware::cuda_pointcloud_preprocessor::TransformStruct;
+using autoware::cuda_pointcloud_preprocessor::TwistSample;
+
+namespace
+{
+// the sensor is 1 m ahead of and 2 m above the base, rotated by 90 degrees in yaw
+constexpr TransformStruct sensor_to_base{{0, -1, 0, 1, 1, 0, 0, 0, 0, 0, 1, 2}};
+
+InputPointType create_point(const float x, const float y, const float z, const uint32_t time_ns)
+{
+  InputPointType p{};
+  p.x = x;
+  p.y = y;
+  p.z = z;
+  p.intensity = 10;
+  p.return_type = 1;
+  p.channel = 5;
+  p.time_stamp = time_ns;
+  return p;
+}
+
+OutputPointType create_output_point(const float x, const float y, const float z)
+{
+  return OutputPointType{x, y, z, 0, 0, 0};
+}
+}  // namespace
+
+TEST(PointOperations, Transform)
+{
+  const auto p = autoware::cuda_pointcloud_preprocessor::transform_point(
+    create_point(1.0f, 0.0f, 0.0f, 0), sensor_to_base, nullptr, 0);
+  EXPECT_NEAR(p.x, 1.0f, 1e-6);
+  EXPECT_NEAR(p.y, 1.0f, 1e-6);
+  EXPECT_NEAR(p.z, 2.0f, 1e-6);
+  EXPECT_EQ(p.intensity, 10);
+  EXPECT_EQ(p.return_type, 1);
+  EXPECT_EQ(p.channel, 5);
+}
+
+TEST(PointOperations, Deskew)
+{
+  // the base moves forward at 10 m/s, so a point measured 0.1 s after the stamp of the cloud is
+  // 1 m further in the base frame at the stamp
+  constexpr std::int64_t cloud_stamp_ns = 1'000'000'000;
+  const std::vector<TwistSample> samples{{cloud_stamp_ns, 10.0f, 0.0f}};
+  const auto segments = create_twist_segments(samples, cloud_stamp_ns, 0.1f);
+  const int num_segments = static_cast<int>(segments.size());
+
+  const auto at_stamp = autoware::cuda_pointcloud_preprocessor::transform_point(
+    create_point(1.0f, 0.0f, 0.0f, 0), sensor_to_base, segments.data(), num_segments);
+  EXPECT_NEAR(at_stamp.x, 1.0f, 1e-5);
+  EXPECT_NEAR(at_stamp.y, 1.0f, 1e-5);
+
+  const auto later = autoware::cuda_pointcloud_preprocessor::transform_point(
+    create_point(1.0f, 0.0f, 0.0f, 100'000'000), sensor_to_base, segments.data(), num_segments);
+  EXPECT_NEAR(later.x, 2.0f, 1e-5);
+  EXPECT_NEAR(later.y, 1.0f, 1e-5);
+  EXPECT_NEAR(later.z, 2.0f, 1e-5);
+}
+
+TEST(PointOperations, CropBox)
+{
+  using autoware::cuda_pointcloud_preprocessor::is_inside;
+  const CropBoxParameters box{-1.0f, -1.0f, -0.5f, 4.0f, 1.0f, 2.5f};
+  EXPECT_TRUE(is_inside(box, create_output_point(0.0f, 0.0f, 0.0f)));
+  EXPECT_TRUE(is_inside(box, create_output_point(4.0f, 1.0f, 2.5f)));
+  EXPECT_FALSE(is_inside(box, create_output_point(4.1f, 0.0f, 0.0f)));
+  EXPECT_FALSE(is_inside(box, create_output_point(0.0f, -1.1f, 0.0f)));
+  EXPECT_FALSE(is_inside(box, create_output_point(0.0f, 0.0f, 3.0f)));
+}
+
+TEST(PointOperations, Finite)
+{
+  using autoware::cuda_pointcloud_preprocessor::is_finite;
+  EXPECT_TRUE(is_finite(create_output_point(0.0f, 1.0f, 2.0f)));
+  EXPECT_FALSE(is_finite(create_output_point(std::numeric_limits<float>::quiet_NaN(), 0.0f, 0.0f)));
+  EXPECT_FALSE(is_finite(create_output_point(0.0f, std::numeric_limits<float>::infinity(), 0.0f)));
+}
+
+TEST(PointOperations, GroundSegmentation)
+{
+  using autoware::cuda_pointcloud_preprocessor::is_ground;
+  using autoware::cuda_pointcloud_preprocessor::to_cell_key;
+  const GroundSegmentationParameters parameters{0.5f, 0.2f, 0.3f};
+
+  // the cells are split at the multiples of the grid size, also for the negative coordinates
+  EXPECT_EQ(
+    to_cell_key(create_output_point(0.1f, 0.1f, 0.0f), parameters),
+    to_cell_key(create_output_point(0.4f, 0.4f, 1.0f), parameters));
+  EXPECT_NE(
+    to_cell_key(create_output_point(0.1f, 0.1f, 0.0f), parameters),
+    to_cell_key(create_output_point(0.6f, 0.1f, 0.0f), parameters));
+  EXPECT_NE(
+    to_cell_key(create_output_point(0.1f, 0.1f, 0.0f), parameters),
+    to_cell_key(create_output_point(-0.1f, 0.1f, 0.0f), parameters));
+  EXPECT_NE(
+    to_cell_key(create_output_point(0.1f, 0.1f, 0.0f), parameters),
+    to_cell_key(create_output_point(0.1f, -0.1f, 0.0f), parameters));
+
+  // the points near the lowest point of the cell
+  EXPECT_TRUE(is_ground(0.0f, 0.0f, parameters));
+  EXPECT_TRUE(is_ground(0.15f, -0.05f, parameters));
+  EXPECT_FALSE(is_ground(0.3f, 0.0f, parameters));
+  // the lowest point is too high to be the ground, e.g. the roof of a car
+  EXPECT_FALSE(is_ground(1.5f, 1.5f, parameters));
+}
@@ -0,0 +1,87 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "twist_integration.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+#include <vector>
+
+using autoware::cuda_pointcloud_preprocessor::create_twist_segments;
+using autoware::cuda_pointcloud_preprocessor::pose_at;
+using autoware::cuda_pointcloud_preprocessor::TwistSample;
+
+namespace
+{
+constexpr std::int64_t cloud_stamp_ns = 1'000'000'000;
+}
+
+TEST(TwistIntegration, Empty)
+{
+  EXPECT_TRUE(create_twist_segments({}, cloud_stamp_ns, 0.1f).empty());
+}
+
+TEST(TwistIntegration, Straight)
+{
+  const std::vector<TwistSample> samples{{cloud_stamp_ns - 10'000'000, 10.0f, 0.0f}};
+  const auto segments = create_twist_segments(samples, cloud_stamp_ns, 0.1f);
+  ASSERT_EQ(segments.size(), 1u);
+
+  const auto pose = pose_at(segments.data(), static_cast<int>(segments.size()), 0.1f);
+  EXPECT_NEAR(pose.x, 1.0f, 1e-5);
+  EXPECT_NEAR(pose.y, 0.0f, 1e-5);
+  EXPECT_NEAR(pose.yaw, 0.0f, 1e-5);
+}
+
+TEST(TwistIntegration, Arc)
+{
+  // a quarter of the circle of radius 1 m
+  const float yaw_rate = static_cast<float>(M_PI / 2.0);
+  const std::vector<TwistSample> samples{{cloud_stamp_ns, yaw_rate, yaw_rate}};
+  const auto segments = create_twist_segments(samples, cloud_stamp_ns, 1.0f);
+
+  const auto pose = pose_at(segments.data(), static_cast<int>(segments.size()), 1.0f);
+  EXPECT_NEAR(pose.x, 1.0f, 1e-5);
+  EXPECT_NEAR(pose.y, 1.0f, 1e-5);
+  EXPECT_NEAR(pose.yaw, yaw_rate, 1e-5);
+}
+
+TEST(TwistIntegration, Segments)
+{
+  const std::vector<TwistSample> samples{
+    {cloud_stamp_ns - 200'000'000, 5.0f, 0.0f},  // replaced by the next one
+    {cloud_stamp_ns - 50'000'000, 10.0f, 0.0f},
+    {cloud_stamp_ns + 50'000'000, 20.0f, 0.0f},
+    {cloud_stamp_ns + 500'000'000, 30.0f, 0.0f},  // after the point cloud
+  };
+  const auto segments = create_twist_segments(samples, cloud_stamp_ns, 0.1f);
+  ASSERT_EQ(segments.size(), 2u);
+  EXPECT_FLOAT_EQ(segments[1].start_time, 0.05f);
+  EXPECT_NEAR(segments[1].x, 0.5f, 1e-5);
+
+  const int n = static_cast<int>(segments.size());
+  EXPECT_NEAR(pose_at(segments.data(), n, 0.02f).x, 0.2f, 1e-5);
+  EXPECT_NEAR(pose_at(segments.data(), n, 0.1f).x, 1.5f, 1e-5);
+}
+
+TEST(TwistIntegration, LaterSamples)
+{
+  // the first sample is used from the stamp of the point cloud
+  const std::vector<TwistSample> samples{{cloud_stamp_ns + 10'000'000, 10.0f, 0.0f}};
+  const auto segments = create_twist_segments(samples, cloud_stamp_ns, 0.1f);
+  ASSERT_EQ(segments.size(), 1u);
+  EXPECT_FLOAT_EQ(segments[0].start_time, 0.0f);
+  EXPECT_FLOAT_EQ(segments[0].velocity, 10.0f);
+}
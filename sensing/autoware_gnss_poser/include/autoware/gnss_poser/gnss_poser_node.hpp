@@ -16,6 +16,7 @@
 
 #include <autoware/component_interface_specs_universe/map.hpp>
 #include <autoware/component_interface_utils/rclcpp.hpp>
+#include <diagnostic_updater/diagnostic_updater.hpp>
 #include <rclcpp/rclcpp.hpp>
 
 #include <autoware_sensing_msgs/msg/gnss_ins_orientation_stamped.hpp>
@@ -49,11 +50,17 @@ public:
 private:
   using MapProjectorInfo = autoware::component_interface_specs_universe::map::MapProjectorInfo;
 
+  // quality of the heading solution of the receiver, e.g. the baseline of dual antennas
+  enum class HeadingStatus { FIXED, FLOAT, INVALID };
+
   void callback_map_projector_info(const MapProjectorInfo::Message::ConstSharedPtr msg);
   void callback_nav_sat_fix(const sensor_msgs::msg::NavSatFix::ConstSharedPtr nav_sat_fix_msg_ptr);
   void callback_gnss_ins_orientation_stamped(
     const autoware_sensing_msgs::msg::GnssInsOrientationStamped::ConstSharedPtr msg);
 
+  HeadingStatus get_heading_status(const rclcpp::Time & stamp) const;
+  void check_gnss_heading(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
   static bool is_fixed(const sensor_msgs::msg::NavSatStatus & nav_sat_status_msg);
   static bool can_get_covariance(const sensor_msgs::msg::NavSatFix & nav_sat_fix_msg);
   static geometry_msgs::msg::Point get_median_position(
@@ -87,6 +94,7 @@ private:
 
   rclcpp::Publisher<geometry_msgs::msg::PoseStamped>::SharedPtr pose_pub_;
   rclcpp::Publisher<geometry_msgs::msg::PoseWithCovarianceStamped>::SharedPtr pose_cov_pub_;
+  rclcpp::Publisher<geometry_msgs::msg::PoseWithCovarianceStamped>::SharedPtr heading_cov_pub_;
   rclcpp::Publisher<tier4_debug_msgs::msg::BoolStamped>::SharedPtr fixed_pub_;
 
   MapProjectorInfo::Message projector_info_;
@@ -95,12 +103,21 @@ private:
   const std::string map_frame_;
   bool received_map_projector_info_ = false;
   bool use_gnss_ins_orientation_;
+  bool use_gnss_heading_;
 
   boost::circular_buffer<geometry_msgs::msg::Point> position_buffer_;
 
   autoware_sensing_msgs::msg::GnssInsOrientationStamped::SharedPtr
     msg_gnss_ins_orientation_stamped_;
+  bool received_gnss_ins_orientation_ = false;
   int gnss_pose_pub_method_;
+
+  const double heading_fixed_rmse_threshold_;
+  const double heading_float_rmse_threshold_;
+  const double heading_timeout_;
+  const bool heading_use_float_solution_;
+
+  diagnostic_updater::Updater diagnostics_updater_;
 };
 }  // namespace autoware::gnss_poser
 
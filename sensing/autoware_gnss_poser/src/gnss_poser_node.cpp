@@ -20,6 +20,7 @@
 #include <autoware_sensing_msgs/msg/gnss_ins_orientation_stamped.hpp>
 
 #include <algorithm>
+#include <cmath>
 #include <memory>
 #include <string>
 #include <vector>
@@ -34,9 +35,15 @@ GNSSPoser::GNSSPoser(const rclcpp::NodeOptions & node_options)
   gnss_base_frame_(declare_parameter<std::string>("gnss_base_frame")),
   map_frame_(declare_parameter<std::string>("map_frame")),
   use_gnss_ins_orientation_(declare_parameter<bool>("use_gnss_ins_orientation")),
+  use_gnss_heading_(declare_parameter<bool>("use_gnss_heading")),
   msg_gnss_ins_orientation_stamped_(
     std::make_shared<autoware_sensing_msgs::msg::GnssInsOrientationStamped>()),
-  gnss_pose_pub_method_(static_cast<int>(declare_parameter<int>("gnss_pose_pub_method")))
+  gnss_pose_pub_method_(static_cast<int>(declare_parameter<int>("gnss_pose_pub_method"))),
+  heading_fixed_rmse_threshold_(declare_parameter<double>("heading.fixed_rmse_threshold")),
+  heading_float_rmse_threshold_(declare_parameter<double>("heading.float_rmse_threshold")),
+  heading_timeout_(declare_parameter<double>("heading.timeout")),
+  heading_use_float_solution_(declare_parameter<bool>("heading.use_float_solution")),
+  diagnostics_updater_(this)
 {
   // Subscribe to map_projector_info topic
   const auto adaptor = autoware::component_interface_utils::NodeAdaptor(this);
@@ -61,8 +68,13 @@ GNSSPoser::GNSSPoser(const rclcpp::NodeOptions & node_options)
   pose_pub_ = create_publisher<geometry_msgs::msg::PoseStamped>("gnss_pose", rclcpp::QoS{1});
   pose_cov_pub_ = create_publisher<geometry_msgs::msg::PoseWithCovarianceStamped>(
     "gnss_pose_cov", rclcpp::QoS{1});
+  heading_cov_pub_ = create_publisher<geometry_msgs::msg::PoseWithCovarianceStamped>(
+    "gnss_heading_cov", rclcpp::QoS{1});
   fixed_pub_ = create_publisher<tier4_debug_msgs::msg::BoolStamped>("gnss_fixed", rclcpp::QoS{1});
 
+  diagnostics_updater_.setHardwareID("gnss_poser");
+  diagnostics_updater_.add("gnss_heading", this, &GNSSPoser::check_gnss_heading);
+
   // Set msg_gnss_ins_orientation_stamped_ with temporary values (not to publish zero value
   // covariances)
   msg_gnss_ins_orientation_stamped_->orientation.rmse_rotation_x = 1.0;
@@ -204,6 +216,17 @@ void GNSSPoser::callback_nav_sat_fix(
 
   pose_cov_pub_->publish(gnss_base_pose_cov_msg);
 
+  // publish the absolute heading only when the heading solution of the receiver is reliable, and
+  // the float solution is fused only when it is allowed explicitly
+  if (use_gnss_ins_orientation_ && use_gnss_heading_) {
+    const auto heading_status = get_heading_status(nav_sat_fix_msg_ptr->header.stamp);
+    if (
+      heading_status == HeadingStatus::FIXED ||
+      (heading_status == HeadingStatus::FLOAT && heading_use_float_solution_)) {
+      heading_cov_pub_->publish(gnss_base_pose_cov_msg);
+    }
+  }
+
   // broadcast map to gnss_base_link
   publish_tf(map_frame_, gnss_base_frame_, gnss_base_pose_msg);
 }
@@ -212,6 +235,54 @@ void GNSSPoser::callback_gnss_ins_orientation_stamped(
   const autoware_sensing_msgs::msg::GnssInsOrientationStamped::ConstSharedPtr msg)
 {
   *msg_gnss_ins_orientation_stamped_ = *msg;
+  received_gnss_ins_orientation_ = true;
+}
+
+GNSSPoser::HeadingStatus GNSSPoser::get_heading_status(const rclcpp::Time & stamp) const
+{
+  if (!received_gnss_ins_orientation_) {
+    return HeadingStatus::INVALID;
+  }
+
+  const rclcpp::Time orientation_stamp(msg_gnss_ins_orientation_stamped_->header.stamp);
+  if (std::abs((stamp - orientation_stamp).seconds()) > heading_timeout_) {
+    return HeadingStatus::INVALID;
+  }
+
+  // the receivers report a larger rmse for a float baseline solution than for a fixed one
+  const double rmse_yaw = msg_gnss_ins_orientation_stamped_->orientation.rmse_rotation_z;
+  if (!std::isfinite(rmse_yaw) || rmse_yaw > heading_float_rmse_threshold_) {
+    return HeadingStatus::INVALID;
+  }
+  return rmse_yaw > heading_fixed_rmse_threshold_ ? HeadingStatus::FLOAT : HeadingStatus::FIXED;
+}
+
+void GNSSPoser::check_gnss_heading(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  if (!use_gnss_ins_orientation_ || !use_gnss_heading_) {
+    stat.summary(DiagnosticStatus::OK, "heading is not used");
+    return;
+  }
+  // the receiver may take a while to solve the baseline after the startup
+  if (!received_gnss_ins_orientation_) {
+    stat.summary(DiagnosticStatus::STALE, "heading is not received yet");
+    return;
+  }
+
+  stat.add("rmse_yaw", msg_gnss_ins_orientation_stamped_->orientation.rmse_rotation_z);
+  switch (get_heading_status(this->now())) {
+    case HeadingStatus::FIXED:
+      stat.summary(DiagnosticStatus::OK, "OK");
+      break;
+    case HeadingStatus::FLOAT:
+      stat.summary(DiagnosticStatus::WARN, "heading is degraded (float solution)");
+      break;
+    default:
+      stat.summary(DiagnosticStatus::ERROR, "heading is invalid or timed out");
+      break;
+  }
 }
 
 bool GNSSPoser::is_fixed(const sensor_msgs::msg::NavSatStatus & nav_sat_status_msg)
@@ -0,0 +1,120 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "ultrasonic_driver_node.hpp"
+
+#include <algorithm>
+#include <string>
+
+namespace autoware::ultrasonic_driver
+{
+UltrasonicDriver::UltrasonicDriver(const rclcpp::NodeOptions & node_options)
+: Node("ultrasonic_driver", node_options), updater_(this)
+{
+  // Parameter
+  const auto no_echo_value = static_cast<uint64_t>(declare_parameter<int>("no_echo_value"));
+  for (const auto & name : declare_parameter<std::vector<std::string>>("sensor_names")) {
+    const auto prefix = "sensors." + name + ".";
+    Sensor sensor;
+    sensor.name = name;
+    sensor.frame_id = declare_parameter<std::string>(prefix + "frame_id");
+    sensor.signal.id = static_cast<uint32_t>(declare_parameter<int>(prefix + "id"));
+    sensor.signal.start_bit = static_cast<uint32_t>(declare_parameter<int>(prefix + "start_bit"));
+    sensor.signal.length = static_cast<uint32_t>(declare_parameter<int>(prefix + "length"));
+    sensor.signal.is_little_endian = declare_parameter<bool>(prefix + "little_endian");
+    sensor.signal.scale = declare_parameter<double>(prefix + "scale");
+    sensor.signal.no_echo_value = no_echo_value;
+    node_param_.sensors.push_back(sensor);
+  }
+  node_param_.field_of_view = declare_parameter<double>("field_of_view");
+  node_param_.min_range = declare_parameter<double>("min_range");
+  node_param_.max_range = declare_parameter<double>("max_range");
+  node_param_.data_timeout = declare_parameter<double>("data_timeout");
+
+  ranges_.resize(node_param_.sensors.size());
+  received_times_.resize(node_param_.sensors.size());
+
+  // Subscriber
+  sub_can_frame_ = create_subscription<can_msgs::msg::Frame>(
+    "input/can_frame", rclcpp::QoS{100},
+    std::bind(&UltrasonicDriver::on_can_frame, this, std::placeholders::_1));
+
+  // Publisher
+  pub_range_ = create_publisher<Range>("output/range", rclcpp::SensorDataQoS());
+
+  // Diagnostic Updater
+  updater_.setHardwareID("ultrasonic_driver");
+  updater_.add("ultrasonic_sensors", this, &UltrasonicDriver::check_sensors);
+}
+
+void UltrasonicDriver::on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg)
+{
+  if (msg->is_rtr || msg->is_error) {
+    return;
+  }
+
+  // a frame usually carries the ranges of several sensors
+  for (size_t i = 0; i < node_param_.sensors.size(); ++i) {
+    const auto & sensor = node_param_.sensors.at(i);
+    if (sensor.signal.id != msg->id) {
+      continue;
+    }
+    const auto range = decode_range(
+      sensor.signal, msg->data.data(), std::min<size_t>(msg->dlc, 8),
+      static_cast<float>(node_param_.min_range), static_cast<float>(node_param_.max_range));
+    if (!range) {
+      continue;
+    }
+    ranges_.at(i) = *range;
+    received_times_.at(i) = now();
+
+    Range range_msg;
+    range_msg.header.stamp = msg->header.stamp;
+    range_msg.header.frame_id = sensor.frame_id;
+    range_msg.radiation_type = Range::ULTRASOUND;
+    range_msg.field_of_view = static_cast<float>(node_param_.field_of_view);
+    range_msg.min_range = static_cast<float>(node_param_.min_range);
+    range_msg.max_range = static_cast<float>(node_param_.max_range);
+    range_msg.range = *range;
+    pub_range_->publish(range_msg);
+  }
+}
+
+void UltrasonicDriver::check_sensors(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  const auto current_time = now();
+  std::string lost_sensors;
+  for (size_t i = 0; i < node_param_.sensors.size(); ++i) {
+    const auto & name = node_param_.sensors.at(i).name;
+    const auto & received_time = received_times_.at(i);
+    if (!received_time || (current_time - *received_time).seconds() > node_param_.data_timeout) {
+      lost_sensors += (lost_sensors.empty() ? "" : ", ") + name;
+      stat.add(name, "lost");
+      continue;
+    }
+    stat.addf(name, "%.2f [m]", *ranges_.at(i));
+  }
+
+  if (!lost_sensors.empty()) {
+    stat.summary(DiagnosticStatus::ERROR, "no range from " + lost_sensors);
+    return;
+  }
+  stat.summary(DiagnosticStatus::OK, "OK");
+}
+}  // namespace autoware::ultrasonic_driver
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::ultrasonic_driver::UltrasonicDriver)
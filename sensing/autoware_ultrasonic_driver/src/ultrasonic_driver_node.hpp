@@ -0,0 +1,79 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef ULTRASONIC_DRIVER_NODE_HPP_
+#define ULTRASONIC_DRIVER_NODE_HPP_
+
+#include "ultrasonic_signal.hpp"
+
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <can_msgs/msg/frame.hpp>
+#include <sensor_msgs/msg/range.hpp>
+
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::ultrasonic_driver
+{
+using sensor_msgs::msg::Range;
+
+struct Sensor
+{
+  std::string name;
+  std::string frame_id;
+  RangeSignal signal;
+};
+
+struct NodeParam
+{
+  std::vector<Sensor> sensors;
+  double field_of_view{};
+  double min_range{};
+  double max_range{};
+  double data_timeout{};
+};
+
+class UltrasonicDriver : public rclcpp::Node
+{
+public:
+  explicit UltrasonicDriver(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<can_msgs::msg::Frame>::SharedPtr sub_can_frame_;
+
+  void on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg);
+
+  // Publisher
+  rclcpp::Publisher<Range>::SharedPtr pub_range_;
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_sensors(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  // the latest range and its received time of each sensor, in the order of the parameter
+  std::vector<std::optional<float>> ranges_;
+  std::vector<std::optional<rclcpp::Time>> received_times_;
+};
+}  // namespace autoware::ultrasonic_driver
+
+#endif  // ULTRASONIC_DRIVER_NODE_HPP_
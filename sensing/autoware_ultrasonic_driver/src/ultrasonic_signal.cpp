@@ -0,0 +1,47 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "ultrasonic_signal.hpp"
+
+#include <limits>
+
+namespace autoware::ultrasonic_driver
+{
+std::optional<float> decode_range(
+  const RangeSignal & signal, const uint8_t * data, size_t size, const float min_range,
+  const float max_range)
+{
+  // the raw value is decoded first to be compared with no_echo_value
+  autoware::can_signal_utils::CanSignal raw_signal = signal;
+  raw_signal.is_signed = false;
+  raw_signal.scale = 1.0;
+  raw_signal.offset = 0.0;
+  const auto raw = autoware::can_signal_utils::decode_signal(raw_signal, data, size);
+  if (!raw) {
+    return std::nullopt;
+  }
+  if (static_cast<uint64_t>(*raw) == signal.no_echo_value) {
+    return std::numeric_limits<float>::infinity();
+  }
+
+  const auto range = static_cast<float>(*raw * signal.scale);
+  if (range < min_range) {
+    return -std::numeric_limits<float>::infinity();
+  }
+  if (range > max_range) {
+    return std::numeric_limits<float>::infinity();
+  }
+  return range;
+}
+}  // namespace autoware::ultrasonic_driver
@@ -0,0 +1,41 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef ULTRASONIC_SIGNAL_HPP_
+#define ULTRASONIC_SIGNAL_HPP_
+
+#include <autoware/can_signal_utils/can_signal.hpp>
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+
+namespace autoware::ultrasonic_driver
+{
+/**
+ * @brief unsigned distance signal of a CAN frame, whose scale is [m] per LSB
+ */
+struct RangeSignal : public autoware::can_signal_utils::CanSignal
+{
+  // the raw value reported when no echo is received
+  uint64_t no_echo_value{};
+};
+
+// Returns the range of REP 117, which is +inf when no echo is received and -inf when the object is
+// closer than min_range. Returns nullopt when the signal does not fit in the data.
+std::optional<float> decode_range(
+  const RangeSignal & signal, const uint8_t * data, size_t size, float min_range, float max_range);
+}  // namespace autoware::ultrasonic_driver
+
+#endif  // ULTRASONIC_SIGNAL_HPP_
@@ -0,0 +1,82 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "ultrasonic_signal.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+
+using autoware::ultrasonic_driver::decode_range;
+using autoware::ultrasonic_driver::RangeSignal;
+
+namespace
+{
+RangeSignal create_signal(const uint32_t start_bit, const bool is_little_endian)
+{
+  RangeSignal signal;
+  signal.id = 0x300;
+  signal.start_bit = start_bit;
+  signal.length = 16;
+  signal.is_little_endian = is_little_endian;
+  signal.scale = 0.01;
+  signal.no_echo_value = 0xFFFF;
+  return signal;
+}
+}  // namespace
+
+TEST(UltrasonicSignal, DecodeLittleEndian)
+{
+  // 0x0096 = 150 [cm] in the second signal
+  const uint8_t data[] = {0x00, 0x00, 0x96, 0x00, 0x00, 0x00, 0x00, 0x00};
+  const auto range = decode_range(create_signal(16, true), data, sizeof(data), 0.2f, 5.0f);
+  ASSERT_TRUE(range.has_value());
+  EXPECT_NEAR(*range, 1.5f, 1e-5);
+}
+
+TEST(UltrasonicSignal, DecodeBigEndian)
+{
+  // the MSB of the first byte is the start bit 7
+  const uint8_t data[] = {0x01, 0x2C};
+  const auto range = decode_range(create_signal(7, false), data, sizeof(data), 0.2f, 5.0f);
+  ASSERT_TRUE(range.has_value());
+  EXPECT_NEAR(*range, 3.0f, 1e-5);
+}
+
+TEST(UltrasonicSignal, OutOfRange)
+{
+  const auto signal = create_signal(0, true);
+
+  const uint8_t no_echo[] = {0xFF, 0xFF};
+  const auto no_echo_range = decode_range(signal, no_echo, sizeof(no_echo), 0.2f, 5.0f);
+  ASSERT_TRUE(no_echo_range.has_value());
+  EXPECT_TRUE(std::isinf(*no_echo_range) && *no_echo_range > 0.0f);
+
+  const uint8_t too_far[] = {0x58, 0x02};
+  const auto too_far_range = decode_range(signal, too_far, sizeof(too_far), 0.2f, 5.0f);
+  ASSERT_TRUE(too_far_range.has_value());
+  EXPECT_TRUE(std::isinf(*too_far_range) && *too_far_range > 0.0f);
+
+  const uint8_t too_close[] = {0x0A, 0x00};
+  const auto too_close_range = decode_range(signal, too_close, sizeof(too_close), 0.2f, 5.0f);
+  ASSERT_TRUE(too_close_range.has_value());
+  EXPECT_TRUE(std::isinf(*too_close_range) && *too_close_range < 0.0f);
+}
+
+TEST(UltrasonicSignal, ShortFrame)
+{
+  const uint8_t data[] = {0x96, 0x00};
+  EXPECT_FALSE(decode_range(create_signal(8, true), data, sizeof(data), 0.2f, 5.0f).has_value());
+  EXPECT_FALSE(decode_range(create_signal(15, false), data, sizeof(data), 0.2f, 5.0f).has_value());
+}
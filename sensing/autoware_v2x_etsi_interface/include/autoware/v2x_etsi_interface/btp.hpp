@@ -0,0 +1,47 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef AUTOWARE__V2X_ETSI_INTERFACE__BTP_HPP_
+#define AUTOWARE__V2X_ETSI_INTERFACE__BTP_HPP_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+// Non-interactive transport header (BTP-B) of ETSI EN 302 636-5-1.
+namespace autoware::v2x_etsi_interface
+{
+namespace btp_port
+{
+constexpr uint16_t CAM = 2001;
+constexpr uint16_t DENM = 2002;
+}  // namespace btp_port
+
+constexpr size_t btp_b_header_size = 4;
+
+struct BtpPacket
+{
+  uint16_t destination_port{0};
+  uint16_t destination_port_info{0};
+  std::vector<uint8_t> payload;
+};
+
+std::vector<uint8_t> serialize_btp_b(const BtpPacket & packet);
+
+// returns empty when the datagram is shorter than the header
+std::optional<BtpPacket> parse_btp_b(const uint8_t * data, size_t size);
+}  // namespace autoware::v2x_etsi_interface
+
+#endif  // AUTOWARE__V2X_ETSI_INTERFACE__BTP_HPP_
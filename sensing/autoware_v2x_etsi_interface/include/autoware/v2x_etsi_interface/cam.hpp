@@ -0,0 +1,75 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef AUTOWARE__V2X_ETSI_INTERFACE__CAM_HPP_
+#define AUTOWARE__V2X_ETSI_INTERFACE__CAM_HPP_
+
+#include "autoware/v2x_etsi_interface/its_container.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+// Cooperative Awareness Message of ETSI EN 302 637-2 v1.4.1.
+// Only the basic container and the high frequency container of a vehicle are supported. When
+// decoding, the optional fields and the other containers are ignored.
+namespace autoware::v2x_etsi_interface
+{
+struct BasicVehicleHighFrequency
+{
+  // [0.1 degree], clockwise from the north
+  uint16_t heading{unavailable::HEADING};
+  uint8_t heading_confidence{unavailable::CONFIDENCE};
+  // [0.01 m/s]
+  uint16_t speed{unavailable::SPEED};
+  uint8_t speed_confidence{unavailable::CONFIDENCE};
+  // forward(0), backward(1), unavailable(2)
+  uint8_t drive_direction{2};
+  // [0.1 m]
+  uint16_t vehicle_length{unavailable::VEHICLE_LENGTH};
+  // unavailable(4)
+  uint8_t vehicle_length_confidence{4};
+  // [0.1 m]
+  uint8_t vehicle_width{unavailable::VEHICLE_WIDTH};
+  // [0.1 m/s^2]
+  int16_t longitudinal_acceleration{unavailable::LONGITUDINAL_ACCELERATION};
+  uint8_t longitudinal_acceleration_confidence{unavailable::ACCELERATION_CONFIDENCE};
+  // [1/10000 1/m]
+  int16_t curvature{unavailable::CURVATURE};
+  uint8_t curvature_confidence{unavailable::CURVATURE_CONFIDENCE};
+  // yawRateUsed(0), yawRateNotUsed(1), unavailable(2)
+  uint8_t curvature_calculation_mode{2};
+  // [0.01 degree/s], positive to the left
+  int32_t yaw_rate{unavailable::YAW_RATE};
+  uint8_t yaw_rate_confidence{unavailable::YAW_RATE_CONFIDENCE};
+};
+
+struct Cam
+{
+  ItsPduHeader header{2, message_id::CAM, 0};
+  // [ms], TimestampIts modulo 65536
+  uint16_t generation_delta_time{0};
+  uint8_t station_type{station_type::UNKNOWN};
+  ReferencePosition reference_position;
+  // empty when the sender is a road side unit
+  std::optional<BasicVehicleHighFrequency> high_frequency;
+};
+
+std::vector<uint8_t> encode_cam(const Cam & cam);
+
+// throws UperError when the payload is not a valid CAM
+Cam decode_cam(const std::vector<uint8_t> & payload);
+}  // namespace autoware::v2x_etsi_interface
+
+#endif  // AUTOWARE__V2X_ETSI_INTERFACE__CAM_HPP_
@@ -0,0 +1,49 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef AUTOWARE__V2X_ETSI_INTERFACE__CONVERSION_HPP_
+#define AUTOWARE__V2X_ETSI_INTERFACE__CONVERSION_HPP_
+
+#include <cstdint>
+
+// Conversion between SI units and the units of ETSI TS 102 894-2.
+// Out of range values are saturated to the largest valid value, not to "unavailable".
+namespace autoware::v2x_etsi_interface
+{
+int32_t to_latitude_value(double latitude_deg);
+int32_t to_longitude_value(double longitude_deg);
+double from_latitude_value(int32_t value);
+double from_longitude_value(int32_t value);
+int32_t to_altitude_value(double altitude_m);
+
+// yaw is counterclockwise from the east (ENU), heading is clockwise from the north
+uint16_t to_heading_value(double yaw_rad);
+double to_yaw(uint16_t heading_value);
+
+uint16_t to_speed_value(double speed_mps);
+double from_speed_value(uint16_t value);
+int32_t to_yaw_rate_value(double yaw_rate_radps);
+int16_t to_longitudinal_acceleration_value(double acceleration_mps2);
+int16_t to_curvature_value(double curvature_per_m);
+uint16_t to_vehicle_length_value(double length_m);
+uint8_t to_vehicle_width_value(double width_m);
+double from_vehicle_length_value(uint16_t value);
+double from_vehicle_width_value(uint8_t value);
+
+// TimestampIts counts milliseconds of TAI since 2004-01-01T00:00:00.000Z
+uint64_t to_timestamp_its(int64_t unix_time_ns, int leap_seconds);
+uint16_t to_generation_delta_time(uint64_t timestamp_its);
+}  // namespace autoware::v2x_etsi_interface
+
+#endif  // AUTOWARE__V2X_ETSI_INTERFACE__CONVERSION_HPP_
@@ -0,0 +1,65 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef AUTOWARE__V2X_ETSI_INTERFACE__DENM_HPP_
+#define AUTOWARE__V2X_ETSI_INTERFACE__DENM_HPP_
+
+#include "autoware/v2x_etsi_interface/its_container.hpp"
+
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+// Decentralized Environmental Notification Message of ETSI EN 302 637-3 v1.3.1.
+// Only the management container and the event type of the situation container are supported.
+// When decoding, the optional fields and the other containers are ignored.
+namespace autoware::v2x_etsi_interface
+{
+struct ManagementContainer
+{
+  uint32_t originating_station_id{0};
+  uint16_t sequence_number{0};
+  // [ms] since 2004-01-01T00:00:00.000Z
+  uint64_t detection_time{0};
+  // [ms] since 2004-01-01T00:00:00.000Z
+  uint64_t reference_time{0};
+  // isCancellation(0), isNegation(1)
+  std::optional<uint8_t> termination;
+  ReferencePosition event_position;
+  // [s]
+  uint32_t validity_duration{600};
+  uint8_t station_type{station_type::UNKNOWN};
+};
+
+struct CauseCode
+{
+  uint8_t cause_code{0};
+  uint8_t sub_cause_code{0};
+};
+
+struct Denm
+{
+  ItsPduHeader header{2, message_id::DENM, 0};
+  ManagementContainer management;
+  std::optional<CauseCode> event_type;
+  uint8_t information_quality{0};
+};
+
+std::vector<uint8_t> encode_denm(const Denm & denm);
+
+// throws UperError when the payload is not a valid DENM
+Denm decode_denm(const std::vector<uint8_t> & payload);
+}  // namespace autoware::v2x_etsi_interface
+
+#endif  // AUTOWARE__V2X_ETSI_INTERFACE__DENM_HPP_
@@ -0,0 +1,100 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef AUTOWARE__V2X_ETSI_INTERFACE__ITS_CONTAINER_HPP_
+#define AUTOWARE__V2X_ETSI_INTERFACE__ITS_CONTAINER_HPP_
+
+#include "autoware/v2x_etsi_interface/uper_bit_stream.hpp"
+
+#include <cstdint>
+
+// Data elements of ETSI TS 102 894-2 v1.3.1 (common data dictionary).
+// All values are kept in the units of the standard, see conversion.hpp for the SI conversion.
+namespace autoware::v2x_etsi_interface
+{
+namespace message_id
+{
+constexpr uint8_t DENM = 1;
+constexpr uint8_t CAM = 2;
+}  // namespace message_id
+
+namespace station_type
+{
+constexpr uint8_t UNKNOWN = 0;
+constexpr uint8_t PEDESTRIAN = 1;
+constexpr uint8_t CYCLIST = 2;
+constexpr uint8_t MOPED = 3;
+constexpr uint8_t MOTORCYCLE = 4;
+constexpr uint8_t PASSENGER_CAR = 5;
+constexpr uint8_t BUS = 6;
+constexpr uint8_t LIGHT_TRUCK = 7;
+constexpr uint8_t HEAVY_TRUCK = 8;
+constexpr uint8_t TRAILER = 9;
+constexpr uint8_t SPECIAL_VEHICLES = 10;
+constexpr uint8_t TRAM = 11;
+constexpr uint8_t ROAD_SIDE_UNIT = 15;
+}  // namespace station_type
+
+namespace unavailable
+{
+constexpr int32_t LATITUDE = 900000001;
+constexpr int32_t LONGITUDE = 1800000001;
+constexpr int32_t ALTITUDE = 800001;
+constexpr uint16_t SEMI_AXIS_LENGTH = 4095;
+constexpr uint16_t HEADING = 3601;
+constexpr uint8_t CONFIDENCE = 127;
+constexpr uint16_t SPEED = 16383;
+constexpr uint16_t VEHICLE_LENGTH = 1023;
+constexpr uint8_t VEHICLE_WIDTH = 62;
+constexpr int16_t LONGITUDINAL_ACCELERATION = 161;
+constexpr uint8_t ACCELERATION_CONFIDENCE = 102;
+constexpr int16_t CURVATURE = 1023;
+constexpr uint8_t CURVATURE_CONFIDENCE = 7;
+constexpr int32_t YAW_RATE = 32767;
+constexpr uint8_t YAW_RATE_CONFIDENCE = 8;
+constexpr uint8_t ALTITUDE_CONFIDENCE = 15;
+}  // namespace unavailable
+
+struct ItsPduHeader
+{
+  uint8_t protocol_version{2};
+  uint8_t message_id{0};
+  uint32_t station_id{0};
+};
+
+struct ReferencePosition
+{
+  // [0.1 micro degree]
+  int32_t latitude{unavailable::LATITUDE};
+  // [0.1 micro degree]
+  int32_t longitude{unavailable::LONGITUDE};
+  // [cm]
+  uint16_t semi_major_confidence{unavailable::SEMI_AXIS_LENGTH};
+  // [cm]
+  uint16_t semi_minor_confidence{unavailable::SEMI_AXIS_LENGTH};
+  // [0.1 degree]
+  uint16_t semi_major_orientation{unavailable::HEADING};
+  // [cm]
+  int32_t altitude{unavailable::ALTITUDE};
+  uint8_t altitude_confidence{unavailable::ALTITUDE_CONFIDENCE};
+};
+
+void encode(BitWriter & writer, const ItsPduHeader & header);
+ItsPduHeader decode_its_pdu_header(BitReader & reader);
+
+void encode(BitWriter & writer, const ReferencePosition & position);
+ReferencePosition decode_reference_position(BitReader & reader);
+}  // namespace autoware::v2x_etsi_interface
+
+#endif  // AUTOWARE__V2X_ETSI_INTERFACE__ITS_CONTAINER_HPP_
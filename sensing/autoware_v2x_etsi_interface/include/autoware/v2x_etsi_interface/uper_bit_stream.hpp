@@ -0,0 +1,90 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef AUTOWARE__V2X_ETSI_INTERFACE__UPER_BIT_STREAM_HPP_
+#define AUTOWARE__V2X_ETSI_INTERFACE__UPER_BIT_STREAM_HPP_
+
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <vector>
+
+namespace autoware::v2x_etsi_interface
+{
+/**
+ * @brief error raised when a message cannot be decoded or a value is out of its constraint
+ */
+class UperError : public std::runtime_error
+{
+public:
+  using std::runtime_error::runtime_error;
+};
+
+/**
+ * @brief number of bits of a constrained whole number in the unaligned PER (X.691)
+ */
+size_t constrained_bit_length(int64_t min, int64_t max);
+
+/**
+ * @brief writer of the subset of the unaligned PER used by the ETSI ITS messages
+ */
+class BitWriter
+{
+public:
+  void write_bits(uint64_t value, size_t bit_length);
+  void write_bool(bool value);
+  void write_constrained(int64_t value, int64_t min, int64_t max);
+  void write_enumerated(uint64_t index, uint64_t size) { write_constrained(index, 0, size - 1); }
+
+  [[nodiscard]] size_t bit_length() const { return bit_length_; }
+
+  // the buffer padded with zero bits up to the octet boundary
+  [[nodiscard]] const std::vector<uint8_t> & data() const { return data_; }
+
+private:
+  std::vector<uint8_t> data_;
+  size_t bit_length_{0};
+};
+
+/**
+ * @brief reader of the subset of the unaligned PER used by the ETSI ITS messages
+ */
+class BitReader
+{
+public:
+  BitReader(const uint8_t * data, size_t size) : data_(data), size_(size) {}
+  explicit BitReader(const std::vector<uint8_t> & data) : BitReader(data.data(), data.size()) {}
+
+  uint64_t read_bits(size_t bit_length);
+  bool read_bool();
+  int64_t read_constrained(int64_t min, int64_t max);
+  uint64_t read_enumerated(uint64_t size) { return read_constrained(0, size - 1); }
+
+  // skip the extension additions of an extensible SEQUENCE whose extension bit is set
+  void skip_sequence_extensions();
+
+  [[nodiscard]] size_t position() const { return position_; }
+  [[nodiscard]] size_t remaining_bits() const { return size_ * 8 - position_; }
+
+private:
+  size_t read_length_determinant();
+  size_t read_normally_small_length();
+
+  const uint8_t * data_;
+  size_t size_;
+  size_t position_{0};
+};
+}  // namespace autoware::v2x_etsi_interface
+
+#endif  // AUTOWARE__V2X_ETSI_INTERFACE__UPER_BIT_STREAM_HPP_
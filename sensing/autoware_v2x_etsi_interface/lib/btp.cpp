@@ -0,0 +1,45 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/btp.hpp"
+
+#include <vector>
+
+namespace autoware::v2x_etsi_interface
+{
+std::vector<uint8_t> serialize_btp_b(const BtpPacket & packet)
+{
+  std::vector<uint8_t> data;
+  data.reserve(btp_b_header_size + packet.payload.size());
+  // both fields are in network byte order
+  data.push_back(static_cast<uint8_t>(packet.destination_port >> 8));
+  data.push_back(static_cast<uint8_t>(packet.destination_port & 0xFF));
+  data.push_back(static_cast<uint8_t>(packet.destination_port_info >> 8));
+  data.push_back(static_cast<uint8_t>(packet.destination_port_info & 0xFF));
+  data.insert(data.end(), packet.payload.begin(), packet.payload.end());
+  return data;
+}
+
+std::optional<BtpPacket> parse_btp_b(const uint8_t * data, const size_t size)
+{
+  if (size < btp_b_header_size) {
+    return std::nullopt;
+  }
+  BtpPacket packet;
+  packet.destination_port = static_cast<uint16_t>((data[0] << 8) | data[1]);
+  packet.destination_port_info = static_cast<uint16_t>((data[2] << 8) | data[3]);
+  packet.payload.assign(data + btp_b_header_size, data + size);
+  return packet;
+}
+}  // namespace autoware::v2x_etsi_interface
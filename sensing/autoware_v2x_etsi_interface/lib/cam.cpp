@@ -0,0 +1,154 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/cam.hpp"
+
+#include <vector>
+
+namespace autoware::v2x_etsi_interface
+{
+namespace
+{
+constexpr size_t high_frequency_optional_count = 7;
+
+void encode(BitWriter & writer, const BasicVehicleHighFrequency & hf)
+{
+  // no optional field is sent
+  writer.write_bits(0, high_frequency_optional_count);
+
+  writer.write_constrained(hf.heading, 0, 3601);
+  writer.write_constrained(hf.heading_confidence, 1, 127);
+  writer.write_constrained(hf.speed, 0, 16383);
+  writer.write_constrained(hf.speed_confidence, 1, 127);
+  writer.write_enumerated(hf.drive_direction, 3);
+  writer.write_constrained(hf.vehicle_length, 1, 1023);
+  writer.write_enumerated(hf.vehicle_length_confidence, 5);
+  writer.write_constrained(hf.vehicle_width, 1, 62);
+  writer.write_constrained(hf.longitudinal_acceleration, -160, 161);
+  writer.write_constrained(hf.longitudinal_acceleration_confidence, 0, 102);
+  writer.write_constrained(hf.curvature, -1023, 1023);
+  writer.write_enumerated(hf.curvature_confidence, 8);
+  // CurvatureCalculationMode is an extensible enumeration
+  writer.write_bool(false);
+  writer.write_enumerated(hf.curvature_calculation_mode, 3);
+  writer.write_constrained(hf.yaw_rate, -32766, 32767);
+  // YawRateConfidence is an extensible enumeration
+  writer.write_bool(false);
+  writer.write_enumerated(hf.yaw_rate_confidence, 9);
+}
+
+BasicVehicleHighFrequency decode_basic_vehicle_high_frequency(BitReader & reader)
+{
+  // the optional fields are encoded after the mandatory ones, so they can be ignored
+  reader.read_bits(high_frequency_optional_count);
+
+  BasicVehicleHighFrequency hf;
+  hf.heading = static_cast<uint16_t>(reader.read_constrained(0, 3601));
+  hf.heading_confidence = static_cast<uint8_t>(reader.read_constrained(1, 127));
+  hf.speed = static_cast<uint16_t>(reader.read_constrained(0, 16383));
+  hf.speed_confidence = static_cast<uint8_t>(reader.read_constrained(1, 127));
+  hf.drive_direction = static_cast<uint8_t>(reader.read_enumerated(3));
+  hf.vehicle_length = static_cast<uint16_t>(reader.read_constrained(1, 1023));
+  hf.vehicle_length_confidence = static_cast<uint8_t>(reader.read_enumerated(5));
+  hf.vehicle_width = static_cast<uint8_t>(reader.read_constrained(1, 62));
+  hf.longitudinal_acceleration = static_cast<int16_t>(reader.read_constrained(-160, 161));
+  hf.longitudinal_acceleration_confidence = static_cast<uint8_t>(reader.read_constrained(0, 102));
+  hf.curvature = static_cast<int16_t>(reader.read_constrained(-1023, 1023));
+  hf.curvature_confidence = static_cast<uint8_t>(reader.read_enumerated(8));
+  if (reader.read_bool()) {
+    // unknown extension value of CurvatureCalculationMode
+    reader.read_bits(7);
+    hf.curvature_calculation_mode = 2;
+  } else {
+    hf.curvature_calculation_mode = static_cast<uint8_t>(reader.read_enumerated(3));
+  }
+  hf.yaw_rate = static_cast<int32_t>(reader.read_constrained(-32766, 32767));
+  if (reader.read_bool()) {
+    // unknown extension value of YawRateConfidence
+    reader.read_bits(7);
+    hf.yaw_rate_confidence = unavailable::YAW_RATE_CONFIDENCE;
+  } else {
+    hf.yaw_rate_confidence = static_cast<uint8_t>(reader.read_enumerated(9));
+  }
+  return hf;
+}
+}  // namespace
+
+std::vector<uint8_t> encode_cam(const Cam & cam)
+{
+  if (!cam.high_frequency) {
+    throw UperError("CAM without the vehicle high frequency container is not supported");
+  }
+
+  BitWriter writer;
+  auto header = cam.header;
+  header.message_id = message_id::CAM;
+  encode(writer, header);
+
+  // CoopAwareness
+  writer.write_constrained(cam.generation_delta_time, 0, 65535);
+
+  // CamParameters: extension bit, lowFrequencyContainer and specialVehicleContainer are absent
+  writer.write_bool(false);
+  writer.write_bool(false);
+  writer.write_bool(false);
+
+  // BasicContainer
+  writer.write_bool(false);
+  writer.write_constrained(cam.station_type, 0, 255);
+  encode(writer, cam.reference_position);
+
+  // HighFrequencyContainer: extension bit and basicVehicleContainerHighFrequency(0)
+  writer.write_bool(false);
+  writer.write_enumerated(0, 2);
+  encode(writer, *cam.high_frequency);
+
+  return writer.data();
+}
+
+Cam decode_cam(const std::vector<uint8_t> & payload)
+{
+  BitReader reader(payload);
+
+  Cam cam;
+  cam.header = decode_its_pdu_header(reader);
+  if (cam.header.message_id != message_id::CAM) {
+    throw UperError("message is not a CAM");
+  }
+
+  cam.generation_delta_time = static_cast<uint16_t>(reader.read_constrained(0, 65535));
+
+  // CamParameters: only the preamble is needed since the optional containers come last
+  reader.read_bool();
+  reader.read_bool();
+  reader.read_bool();
+
+  // BasicContainer
+  const bool has_basic_container_extension = reader.read_bool();
+  cam.station_type = static_cast<uint8_t>(reader.read_constrained(0, 255));
+  cam.reference_position = decode_reference_position(reader);
+  if (has_basic_container_extension) {
+    reader.skip_sequence_extensions();
+  }
+
+  // HighFrequencyContainer
+  if (reader.read_bool()) {
+    throw UperError("unknown high frequency container");
+  }
+  if (reader.read_enumerated(2) == 0) {
+    cam.high_frequency = decode_basic_vehicle_high_frequency(reader);
+  }
+  return cam;
+}
+}  // namespace autoware::v2x_etsi_interface
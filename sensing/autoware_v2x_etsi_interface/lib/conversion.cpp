@@ -0,0 +1,133 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/conversion.hpp"
+
+#include <algorithm>
+#include <cmath>
+
+namespace autoware::v2x_etsi_interface
+{
+namespace
+{
+constexpr double rad2deg = 180.0 / M_PI;
+constexpr double deg2rad = M_PI / 180.0;
+constexpr int64_t its_epoch_unix_ms = 1072915200000;
+
+template <typename T>
+T saturate(const double value, const T min, const T max)
+{
+  return static_cast<T>(
+    std::clamp(std::round(value), static_cast<double>(min), static_cast<double>(max)));
+}
+}  // namespace
+
+int32_t to_latitude_value(const double latitude_deg)
+{
+  return saturate<int32_t>(latitude_deg * 1e7, -900000000, 900000000);
+}
+
+int32_t to_longitude_value(const double longitude_deg)
+{
+  return saturate<int32_t>(longitude_deg * 1e7, -1800000000, 1800000000);
+}
+
+double from_latitude_value(const int32_t value)
+{
+  return static_cast<double>(value) * 1e-7;
+}
+
+double from_longitude_value(const int32_t value)
+{
+  return static_cast<double>(value) * 1e-7;
+}
+
+int32_t to_altitude_value(const double altitude_m)
+{
+  return saturate<int32_t>(altitude_m * 100.0, -100000, 800000);
+}
+
+uint16_t to_heading_value(const double yaw_rad)
+{
+  double heading_deg = std::fmod(90.0 - yaw_rad * rad2deg, 360.0);
+  if (heading_deg < 0.0) {
+    heading_deg += 360.0;
+  }
+  const auto value = static_cast<uint16_t>(std::round(heading_deg * 10.0));
+  return value >= 3600 ? 0 : value;
+}
+
+double to_yaw(const uint16_t heading_value)
+{
+  const double yaw = (90.0 - static_cast<double>(heading_value) * 0.1) * deg2rad;
+  return std::atan2(std::sin(yaw), std::cos(yaw));
+}
+
+uint16_t to_speed_value(const double speed_mps)
+{
+  return saturate<uint16_t>(std::abs(speed_mps) * 100.0, 0, 16382);
+}
+
+double from_speed_value(const uint16_t value)
+{
+  return static_cast<double>(value) * 0.01;
+}
+
+int32_t to_yaw_rate_value(const double yaw_rate_radps)
+{
+  return saturate<int32_t>(yaw_rate_radps * rad2deg * 100.0, -32766, 32766);
+}
+
+int16_t to_longitudinal_acceleration_value(const double acceleration_mps2)
+{
+  return saturate<int16_t>(acceleration_mps2 * 10.0, -160, 160);
+}
+
+int16_t to_curvature_value(const double curvature_per_m)
+{
+  return saturate<int16_t>(curvature_per_m * 10000.0, -1022, 1022);
+}
+
+uint16_t to_vehicle_length_value(const double length_m)
+{
+  return saturate<uint16_t>(length_m * 10.0, 1, 1022);
+}
+
+uint8_t to_vehicle_width_value(const double width_m)
+{
+  return saturate<uint8_t>(width_m * 10.0, 1, 61);
+}
+
+double from_vehicle_length_value(const uint16_t value)
+{
+  return static_cast<double>(value) * 0.1;
+}
+
+double from_vehicle_width_value(const uint8_t value)
+{
+  return static_cast<double>(value) * 0.1;
+}
+
+uint64_t to_timestamp_its(const int64_t unix_time_ns, const int leap_seconds)
+{
+  const int64_t unix_time_ms = unix_time_ns / 1000000;
+  return static_cast<uint64_t>(
+    std::max<int64_t>(0, unix_time_ms - its_epoch_unix_ms + leap_seconds * 1000));
+}
+
+uint16_t to_generation_delta_time(const uint64_t timestamp_its)
+{
+  return static_cast<uint16_t>(timestamp_its % 65536);
+}
+}  // namespace autoware::v2x_etsi_interface
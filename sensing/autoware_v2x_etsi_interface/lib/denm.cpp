@@ -0,0 +1,152 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/denm.hpp"
+
+#include <vector>
+
+namespace autoware::v2x_etsi_interface
+{
+namespace
+{
+constexpr int64_t max_timestamp_its = 4398046511103;
+constexpr uint32_t default_validity_duration = 600;
+
+void encode(BitWriter & writer, const ManagementContainer & management)
+{
+  const bool has_validity_duration = management.validity_duration != default_validity_duration;
+
+  // extension bit and the presence of termination, relevanceDistance, relevanceTrafficDirection,
+  // validityDuration and transmissionInterval
+  writer.write_bool(false);
+  writer.write_bool(management.termination.has_value());
+  writer.write_bool(false);
+  writer.write_bool(false);
+  writer.write_bool(has_validity_duration);
+  writer.write_bool(false);
+
+  writer.write_constrained(management.originating_station_id, 0, 4294967295);
+  writer.write_constrained(management.sequence_number, 0, 65535);
+  writer.write_constrained(static_cast<int64_t>(management.detection_time), 0, max_timestamp_its);
+  writer.write_constrained(static_cast<int64_t>(management.reference_time), 0, max_timestamp_its);
+  if (management.termination) {
+    writer.write_enumerated(*management.termination, 2);
+  }
+  encode(writer, management.event_position);
+  if (has_validity_duration) {
+    writer.write_constrained(management.validity_duration, 0, 86400);
+  }
+  writer.write_constrained(management.station_type, 0, 255);
+}
+
+ManagementContainer decode_management_container(BitReader & reader)
+{
+  const bool has_extension = reader.read_bool();
+  const bool has_termination = reader.read_bool();
+  const bool has_relevance_distance = reader.read_bool();
+  const bool has_relevance_traffic_direction = reader.read_bool();
+  const bool has_validity_duration = reader.read_bool();
+  const bool has_transmission_interval = reader.read_bool();
+
+  ManagementContainer management;
+  management.originating_station_id = static_cast<uint32_t>(reader.read_constrained(0, 4294967295));
+  management.sequence_number = static_cast<uint16_t>(reader.read_constrained(0, 65535));
+  management.detection_time = static_cast<uint64_t>(reader.read_constrained(0, max_timestamp_its));
+  management.reference_time = static_cast<uint64_t>(reader.read_constrained(0, max_timestamp_its));
+  if (has_termination) {
+    management.termination = static_cast<uint8_t>(reader.read_enumerated(2));
+  }
+  management.event_position = decode_reference_position(reader);
+  if (has_relevance_distance) {
+    reader.read_enumerated(8);
+  }
+  if (has_relevance_traffic_direction) {
+    reader.read_enumerated(4);
+  }
+  management.validity_duration =
+    has_validity_duration ? static_cast<uint32_t>(reader.read_constrained(0, 86400))
+                          : default_validity_duration;
+  if (has_transmission_interval) {
+    reader.read_constrained(1, 10000);
+  }
+  management.station_type = static_cast<uint8_t>(reader.read_constrained(0, 255));
+  if (has_extension) {
+    reader.skip_sequence_extensions();
+  }
+  return management;
+}
+}  // namespace
+
+std::vector<uint8_t> encode_denm(const Denm & denm)
+{
+  BitWriter writer;
+  auto header = denm.header;
+  header.message_id = message_id::DENM;
+  encode(writer, header);
+
+  // presence of situation, location and alacarte containers
+  writer.write_bool(denm.event_type.has_value());
+  writer.write_bool(false);
+  writer.write_bool(false);
+
+  encode(writer, denm.management);
+
+  if (denm.event_type) {
+    // SituationContainer: extension bit, linkedCause and eventHistory are absent
+    writer.write_bool(false);
+    writer.write_bool(false);
+    writer.write_bool(false);
+    writer.write_constrained(denm.information_quality, 0, 7);
+    // CauseCode: extension bit
+    writer.write_bool(false);
+    writer.write_constrained(denm.event_type->cause_code, 0, 255);
+    writer.write_constrained(denm.event_type->sub_cause_code, 0, 255);
+  }
+  return writer.data();
+}
+
+Denm decode_denm(const std::vector<uint8_t> & payload)
+{
+  BitReader reader(payload);
+
+  Denm denm;
+  denm.header = decode_its_pdu_header(reader);
+  if (denm.header.message_id != message_id::DENM) {
+    throw UperError("message is not a DENM");
+  }
+
+  const bool has_situation = reader.read_bool();
+  reader.read_bool();
+  reader.read_bool();
+
+  denm.management = decode_management_container(reader);
+
+  if (has_situation) {
+    // the situation container follows the management container directly
+    reader.read_bool();
+    reader.read_bool();
+    reader.read_bool();
+    denm.information_quality = static_cast<uint8_t>(reader.read_constrained(0, 7));
+    const bool has_cause_code_extension = reader.read_bool();
+    CauseCode event_type;
+    event_type.cause_code = static_cast<uint8_t>(reader.read_constrained(0, 255));
+    event_type.sub_cause_code = static_cast<uint8_t>(reader.read_constrained(0, 255));
+    if (has_cause_code_extension) {
+      reader.skip_sequence_extensions();
+    }
+    denm.event_type = event_type;
+  }
+  return denm;
+}
+}  // namespace autoware::v2x_etsi_interface
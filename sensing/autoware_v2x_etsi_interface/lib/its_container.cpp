@@ -0,0 +1,60 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/its_container.hpp"
+
+namespace autoware::v2x_etsi_interface
+{
+void encode(BitWriter & writer, const ItsPduHeader & header)
+{
+  writer.write_constrained(header.protocol_version, 0, 255);
+  writer.write_constrained(header.message_id, 0, 255);
+  writer.write_constrained(header.station_id, 0, 4294967295);
+}
+
+ItsPduHeader decode_its_pdu_header(BitReader & reader)
+{
+  ItsPduHeader header;
+  header.protocol_version = static_cast<uint8_t>(reader.read_constrained(0, 255));
+  header.message_id = static_cast<uint8_t>(reader.read_constrained(0, 255));
+  header.station_id = static_cast<uint32_t>(reader.read_constrained(0, 4294967295));
+  return header;
+}
+
+void encode(BitWriter & writer, const ReferencePosition & position)
+{
+  writer.write_constrained(position.latitude, -900000000, 900000001);
+  writer.write_constrained(position.longitude, -1800000000, 1800000001);
+  // PosConfidenceEllipse
+  writer.write_constrained(position.semi_major_confidence, 0, 4095);
+  writer.write_constrained(position.semi_minor_confidence, 0, 4095);
+  writer.write_constrained(position.semi_major_orientation, 0, 3601);
+  // Altitude
+  writer.write_constrained(position.altitude, -100000, 800001);
+  writer.write_enumerated(position.altitude_confidence, 16);
+}
+
+ReferencePosition decode_reference_position(BitReader & reader)
+{
+  ReferencePosition position;
+  position.latitude = static_cast<int32_t>(reader.read_constrained(-900000000, 900000001));
+  position.longitude = static_cast<int32_t>(reader.read_constrained(-1800000000, 1800000001));
+  position.semi_major_confidence = static_cast<uint16_t>(reader.read_constrained(0, 4095));
+  position.semi_minor_confidence = static_cast<uint16_t>(reader.read_constrained(0, 4095));
+  position.semi_major_orientation = static_cast<uint16_t>(reader.read_constrained(0, 3601));
+  position.altitude = static_cast<int32_t>(reader.read_constrained(-100000, 800001));
+  position.altitude_confidence = static_cast<uint8_t>(reader.read_enumerated(16));
+  return position;
+}
+}  // namespace autoware::v2x_etsi_interface
@@ -0,0 +1,131 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/uper_bit_stream.hpp"
+
+#include <string>
+
+namespace autoware::v2x_etsi_interface
+{
+size_t constrained_bit_length(const int64_t min, const int64_t max)
+{
+  if (max < min) {
+    throw UperError("invalid constraint");
+  }
+  const uint64_t range = static_cast<uint64_t>(max - min) + 1;
+  size_t bit_length = 0;
+  while (bit_length < 64 && (uint64_t{1} << bit_length) < range) {
+    ++bit_length;
+  }
+  return bit_length;
+}
+
+void BitWriter::write_bits(const uint64_t value, const size_t bit_length)
+{
+  for (size_t i = 0; i < bit_length; ++i) {
+    const bool bit = (value >> (bit_length - 1 - i)) & 1U;
+    if (bit_length_ % 8 == 0) {
+      data_.push_back(0);
+    }
+    if (bit) {
+      data_.back() |= static_cast<uint8_t>(0x80U >> (bit_length_ % 8));
+    }
+    ++bit_length_;
+  }
+}
+
+void BitWriter::write_bool(const bool value)
+{
+  write_bits(value ? 1 : 0, 1);
+}
+
+void BitWriter::write_constrained(const int64_t value, const int64_t min, const int64_t max)
+{
+  if (value < min || max < value) {
+    throw UperError(
+      "value " + std::to_string(value) + " is out of range [" + std::to_string(min) + ", " +
+      std::to_string(max) + "]");
+  }
+  write_bits(static_cast<uint64_t>(value - min), constrained_bit_length(min, max));
+}
+
+uint64_t BitReader::read_bits(const size_t bit_length)
+{
+  if (remaining_bits() < bit_length) {
+    throw UperError("unexpected end of message");
+  }
+  uint64_t value = 0;
+  for (size_t i = 0; i < bit_length; ++i) {
+    const uint8_t byte = data_[position_ / 8];
+    const bool bit = (byte >> (7 - position_ % 8)) & 1U;
+    value = (value << 1) | (bit ? 1U : 0U);
+    ++position_;
+  }
+  return value;
+}
+
+bool BitReader::read_bool()
+{
+  return read_bits(1) == 1;
+}
+
+int64_t BitReader::read_constrained(const int64_t min, const int64_t max)
+{
+  const auto offset = read_bits(constrained_bit_length(min, max));
+  const auto value = min + static_cast<int64_t>(offset);
+  if (max < value) {
+    throw UperError("decoded value is out of range");
+  }
+  return value;
+}
+
+size_t BitReader::read_normally_small_length()
+{
+  if (read_bool()) {
+    throw UperError("too many extension additions");
+  }
+  return static_cast<size_t>(read_bits(6)) + 1;
+}
+
+size_t BitReader::read_length_determinant()
+{
+  if (!read_bool()) {
+    return static_cast<size_t>(read_bits(7));
+  }
+  if (!read_bool()) {
+    return static_cast<size_t>(read_bits(14));
+  }
+  throw UperError("fragmented open type is not supported");
+}
+
+void BitReader::skip_sequence_extensions()
+{
+  const size_t addition_count = read_normally_small_length();
+  std::vector<bool> present(addition_count);
+  for (size_t i = 0; i < addition_count; ++i) {
+    present.at(i) = read_bool();
+  }
+  for (const bool is_present : present) {
+    if (!is_present) {
+      continue;
+    }
+    // each extension addition is wrapped in an open type, so it can be skipped without knowing it
+    const size_t octet_length = read_length_determinant();
+    if (remaining_bits() < octet_length * 8) {
+      throw UperError("unexpected end of message");
+    }
+    position_ += octet_length * 8;
+  }
+}
+}  // namespace autoware::v2x_etsi_interface
@@ -0,0 +1,502 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "v2x_etsi_interface_node.hpp"
+
+#include "autoware/v2x_etsi_interface/btp.hpp"
+#include "autoware/v2x_etsi_interface/conversion.hpp"
+
+#include <autoware/geography_utils/height.hpp>
+#include <autoware/geography_utils/projection.hpp>
+#include <autoware/universe_utils/geometry/geometry.hpp>
+#include <autoware/universe_utils/math/normalization.hpp>
+
+#include <autoware_perception_msgs/msg/object_classification.hpp>
+#include <autoware_perception_msgs/msg/shape.hpp>
+#include <geographic_msgs/msg/geo_point.hpp>
+
+#include <tf2/utils.h>
+
+#include <arpa/inet.h>
+#include <sys/socket.h>
+#include <unistd.h>
+
+#include <array>
+#include <cerrno>
+#include <cmath>
+#include <cstring>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::v2x_etsi_interface
+{
+namespace
+{
+using autoware_perception_msgs::msg::DetectedObjectKinematics;
+using autoware_perception_msgs::msg::ObjectClassification;
+using autoware_perception_msgs::msg::Shape;
+
+constexpr size_t max_datagram_size = 2048;
+
+uint8_t to_label(const uint8_t type)
+{
+  switch (type) {
+    case station_type::PEDESTRIAN:
+      return ObjectClassification::PEDESTRIAN;
+    case station_type::CYCLIST:
+      return ObjectClassification::BICYCLE;
+    case station_type::MOPED:
+    case station_type::MOTORCYCLE:
+      return ObjectClassification::MOTORCYCLE;
+    case station_type::PASSENGER_CAR:
+    case station_type::SPECIAL_VEHICLES:
+      return ObjectClassification::CAR;
+    case station_type::BUS:
+    case station_type::TRAM:
+      return ObjectClassification::BUS;
+    case station_type::LIGHT_TRUCK:
+    case station_type::HEAVY_TRUCK:
+      return ObjectClassification::TRUCK;
+    case station_type::TRAILER:
+      return ObjectClassification::TRAILER;
+    default:
+      return ObjectClassification::UNKNOWN;
+  }
+}
+
+ObjectClassification create_classification(const uint8_t label)
+{
+  ObjectClassification classification;
+  classification.label = label;
+  classification.probability = 1.0;
+  return classification;
+}
+
+uint64_t now_its(const rclcpp::Time & now, const int leap_seconds)
+{
+  return to_timestamp_its(now.nanoseconds(), leap_seconds);
+}
+}  // namespace
+
+V2xEtsiInterface::V2xEtsiInterface(const rclcpp::NodeOptions & options)
+: Node("v2x_etsi_interface", options),
+  vehicle_info_(autoware::vehicle_info_utils::VehicleInfoUtils(*this).getVehicleInfo())
+{
+  // Parameter
+  node_param_.station_id = static_cast<uint32_t>(declare_parameter<int64_t>("station_id"));
+  node_param_.station_type = static_cast<uint8_t>(declare_parameter<int>("station_type"));
+  node_param_.use_btp_header = declare_parameter<bool>("use_btp_header");
+  node_param_.leap_seconds = declare_parameter<int>("leap_seconds");
+  node_param_.frame_id = declare_parameter<std::string>("frame_id");
+  node_param_.receive_rate = declare_parameter<double>("receive_rate");
+
+  cam_param_.min_interval = declare_parameter<double>("cam_generation.min_interval");
+  cam_param_.max_interval = declare_parameter<double>("cam_generation.max_interval");
+  cam_param_.heading_threshold = declare_parameter<double>("cam_generation.heading_threshold");
+  cam_param_.position_threshold = declare_parameter<double>("cam_generation.position_threshold");
+  cam_param_.speed_threshold = declare_parameter<double>("cam_generation.speed_threshold");
+
+  remote_param_.cam_timeout = declare_parameter<double>("remote_object.cam_timeout");
+  remote_param_.default_length = declare_parameter<double>("remote_object.default_length");
+  remote_param_.default_width = declare_parameter<double>("remote_object.default_width");
+  remote_param_.height = declare_parameter<double>("remote_object.height");
+  remote_param_.hazard_size = declare_parameter<double>("remote_object.hazard_size");
+
+  const auto obu_address = declare_parameter<std::string>("obu.address");
+  const auto obu_port = declare_parameter<int>("obu.port");
+  const auto local_port = declare_parameter<int>("local_port");
+
+  // Socket
+  obu_address_.sin_family = AF_INET;
+  obu_address_.sin_port = htons(static_cast<uint16_t>(obu_port));
+  if (inet_pton(AF_INET, obu_address.c_str(), &obu_address_.sin_addr) != 1) {
+    throw std::invalid_argument("invalid obu.address: " + obu_address);
+  }
+  socket_fd_ = socket(AF_INET, SOCK_DGRAM, 0);
+  if (socket_fd_ < 0) {
+    throw std::runtime_error(std::string("failed to create a socket: ") + std::strerror(errno));
+  }
+  sockaddr_in local_address{};
+  local_address.sin_family = AF_INET;
+  local_address.sin_addr.s_addr = htonl(INADDR_ANY);
+  local_address.sin_port = htons(static_cast<uint16_t>(local_port));
+  if (bind(socket_fd_, reinterpret_cast<sockaddr *>(&local_address), sizeof(local_address)) < 0) {
+    const std::string error = std::strerror(errno);
+    // the destructor is not called when the constructor throws
+    close(socket_fd_);
+    socket_fd_ = -1;
+    throw std::runtime_error("failed to bind port " + std::to_string(local_port) + ": " + error);
+  }
+
+  // Subscriber
+  sub_kinematic_state_ = create_subscription<Odometry>(
+    "input/kinematic_state", rclcpp::QoS{1},
+    [this](const Odometry::ConstSharedPtr msg) { on_kinematic_state(msg); });
+  const auto adaptor = autoware::component_interface_utils::NodeAdaptor(this);
+  adaptor.init_sub(
+    sub_map_projector_info_,
+    [this](const MapProjectorInfo::Message::ConstSharedPtr msg) { projector_info_ = *msg; });
+
+  // Publisher
+  pub_objects_ = create_publisher<DetectedObjects>("output/objects", rclcpp::QoS{1});
+  pub_hazards_ = create_publisher<DetectedObjects>("output/hazards", rclcpp::QoS{1});
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.receive_rate).period();
+  timer_ = rclcpp::create_timer(this, get_clock(), period_ns, [this]() { on_timer(); });
+}
+
+V2xEtsiInterface::~V2xEtsiInterface()
+{
+  if (socket_fd_ >= 0) {
+    close(socket_fd_);
+  }
+}
+
+void V2xEtsiInterface::on_kinematic_state(const Odometry::ConstSharedPtr msg)
+{
+  kinematic_state_ = *msg;
+
+  const auto cam = create_cam(*msg);
+  if (!cam) {
+    return;
+  }
+
+  const SentCam candidate{*cam, msg->pose.pose.position, now()};
+  if (!is_cam_triggered(candidate)) {
+    return;
+  }
+
+  send(btp_port::CAM, encode_cam(*cam));
+  last_sent_ = candidate;
+}
+
+void V2xEtsiInterface::on_timer()
+{
+  receive();
+
+  const auto current_time = now();
+  publish_objects(current_time);
+  publish_hazards(current_time);
+}
+
+std::optional<Cam> V2xEtsiInterface::create_cam(const Odometry & odometry) const
+{
+  if (!projector_info_) {
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 5000, "map_projector_info is not received yet.");
+    return std::nullopt;
+  }
+  if (projector_info_->projector_type == MapProjectorInfo::Message::LOCAL) {
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 5000, "CAM cannot be generated with the local projector.");
+    return std::nullopt;
+  }
+
+  // the reference point of a vehicle is the center of the front side of its bounding box
+  const double front_offset = vehicle_info_.wheel_base_m + vehicle_info_.front_overhang_m;
+  const auto reference_pose =
+    autoware::universe_utils::calcOffsetPose(odometry.pose.pose, front_offset, 0.0, 0.0);
+  const auto gps_point =
+    autoware::geography_utils::project_reverse(reference_pose.position, *projector_info_);
+  const double altitude = autoware::geography_utils::convert_height(
+    gps_point.altitude, gps_point.latitude, gps_point.longitude, projector_info_->vertical_datum,
+    MapProjectorInfo::Message::WGS84);
+
+  const double yaw = tf2::getYaw(odometry.pose.pose.orientation);
+  const double speed = odometry.twist.twist.linear.x;
+  const double yaw_rate = odometry.twist.twist.angular.z;
+
+  Cam cam;
+  cam.header.station_id = node_param_.station_id;
+  const rclcpp::Time stamp(odometry.header.stamp);
+  cam.generation_delta_time = to_generation_delta_time(now_its(stamp, node_param_.leap_seconds));
+  cam.station_type = node_param_.station_type;
+  cam.reference_position.latitude = to_latitude_value(gps_point.latitude);
+  cam.reference_position.longitude = to_longitude_value(gps_point.longitude);
+  cam.reference_position.altitude = to_altitude_value(altitude);
+
+  BasicVehicleHighFrequency hf;
+  hf.heading = to_heading_value(yaw);
+  hf.speed = to_speed_value(speed);
+  hf.drive_direction = speed < 0.0 ? 1 : 0;
+  hf.vehicle_length = to_vehicle_length_value(vehicle_info_.vehicle_length_m);
+  hf.vehicle_width = to_vehicle_width_value(vehicle_info_.vehicle_width_m);
+  hf.yaw_rate = to_yaw_rate_value(yaw_rate);
+  if (std::abs(speed) > 0.1) {
+    hf.curvature = to_curvature_value(yaw_rate / speed);
+    hf.curvature_calculation_mode = 0;
+  }
+  cam.high_frequency = hf;
+  return cam;
+}
+
+bool V2xEtsiInterface::is_cam_triggered(const SentCam & candidate) const
+{
+  if (!last_sent_) {
+    return true;
+  }
+
+  const double elapsed = (candidate.stamp - last_sent_->stamp).seconds();
+  if (elapsed < cam_param_.min_interval) {
+    return false;
+  }
+  if (elapsed >= cam_param_.max_interval) {
+    return true;
+  }
+
+  const auto & hf = *candidate.cam.high_frequency;
+  const auto & last_hf = *last_sent_->cam.high_frequency;
+
+  const double heading_diff = autoware::universe_utils::normalizeDegree(
+    (static_cast<double>(hf.heading) - static_cast<double>(last_hf.heading)) * 0.1);
+  if (std::abs(heading_diff) > cam_param_.heading_threshold) {
+    return true;
+  }
+
+  const double distance =
+    autoware::universe_utils::calcDistance2d(candidate.position, last_sent_->position);
+  if (distance > cam_param_.position_threshold) {
+    return true;
+  }
+
+  const double speed_diff = from_speed_value(hf.speed) - from_speed_value(last_hf.speed);
+  return std::abs(speed_diff) > cam_param_.speed_threshold;
+}
+
+void V2xEtsiInterface::send(const uint16_t btp_port, const std::vector<uint8_t> & payload)
+{
+  const auto datagram =
+    node_param_.use_btp_header ? serialize_btp_b(BtpPacket{btp_port, 0, payload}) : payload;
+  const auto sent = sendto(
+    socket_fd_, datagram.data(), datagram.size(), 0, reinterpret_cast<sockaddr *>(&obu_address_),
+    sizeof(obu_address_));
+  if (sent < 0) {
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 5000, "failed to send to the OBU: %s", std::strerror(errno));
+  }
+}
+
+void V2xEtsiInterface::receive()
+{
+  std::array<uint8_t, max_datagram_size> buffer{};
+  while (true) {
+    const auto size = recv(socket_fd_, buffer.data(), buffer.size(), MSG_DONTWAIT);
+    if (size < 0) {
+      if (errno != EAGAIN && errno != EWOULDBLOCK) {
+        RCLCPP_WARN_THROTTLE(
+          get_logger(), *get_clock(), 5000, "failed to receive from the OBU: %s",
+          std::strerror(errno));
+      }
+      return;
+    }
+
+    if (!node_param_.use_btp_header) {
+      handle_payload(std::vector<uint8_t>(buffer.begin(), buffer.begin() + size), std::nullopt);
+      continue;
+    }
+    const auto packet = parse_btp_b(buffer.data(), static_cast<size_t>(size));
+    if (!packet) {
+      RCLCPP_DEBUG(get_logger(), "ignore a datagram shorter than the BTP-B header");
+      continue;
+    }
+    handle_payload(packet->payload, packet->destination_port);
+  }
+}
+
+void V2xEtsiInterface::handle_payload(
+  const std::vector<uint8_t> & payload, const std::optional<uint16_t> btp_port)
+{
+  // without the BTP header, the messageID of the ItsPduHeader is the second octet
+  uint8_t message = 0;
+  if (btp_port == btp_port::CAM) {
+    message = message_id::CAM;
+  } else if (btp_port == btp_port::DENM) {
+    message = message_id::DENM;
+  } else if (!btp_port && payload.size() >= 2) {
+    message = payload[1];
+  }
+
+  try {
+    if (message == message_id::CAM) {
+      handle_cam(decode_cam(payload));
+    } else if (message == message_id::DENM) {
+      handle_denm(decode_denm(payload));
+    }
+  } catch (const UperError & e) {
+    RCLCPP_WARN_THROTTLE(get_logger(), *get_clock(), 5000, "failed to decode: %s", e.what());
+  }
+}
+
+void V2xEtsiInterface::handle_cam(const Cam & cam)
+{
+  // the own CAM may be looped back by the OBU
+  if (cam.header.station_id == node_param_.station_id) {
+    return;
+  }
+  remote_stations_[cam.header.station_id] = RemoteStation{cam, now()};
+}
+
+void V2xEtsiInterface::handle_denm(const Denm & denm)
+{
+  const auto key =
+    std::make_pair(denm.management.originating_station_id, denm.management.sequence_number);
+  if (denm.management.termination) {
+    active_events_.erase(key);
+    return;
+  }
+  active_events_[key] = denm;
+}
+
+void V2xEtsiInterface::publish_objects(const rclcpp::Time & now)
+{
+  DetectedObjects objects;
+  objects.header.stamp = now;
+  objects.header.frame_id = node_param_.frame_id;
+
+  for (auto it = remote_stations_.begin(); it != remote_stations_.end();) {
+    if ((now - it->second.last_received).seconds() > remote_param_.cam_timeout) {
+      it = remote_stations_.erase(it);
+      continue;
+    }
+    if (const auto object = to_object(it->second.cam)) {
+      objects.objects.push_back(*object);
+    }
+    ++it;
+  }
+  pub_objects_->publish(objects);
+}
+
+void V2xEtsiInterface::publish_hazards(const rclcpp::Time & now)
+{
+  DetectedObjects hazards;
+  hazards.header.stamp = now;
+  hazards.header.frame_id = node_param_.frame_id;
+
+  const auto current_its = now_its(now, node_param_.leap_seconds);
+  for (auto it = active_events_.begin(); it != active_events_.end();) {
+    const auto & management = it->second.management;
+    const auto expiry = management.detection_time + management.validity_duration * 1000ULL;
+    if (expiry < current_its) {
+      it = active_events_.erase(it);
+      continue;
+    }
+    if (const auto object = to_object(it->second)) {
+      hazards.objects.push_back(*object);
+    }
+    ++it;
+  }
+  pub_hazards_->publish(hazards);
+}
+
+std::optional<DetectedObject> V2xEtsiInterface::to_object(const Cam & cam) const
+{
+  if (
+    !projector_info_ || projector_info_->projector_type == MapProjectorInfo::Message::LOCAL ||
+    !cam.high_frequency || cam.reference_position.latitude == unavailable::LATITUDE ||
+    cam.reference_position.longitude == unavailable::LONGITUDE) {
+    return std::nullopt;
+  }
+  const auto & hf = *cam.high_frequency;
+
+  geographic_msgs::msg::GeoPoint gps_point;
+  gps_point.latitude = from_latitude_value(cam.reference_position.latitude);
+  gps_point.longitude = from_longitude_value(cam.reference_position.longitude);
+  gps_point.altitude = 0.0;
+  auto position = autoware::geography_utils::project_forward(gps_point, *projector_info_);
+  if (cam.reference_position.altitude != unavailable::ALTITUDE) {
+    position.z = autoware::geography_utils::convert_height(
+      static_cast<double>(cam.reference_position.altitude) * 0.01, gps_point.latitude,
+      gps_point.longitude, MapProjectorInfo::Message::WGS84, projector_info_->vertical_datum);
+  } else if (kinematic_state_) {
+    position.z = kinematic_state_->pose.pose.position.z;
+  }
+
+  const double length = hf.vehicle_length == unavailable::VEHICLE_LENGTH
+                          ? remote_param_.default_length
+                          : from_vehicle_length_value(hf.vehicle_length);
+  const double width = hf.vehicle_width == unavailable::VEHICLE_WIDTH
+                         ? remote_param_.default_width
+                         : from_vehicle_width_value(hf.vehicle_width);
+  const bool has_heading = hf.heading != unavailable::HEADING;
+  const double yaw = has_heading ? to_yaw(hf.heading) : 0.0;
+
+  geometry_msgs::msg::Pose reference_pose;
+  reference_pose.position = position;
+  reference_pose.orientation = autoware::universe_utils::createQuaternionFromYaw(yaw);
+
+  DetectedObject object;
+  object.existence_probability = 1.0;
+  object.classification.push_back(create_classification(to_label(cam.station_type)));
+
+  auto & kinematics = object.kinematics;
+  // the reference point is at the front of the vehicle, and the base of the bbox is at the ground
+  kinematics.pose_with_covariance.pose = autoware::universe_utils::calcOffsetPose(
+    reference_pose, -length / 2.0, 0.0, remote_param_.height / 2.0);
+  kinematics.orientation_availability =
+    has_heading ? DetectedObjectKinematics::AVAILABLE : DetectedObjectKinematics::UNAVAILABLE;
+  if (hf.speed != unavailable::SPEED) {
+    const double speed = from_speed_value(hf.speed);
+    kinematics.twist_with_covariance.twist.linear.x = hf.drive_direction == 1 ? -speed : speed;
+    kinematics.has_twist = true;
+  }
+
+  object.shape.type = Shape::BOUNDING_BOX;
+  object.shape.dimensions.x = length;
+  object.shape.dimensions.y = width;
+  object.shape.dimensions.z = remote_param_.height;
+  return object;
+}
+
+std::optional<DetectedObject> V2xEtsiInterface::to_object(const Denm & denm) const
+{
+  const auto & event_position = denm.management.event_position;
+  if (
+    !projector_info_ || projector_info_->projector_type == MapProjectorInfo::Message::LOCAL ||
+    event_position.latitude == unavailable::LATITUDE ||
+    event_position.longitude == unavailable::LONGITUDE) {
+    return std::nullopt;
+  }
+
+  geographic_msgs::msg::GeoPoint gps_point;
+  gps_point.latitude = from_latitude_value(event_position.latitude);
+  gps_point.longitude = from_longitude_value(event_position.longitude);
+  gps_point.altitude = 0.0;
+  auto position = autoware::geography_utils::project_forward(gps_point, *projector_info_);
+  if (event_position.altitude != unavailable::ALTITUDE) {
+    position.z = autoware::geography_utils::convert_height(
+      static_cast<double>(event_position.altitude) * 0.01, gps_point.latitude, gps_point.longitude,
+      MapProjectorInfo::Message::WGS84, projector_info_->vertical_datum);
+  } else if (kinematic_state_) {
+    position.z = kinematic_state_->pose.pose.position.z;
+  }
+  position.z += remote_param_.height / 2.0;
+
+  DetectedObject object;
+  object.existence_probability = 1.0;
+  object.classification.push_back(create_classification(ObjectClassification::UNKNOWN));
+  object.kinematics.pose_with_covariance.pose.position = position;
+  object.kinematics.pose_with_covariance.pose.orientation.w = 1.0;
+  object.kinematics.orientation_availability = DetectedObjectKinematics::UNAVAILABLE;
+  object.shape.type = Shape::BOUNDING_BOX;
+  object.shape.dimensions.x = remote_param_.hazard_size;
+  object.shape.dimensions.y = remote_param_.hazard_size;
+  object.shape.dimensions.z = remote_param_.height;
+  return object;
+}
+}  // namespace autoware::v2x_etsi_interface
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::v2x_etsi_interface::V2xEtsiInterface)
@@ -0,0 +1,143 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef V2X_ETSI_INTERFACE_NODE_HPP_
+#define V2X_ETSI_INTERFACE_NODE_HPP_
+
+#include "autoware/v2x_etsi_interface/cam.hpp"
+#include "autoware/v2x_etsi_interface/denm.hpp"
+
+#include <autoware/component_interface_specs_universe/map.hpp>
+#include <autoware/component_interface_utils/rclcpp.hpp>
+#include <autoware_vehicle_info_utils/vehicle_info_utils.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_perception_msgs/msg/detected_objects.hpp>
+#include <geometry_msgs/msg/point.hpp>
+#include <nav_msgs/msg/odometry.hpp>
+
+#include <netinet/in.h>
+
+#include <cstdint>
+#include <map>
+#include <optional>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace autoware::v2x_etsi_interface
+{
+using autoware_perception_msgs::msg::DetectedObject;
+using autoware_perception_msgs::msg::DetectedObjects;
+using nav_msgs::msg::Odometry;
+using MapProjectorInfo = autoware::component_interface_specs_universe::map::MapProjectorInfo;
+
+struct NodeParam
+{
+  uint32_t station_id;
+  uint8_t station_type;
+  bool use_btp_header;
+  int leap_seconds;
+  std::string frame_id;
+  double receive_rate;
+};
+
+// generation rules of EN 302 637-2 section 6.1.3
+struct CamGenerationParam
+{
+  double min_interval;
+  double max_interval;
+  double heading_threshold;
+  double position_threshold;
+  double speed_threshold;
+};
+
+struct RemoteObjectParam
+{
+  double cam_timeout;
+  double default_length;
+  double default_width;
+  double height;
+  double hazard_size;
+};
+
+struct SentCam
+{
+  Cam cam;
+  geometry_msgs::msg::Point position;
+  rclcpp::Time stamp;
+};
+
+struct RemoteStation
+{
+  Cam cam;
+  rclcpp::Time last_received;
+};
+
+class V2xEtsiInterface : public rclcpp::Node
+{
+public:
+  explicit V2xEtsiInterface(const rclcpp::NodeOptions & options);
+  ~V2xEtsiInterface() override;
+
+private:
+  // Parameter
+  NodeParam node_param_;
+  CamGenerationParam cam_param_;
+  RemoteObjectParam remote_param_;
+  autoware::vehicle_info_utils::VehicleInfo vehicle_info_;
+
+  // Subscriber
+  rclcpp::Subscription<Odometry>::SharedPtr sub_kinematic_state_;
+  autoware::component_interface_utils::Subscription<MapProjectorInfo>::SharedPtr
+    sub_map_projector_info_;
+
+  // Publisher
+  rclcpp::Publisher<DetectedObjects>::SharedPtr pub_objects_;
+  rclcpp::Publisher<DetectedObjects>::SharedPtr pub_hazards_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  // Socket
+  int socket_fd_{-1};
+  sockaddr_in obu_address_{};
+
+  // State
+  std::optional<MapProjectorInfo::Message> projector_info_;
+  std::optional<Odometry> kinematic_state_;
+  std::optional<SentCam> last_sent_;
+  std::map<uint32_t, RemoteStation> remote_stations_;
+  // keyed by the originating station and the sequence number (ActionID)
+  std::map<std::pair<uint32_t, uint16_t>, Denm> active_events_;
+
+  void on_kinematic_state(const Odometry::ConstSharedPtr msg);
+  void on_timer();
+
+  [[nodiscard]] std::optional<Cam> create_cam(const Odometry & odometry) const;
+  [[nodiscard]] bool is_cam_triggered(const SentCam & candidate) const;
+  void send(uint16_t btp_port, const std::vector<uint8_t> & payload);
+  void receive();
+  void handle_payload(const std::vector<uint8_t> & payload, std::optional<uint16_t> btp_port);
+  void handle_cam(const Cam & cam);
+  void handle_denm(const Denm & denm);
+  void publish_objects(const rclcpp::Time & now);
+  void publish_hazards(const rclcpp::Time & now);
+
+  [[nodiscard]] std::optional<DetectedObject> to_object(const Cam & cam) const;
+  [[nodiscard]] std::optional<DetectedObject> to_object(const Denm & denm) const;
+};
+}  // namespace autoware::v2x_etsi_interface
+
+#endif  // V2X_ETSI_INTERFACE_NODE_HPP_
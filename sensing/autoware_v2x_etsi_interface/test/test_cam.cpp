@@ -0,0 +1,150 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/cam.hpp"
+#include "autoware/v2x_etsi_interface/denm.hpp"
+
+#include <gtest/gtest.h>
+
+#include <vector>
+
+using autoware::v2x_etsi_interface::BasicVehicleHighFrequency;
+using autoware::v2x_etsi_interface::Cam;
+using autoware::v2x_etsi_interface::UperError;
+
+namespace
+{
+Cam create_cam()
+{
+  Cam cam;
+  cam.header.station_id = 1234;
+  cam.generation_delta_time = 40000;
+  cam.station_type = autoware::v2x_etsi_interface::station_type::PASSENGER_CAR;
+  cam.reference_position.latitude = 356812000;
+  cam.reference_position.longitude = 1397671000;
+  cam.reference_position.altitude = 4000;
+
+  BasicVehicleHighFrequency hf;
+  hf.heading = 900;
+  hf.heading_confidence = 10;
+  hf.speed = 1389;
+  hf.speed_confidence = 5;
+  hf.drive_direction = 0;
+  hf.vehicle_length = 45;
+  hf.vehicle_length_confidence = 0;
+  hf.vehicle_width = 18;
+  hf.longitudinal_acceleration = -12;
+  hf.longitudinal_acceleration_confidence = 3;
+  hf.curvature = 25;
+  hf.curvature_confidence = 3;
+  hf.curvature_calculation_mode = 0;
+  hf.yaw_rate = -150;
+  hf.yaw_rate_confidence = 2;
+  cam.high_frequency = hf;
+  return cam;
+}
+}  // namespace
+
+TEST(Cam, RoundTrip)
+{
+  const auto cam = create_cam();
+  const auto decoded = autoware::v2x_etsi_interface::decode_cam(
+    autoware::v2x_etsi_interface::encode_cam(cam));
+
+  EXPECT_EQ(decoded.header.protocol_version, 2);
+  EXPECT_EQ(decoded.header.message_id, autoware::v2x_etsi_interface::message_id::CAM);
+  EXPECT_EQ(decoded.header.station_id, 1234u);
+  EXPECT_EQ(decoded.generation_delta_time, 40000);
+  EXPECT_EQ(decoded.station_type, cam.station_type);
+  EXPECT_EQ(decoded.reference_position.latitude, 356812000);
+  EXPECT_EQ(decoded.reference_position.longitude, 1397671000);
+  EXPECT_EQ(decoded.reference_position.altitude, 4000);
+
+  ASSERT_TRUE(decoded.high_frequency.has_value());
+  const auto & hf = *decoded.high_frequency;
+  EXPECT_EQ(hf.heading, 900);
+  EXPECT_EQ(hf.heading_confidence, 10);
+  EXPECT_EQ(hf.speed, 1389);
+  EXPECT_EQ(hf.speed_confidence, 5);
+  EXPECT_EQ(hf.drive_direction, 0);
+  EXPECT_EQ(hf.vehicle_length, 45);
+  EXPECT_EQ(hf.vehicle_width, 18);
+  EXPECT_EQ(hf.longitudinal_acceleration, -12);
+  EXPECT_EQ(hf.curvature, 25);
+  EXPECT_EQ(hf.curvature_calculation_mode, 0);
+  EXPECT_EQ(hf.yaw_rate, -150);
+  EXPECT_EQ(hf.yaw_rate_confidence, 2);
+}
+
+TEST(Cam, EncodedLength)
+{
+  // header(48) + generationDeltaTime(16) + CamParameters(3) + BasicContainer(1 + 8 + 123) +
+  // HighFrequencyContainer(2 + 7 + 115) = 323 bits
+  const auto payload = autoware::v2x_etsi_interface::encode_cam(create_cam());
+  EXPECT_EQ(payload.size(), 41u);
+}
+
+TEST(Cam, KnownEncoding)
+{
+  // laid out field by field from the ASN.1 of EN 302 637-2 and TS 102 894-2 by the rules of X.691
+  const std::vector<uint8_t> expected{
+    0x02, 0x02, 0x00, 0x00, 0x04, 0xD2, 0x9C, 0x40, 0x00, 0x59, 0x5D, 0x2D, 0xBC, 0x17,
+    0xD3, 0x12, 0xCB, 0x1F, 0xFF, 0xFF, 0xFC, 0x22, 0x32, 0xC8, 0x1E, 0x00, 0x38, 0x41,
+    0x22, 0xB6, 0x84, 0x02, 0xC0, 0x8A, 0x50, 0x1C, 0x18, 0x61, 0xFD, 0xA0, 0x40};
+
+  EXPECT_EQ(autoware::v2x_etsi_interface::encode_cam(create_cam()), expected);
+
+  const auto decoded = autoware::v2x_etsi_interface::decode_cam(expected);
+  EXPECT_EQ(decoded.header.station_id, 1234u);
+  EXPECT_EQ(decoded.reference_position.latitude, 356812000);
+  ASSERT_TRUE(decoded.high_frequency.has_value());
+  EXPECT_EQ(decoded.high_frequency->speed, 1389);
+  EXPECT_EQ(decoded.high_frequency->yaw_rate, -150);
+}
+
+TEST(Cam, DecodeSkipsYawRateConfidenceExtension)
+{
+  // set the extension bit of YawRateConfidence (bit 318), which is followed by a normally small
+  // number of 7 bits instead of the 4 bits of the root enumeration
+  auto payload = autoware::v2x_etsi_interface::encode_cam(create_cam());
+  payload.at(39) |= 0x02;
+
+  const auto decoded = autoware::v2x_etsi_interface::decode_cam(payload);
+  ASSERT_TRUE(decoded.high_frequency.has_value());
+  EXPECT_EQ(decoded.high_frequency->yaw_rate, -150);
+  EXPECT_EQ(
+    decoded.high_frequency->yaw_rate_confidence,
+    autoware::v2x_etsi_interface::unavailable::YAW_RATE_CONFIDENCE);
+}
+
+TEST(Cam, DecodeRejectsOtherMessages)
+{
+  autoware::v2x_etsi_interface::Denm denm;
+  const auto payload = autoware::v2x_etsi_interface::encode_denm(denm);
+  EXPECT_THROW(autoware::v2x_etsi_interface::decode_cam(payload), UperError);
+}
+
+TEST(Cam, DecodeRejectsTruncatedMessage)
+{
+  auto payload = autoware::v2x_etsi_interface::encode_cam(create_cam());
+  payload.resize(20);
+  EXPECT_THROW(autoware::v2x_etsi_interface::decode_cam(payload), UperError);
+}
+
+TEST(Cam, EncodeRequiresHighFrequencyContainer)
+{
+  auto cam = create_cam();
+  cam.high_frequency.reset();
+  EXPECT_THROW(autoware::v2x_etsi_interface::encode_cam(cam), UperError);
+}
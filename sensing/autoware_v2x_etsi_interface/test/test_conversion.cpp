@@ -0,0 +1,62 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/conversion.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+
+namespace v2x = autoware::v2x_etsi_interface;
+
+TEST(Conversion, Heading)
+{
+  // east in ENU is 90 degrees clockwise from the north
+  EXPECT_EQ(v2x::to_heading_value(0.0), 900);
+  EXPECT_EQ(v2x::to_heading_value(M_PI_2), 0);
+  EXPECT_EQ(v2x::to_heading_value(M_PI), 2700);
+  EXPECT_EQ(v2x::to_heading_value(-M_PI_2), 1800);
+
+  EXPECT_NEAR(v2x::to_yaw(900), 0.0, 1e-9);
+  EXPECT_NEAR(v2x::to_yaw(0), M_PI_2, 1e-9);
+  EXPECT_NEAR(v2x::to_yaw(1800), -M_PI_2, 1e-9);
+}
+
+TEST(Conversion, SaturatesOutOfRangeValues)
+{
+  EXPECT_EQ(v2x::to_speed_value(-1.0), 100);
+  EXPECT_EQ(v2x::to_speed_value(1000.0), 16382);
+  EXPECT_EQ(v2x::to_longitudinal_acceleration_value(-100.0), -160);
+  EXPECT_EQ(v2x::to_vehicle_length_value(0.0), 1);
+  EXPECT_EQ(v2x::to_vehicle_width_value(10.0), 61);
+  EXPECT_EQ(v2x::to_yaw_rate_value(100.0), 32766);
+}
+
+TEST(Conversion, Position)
+{
+  EXPECT_EQ(v2x::to_latitude_value(35.6812), 356812000);
+  EXPECT_EQ(v2x::to_longitude_value(139.7671), 1397671000);
+  EXPECT_NEAR(v2x::from_latitude_value(356812000), 35.6812, 1e-9);
+  EXPECT_NEAR(v2x::from_longitude_value(1397671000), 139.7671, 1e-9);
+  EXPECT_EQ(v2x::to_altitude_value(40.0), 4000);
+}
+
+TEST(Conversion, TimestampIts)
+{
+  // 2004-01-01T00:00:00Z in unix time
+  const int64_t its_epoch_ns = 1072915200LL * 1000000000LL;
+  EXPECT_EQ(v2x::to_timestamp_its(its_epoch_ns, 0), 0u);
+  EXPECT_EQ(v2x::to_timestamp_its(its_epoch_ns + 1500000000LL, 5), 6500u);
+  EXPECT_EQ(v2x::to_generation_delta_time(65536 + 10), 10);
+}
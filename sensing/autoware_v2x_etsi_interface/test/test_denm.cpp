@@ -0,0 +1,114 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/denm.hpp"
+
+#include <gtest/gtest.h>
+
+#include <vector>
+
+using autoware::v2x_etsi_interface::CauseCode;
+using autoware::v2x_etsi_interface::Denm;
+using autoware::v2x_etsi_interface::UperError;
+
+namespace
+{
+Denm create_denm()
+{
+  Denm denm;
+  denm.header.station_id = 42;
+  denm.management.originating_station_id = 42;
+  denm.management.sequence_number = 7;
+  denm.management.detection_time = 662774400000;
+  denm.management.reference_time = 662774400100;
+  denm.management.event_position.latitude = 356812000;
+  denm.management.event_position.longitude = 1397671000;
+  denm.management.validity_duration = 60;
+  denm.management.station_type = autoware::v2x_etsi_interface::station_type::ROAD_SIDE_UNIT;
+  denm.information_quality = 4;
+  denm.event_type = CauseCode{3, 1};
+  return denm;
+}
+}  // namespace
+
+TEST(Denm, RoundTripWithSituation)
+{
+  const auto denm = create_denm();
+  const auto decoded = autoware::v2x_etsi_interface::decode_denm(
+    autoware::v2x_etsi_interface::encode_denm(denm));
+
+  EXPECT_EQ(decoded.header.message_id, autoware::v2x_etsi_interface::message_id::DENM);
+  EXPECT_EQ(decoded.header.station_id, 42u);
+  EXPECT_EQ(decoded.management.originating_station_id, 42u);
+  EXPECT_EQ(decoded.management.sequence_number, 7);
+  EXPECT_EQ(decoded.management.detection_time, 662774400000u);
+  EXPECT_EQ(decoded.management.reference_time, 662774400100u);
+  EXPECT_FALSE(decoded.management.termination.has_value());
+  EXPECT_EQ(decoded.management.event_position.latitude, 356812000);
+  EXPECT_EQ(decoded.management.event_position.longitude, 1397671000);
+  EXPECT_EQ(decoded.management.validity_duration, 60u);
+  EXPECT_EQ(decoded.management.station_type, denm.management.station_type);
+  EXPECT_EQ(decoded.information_quality, 4);
+  ASSERT_TRUE(decoded.event_type.has_value());
+  EXPECT_EQ(decoded.event_type->cause_code, 3);
+  EXPECT_EQ(decoded.event_type->sub_cause_code, 1);
+}
+
+TEST(Denm, DefaultValidityDurationAndTermination)
+{
+  Denm denm;
+  denm.management.termination = 0;
+
+  const auto decoded = autoware::v2x_etsi_interface::decode_denm(
+    autoware::v2x_etsi_interface::encode_denm(denm));
+
+  ASSERT_TRUE(decoded.management.termination.has_value());
+  EXPECT_EQ(*decoded.management.termination, 0);
+  EXPECT_EQ(decoded.management.validity_duration, 600u);
+  EXPECT_FALSE(decoded.event_type.has_value());
+}
+
+TEST(Denm, KnownEncoding)
+{
+  // laid out field by field from the ASN.1 of EN 302 637-3 and TS 102 894-2 by the rules of X.691:
+  // header(48) + preamble(3) + ManagementContainer(6 + 48 + 84 + 123 + 17 + 8) +
+  // SituationContainer(3 + 3 + 1 + 16) = 360 bits
+  const std::vector<uint8_t> expected{
+    0x02, 0x01, 0x00, 0x00, 0x00, 0x2A, 0x81, 0x00, 0x00, 0x00, 0x15, 0x00, 0x03, 0x93, 0x4A,
+    0x0D, 0xD8, 0x80, 0x04, 0xD2, 0x83, 0x76, 0x23, 0x24, 0xAE, 0x96, 0xDE, 0x0B, 0xE9, 0x89,
+    0x65, 0x8F, 0xFF, 0xFF, 0xFE, 0x11, 0xDB, 0xBA, 0x1F, 0x00, 0x1E, 0x07, 0x88, 0x03, 0x01};
+
+  EXPECT_EQ(autoware::v2x_etsi_interface::encode_denm(create_denm()), expected);
+
+  const auto decoded = autoware::v2x_etsi_interface::decode_denm(expected);
+  EXPECT_EQ(decoded.management.validity_duration, 60u);
+  ASSERT_TRUE(decoded.event_type.has_value());
+  EXPECT_EQ(decoded.event_type->cause_code, 3);
+  EXPECT_EQ(decoded.event_type->sub_cause_code, 1);
+}
+
+TEST(Denm, DecodeSkipsCauseCodeExtension)
+{
+  auto payload = autoware::v2x_etsi_interface::encode_denm(create_denm());
+  // set the extension bit of CauseCode (bit 343), which requires the extension additions
+  payload.at(42) |= 0x01;
+  EXPECT_THROW(autoware::v2x_etsi_interface::decode_denm(payload), UperError);
+
+  // an absent extension addition: normally small length 1 (0000000) and its presence bit 0
+  payload.push_back(0x00);
+  const auto decoded = autoware::v2x_etsi_interface::decode_denm(payload);
+  ASSERT_TRUE(decoded.event_type.has_value());
+  EXPECT_EQ(decoded.event_type->cause_code, 3);
+  EXPECT_EQ(decoded.event_type->sub_cause_code, 1);
+}
@@ -0,0 +1,105 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "autoware/v2x_etsi_interface/uper_bit_stream.hpp"
+
+#include <gtest/gtest.h>
+
+#include <vector>
+
+using autoware::v2x_etsi_interface::BitReader;
+using autoware::v2x_etsi_interface::BitWriter;
+using autoware::v2x_etsi_interface::constrained_bit_length;
+using autoware::v2x_etsi_interface::UperError;
+
+TEST(UperBitStream, ConstrainedBitLength)
+{
+  EXPECT_EQ(constrained_bit_length(0, 0), 0u);
+  EXPECT_EQ(constrained_bit_length(0, 1), 1u);
+  EXPECT_EQ(constrained_bit_length(0, 255), 8u);
+  EXPECT_EQ(constrained_bit_length(1, 127), 7u);
+  EXPECT_EQ(constrained_bit_length(0, 3601), 12u);
+  EXPECT_EQ(constrained_bit_length(-900000000, 900000001), 31u);
+  EXPECT_EQ(constrained_bit_length(-1800000000, 1800000001), 32u);
+  EXPECT_EQ(constrained_bit_length(0, 4398046511103), 42u);
+}
+
+TEST(UperBitStream, WriteBitsMostSignificantFirst)
+{
+  BitWriter writer;
+  writer.write_bool(true);
+  writer.write_bits(0b0101, 4);
+  writer.write_constrained(3, 0, 7);
+
+  EXPECT_EQ(writer.bit_length(), 8u);
+  ASSERT_EQ(writer.data().size(), 1u);
+  EXPECT_EQ(writer.data().at(0), 0b10101011);
+}
+
+TEST(UperBitStream, PadsLastOctetWithZero)
+{
+  BitWriter writer;
+  writer.write_bits(0b111, 3);
+
+  ASSERT_EQ(writer.data().size(), 1u);
+  EXPECT_EQ(writer.data().at(0), 0b11100000);
+}
+
+TEST(UperBitStream, RoundTrip)
+{
+  BitWriter writer;
+  writer.write_constrained(-123456789, -900000000, 900000001);
+  writer.write_constrained(4294967295, 0, 4294967295);
+  writer.write_enumerated(8, 9);
+  writer.write_bool(false);
+
+  BitReader reader(writer.data());
+  EXPECT_EQ(reader.read_constrained(-900000000, 900000001), -123456789);
+  EXPECT_EQ(reader.read_constrained(0, 4294967295), 4294967295);
+  EXPECT_EQ(reader.read_enumerated(9), 8u);
+  EXPECT_FALSE(reader.read_bool());
+}
+
+TEST(UperBitStream, OutOfRangeValueThrows)
+{
+  BitWriter writer;
+  EXPECT_THROW(writer.write_constrained(128, 1, 127), UperError);
+  EXPECT_THROW(writer.write_constrained(0, 1, 127), UperError);
+}
+
+TEST(UperBitStream, ReadingPastTheEndThrows)
+{
+  const std::vector<uint8_t> data{0xFF};
+  BitReader reader(data);
+  reader.read_bits(6);
+  EXPECT_THROW(reader.read_bits(3), UperError);
+}
+
+TEST(UperBitStream, SkipSequenceExtensions)
+{
+  BitWriter writer;
+  // two extension additions, only the second one is present with a two octet open type
+  writer.write_bool(false);
+  writer.write_bits(1, 6);
+  writer.write_bool(false);
+  writer.write_bool(true);
+  writer.write_bool(false);
+  writer.write_bits(2, 7);
+  writer.write_bits(0xABCD, 16);
+  writer.write_bits(0x5, 3);
+
+  BitReader reader(writer.data());
+  reader.skip_sequence_extensions();
+  EXPECT_EQ(reader.read_bits(3), 0x5u);
+}
@@ -0,0 +1,88 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "wheel_odometry.hpp"
+
+#include <algorithm>
+#include <cmath>
+
+namespace autoware::wheel_odometry
+{
+namespace
+{
+double calc_median(WheelSpeeds speeds)
+{
+  std::sort(speeds.begin(), speeds.end());
+  return (speeds.at(1) + speeds.at(2)) / 2.0;
+}
+}  // namespace
+
+double calc_pulse_speed(
+  const uint64_t previous_count, const uint64_t count, const uint32_t length,
+  const double distance_per_pulse, const double dt)
+{
+  if (dt <= 0.0) {
+    return 0.0;
+  }
+  // the counter wraps around at 2^length
+  uint64_t increment = count - previous_count;
+  if (length < 64) {
+    increment &= (uint64_t{1} << length) - 1;
+  }
+  return static_cast<double>(increment) * distance_per_pulse / dt;
+}
+
+std::array<bool, 4> detect_slip(const WheelSpeeds & speeds, const SlipParam & param)
+{
+  const double median = calc_median(speeds);
+  const double threshold =
+    std::max(param.ratio_threshold * std::abs(median), param.min_speed_difference);
+
+  std::array<bool, 4> is_slipping{};
+  for (size_t i = 0; i < speeds.size(); ++i) {
+    is_slipping.at(i) = std::abs(speeds.at(i) - median) > threshold;
+  }
+  return is_slipping;
+}
+
+WheelOdometry calc_wheel_odometry(
+  const WheelSpeeds & speeds, const double wheel_tread, const SlipParam & param)
+{
+  WheelOdometry odometry;
+  odometry.is_slipping = detect_slip(speeds, param);
+  const auto & is_slipping = odometry.is_slipping;
+
+  // the rear wheels are not steered, so their mean is the velocity of base_link
+  if (!is_slipping.at(REAR_LEFT) && !is_slipping.at(REAR_RIGHT)) {
+    odometry.velocity = (speeds.at(REAR_LEFT) + speeds.at(REAR_RIGHT)) / 2.0;
+    odometry.yaw_rate = (speeds.at(REAR_RIGHT) - speeds.at(REAR_LEFT)) / wheel_tread;
+    return odometry;
+  }
+  if (!is_slipping.at(REAR_LEFT) || !is_slipping.at(REAR_RIGHT)) {
+    odometry.velocity = is_slipping.at(REAR_LEFT) ? speeds.at(REAR_RIGHT) : speeds.at(REAR_LEFT);
+    return odometry;
+  }
+
+  double sum = 0.0;
+  size_t count = 0;
+  for (const auto index : {FRONT_LEFT, FRONT_RIGHT}) {
+    if (!is_slipping.at(index)) {
+      sum += speeds.at(index);
+      ++count;
+    }
+  }
+  odometry.velocity = count > 0 ? sum / static_cast<double>(count) : calc_median(speeds);
+  return odometry;
+}
+}  // namespace autoware::wheel_odometry
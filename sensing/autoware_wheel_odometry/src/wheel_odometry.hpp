@@ -0,0 +1,56 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef WHEEL_ODOMETRY_HPP_
+#define WHEEL_ODOMETRY_HPP_
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+
+namespace autoware::wheel_odometry
+{
+enum WheelIndex : size_t { FRONT_LEFT = 0, FRONT_RIGHT = 1, REAR_LEFT = 2, REAR_RIGHT = 3 };
+
+// signed speeds of the wheels [m/s] in the order of WheelIndex
+using WheelSpeeds = std::array<double, 4>;
+
+struct SlipParam
+{
+  // a wheel slips when its speed differs from the median of the wheels more than both of them
+  double ratio_threshold{};
+  double min_speed_difference{};
+};
+
+struct WheelOdometry
+{
+  double velocity{};
+  // nullopt when a rear wheel slips
+  std::optional<double> yaw_rate;
+  std::array<bool, 4> is_slipping{};
+};
+
+// Converts the increment of a rolling pulse counter of `length` bits into the speed [m/s].
+double calc_pulse_speed(
+  uint64_t previous_count, uint64_t count, uint32_t length, double distance_per_pulse, double dt);
+
+std::array<bool, 4> detect_slip(const WheelSpeeds & speeds, const SlipParam & param);
+
+// Calculates the twist of base_link at the center of the rear axle from the wheels without slip.
+WheelOdometry calc_wheel_odometry(
+  const WheelSpeeds & speeds, double wheel_tread, const SlipParam & param);
+}  // namespace autoware::wheel_odometry
+
+#endif  // WHEEL_ODOMETRY_HPP_
@@ -0,0 +1,200 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "wheel_odometry_node.hpp"
+
+#include <autoware_vehicle_info_utils/vehicle_info_utils.hpp>
+
+#include <algorithm>
+#include <cmath>
+#include <stdexcept>
+#include <string>
+
+namespace autoware::wheel_odometry
+{
+namespace
+{
+constexpr std::array<const char *, 4> wheel_names = {
+  "front_left", "front_right", "rear_left", "rear_right"};
+}  // namespace
+
+WheelOdometryNode::WheelOdometryNode(const rclcpp::NodeOptions & node_options)
+: Node("wheel_odometry", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.publish_rate = declare_parameter<double>("publish_rate");
+  const auto input_type = declare_parameter<std::string>("input_type");
+  if (input_type == "speed") {
+    node_param_.input_type = InputType::SPEED;
+  } else if (input_type == "pulse") {
+    node_param_.input_type = InputType::PULSE;
+  } else {
+    throw std::invalid_argument("unknown input_type: " + input_type);
+  }
+  node_param_.distance_per_pulse = declare_parameter<double>("distance_per_pulse");
+  for (size_t i = 0; i < wheel_names.size(); ++i) {
+    const auto prefix = std::string("wheels.") + wheel_names.at(i) + ".";
+    auto & signal = node_param_.signals.at(i);
+    signal.id = static_cast<uint32_t>(declare_parameter<int>(prefix + "id"));
+    signal.start_bit = static_cast<uint32_t>(declare_parameter<int>(prefix + "start_bit"));
+    signal.length = static_cast<uint32_t>(declare_parameter<int>(prefix + "length"));
+    signal.is_little_endian = declare_parameter<bool>(prefix + "little_endian");
+    signal.is_signed = declare_parameter<bool>(prefix + "signed");
+    signal.scale = declare_parameter<double>(prefix + "scale");
+    signal.offset = declare_parameter<double>(prefix + "offset");
+    node_param_.scale_factors.at(i) = declare_parameter<double>(prefix + "scale_factor");
+  }
+  node_param_.direction_from_gear = declare_parameter<bool>("direction_from_gear");
+  node_param_.slip.ratio_threshold = declare_parameter<double>("slip.ratio_threshold");
+  node_param_.slip.min_speed_difference = declare_parameter<double>("slip.min_speed_difference");
+  node_param_.velocity_stddev_xx = declare_parameter<double>("velocity_stddev_xx");
+  node_param_.slip_velocity_stddev_xx = declare_parameter<double>("slip_velocity_stddev_xx");
+  node_param_.angular_velocity_stddev_zz = declare_parameter<double>("angular_velocity_stddev_zz");
+  node_param_.frame_id = declare_parameter<std::string>("frame_id");
+  node_param_.data_timeout = declare_parameter<double>("data_timeout");
+
+  wheel_tread_ =
+    autoware::vehicle_info_utils::VehicleInfoUtils(*this).getVehicleInfo().wheel_tread_m;
+
+  // Subscriber
+  sub_can_frame_ = create_subscription<can_msgs::msg::Frame>(
+    "input/can_frame", rclcpp::QoS{100},
+    std::bind(&WheelOdometryNode::on_can_frame, this, std::placeholders::_1));
+  sub_gear_status_ = create_subscription<GearReport>(
+    "input/gear_status", rclcpp::QoS{1},
+    [this](const GearReport::ConstSharedPtr msg) { gear_status_ = msg; });
+
+  // Publisher
+  pub_twist_with_covariance_ =
+    create_publisher<TwistWithCovarianceStamped>("output/twist_with_covariance", rclcpp::QoS{10});
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.publish_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&WheelOdometryNode::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("wheel_odometry");
+  updater_.add("wheel_odometry", this, &WheelOdometryNode::check_wheel_odometry);
+}
+
+void WheelOdometryNode::on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg)
+{
+  if (msg->is_rtr || msg->is_error) {
+    return;
+  }
+
+  const rclcpp::Time stamp(msg->header.stamp);
+  for (size_t i = 0; i < node_param_.signals.size(); ++i) {
+    const auto & signal = node_param_.signals.at(i);
+    if (signal.id != msg->id) {
+      continue;
+    }
+    const auto value = decode_signal(signal, msg->data.data(), std::min<size_t>(msg->dlc, 8));
+    if (!value) {
+      continue;
+    }
+
+    double speed = *value;
+    if (node_param_.input_type == InputType::PULSE) {
+      const auto count = static_cast<uint64_t>(*value);
+      auto & pulse_state = pulse_states_.at(i);
+      const bool has_previous = pulse_state.has_value();
+      const auto previous = pulse_state.value_or(PulseState{count, stamp});
+      pulse_state = PulseState{count, stamp};
+      // the speed is unknown until the second frame
+      if (!has_previous) {
+        continue;
+      }
+      speed = calc_pulse_speed(
+        previous.count, count, signal.length, node_param_.distance_per_pulse,
+        (stamp - previous.stamp).seconds());
+    }
+    speeds_.at(i) = speed * node_param_.scale_factors.at(i);
+    received_times_.at(i) = now();
+  }
+}
+
+void WheelOdometryNode::on_timer()
+{
+  const auto current_time = now();
+  for (const auto & received_time : received_times_) {
+    if (!received_time || (current_time - *received_time).seconds() > node_param_.data_timeout) {
+      odometry_.reset();
+      return;
+    }
+  }
+
+  // the speeds without sign are negated in reverse
+  auto speeds = speeds_;
+  const bool is_reverse =
+    gear_status_ &&
+    (gear_status_->report == GearReport::REVERSE || gear_status_->report == GearReport::REVERSE_2);
+  if (node_param_.direction_from_gear && is_reverse) {
+    for (auto & speed : speeds) {
+      speed = -std::abs(speed);
+    }
+  }
+  odometry_ = calc_wheel_odometry(speeds, wheel_tread_, node_param_.slip);
+
+  const bool is_slipping = std::any_of(
+    odometry_->is_slipping.begin(), odometry_->is_slipping.end(), [](const bool b) { return b; });
+  const double stddev_vx =
+    is_slipping ? node_param_.slip_velocity_stddev_xx : node_param_.velocity_stddev_xx;
+
+  TwistWithCovarianceStamped twist_with_covariance;
+  twist_with_covariance.header.stamp = current_time;
+  twist_with_covariance.header.frame_id = node_param_.frame_id;
+  twist_with_covariance.twist.twist.linear.x = odometry_->velocity;
+  twist_with_covariance.twist.twist.angular.z = odometry_->yaw_rate.value_or(0.0);
+  twist_with_covariance.twist.covariance[0 + 0 * 6] = stddev_vx * stddev_vx;
+  twist_with_covariance.twist.covariance[1 + 1 * 6] = 10000.0;
+  twist_with_covariance.twist.covariance[2 + 2 * 6] = 10000.0;
+  twist_with_covariance.twist.covariance[3 + 3 * 6] = 10000.0;
+  twist_with_covariance.twist.covariance[4 + 4 * 6] = 10000.0;
+  twist_with_covariance.twist.covariance[5 + 5 * 6] =
+    odometry_->yaw_rate
+      ? node_param_.angular_velocity_stddev_zz * node_param_.angular_velocity_stddev_zz
+      : 10000.0;
+  pub_twist_with_covariance_->publish(twist_with_covariance);
+}
+
+void WheelOdometryNode::check_wheel_odometry(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  if (!odometry_) {
+    stat.summary(DiagnosticStatus::ERROR, "the wheel speeds are not received");
+    return;
+  }
+
+  std::string slipping_wheels;
+  for (size_t i = 0; i < wheel_names.size(); ++i) {
+    stat.addf(wheel_names.at(i), "%.2f [m/s]", speeds_.at(i));
+    if (odometry_->is_slipping.at(i)) {
+      slipping_wheels += std::string(slipping_wheels.empty() ? "" : ", ") + wheel_names.at(i);
+    }
+  }
+  stat.addf("velocity", "%.2f [m/s]", odometry_->velocity);
+
+  if (!slipping_wheels.empty()) {
+    stat.summary(DiagnosticStatus::WARN, "slip is detected on " + slipping_wheels);
+    return;
+  }
+  stat.summary(DiagnosticStatus::OK, "OK");
+}
+}  // namespace autoware::wheel_odometry
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::wheel_odometry::WheelOdometryNode)
@@ -0,0 +1,102 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef WHEEL_ODOMETRY_NODE_HPP_
+#define WHEEL_ODOMETRY_NODE_HPP_
+
+#include "wheel_odometry.hpp"
+
+#include <autoware/can_signal_utils/can_signal.hpp>
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_vehicle_msgs/msg/gear_report.hpp>
+#include <can_msgs/msg/frame.hpp>
+#include <geometry_msgs/msg/twist_with_covariance_stamped.hpp>
+
+#include <array>
+#include <optional>
+#include <string>
+
+namespace autoware::wheel_odometry
+{
+using autoware::can_signal_utils::CanSignal;
+using autoware::can_signal_utils::decode_signal;
+using autoware_vehicle_msgs::msg::GearReport;
+using geometry_msgs::msg::TwistWithCovarianceStamped;
+
+enum class InputType { SPEED, PULSE };
+
+struct NodeParam
+{
+  double publish_rate{};
+  InputType input_type{};
+  double distance_per_pulse{};
+  std::array<CanSignal, 4> signals{};
+  std::array<double, 4> scale_factors{};
+  bool direction_from_gear{};
+  SlipParam slip{};
+  double velocity_stddev_xx{};
+  double slip_velocity_stddev_xx{};
+  double angular_velocity_stddev_zz{};
+  std::string frame_id;
+  double data_timeout{};
+};
+
+// the raw pulse count and the time of the last frame, used to differentiate the counter
+struct PulseState
+{
+  uint64_t count{};
+  rclcpp::Time stamp;
+};
+
+class WheelOdometryNode : public rclcpp::Node
+{
+public:
+  explicit WheelOdometryNode(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+  double wheel_tread_{};
+
+  // Subscriber
+  rclcpp::Subscription<can_msgs::msg::Frame>::SharedPtr sub_can_frame_;
+  rclcpp::Subscription<GearReport>::SharedPtr sub_gear_status_;
+
+  void on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg);
+
+  // Publisher
+  rclcpp::Publisher<TwistWithCovarianceStamped>::SharedPtr pub_twist_with_covariance_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_wheel_odometry(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  GearReport::ConstSharedPtr gear_status_;
+  WheelSpeeds speeds_{};
+  std::array<std::optional<rclcpp::Time>, 4> received_times_{};
+  std::array<std::optional<PulseState>, 4> pulse_states_{};
+  std::optional<WheelOdometry> odometry_;
+};
+}  // namespace autoware::wheel_odometry
+
+#endif  // WHEEL_ODOMETRY_NODE_HPP_
@@ -0,0 +1,92 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "wheel_odometry.hpp"
+
+#include <autoware/can_signal_utils/can_signal.hpp>
+
+#include <gtest/gtest.h>
+
+using autoware::wheel_odometry::SlipParam;
+using autoware::wheel_odometry::WheelSpeeds;
+
+namespace
+{
+SlipParam create_slip_param()
+{
+  SlipParam param;
+  param.ratio_threshold = 0.3;
+  param.min_speed_difference = 0.5;
+  return param;
+}
+}  // namespace
+
+TEST(WheelOdometry, DecodeSignal)
+{
+  using autoware::can_signal_utils::CanSignal;
+  using autoware::can_signal_utils::decode_signal;
+  CanSignal signal;
+  signal.start_bit = 16;
+  signal.length = 16;
+  signal.is_little_endian = true;
+  signal.scale = 0.01 / 3.6;
+
+  // 3600 = 36 [km/h]
+  const uint8_t data[] = {0x00, 0x00, 0x10, 0x0E, 0x00, 0x00, 0x00, 0x00};
+  const auto speed = decode_signal(signal, data, sizeof(data));
+  ASSERT_TRUE(speed.has_value());
+  EXPECT_NEAR(*speed, 10.0, 1e-9);
+
+  EXPECT_FALSE(decode_signal(signal, data, 3).has_value());
+}
+
+TEST(WheelOdometry, CalcPulseSpeed)
+{
+  using autoware::wheel_odometry::calc_pulse_speed;
+  EXPECT_DOUBLE_EQ(calc_pulse_speed(100, 150, 10, 0.02, 0.1), 10.0);
+  // the 10-bit counter wraps around from 1000 to 26
+  EXPECT_DOUBLE_EQ(calc_pulse_speed(1000, 26, 10, 0.02, 0.1), 10.0);
+  EXPECT_DOUBLE_EQ(calc_pulse_speed(100, 100, 10, 0.02, 0.1), 0.0);
+  EXPECT_DOUBLE_EQ(calc_pulse_speed(100, 150, 10, 0.02, 0.0), 0.0);
+}
+
+TEST(WheelOdometry, CalcWheelOdometry)
+{
+  using autoware::wheel_odometry::calc_wheel_odometry;
+  const auto param = create_slip_param();
+
+  // turning left
+  const WheelSpeeds turning{9.8, 10.6, 9.6, 10.4};
+  const auto odometry = calc_wheel_odometry(turning, 1.6, param);
+  EXPECT_DOUBLE_EQ(odometry.velocity, 10.0);
+  ASSERT_TRUE(odometry.yaw_rate.has_value());
+  EXPECT_NEAR(*odometry.yaw_rate, 0.5, 1e-9);
+  for (const auto is_slipping : odometry.is_slipping) {
+    EXPECT_FALSE(is_slipping);
+  }
+
+  // the rear left wheel spins
+  const WheelSpeeds spinning{5.0, 5.0, 9.0, 5.2};
+  const auto spinning_odometry = calc_wheel_odometry(spinning, 1.6, param);
+  EXPECT_TRUE(spinning_odometry.is_slipping.at(autoware::wheel_odometry::REAR_LEFT));
+  EXPECT_FALSE(spinning_odometry.is_slipping.at(autoware::wheel_odometry::REAR_RIGHT));
+  EXPECT_DOUBLE_EQ(spinning_odometry.velocity, 5.2);
+  EXPECT_FALSE(spinning_odometry.yaw_rate.has_value());
+
+  // the both rear wheels slip in the opposite directions
+  const WheelSpeeds rear_slipping{5.0, 5.1, 3.0, 7.0};
+  const auto rear_slipping_odometry = calc_wheel_odometry(rear_slipping, 1.6, param);
+  EXPECT_DOUBLE_EQ(rear_slipping_odometry.velocity, 5.05);
+  EXPECT_FALSE(rear_slipping_odometry.yaw_rate.has_value());
+}
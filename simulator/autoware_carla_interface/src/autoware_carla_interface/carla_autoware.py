@@ -34,6 +34,7 @@ class SensorLoop(object):
         self.running = False
         self.timestamp_last_run = 0.0
         self.timeout = 20.0
+        self.is_primary = True
 
     def _stop_loop(self):
         self.running = False
@@ -49,7 +50,11 @@ class SensorLoop(object):
                 raise RuntimeError(e)
             self.ego_actor.apply_control(ego_action)
         if self.running:
-            CarlaDataProvider.get_world().tick()
+            # only the primary instance advances the world, and the others follow its ticks
+            if self.is_primary:
+                CarlaDataProvider.get_world().tick()
+            else:
+                CarlaDataProvider.get_world().wait_for_tick()
 
 
 class InitializeInterface(object):
@@ -73,18 +78,23 @@ class InitializeInterface(object):
         self.spawn_point = self.param_["spawn_point"]
         self.use_traffic_manager = self.param_["use_traffic_manager"]
         self.max_real_delta_seconds = self.param_["max_real_delta_seconds"]
+        self.is_primary = self.param_["is_primary"]
 
     def load_world(self):
         client = carla.Client(self.local_host, self.port)
         client.set_timeout(self.timeout)
-        client.load_world(self.carla_map)
+        # the secondary instances join the world loaded by the primary one
+        if self.is_primary:
+            client.load_world(self.carla_map)
         self.world = client.get_world()
-        settings = self.world.get_settings()
-        settings.fixed_delta_seconds = self.fixed_delta_seconds
-        settings.synchronous_mode = self.sync_mode
-        self.world.apply_settings(settings)
+        if self.is_primary:
+            settings = self.world.get_settings()
+            settings.fixed_delta_seconds = self.fixed_delta_seconds
+            settings.synchronous_mode = self.sync_mode
+            self.world.apply_settings(settings)
         CarlaDataProvider.set_world(self.world)
         CarlaDataProvider.set_client(client)
+        CarlaDataProvider.set_primary(self.is_primary)
         spawn_point = carla.Transform()
         point_items = self.spawn_point.split(",")
         randomize = False
@@ -111,7 +121,7 @@ class InitializeInterface(object):
         # TRAFFIC MANAGER
         ##########################################################################################################################################################
         # cspell:ignore trafficmanager
-        if self.use_traffic_manager:
+        if self.use_traffic_manager and self.is_primary:
             traffic_manager = client.get_trafficmanager()
             traffic_manager.set_synchronous_mode(True)
             traffic_manager.set_random_device_seed(0)
@@ -150,6 +160,7 @@ class InitializeInterface(object):
         self.bridge_loop = SensorLoop()
         self.bridge_loop.sensor = self.sensor_wrapper
         self.bridge_loop.ego_actor = self.ego_actor
+        self.bridge_loop.is_primary = self.is_primary
         self.bridge_loop.start_system_time = time.time()
         self.bridge_loop.start_game_time = GameTime.get_time()
         self.bridge_loop.running = True
@@ -161,11 +172,13 @@ class InitializeInterface(object):
                 if snapshot:
                     timestamp = snapshot.timestamp
             if timestamp:
-                delta_step = time.time() - self.prev_tick_wall_time
-                if delta_step <= self.max_real_delta_seconds:
-                    # Add a wait to match the max_real_delta_seconds
-                    time.sleep(self.max_real_delta_seconds - delta_step)
-                self.prev_tick_wall_time = time.time()
+                # the secondary instances are paced by the ticks of the primary one
+                if self.is_primary:
+                    delta_step = time.time() - self.prev_tick_wall_time
+                    if delta_step <= self.max_real_delta_seconds:
+                        # Add a wait to match the max_real_delta_seconds
+                        time.sleep(self.max_real_delta_seconds - delta_step)
+                    self.prev_tick_wall_time = time.time()
                 self.bridge_loop._tick_sensor(timestamp)
 
     def _stop_loop(self, sign, frame):
@@ -88,24 +88,28 @@ class carla_ros2_interface(object):
             "objects_definition_file": rclpy.Parameter.Type.STRING,
             "use_traffic_manager": rclpy.Parameter.Type.BOOL,
             "max_real_delta_seconds": rclpy.Parameter.Type.DOUBLE,
+            "is_primary": rclpy.Parameter.Type.BOOL,
+            "publish_clock": rclpy.Parameter.Type.BOOL,
         }
         self.param_values = {}
         for param_name, param_type in self.parameters.items():
             self.ros2_node.declare_parameter(param_name, param_type)
             self.param_values[param_name] = self.ros2_node.get_parameter(param_name).value
 
-        # Publish clock
-        self.clock_publisher = self.ros2_node.create_publisher(Clock, "/clock", 10)
-        obj_clock = Clock()
-        obj_clock.clock = Time(sec=0)
-        self.clock_publisher.publish(obj_clock)
+        # Publish clock, which must be published by only one instance in a ROS domain
+        self.publish_clock = self.param_values["publish_clock"]
+        if self.publish_clock:
+            self.clock_publisher = self.ros2_node.create_publisher(Clock, "/clock", 10)
+            obj_clock = Clock()
+            obj_clock.clock = Time(sec=0)
+            self.clock_publisher.publish(obj_clock)
 
         # Sensor Config (Edit your sensor here)
         self.sensors = json.load(open(self.param_values["objects_definition_file"]))
 
         # Subscribing Autoware Control messages and converting to CARLA control
         self.sub_control = self.ros2_node.create_subscription(
-            ActuationCommandStamped, "/control/command/actuation_cmd", self.control_callback, 1
+            ActuationCommandStamped, "control/command/actuation_cmd", self.control_callback, 1
         )
 
         self.sub_vehicle_initialpose = self.ros2_node.create_subscription(
@@ -116,22 +120,22 @@ class carla_ros2_interface(object):
 
         # Direct data publishing from CARLA for Autoware
         self.pub_pose_with_cov = self.ros2_node.create_publisher(
-            PoseWithCovarianceStamped, "/sensing/gnss/pose_with_covariance", 1
+            PoseWithCovarianceStamped, "sensing/gnss/pose_with_covariance", 1
         )
         self.pub_vel_state = self.ros2_node.create_publisher(
-            VelocityReport, "/vehicle/status/velocity_status", 1
+            VelocityReport, "vehicle/status/velocity_status", 1
         )
         self.pub_steering_state = self.ros2_node.create_publisher(
-            SteeringReport, "/vehicle/status/steering_status", 1
+            SteeringReport, "vehicle/status/steering_status", 1
         )
         self.pub_ctrl_mode = self.ros2_node.create_publisher(
-            ControlModeReport, "/vehicle/status/control_mode", 1
+            ControlModeReport, "vehicle/status/control_mode", 1
         )
         self.pub_gear_state = self.ros2_node.create_publisher(
-            GearReport, "/vehicle/status/gear_status", 1
+            GearReport, "vehicle/status/gear_status", 1
         )
         self.pub_actuation_status = self.ros2_node.create_publisher(
-            ActuationStatusStamped, "/vehicle/status/actuation_status", 1
+            ActuationStatusStamped, "vehicle/status/actuation_status", 1
         )
 
         # Create Publisher for each Physical Sensors
@@ -139,15 +143,15 @@ class carla_ros2_interface(object):
             self.id_to_sensor_type_map[sensor["id"]] = sensor["type"]
             if sensor["type"] == "sensor.camera.rgb":
                 self.pub_camera = self.ros2_node.create_publisher(
-                    Image, "/sensing/camera/traffic_light/image_raw", 1
+                    Image, "sensing/camera/traffic_light/image_raw", 1
                 )
                 self.pub_camera_info = self.ros2_node.create_publisher(
-                    CameraInfo, "/sensing/camera/traffic_light/camera_info", 1
+                    CameraInfo, "sensing/camera/traffic_light/camera_info", 1
                 )
             elif sensor["type"] == "sensor.lidar.ray_cast":
                 if sensor["id"] in self.sensor_frequencies:
                     self.pub_lidar[sensor["id"]] = self.ros2_node.create_publisher(
-                        PointCloud2, f'/sensing/lidar/{sensor["id"]}/pointcloud_before_sync', 10
+                        PointCloud2, f'sensing/lidar/{sensor["id"]}/pointcloud_before_sync', 10
                     )
                 else:
                     self.ros2_node.get_logger().info(
@@ -155,7 +159,7 @@ class carla_ros2_interface(object):
                     )
             elif sensor["type"] == "sensor.other.imu":
                 self.pub_imu = self.ros2_node.create_publisher(
-                    Imu, "/sensing/imu/tamagawa/imu_raw", 1
+                    Imu, "sensing/imu/tamagawa/imu_raw", 1
                 )
             else:
                 self.ros2_node.get_logger().info(f'No Publisher for {sensor["type"]} Sensor')
@@ -458,11 +462,12 @@ class carla_ros2_interface(object):
 
     def run_step(self, input_data, timestamp):
         self.timestamp = timestamp
-        seconds = int(self.timestamp)
-        nanoseconds = int((self.timestamp - int(self.timestamp)) * 1000000000.0)
-        obj_clock = Clock()
-        obj_clock.clock = Time(sec=seconds, nanosec=nanoseconds)
-        self.clock_publisher.publish(obj_clock)
+        if self.publish_clock:
+            seconds = int(self.timestamp)
+            nanoseconds = int((self.timestamp - int(self.timestamp)) * 1000000000.0)
+            obj_clock = Clock()
+            obj_clock.clock = Time(sec=seconds, nanosec=nanoseconds)
+            self.clock_publisher.publish(obj_clock)
 
         # publish data of all sensors
         for key, data in input_data.items():
@@ -54,6 +54,7 @@ class CarlaDataProvider(object):  # pylint: disable=too-many-public-methods
     _rng = random.RandomState(_random_seed)
     _local_planner = None
     _runtime_init_flag = False
+    _primary_flag = True
     _lock = threading.Lock()
 
     @staticmethod
@@ -233,6 +234,24 @@ class CarlaDataProvider(object):  # pylint: disable=too-many-public-methods
         """Return true if runtime init mode is used."""
         return CarlaDataProvider._runtime_init_flag
 
+    @staticmethod
+    def set_primary(flag):
+        """Set whether this client advances the world, or follows the ticks of another one."""
+        CarlaDataProvider._primary_flag = flag
+
+    @staticmethod
+    def is_primary():
+        """Return true if this client advances the world."""
+        return CarlaDataProvider._primary_flag
+
+    @staticmethod
+    def tick_or_wait():
+        """Advance the world in sync mode on the primary client, and wait for a tick otherwise."""
+        if CarlaDataProvider.is_sync_mode() and CarlaDataProvider.is_primary():
+            CarlaDataProvider._world.tick()
+        else:
+            CarlaDataProvider._world.wait_for_tick()
+
     @staticmethod
     def find_weather_presets():
         """Get weather presets from CARLA."""
@@ -463,7 +482,8 @@ class CarlaDataProvider(object):  # pylint: disable=too-many-public-methods
         actors = []
 
         if CarlaDataProvider._client:
-            responses = CarlaDataProvider._client.apply_batch_sync(batch, sync_mode and tick)
+            do_tick = sync_mode and tick and CarlaDataProvider.is_primary()
+            responses = CarlaDataProvider._client.apply_batch_sync(batch, do_tick)
         else:
             raise ValueError("class member 'client'' not initialized yet")
 
@@ -472,10 +492,8 @@ class CarlaDataProvider(object):  # pylint: disable=too-many-public-methods
             pass
         elif CarlaDataProvider.is_runtime_init_mode():
             CarlaDataProvider._world.wait_for_tick()
-        elif sync_mode:
-            CarlaDataProvider._world.tick()
         else:
-            CarlaDataProvider._world.wait_for_tick()
+            CarlaDataProvider.tick_or_wait()
 
         actor_ids = [r.actor_id for r in responses if not r.error]
         for r in responses:
@@ -537,10 +555,8 @@ class CarlaDataProvider(object):  # pylint: disable=too-many-public-methods
             pass
         elif CarlaDataProvider.is_runtime_init_mode():
             CarlaDataProvider._world.wait_for_tick()
-        elif CarlaDataProvider.is_sync_mode():
-            CarlaDataProvider._world.tick()
         else:
-            CarlaDataProvider._world.wait_for_tick()
+            CarlaDataProvider.tick_or_wait()
 
         if actor is None:
             return None
@@ -812,6 +828,7 @@ class CarlaDataProvider(object):  # pylint: disable=too-many-public-methods
         CarlaDataProvider._spawn_index = 0
         CarlaDataProvider._rng = random.RandomState(CarlaDataProvider._random_seed)
         CarlaDataProvider._runtime_init_flag = False
+        CarlaDataProvider._primary_flag = True
 
     @property
     def world(self):
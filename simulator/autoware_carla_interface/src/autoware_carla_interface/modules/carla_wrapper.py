@@ -218,8 +218,8 @@ class SensorWrapper(object):
             sensor.listen(CallBack(sensor_spec["id"], sensor, self._agent.sensor_interface))
             self._sensors_list.append(sensor)
 
-        # Tick once to spawn the sensors
-        CarlaDataProvider.get_world().tick()
+        # Tick once to spawn the sensors, or wait for the tick of the primary client
+        CarlaDataProvider.tick_or_wait()
 
     def cleanup(self):
         """Cleanup sensors."""
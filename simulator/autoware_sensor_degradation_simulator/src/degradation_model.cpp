@@ -0,0 +1,72 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "degradation_model.hpp"
+
+#include <cmath>
+
+namespace autoware::sensor_degradation_simulator
+{
+double NoiseGenerator::gaussian(const double stddev)
+{
+  if (stddev <= 0.0) {
+    return 0.0;
+  }
+  std::normal_distribution<double> distribution(0.0, stddev);
+  return distribution(engine_);
+}
+
+bool NoiseGenerator::bernoulli(const double probability)
+{
+  if (probability <= 0.0) {
+    return false;
+  }
+  if (probability >= 1.0) {
+    return true;
+  }
+  std::bernoulli_distribution distribution(probability);
+  return distribution(engine_);
+}
+
+double RandomWalk::step(NoiseGenerator & generator, const double dt)
+{
+  if (dt > 0.0) {
+    value_ += generator.gaussian(stddev_per_sqrt_second_ * std::sqrt(dt));
+  }
+  return value_;
+}
+
+bool OutageModel::update(NoiseGenerator & generator, const double now_seconds)
+{
+  const double dt = last_update_ ? now_seconds - *last_update_ : 0.0;
+  last_update_ = now_seconds;
+
+  if (outage_end_) {
+    if (now_seconds < *outage_end_) {
+      return true;
+    }
+    outage_end_.reset();
+  }
+
+  if (mean_interval_ <= 0.0 || dt <= 0.0) {
+    return false;
+  }
+  // probability that at least one event of the Poisson process occurs during dt
+  if (generator.bernoulli(1.0 - std::exp(-dt / mean_interval_))) {
+    outage_end_ = now_seconds + duration_;
+    return true;
+  }
+  return false;
+}
+}  // namespace autoware::sensor_degradation_simulator
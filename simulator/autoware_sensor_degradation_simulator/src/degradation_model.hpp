@@ -0,0 +1,115 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef DEGRADATION_MODEL_HPP_
+#define DEGRADATION_MODEL_HPP_
+
+#include <algorithm>
+#include <cstdint>
+#include <deque>
+#include <optional>
+#include <random>
+#include <utility>
+#include <vector>
+
+namespace autoware::sensor_degradation_simulator
+{
+/**
+ * @brief random source shared by the models of a node, seeded for reproducible runs
+ */
+class NoiseGenerator
+{
+public:
+  explicit NoiseGenerator(const uint32_t seed) : engine_(seed) {}
+
+  double gaussian(const double stddev);
+  bool bernoulli(const double probability);
+
+private:
+  std::mt19937 engine_;
+};
+
+/**
+ * @brief bias which drifts as a random walk, the stddev grows with the square root of time
+ */
+class RandomWalk
+{
+public:
+  RandomWalk(const double initial_value, const double stddev_per_sqrt_second)
+  : value_(initial_value), stddev_per_sqrt_second_(stddev_per_sqrt_second)
+  {
+  }
+
+  double step(NoiseGenerator & generator, double dt);
+  [[nodiscard]] double value() const { return value_; }
+
+private:
+  double value_;
+  double stddev_per_sqrt_second_;
+};
+
+/**
+ * @brief sensor outage which starts as a Poisson process and lasts for a fixed duration
+ */
+class OutageModel
+{
+public:
+  // an outage never starts when the mean interval is not positive
+  OutageModel(const double mean_interval, const double duration)
+  : mean_interval_(mean_interval), duration_(duration)
+  {
+  }
+
+  // returns true while the sensor is out
+  bool update(NoiseGenerator & generator, double now_seconds);
+
+private:
+  double mean_interval_;
+  double duration_;
+  std::optional<double> last_update_;
+  std::optional<double> outage_end_;
+};
+
+/**
+ * @brief FIFO which releases each element after its latency, keeping the order of the input
+ */
+template <typename T>
+class DelayQueue
+{
+public:
+  void push(const double ready_time, T value)
+  {
+    // a later message is never released before an earlier one
+    const double time = queue_.empty() ? ready_time : std::max(ready_time, queue_.back().first);
+    queue_.emplace_back(time, std::move(value));
+  }
+
+  std::vector<T> pop_ready(const double now_seconds)
+  {
+    std::vector<T> ready;
+    while (!queue_.empty() && queue_.front().first <= now_seconds) {
+      ready.push_back(std::move(queue_.front().second));
+      queue_.pop_front();
+    }
+    return ready;
+  }
+
+  [[nodiscard]] size_t size() const { return queue_.size(); }
+
+private:
+  std::deque<std::pair<double, T>> queue_;
+};
+}  // namespace autoware::sensor_degradation_simulator
+
+#endif  // DEGRADATION_MODEL_HPP_
@@ -0,0 +1,139 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef DEGRADATION_NODE_BASE_HPP_
+#define DEGRADATION_NODE_BASE_HPP_
+
+#include "degradation_model.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <algorithm>
+#include <functional>
+#include <memory>
+#include <optional>
+#include <string>
+
+namespace autoware::sensor_degradation_simulator
+{
+struct CommonParam
+{
+  int seed{};
+  double drop_probability{};
+  double outage_mean_interval{};
+  double outage_duration{};
+  double latency{};
+  double latency_jitter{};
+};
+
+/**
+ * @brief pass-through node which drops and delays the messages, and lets the derived class
+ *        add the sensor specific noise
+ */
+template <typename MessageT>
+class DegradationNodeBase : public rclcpp::Node
+{
+public:
+  DegradationNodeBase(const std::string & node_name, const rclcpp::NodeOptions & node_options)
+  : Node(node_name, node_options),
+    common_param_(declare_common_param()),
+    generator_(static_cast<uint32_t>(common_param_.seed)),
+    outage_(common_param_.outage_mean_interval, common_param_.outage_duration)
+  {
+    // Subscriber
+    sub_input_ = create_subscription<MessageT>(
+      "input", rclcpp::SensorDataQoS().keep_last(1),
+      std::bind(&DegradationNodeBase::on_input, this, std::placeholders::_1));
+
+    // Publisher
+    pub_output_ = create_publisher<MessageT>("output", rclcpp::SensorDataQoS().keep_last(1));
+
+    // Timer
+    if (common_param_.latency > 0.0 || common_param_.latency_jitter > 0.0) {
+      const auto period_ns = rclcpp::Rate(1000.0).period();
+      timer_ = rclcpp::create_timer(
+        this, get_clock(), period_ns, std::bind(&DegradationNodeBase::on_timer, this));
+    }
+  }
+
+protected:
+  // adds the noise to the message, dt is the time since the previous message in seconds
+  virtual void degrade(MessageT & msg, double dt) = 0;
+
+  NoiseGenerator & generator() { return generator_; }
+
+private:
+  CommonParam declare_common_param()
+  {
+    CommonParam p;
+    p.seed = declare_parameter<int>("seed");
+    p.drop_probability = declare_parameter<double>("drop_probability");
+    p.outage_mean_interval = declare_parameter<double>("outage.mean_interval");
+    p.outage_duration = declare_parameter<double>("outage.duration");
+    p.latency = declare_parameter<double>("latency.mean");
+    p.latency_jitter = declare_parameter<double>("latency.jitter");
+    return p;
+  }
+
+  void on_input(typename MessageT::ConstSharedPtr msg)
+  {
+    const double now = this->now().seconds();
+    const double dt = last_input_time_ ? now - *last_input_time_ : 0.0;
+    last_input_time_ = now;
+
+    if (outage_.update(generator_, now) || generator_.bernoulli(common_param_.drop_probability)) {
+      return;
+    }
+
+    // the header is kept, so the consumers see the original stamp after the latency
+    auto output = std::make_unique<MessageT>(*msg);
+    degrade(*output, dt);
+
+    if (!timer_) {
+      pub_output_->publish(std::move(output));
+      return;
+    }
+    const double latency = std::max(
+      0.0, common_param_.latency + generator_.gaussian(common_param_.latency_jitter));
+    delay_queue_.push(now + latency, std::move(output));
+  }
+
+  void on_timer()
+  {
+    for (auto & output : delay_queue_.pop_ready(this->now().seconds())) {
+      pub_output_->publish(std::move(output));
+    }
+  }
+
+  // Parameter
+  CommonParam common_param_;
+
+  // Subscriber
+  typename rclcpp::Subscription<MessageT>::SharedPtr sub_input_;
+
+  // Publisher
+  typename rclcpp::Publisher<MessageT>::SharedPtr pub_output_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  // State
+  NoiseGenerator generator_;
+  OutageModel outage_;
+  DelayQueue<std::unique_ptr<MessageT>> delay_queue_;
+  std::optional<double> last_input_time_;
+};
+}  // namespace autoware::sensor_degradation_simulator
+
+#endif  // DEGRADATION_NODE_BASE_HPP_
@@ -0,0 +1,77 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "gnss_degradation_node.hpp"
+
+#include <cmath>
+
+namespace autoware::sensor_degradation_simulator
+{
+namespace
+{
+// the radius of WGS84, which is accurate enough to convert small errors into degrees
+constexpr double earth_radius = 6378137.0;
+
+double to_degrees(const double radians)
+{
+  return radians * 180.0 / M_PI;
+}
+}  // namespace
+
+GnssDegradation::GnssDegradation(const rclcpp::NodeOptions & node_options)
+: DegradationNodeBase("gnss_degradation", node_options)
+{
+  // Parameter
+  node_param_.horizontal_stddev = declare_parameter<double>("horizontal_stddev");
+  node_param_.vertical_stddev = declare_parameter<double>("vertical_stddev");
+  node_param_.horizontal_bias_drift = declare_parameter<double>("horizontal_bias_drift");
+  node_param_.vertical_bias_drift = declare_parameter<double>("vertical_bias_drift");
+
+  // State
+  bias_east_ = RandomWalk(0.0, node_param_.horizontal_bias_drift);
+  bias_north_ = RandomWalk(0.0, node_param_.horizontal_bias_drift);
+  bias_up_ = RandomWalk(0.0, node_param_.vertical_bias_drift);
+}
+
+void GnssDegradation::degrade(sensor_msgs::msg::NavSatFix & msg, const double dt)
+{
+  // no position to degrade without a fix
+  if (msg.status.status < sensor_msgs::msg::NavSatStatus::STATUS_FIX) {
+    return;
+  }
+
+  auto & g = generator();
+  const double east = bias_east_.step(g, dt) + g.gaussian(node_param_.horizontal_stddev);
+  const double north = bias_north_.step(g, dt) + g.gaussian(node_param_.horizontal_stddev);
+  const double up = bias_up_.step(g, dt) + g.gaussian(node_param_.vertical_stddev);
+
+  const double cos_latitude = std::cos(msg.latitude * M_PI / 180.0);
+  msg.latitude += to_degrees(north / earth_radius);
+  if (std::abs(cos_latitude) > 1e-6) {
+    msg.longitude += to_degrees(east / (earth_radius * cos_latitude));
+  }
+  msg.altitude += up;
+
+  // the covariance is in ENU and in square meters, so the variance of the noise is added as it is
+  if (msg.position_covariance_type != sensor_msgs::msg::NavSatFix::COVARIANCE_TYPE_UNKNOWN) {
+    const double horizontal_variance = std::pow(node_param_.horizontal_stddev, 2);
+    msg.position_covariance[0] += horizontal_variance;
+    msg.position_covariance[4] += horizontal_variance;
+    msg.position_covariance[8] += std::pow(node_param_.vertical_stddev, 2);
+  }
+}
+}  // namespace autoware::sensor_degradation_simulator
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::sensor_degradation_simulator::GnssDegradation)
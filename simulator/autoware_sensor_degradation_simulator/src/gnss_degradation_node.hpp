@@ -0,0 +1,50 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef GNSS_DEGRADATION_NODE_HPP_
+#define GNSS_DEGRADATION_NODE_HPP_
+
+#include "degradation_node_base.hpp"
+
+#include <sensor_msgs/msg/nav_sat_fix.hpp>
+
+namespace autoware::sensor_degradation_simulator
+{
+class GnssDegradation : public DegradationNodeBase<sensor_msgs::msg::NavSatFix>
+{
+public:
+  explicit GnssDegradation(const rclcpp::NodeOptions & node_options);
+
+private:
+  struct NodeParam
+  {
+    double horizontal_stddev{};
+    double vertical_stddev{};
+    double horizontal_bias_drift{};
+    double vertical_bias_drift{};
+  };
+
+  void degrade(sensor_msgs::msg::NavSatFix & msg, double dt) override;
+
+  // Parameter
+  NodeParam node_param_{};
+
+  // State
+  RandomWalk bias_east_{0.0, 0.0};
+  RandomWalk bias_north_{0.0, 0.0};
+  RandomWalk bias_up_{0.0, 0.0};
+};
+}  // namespace autoware::sensor_degradation_simulator
+
+#endif  // GNSS_DEGRADATION_NODE_HPP_
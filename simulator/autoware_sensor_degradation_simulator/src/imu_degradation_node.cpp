@@ -0,0 +1,73 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "imu_degradation_node.hpp"
+
+#include <cmath>
+
+namespace autoware::sensor_degradation_simulator
+{
+ImuDegradation::ImuDegradation(const rclcpp::NodeOptions & node_options)
+: DegradationNodeBase("imu_degradation", node_options)
+{
+  // Parameter
+  node_param_.angular_velocity_stddev = declare_parameter<double>("angular_velocity.stddev");
+  node_param_.angular_velocity_bias_drift =
+    declare_parameter<double>("angular_velocity.bias_drift");
+  node_param_.linear_acceleration_stddev = declare_parameter<double>("linear_acceleration.stddev");
+  node_param_.linear_acceleration_bias_drift =
+    declare_parameter<double>("linear_acceleration.bias_drift");
+
+  // State
+  for (auto & bias : angular_velocity_bias_) {
+    bias = RandomWalk(0.0, node_param_.angular_velocity_bias_drift);
+  }
+  for (auto & bias : linear_acceleration_bias_) {
+    bias = RandomWalk(0.0, node_param_.linear_acceleration_bias_drift);
+  }
+}
+
+void ImuDegradation::degrade(sensor_msgs::msg::Imu & msg, const double dt)
+{
+  add_noise(msg.angular_velocity, angular_velocity_bias_, node_param_.angular_velocity_stddev, dt);
+  add_noise(
+    msg.linear_acceleration, linear_acceleration_bias_, node_param_.linear_acceleration_stddev,
+    dt);
+
+  // the covariance is left unknown when the driver does not provide it
+  const auto add_variance = [](auto & covariance, const double stddev) {
+    if (covariance[0] < 0.0) {
+      return;
+    }
+    // the diagonal of the row-major 3x3 matrix
+    for (size_t i = 0; i < covariance.size(); i += 4) {
+      covariance[i] += std::pow(stddev, 2);
+    }
+  };
+  add_variance(msg.angular_velocity_covariance, node_param_.angular_velocity_stddev);
+  add_variance(msg.linear_acceleration_covariance, node_param_.linear_acceleration_stddev);
+}
+
+void ImuDegradation::add_noise(
+  geometry_msgs::msg::Vector3 & v, AxisBias & bias, const double stddev, const double dt)
+{
+  auto & g = generator();
+  v.x += bias[0].step(g, dt) + g.gaussian(stddev);
+  v.y += bias[1].step(g, dt) + g.gaussian(stddev);
+  v.z += bias[2].step(g, dt) + g.gaussian(stddev);
+}
+}  // namespace autoware::sensor_degradation_simulator
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::sensor_degradation_simulator::ImuDegradation)
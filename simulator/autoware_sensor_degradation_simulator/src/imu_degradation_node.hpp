@@ -0,0 +1,55 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef IMU_DEGRADATION_NODE_HPP_
+#define IMU_DEGRADATION_NODE_HPP_
+
+#include "degradation_node_base.hpp"
+
+#include <geometry_msgs/msg/vector3.hpp>
+#include <sensor_msgs/msg/imu.hpp>
+
+#include <array>
+
+namespace autoware::sensor_degradation_simulator
+{
+class ImuDegradation : public DegradationNodeBase<sensor_msgs::msg::Imu>
+{
+public:
+  explicit ImuDegradation(const rclcpp::NodeOptions & node_options);
+
+private:
+  struct NodeParam
+  {
+    double angular_velocity_stddev{};
+    double angular_velocity_bias_drift{};
+    double linear_acceleration_stddev{};
+    double linear_acceleration_bias_drift{};
+  };
+
+  using AxisBias = std::array<RandomWalk, 3>;
+
+  void degrade(sensor_msgs::msg::Imu & msg, double dt) override;
+  void add_noise(geometry_msgs::msg::Vector3 & v, AxisBias & bias, double stddev, double dt);
+
+  // Parameter
+  NodeParam node_param_{};
+
+  // State
+  AxisBias angular_velocity_bias_{RandomWalk{0.0, 0.0}, {0.0, 0.0}, {0.0, 0.0}};
+  AxisBias linear_acceleration_bias_{RandomWalk{0.0, 0.0}, {0.0, 0.0}, {0.0, 0.0}};
+};
+}  // namespace autoware::sensor_degradation_simulator
+
+#endif  // IMU_DEGRADATION_NODE_HPP_
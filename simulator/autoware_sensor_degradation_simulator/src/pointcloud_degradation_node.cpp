@@ -0,0 +1,86 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "pointcloud_degradation_node.hpp"
+
+#include <sensor_msgs/point_cloud2_iterator.hpp>
+
+#include <cstddef>
+#include <utility>
+#include <vector>
+
+namespace autoware::sensor_degradation_simulator
+{
+PointCloudDegradation::PointCloudDegradation(const rclcpp::NodeOptions & node_options)
+: DegradationNodeBase("pointcloud_degradation", node_options)
+{
+  // Parameter
+  node_param_.point_drop_ratio = declare_parameter<double>("point_drop_ratio");
+  node_param_.position_stddev = declare_parameter<double>("position_stddev");
+}
+
+void PointCloudDegradation::degrade(sensor_msgs::msg::PointCloud2 & msg, [[maybe_unused]] double dt)
+{
+  // a malformed cloud is passed through unchanged since its points cannot be located
+  const size_t row_size = static_cast<size_t>(msg.width) * msg.point_step;
+  if (
+    msg.point_step == 0 || msg.row_step < row_size ||
+    msg.data.size() < static_cast<size_t>(msg.row_step) * msg.height) {
+    return;
+  }
+
+  auto & g = generator();
+
+  // the points are repacked without the padding at the end of the rows, which the iterators below
+  // would otherwise visit as points
+  const bool is_dropped = node_param_.point_drop_ratio > 0.0;
+  if (is_dropped || msg.row_step != row_size) {
+    std::vector<uint8_t> data;
+    data.reserve(row_size * msg.height);
+    for (size_t row = 0; row < msg.height; ++row) {
+      for (size_t col = 0; col < msg.width; ++col) {
+        if (is_dropped && g.bernoulli(node_param_.point_drop_ratio)) {
+          continue;
+        }
+        const auto begin =
+          msg.data.begin() + static_cast<std::ptrdiff_t>(row * msg.row_step + col * msg.point_step);
+        data.insert(data.end(), begin, begin + msg.point_step);
+      }
+    }
+    msg.data = std::move(data);
+    // the dropped points leave holes in the rows, so the output is then an unorganized cloud
+    if (is_dropped) {
+      msg.height = 1;
+      msg.width = static_cast<uint32_t>(msg.data.size() / msg.point_step);
+    }
+    msg.row_step = msg.width * msg.point_step;
+  }
+
+  if (node_param_.position_stddev <= 0.0 || msg.data.empty()) {
+    return;
+  }
+
+  sensor_msgs::PointCloud2Iterator<float> iter_x(msg, "x");
+  sensor_msgs::PointCloud2Iterator<float> iter_y(msg, "y");
+  sensor_msgs::PointCloud2Iterator<float> iter_z(msg, "z");
+  for (; iter_x != iter_x.end(); ++iter_x, ++iter_y, ++iter_z) {
+    *iter_x += static_cast<float>(g.gaussian(node_param_.position_stddev));
+    *iter_y += static_cast<float>(g.gaussian(node_param_.position_stddev));
+    *iter_z += static_cast<float>(g.gaussian(node_param_.position_stddev));
+  }
+}
+}  // namespace autoware::sensor_degradation_simulator
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::sensor_degradation_simulator::PointCloudDegradation)
@@ -0,0 +1,43 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef POINTCLOUD_DEGRADATION_NODE_HPP_
+#define POINTCLOUD_DEGRADATION_NODE_HPP_
+
+#include "degradation_node_base.hpp"
+
+#include <sensor_msgs/msg/point_cloud2.hpp>
+
+namespace autoware::sensor_degradation_simulator
+{
+class PointCloudDegradation : public DegradationNodeBase<sensor_msgs::msg::PointCloud2>
+{
+public:
+  explicit PointCloudDegradation(const rclcpp::NodeOptions & node_options);
+
+private:
+  struct NodeParam
+  {
+    double point_drop_ratio{};
+    double position_stddev{};
+  };
+
+  void degrade(sensor_msgs::msg::PointCloud2 & msg, double dt) override;
+
+  // Parameter
+  NodeParam node_param_{};
+};
+}  // namespace autoware::sensor_degradation_simulator
+
+#endif  // POINTCLOUD_DEGRADATION_NODE_HPP_
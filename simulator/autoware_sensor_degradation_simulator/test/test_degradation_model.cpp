@@ -0,0 +1,107 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "degradation_model.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+#include <vector>
+
+using autoware::sensor_degradation_simulator::DelayQueue;
+using autoware::sensor_degradation_simulator::NoiseGenerator;
+using autoware::sensor_degradation_simulator::OutageModel;
+using autoware::sensor_degradation_simulator::RandomWalk;
+
+TEST(NoiseGenerator, Reproducible)
+{
+  NoiseGenerator a(42);
+  NoiseGenerator b(42);
+  for (int i = 0; i < 10; ++i) {
+    const double x = a.gaussian(1.0);
+    const double y = b.gaussian(1.0);
+    EXPECT_DOUBLE_EQ(x, y);
+  }
+}
+
+TEST(NoiseGenerator, Degenerate)
+{
+  NoiseGenerator g(0);
+  EXPECT_DOUBLE_EQ(g.gaussian(0.0), 0.0);
+  EXPECT_FALSE(g.bernoulli(0.0));
+  EXPECT_TRUE(g.bernoulli(1.0));
+}
+
+TEST(NoiseGenerator, GaussianStddev)
+{
+  NoiseGenerator g(1);
+  constexpr int n = 10000;
+  double sum = 0.0;
+  double sum_sq = 0.0;
+  for (int i = 0; i < n; ++i) {
+    const double x = g.gaussian(2.0);
+    sum += x;
+    sum_sq += x * x;
+  }
+  const double mean = sum / n;
+  EXPECT_NEAR(mean, 0.0, 0.1);
+  EXPECT_NEAR(std::sqrt(sum_sq / n - mean * mean), 2.0, 0.1);
+}
+
+TEST(RandomWalk, Drift)
+{
+  NoiseGenerator g(0);
+  RandomWalk still(1.0, 0.0);
+  EXPECT_DOUBLE_EQ(still.step(g, 1.0), 1.0);
+
+  RandomWalk walk(0.0, 1.0);
+  EXPECT_DOUBLE_EQ(walk.step(g, 0.0), 0.0);
+  const double value = walk.step(g, 1.0);
+  EXPECT_NE(value, 0.0);
+  // a negative time step keeps the value
+  EXPECT_DOUBLE_EQ(walk.step(g, -1.0), value);
+}
+
+TEST(OutageModel, Disabled)
+{
+  NoiseGenerator g(0);
+  OutageModel outage(0.0, 1.0);
+  for (int i = 0; i < 100; ++i) {
+    EXPECT_FALSE(outage.update(g, i * 0.1));
+  }
+}
+
+TEST(OutageModel, Duration)
+{
+  NoiseGenerator g(0);
+  // an outage starts almost surely in the first step
+  OutageModel outage(1e-6, 1.0);
+  EXPECT_FALSE(outage.update(g, 0.0));
+  EXPECT_TRUE(outage.update(g, 0.1));
+  EXPECT_TRUE(outage.update(g, 1.0));
+}
+
+TEST(DelayQueue, Order)
+{
+  DelayQueue<int> queue;
+  queue.push(1.0, 1);
+  queue.push(0.5, 2);  // released after the earlier message
+  queue.push(2.0, 3);
+
+  EXPECT_TRUE(queue.pop_ready(0.9).empty());
+  EXPECT_EQ(queue.pop_ready(1.0), (std::vector<int>{1, 2}));
+  EXPECT_EQ(queue.size(), 1u);
+  EXPECT_EQ(queue.pop_ready(3.0), (std::vector<int>{3}));
+  EXPECT_EQ(queue.size(), 0u);
+}
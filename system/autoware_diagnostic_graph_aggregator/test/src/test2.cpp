@@ -149,4 +149,15 @@ INSTANTIATE_TEST_SUITE_P(WarnToError, GraphTest,
   )
 );
 
+// inputs are scan_matching_status, dead_reckoning and sensor_fusion_status of the localization,
+// and the second case is the dead reckoning within max_duration, e.g. 2 s to 10 s without NDT
+INSTANTIATE_TEST_SUITE_P(DeadReckoning, GraphTest,
+  testing::Values(
+    GraphTestParam{"test2/dead-reckoning.yaml", {OK,    OK,    OK   }, OK   },
+    GraphTestParam{"test2/dead-reckoning.yaml", {ERROR, WARN,  WARN }, WARN },
+    GraphTestParam{"test2/dead-reckoning.yaml", {ERROR, ERROR, WARN }, ERROR},
+    GraphTestParam{"test2/dead-reckoning.yaml", {ERROR, STALE, ERROR}, ERROR}
+  )
+);
+
 // clang-format on
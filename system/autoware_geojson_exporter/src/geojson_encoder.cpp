@@ -0,0 +1,138 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "geojson_encoder.hpp"
+
+#include <autoware/object_recognition_utils/object_classification.hpp>
+#include <autoware/universe_utils/geometry/boost_polygon_utils.hpp>
+#include <autoware/universe_utils/ros/uuid_helper.hpp>
+#include <nlohmann/json.hpp>
+
+#include <tf2/utils.h>
+
+#include <cmath>
+#include <string>
+
+namespace autoware::geojson_exporter
+{
+namespace
+{
+double to_seconds(const builtin_interfaces::msg::Time & stamp)
+{
+  return static_cast<double>(stamp.sec) + static_cast<double>(stamp.nanosec) * 1e-9;
+}
+
+// the position of GeoJSON is in the order of longitude, latitude and altitude
+nlohmann::json to_position(const geometry_msgs::msg::Point & point, const Projector & projector)
+{
+  const auto gps_point = projector(point);
+  return nlohmann::json::array({gps_point.longitude, gps_point.latitude, gps_point.altitude});
+}
+
+nlohmann::json create_feature_collection(
+  const std::string & kind, const builtin_interfaces::msg::Time & stamp)
+{
+  nlohmann::json j;
+  j["type"] = "FeatureCollection";
+  j["kind"] = kind;
+  j["stamp"] = to_seconds(stamp);
+  j["features"] = nlohmann::json::array();
+  return j;
+}
+
+nlohmann::json create_feature(const std::string & type, const nlohmann::json & coordinates)
+{
+  nlohmann::json j;
+  j["type"] = "Feature";
+  j["geometry"] = {{"type", type}, {"coordinates", coordinates}};
+  j["properties"] = nlohmann::json::object();
+  return j;
+}
+}  // namespace
+
+double to_heading_deg(const double yaw)
+{
+  const double heading = std::fmod(90.0 - yaw * 180.0 / M_PI, 360.0);
+  return heading < 0.0 ? heading + 360.0 : heading;
+}
+
+std::string encode_ego_pose(const Odometry & odometry, const Projector & projector)
+{
+  auto j = create_feature_collection("ego_pose", odometry.header.stamp);
+  const auto & pose = odometry.pose.pose;
+
+  auto feature = create_feature("Point", to_position(pose.position, projector));
+  feature["properties"]["heading"] = to_heading_deg(tf2::getYaw(pose.orientation));
+  feature["properties"]["velocity"] = odometry.twist.twist.linear.x;
+  j["features"].push_back(feature);
+  return j.dump();
+}
+
+std::string encode_trajectory(const Trajectory & trajectory, const Projector & projector)
+{
+  auto j = create_feature_collection("trajectory", trajectory.header.stamp);
+
+  // a LineString requires two or more positions
+  if (trajectory.points.size() < 2) {
+    return j.dump();
+  }
+
+  auto coordinates = nlohmann::json::array();
+  auto velocities = nlohmann::json::array();
+  for (const auto & point : trajectory.points) {
+    coordinates.push_back(to_position(point.pose.position, projector));
+    velocities.push_back(point.longitudinal_velocity_mps);
+  }
+
+  auto feature = create_feature("LineString", coordinates);
+  feature["properties"]["velocities"] = velocities;
+  j["features"].push_back(feature);
+  return j.dump();
+}
+
+std::string encode_objects(const PredictedObjects & objects, const Projector & projector)
+{
+  auto j = create_feature_collection("objects", objects.header.stamp);
+
+  for (const auto & object : objects.objects) {
+    const auto & pose = object.kinematics.initial_pose_with_covariance.pose;
+    const auto polygon = autoware::universe_utils::toPolygon2d(object);
+
+    // the exterior ring of RFC 7946 is closed and counterclockwise, while boost is clockwise
+    const auto & outer = polygon.outer();
+    auto ring = nlohmann::json::array();
+    for (auto it = outer.rbegin(); it != outer.rend(); ++it) {
+      geometry_msgs::msg::Point point;
+      point.x = it->x();
+      point.y = it->y();
+      point.z = pose.position.z;
+      ring.push_back(to_position(point, projector));
+    }
+    if (!ring.empty() && ring.front() != ring.back()) {
+      ring.push_back(ring.front());
+    }
+
+    auto feature = create_feature("Polygon", nlohmann::json::array({ring}));
+    auto & properties = feature["properties"];
+    properties["uuid"] = autoware::universe_utils::toHexString(object.object_id);
+    properties["label"] =
+      autoware::object_recognition_utils::convertLabelToString(object.classification);
+    properties["existence_probability"] = object.existence_probability;
+    properties["heading"] = to_heading_deg(tf2::getYaw(pose.orientation));
+    properties["velocity"] = object.kinematics.initial_twist_with_covariance.twist.linear.x;
+    j["features"].push_back(feature);
+  }
+  return j.dump();
+}
+}  // namespace autoware::geojson_exporter
@@ -0,0 +1,45 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef GEOJSON_ENCODER_HPP_
+#define GEOJSON_ENCODER_HPP_
+
+#include <autoware_perception_msgs/msg/predicted_objects.hpp>
+#include <autoware_planning_msgs/msg/trajectory.hpp>
+#include <geographic_msgs/msg/geo_point.hpp>
+#include <geometry_msgs/msg/point.hpp>
+#include <nav_msgs/msg/odometry.hpp>
+
+#include <functional>
+#include <string>
+
+namespace autoware::geojson_exporter
+{
+using autoware_perception_msgs::msg::PredictedObjects;
+using autoware_planning_msgs::msg::Trajectory;
+using nav_msgs::msg::Odometry;
+
+// converts a point in the map frame into WGS84
+using Projector = std::function<geographic_msgs::msg::GeoPoint(const geometry_msgs::msg::Point &)>;
+
+// Every function returns a FeatureCollection of RFC 7946 with the "kind" and "stamp" members.
+std::string encode_ego_pose(const Odometry & odometry, const Projector & projector);
+std::string encode_trajectory(const Trajectory & trajectory, const Projector & projector);
+std::string encode_objects(const PredictedObjects & objects, const Projector & projector);
+
+// yaw is counterclockwise from the east (ENU), heading is clockwise from the north in [0, 360)
+double to_heading_deg(double yaw);
+}  // namespace autoware::geojson_exporter
+
+#endif  // GEOJSON_ENCODER_HPP_
@@ -0,0 +1,106 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "geojson_exporter_node.hpp"
+
+#include <autoware/geography_utils/height.hpp>
+#include <autoware/geography_utils/projection.hpp>
+
+#include <functional>
+
+namespace autoware::geojson_exporter
+{
+GeoJsonExporter::GeoJsonExporter(const rclcpp::NodeOptions & options)
+: Node("geojson_exporter", options)
+{
+  // Parameter
+  const auto publish_rate = declare_parameter<double>("publish_rate");
+
+  // Subscriber
+  sub_odometry_ = create_subscription<Odometry>(
+    "input/odometry", rclcpp::QoS{1},
+    [this](const Odometry::ConstSharedPtr msg) { odometry_ = *msg; });
+  sub_trajectory_ = create_subscription<Trajectory>(
+    "input/trajectory", rclcpp::QoS{1},
+    [this](const Trajectory::ConstSharedPtr msg) { trajectory_ = *msg; });
+  sub_objects_ = create_subscription<PredictedObjects>(
+    "input/objects", rclcpp::QoS{1},
+    [this](const PredictedObjects::ConstSharedPtr msg) { objects_ = *msg; });
+  const auto adaptor = autoware::component_interface_utils::NodeAdaptor(this);
+  adaptor.init_sub(
+    sub_map_projector_info_,
+    [this](const MapProjectorInfo::Message::ConstSharedPtr msg) { projector_info_ = *msg; });
+
+  // Publisher
+  pub_ego_pose_ = create_publisher<String>("output/ego_pose", rclcpp::QoS{1});
+  pub_trajectory_ = create_publisher<String>("output/trajectory", rclcpp::QoS{1});
+  pub_objects_ = create_publisher<String>("output/objects", rclcpp::QoS{1});
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(publish_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&GeoJsonExporter::on_timer, this));
+}
+
+void GeoJsonExporter::on_timer()
+{
+  if (!projector_info_) {
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 5000, "map_projector_info is not received yet.");
+    return;
+  }
+  if (projector_info_->projector_type == MapProjectorInfo::Message::LOCAL) {
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 5000, "GeoJSON cannot be generated with the local projector.");
+    return;
+  }
+
+  const Projector projector = [this](const geometry_msgs::msg::Point & point) {
+    return project(point);
+  };
+
+  // only messages received since the last cycle are published
+  if (odometry_) {
+    String msg;
+    msg.data = encode_ego_pose(*odometry_, projector);
+    pub_ego_pose_->publish(msg);
+    odometry_.reset();
+  }
+  if (trajectory_) {
+    String msg;
+    msg.data = encode_trajectory(*trajectory_, projector);
+    pub_trajectory_->publish(msg);
+    trajectory_.reset();
+  }
+  if (objects_) {
+    String msg;
+    msg.data = encode_objects(*objects_, projector);
+    pub_objects_->publish(msg);
+    objects_.reset();
+  }
+}
+
+geographic_msgs::msg::GeoPoint GeoJsonExporter::project(
+  const geometry_msgs::msg::Point & point) const
+{
+  auto gps_point = autoware::geography_utils::project_reverse(point, *projector_info_);
+  gps_point.altitude = autoware::geography_utils::convert_height(
+    gps_point.altitude, gps_point.latitude, gps_point.longitude, projector_info_->vertical_datum,
+    MapProjectorInfo::Message::WGS84);
+  return gps_point;
+}
+}  // namespace autoware::geojson_exporter
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::geojson_exporter::GeoJsonExporter)
@@ -0,0 +1,66 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef GEOJSON_EXPORTER_NODE_HPP_
+#define GEOJSON_EXPORTER_NODE_HPP_
+
+#include "geojson_encoder.hpp"
+
+#include <autoware/component_interface_specs_universe/map.hpp>
+#include <autoware/component_interface_utils/rclcpp.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <std_msgs/msg/string.hpp>
+
+#include <optional>
+
+namespace autoware::geojson_exporter
+{
+using MapProjectorInfo = autoware::component_interface_specs_universe::map::MapProjectorInfo;
+using std_msgs::msg::String;
+
+class GeoJsonExporter : public rclcpp::Node
+{
+public:
+  explicit GeoJsonExporter(const rclcpp::NodeOptions & options);
+
+private:
+  // Subscriber
+  rclcpp::Subscription<Odometry>::SharedPtr sub_odometry_;
+  rclcpp::Subscription<Trajectory>::SharedPtr sub_trajectory_;
+  rclcpp::Subscription<PredictedObjects>::SharedPtr sub_objects_;
+  autoware::component_interface_utils::Subscription<MapProjectorInfo>::SharedPtr
+    sub_map_projector_info_;
+
+  // Publisher
+  rclcpp::Publisher<String>::SharedPtr pub_ego_pose_;
+  rclcpp::Publisher<String>::SharedPtr pub_trajectory_;
+  rclcpp::Publisher<String>::SharedPtr pub_objects_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  // State
+  std::optional<MapProjectorInfo::Message> projector_info_;
+  std::optional<Odometry> odometry_;
+  std::optional<Trajectory> trajectory_;
+  std::optional<PredictedObjects> objects_;
+
+  void on_timer();
+  [[nodiscard]] geographic_msgs::msg::GeoPoint project(
+    const geometry_msgs::msg::Point & point) const;
+};
+}  // namespace autoware::geojson_exporter
+
+#endif  // GEOJSON_EXPORTER_NODE_HPP_
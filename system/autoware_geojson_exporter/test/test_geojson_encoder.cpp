@@ -0,0 +1,158 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "geojson_encoder.hpp"
+
+#include <autoware/universe_utils/geometry/geometry.hpp>
+#include <nlohmann/json.hpp>
+
+#include <autoware_perception_msgs/msg/object_classification.hpp>
+#include <autoware_perception_msgs/msg/shape.hpp>
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+
+using autoware::geojson_exporter::Odometry;
+using autoware::geojson_exporter::PredictedObjects;
+using autoware::geojson_exporter::Projector;
+using autoware::geojson_exporter::Trajectory;
+
+namespace
+{
+// 1 m of the map is 1e-5 degree, which is enough to check the order of the coordinates
+geographic_msgs::msg::GeoPoint project(const geometry_msgs::msg::Point & point)
+{
+  geographic_msgs::msg::GeoPoint gps_point;
+  gps_point.latitude = 35.0 + point.y * 1e-5;
+  gps_point.longitude = 139.0 + point.x * 1e-5;
+  gps_point.altitude = point.z;
+  return gps_point;
+}
+
+const Projector projector = project;
+}  // namespace
+
+TEST(GeoJsonEncoder, HeadingDegree)
+{
+  using autoware::geojson_exporter::to_heading_deg;
+  EXPECT_NEAR(to_heading_deg(0.0), 90.0, 1e-9);
+  EXPECT_NEAR(to_heading_deg(M_PI / 2.0), 0.0, 1e-9);
+  EXPECT_NEAR(to_heading_deg(M_PI), 270.0, 1e-9);
+  EXPECT_NEAR(to_heading_deg(-M_PI / 2.0), 180.0, 1e-9);
+}
+
+TEST(GeoJsonEncoder, EncodeEgoPose)
+{
+  Odometry odometry;
+  odometry.header.stamp.sec = 10;
+  odometry.header.stamp.nanosec = 500000000;
+  odometry.pose.pose.position.x = 100.0;
+  odometry.pose.pose.position.y = 200.0;
+  odometry.pose.pose.position.z = 3.0;
+  odometry.pose.pose.orientation = autoware::universe_utils::createQuaternionFromYaw(M_PI / 2.0);
+  odometry.twist.twist.linear.x = 5.0;
+
+  const auto j =
+    nlohmann::json::parse(autoware::geojson_exporter::encode_ego_pose(odometry, projector));
+  EXPECT_EQ(j["type"], "FeatureCollection");
+  EXPECT_EQ(j["kind"], "ego_pose");
+  EXPECT_DOUBLE_EQ(j["stamp"].get<double>(), 10.5);
+  ASSERT_EQ(j["features"].size(), 1u);
+
+  const auto & feature = j["features"][0];
+  EXPECT_EQ(feature["type"], "Feature");
+  EXPECT_EQ(feature["geometry"]["type"], "Point");
+  const auto & coordinates = feature["geometry"]["coordinates"];
+  EXPECT_NEAR(coordinates[0].get<double>(), 139.001, 1e-9);
+  EXPECT_NEAR(coordinates[1].get<double>(), 35.002, 1e-9);
+  EXPECT_DOUBLE_EQ(coordinates[2].get<double>(), 3.0);
+  EXPECT_NEAR(feature["properties"]["heading"].get<double>(), 0.0, 1e-9);
+  EXPECT_DOUBLE_EQ(feature["properties"]["velocity"].get<double>(), 5.0);
+}
+
+TEST(GeoJsonEncoder, EncodeTrajectory)
+{
+  Trajectory trajectory;
+  for (int i = 0; i < 3; ++i) {
+    autoware_planning_msgs::msg::TrajectoryPoint point;
+    point.pose.position.x = static_cast<double>(i);
+    point.longitudinal_velocity_mps = static_cast<float>(i) * 2.0f;
+    trajectory.points.push_back(point);
+  }
+
+  const auto j =
+    nlohmann::json::parse(autoware::geojson_exporter::encode_trajectory(trajectory, projector));
+  EXPECT_EQ(j["kind"], "trajectory");
+  ASSERT_EQ(j["features"].size(), 1u);
+
+  const auto & feature = j["features"][0];
+  EXPECT_EQ(feature["geometry"]["type"], "LineString");
+  ASSERT_EQ(feature["geometry"]["coordinates"].size(), 3u);
+  EXPECT_NEAR(feature["geometry"]["coordinates"][2][0].get<double>(), 139.00002, 1e-9);
+  ASSERT_EQ(feature["properties"]["velocities"].size(), 3u);
+  EXPECT_DOUBLE_EQ(feature["properties"]["velocities"][2].get<double>(), 4.0);
+}
+
+TEST(GeoJsonEncoder, EncodeTrajectoryWithSinglePoint)
+{
+  Trajectory trajectory;
+  trajectory.points.emplace_back();
+
+  const auto j =
+    nlohmann::json::parse(autoware::geojson_exporter::encode_trajectory(trajectory, projector));
+  EXPECT_TRUE(j["features"].empty());
+}
+
+TEST(GeoJsonEncoder, EncodeObjects)
+{
+  autoware_perception_msgs::msg::PredictedObject object;
+  object.object_id.uuid.fill(0);
+  object.object_id.uuid[15] = 0xab;
+  object.existence_probability = 0.8f;
+  autoware_perception_msgs::msg::ObjectClassification classification;
+  classification.label = autoware_perception_msgs::msg::ObjectClassification::CAR;
+  classification.probability = 1.0f;
+  object.classification.push_back(classification);
+  object.kinematics.initial_pose_with_covariance.pose.orientation.w = 1.0;
+  object.shape.type = autoware_perception_msgs::msg::Shape::BOUNDING_BOX;
+  object.shape.dimensions.x = 4.0;
+  object.shape.dimensions.y = 2.0;
+
+  PredictedObjects objects;
+  objects.objects.push_back(object);
+
+  const auto j =
+    nlohmann::json::parse(autoware::geojson_exporter::encode_objects(objects, projector));
+  EXPECT_EQ(j["kind"], "objects");
+  ASSERT_EQ(j["features"].size(), 1u);
+
+  const auto & feature = j["features"][0];
+  EXPECT_EQ(feature["geometry"]["type"], "Polygon");
+  const auto & ring = feature["geometry"]["coordinates"][0];
+  ASSERT_EQ(ring.size(), 5u);
+  EXPECT_EQ(ring.front(), ring.back());
+
+  // the exterior ring must be counterclockwise
+  double signed_area = 0.0;
+  for (size_t i = 0; i + 1 < ring.size(); ++i) {
+    signed_area += ring[i][0].get<double>() * ring[i + 1][1].get<double>() -
+                   ring[i + 1][0].get<double>() * ring[i][1].get<double>();
+  }
+  EXPECT_GT(signed_area, 0.0);
+
+  EXPECT_EQ(feature["properties"]["uuid"], "000000000000000000000000000000ab");
+  EXPECT_EQ(feature["properties"]["label"], "CAR");
+  EXPECT_NEAR(feature["properties"]["existence_probability"].get<double>(), 0.8, 1e-6);
+}
@@ -0,0 +1,116 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "mqtt_client.hpp"
+
+#include <mosquitto.h>
+
+#include <mutex>
+#include <string>
+
+namespace autoware::mqtt_telemetry_uplink
+{
+namespace
+{
+const char * to_c_str_or_null(const std::string & str)
+{
+  return str.empty() ? nullptr : str.c_str();
+}
+}  // namespace
+
+MqttClient::MqttClient(const MqttConfig & config) : config_(config)
+{
+  static std::once_flag init_flag;
+  std::call_once(init_flag, [] { mosquitto_lib_init(); });
+}
+
+MqttClient::~MqttClient()
+{
+  if (!mosq_) {
+    return;
+  }
+  mosquitto_disconnect(mosq_);
+  if (loop_started_) {
+    mosquitto_loop_stop(mosq_, false);
+  }
+  mosquitto_destroy(mosq_);
+}
+
+bool MqttClient::start(std::string & error_message)
+{
+  mosq_ = mosquitto_new(to_c_str_or_null(config_.client_id), true, this);
+  if (!mosq_) {
+    error_message = "failed to create mosquitto instance";
+    return false;
+  }
+
+  mosquitto_connect_callback_set(mosq_, &MqttClient::on_connect);
+  mosquitto_disconnect_callback_set(mosq_, &MqttClient::on_disconnect);
+  mosquitto_reconnect_delay_set(mosq_, 1, 30, true);
+
+  if (!config_.username.empty()) {
+    mosquitto_username_pw_set(
+      mosq_, config_.username.c_str(), to_c_str_or_null(config_.password));
+  }
+
+  if (config_.use_tls) {
+    const int rc = mosquitto_tls_set(
+      mosq_, to_c_str_or_null(config_.ca_file), nullptr, to_c_str_or_null(config_.cert_file),
+      to_c_str_or_null(config_.key_file), nullptr);
+    if (rc != MOSQ_ERR_SUCCESS) {
+      error_message = std::string("failed to configure TLS: ") + mosquitto_strerror(rc);
+      return false;
+    }
+  }
+
+  // connect_async does not block even if the broker is unreachable, the network loop retries
+  const int rc =
+    mosquitto_connect_async(mosq_, config_.host.c_str(), config_.port, config_.keepalive);
+  if (rc != MOSQ_ERR_SUCCESS && rc != MOSQ_ERR_ERRNO) {
+    error_message = std::string("failed to start connection: ") + mosquitto_strerror(rc);
+    return false;
+  }
+
+  const int loop_rc = mosquitto_loop_start(mosq_);
+  if (loop_rc != MOSQ_ERR_SUCCESS) {
+    error_message = std::string("failed to start network loop: ") + mosquitto_strerror(loop_rc);
+    return false;
+  }
+  loop_started_ = true;
+  return true;
+}
+
+bool MqttClient::publish(
+  const std::string & topic, const std::string & payload, const int qos, const bool retain)
+{
+  if (!mosq_ || !connected_.load()) {
+    return false;
+  }
+  const int rc = mosquitto_publish(
+    mosq_, nullptr, topic.c_str(), static_cast<int>(payload.size()), payload.data(), qos, retain);
+  return rc == MOSQ_ERR_SUCCESS;
+}
+
+void MqttClient::on_connect(mosquitto *, void * obj, const int reason_code)
+{
+  auto * self = static_cast<MqttClient *>(obj);
+  self->connected_.store(reason_code == 0);
+}
+
+void MqttClient::on_disconnect(mosquitto *, void * obj, const int)
+{
+  auto * self = static_cast<MqttClient *>(obj);
+  self->connected_.store(false);
+}
+}  // namespace autoware::mqtt_telemetry_uplink
@@ -0,0 +1,66 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef MQTT_CLIENT_HPP_
+#define MQTT_CLIENT_HPP_
+
+#include <atomic>
+#include <string>
+
+struct mosquitto;
+
+namespace autoware::mqtt_telemetry_uplink
+{
+struct MqttConfig
+{
+  std::string host;
+  int port;
+  std::string client_id;
+  std::string username;
+  std::string password;
+  int keepalive;
+  bool use_tls;
+  std::string ca_file;
+  std::string cert_file;
+  std::string key_file;
+};
+
+/**
+ * @brief thin wrapper of libmosquitto running its network loop in a background thread
+ */
+class MqttClient
+{
+public:
+  explicit MqttClient(const MqttConfig & config);
+  ~MqttClient();
+  MqttClient(const MqttClient &) = delete;
+  MqttClient & operator=(const MqttClient &) = delete;
+
+  // start connecting asynchronously, reconnection is handled by the network loop
+  bool start(std::string & error_message);
+  bool publish(const std::string & topic, const std::string & payload, int qos, bool retain);
+  [[nodiscard]] bool is_connected() const { return connected_.load(); }
+
+private:
+  static void on_connect(mosquitto * mosq, void * obj, int reason_code);
+  static void on_disconnect(mosquitto * mosq, void * obj, int reason_code);
+
+  MqttConfig config_;
+  mosquitto * mosq_{nullptr};
+  std::atomic<bool> connected_{false};
+  bool loop_started_{false};
+};
+}  // namespace autoware::mqtt_telemetry_uplink
+
+#endif  // MQTT_CLIENT_HPP_
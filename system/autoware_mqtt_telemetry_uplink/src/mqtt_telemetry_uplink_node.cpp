@@ -0,0 +1,159 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "mqtt_telemetry_uplink_node.hpp"
+
+#include <memory>
+#include <stdexcept>
+#include <string>
+#include <utility>
+
+namespace autoware::mqtt_telemetry_uplink
+{
+MqttTelemetryUplink::MqttTelemetryUplink(const rclcpp::NodeOptions & options)
+: Node("mqtt_telemetry_uplink", options),
+  offline_buffer_(static_cast<size_t>(declare_parameter<int>("offline_buffer_size")))
+{
+  // Parameter
+  node_param_.vehicle_id = declare_parameter<std::string>("vehicle_id");
+  node_param_.topic_prefix = declare_parameter<std::string>("topic_prefix");
+  node_param_.pose_topic = declare_parameter<std::string>("topics.pose");
+  node_param_.speed_topic = declare_parameter<std::string>("topics.speed");
+  node_param_.battery_topic = declare_parameter<std::string>("topics.battery");
+  node_param_.health_topic = declare_parameter<std::string>("topics.health");
+  node_param_.qos = static_cast<int>(declare_parameter<int>("qos"));
+  node_param_.retain = declare_parameter<bool>("retain");
+  node_param_.publish_rate = declare_parameter<double>("publish_rate");
+
+  if (node_param_.qos < 0 || node_param_.qos > 2) {
+    throw std::invalid_argument("qos must be 0, 1 or 2");
+  }
+
+  MqttConfig config;
+  config.host = declare_parameter<std::string>("broker.host");
+  config.port = static_cast<int>(declare_parameter<int>("broker.port"));
+  config.client_id = declare_parameter<std::string>("broker.client_id");
+  config.username = declare_parameter<std::string>("broker.username");
+  config.password = declare_parameter<std::string>("broker.password");
+  config.keepalive = static_cast<int>(declare_parameter<int>("broker.keepalive"));
+  config.use_tls = declare_parameter<bool>("tls.enable");
+  config.ca_file = declare_parameter<std::string>("tls.ca_file");
+  config.cert_file = declare_parameter<std::string>("tls.cert_file");
+  config.key_file = declare_parameter<std::string>("tls.key_file");
+
+  client_ = std::make_unique<MqttClient>(config);
+  std::string error_message;
+  if (!client_->start(error_message)) {
+    RCLCPP_ERROR(get_logger(), "MQTT client failed to start: %s", error_message.c_str());
+  }
+
+  // Subscriber
+  sub_odometry_ = create_subscription<Odometry>(
+    "input/odometry", rclcpp::QoS{1},
+    [this](const Odometry::ConstSharedPtr msg) { odometry_ = *msg; });
+  sub_battery_ = create_subscription<BatteryStatus>(
+    "input/battery", rclcpp::QoS{1},
+    [this](const BatteryStatus::ConstSharedPtr msg) { battery_ = *msg; });
+  sub_mrm_state_ = create_subscription<MrmState>(
+    "input/mrm_state", rclcpp::QoS{1},
+    [this](const MrmState::ConstSharedPtr msg) { mrm_state_ = *msg; });
+  sub_operation_mode_ = create_subscription<OperationModeState>(
+    "input/operation_mode", rclcpp::QoS{1}.transient_local(),
+    [this](const OperationModeState::ConstSharedPtr msg) { operation_mode_ = *msg; });
+
+  diagnostics_ =
+    std::make_unique<autoware::universe_utils::DiagnosticsInterface>(this, "mqtt_connection");
+
+  const auto period_ns = rclcpp::Rate(node_param_.publish_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&MqttTelemetryUplink::on_timer, this));
+}
+
+void MqttTelemetryUplink::on_timer()
+{
+  // only messages received since the last cycle are sent to avoid stale duplicates on the broker
+  if (odometry_) {
+    enqueue(node_param_.pose_topic, encode_pose(*odometry_, node_param_.vehicle_id));
+    enqueue(node_param_.speed_topic, encode_speed(*odometry_, node_param_.vehicle_id));
+    odometry_.reset();
+  }
+  if (battery_) {
+    enqueue(node_param_.battery_topic, encode_battery(*battery_, node_param_.vehicle_id));
+    battery_.reset();
+  }
+  if (mrm_state_) {
+    enqueue(
+      node_param_.health_topic,
+      encode_health(*mrm_state_, operation_mode_, node_param_.vehicle_id));
+    mrm_state_.reset();
+  }
+
+  flush();
+  publish_diagnostics();
+}
+
+void MqttTelemetryUplink::enqueue(const std::string & topic, const std::string & payload)
+{
+  if (topic.empty()) {
+    return;
+  }
+  offline_buffer_.push(PendingMessage{to_mqtt_topic(topic), payload});
+}
+
+void MqttTelemetryUplink::flush()
+{
+  if (!client_->is_connected()) {
+    return;
+  }
+  while (auto message = offline_buffer_.pop()) {
+    if (!client_->publish(message->topic, message->payload, node_param_.qos, node_param_.retain)) {
+      // keep the order; the remaining messages are sent after reconnection
+      offline_buffer_.push_front(std::move(*message));
+      break;
+    }
+  }
+}
+
+void MqttTelemetryUplink::publish_diagnostics()
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  diagnostics_->clear();
+  diagnostics_->add_key_value("connected", client_->is_connected());
+  diagnostics_->add_key_value("buffered_messages", offline_buffer_.size());
+  diagnostics_->add_key_value("dropped_messages", offline_buffer_.dropped_count());
+
+  if (!client_->is_connected()) {
+    diagnostics_->update_level_and_message(
+      DiagnosticStatus::WARN, "not connected to the MQTT broker, buffering telemetry");
+  } else {
+    diagnostics_->update_level_and_message(DiagnosticStatus::OK, "OK");
+  }
+  diagnostics_->publish(now());
+}
+
+std::string MqttTelemetryUplink::to_mqtt_topic(const std::string & suffix) const
+{
+  if (node_param_.topic_prefix.empty()) {
+    return suffix;
+  }
+  if (node_param_.topic_prefix.back() == '/') {
+    return node_param_.topic_prefix + suffix;
+  }
+  return node_param_.topic_prefix + "/" + suffix;
+}
+}  // namespace autoware::mqtt_telemetry_uplink
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::mqtt_telemetry_uplink::MqttTelemetryUplink)
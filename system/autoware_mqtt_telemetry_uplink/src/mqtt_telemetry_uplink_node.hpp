@@ -0,0 +1,89 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef MQTT_TELEMETRY_UPLINK_NODE_HPP_
+#define MQTT_TELEMETRY_UPLINK_NODE_HPP_
+
+#include "mqtt_client.hpp"
+#include "offline_buffer.hpp"
+#include "telemetry_encoder.hpp"
+
+#include <autoware/universe_utils/ros/diagnostics_interface.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <memory>
+#include <optional>
+#include <string>
+
+namespace autoware::mqtt_telemetry_uplink
+{
+struct PendingMessage
+{
+  std::string topic;
+  std::string payload;
+};
+
+struct NodeParam
+{
+  std::string vehicle_id;
+  std::string topic_prefix;
+  std::string pose_topic;
+  std::string speed_topic;
+  std::string battery_topic;
+  std::string health_topic;
+  int qos;
+  bool retain;
+  double publish_rate;
+};
+
+class MqttTelemetryUplink : public rclcpp::Node
+{
+public:
+  explicit MqttTelemetryUplink(const rclcpp::NodeOptions & options);
+
+private:
+  // Parameter
+  NodeParam node_param_;
+
+  // Subscriber
+  rclcpp::Subscription<Odometry>::SharedPtr sub_odometry_;
+  rclcpp::Subscription<BatteryStatus>::SharedPtr sub_battery_;
+  rclcpp::Subscription<MrmState>::SharedPtr sub_mrm_state_;
+  rclcpp::Subscription<OperationModeState>::SharedPtr sub_operation_mode_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  // Diagnostics
+  std::unique_ptr<autoware::universe_utils::DiagnosticsInterface> diagnostics_;
+
+  // MQTT
+  std::unique_ptr<MqttClient> client_;
+  OfflineBuffer<PendingMessage> offline_buffer_;
+
+  // State
+  std::optional<Odometry> odometry_;
+  std::optional<BatteryStatus> battery_;
+  std::optional<MrmState> mrm_state_;
+  OperationModeState operation_mode_;
+
+  void on_timer();
+  void enqueue(const std::string & topic, const std::string & payload);
+  void flush();
+  void publish_diagnostics();
+  [[nodiscard]] std::string to_mqtt_topic(const std::string & suffix) const;
+};
+}  // namespace autoware::mqtt_telemetry_uplink
+
+#endif  // MQTT_TELEMETRY_UPLINK_NODE_HPP_
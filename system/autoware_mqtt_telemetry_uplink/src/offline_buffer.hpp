@@ -0,0 +1,83 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef OFFLINE_BUFFER_HPP_
+#define OFFLINE_BUFFER_HPP_
+
+#include <cstddef>
+#include <deque>
+#include <optional>
+#include <utility>
+
+namespace autoware::mqtt_telemetry_uplink
+{
+/**
+ * @brief bounded FIFO which keeps the newest elements and drops the oldest ones when full
+ */
+template <typename T>
+class OfflineBuffer
+{
+public:
+  explicit OfflineBuffer(const size_t capacity) : capacity_(capacity) {}
+
+  void push(T value)
+  {
+    if (capacity_ == 0) {
+      ++dropped_count_;
+      return;
+    }
+    if (buffer_.size() >= capacity_) {
+      buffer_.pop_front();
+      ++dropped_count_;
+    }
+    buffer_.push_back(std::move(value));
+  }
+
+  // return the element to the head of the queue, e.g. when sending it failed again
+  void push_front(T value)
+  {
+    if (capacity_ == 0) {
+      ++dropped_count_;
+      return;
+    }
+    if (buffer_.size() >= capacity_) {
+      // the element being returned is older than everything else, so it is the one to drop
+      ++dropped_count_;
+      return;
+    }
+    buffer_.push_front(std::move(value));
+  }
+
+  std::optional<T> pop()
+  {
+    if (buffer_.empty()) {
+      return std::nullopt;
+    }
+    T value = std::move(buffer_.front());
+    buffer_.pop_front();
+    return value;
+  }
+
+  [[nodiscard]] size_t size() const { return buffer_.size(); }
+  [[nodiscard]] bool empty() const { return buffer_.empty(); }
+  [[nodiscard]] size_t dropped_count() const { return dropped_count_; }
+
+private:
+  size_t capacity_;
+  size_t dropped_count_{0};
+  std::deque<T> buffer_;
+};
+}  // namespace autoware::mqtt_telemetry_uplink
+
+#endif  // OFFLINE_BUFFER_HPP_
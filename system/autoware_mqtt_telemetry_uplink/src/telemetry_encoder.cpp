@@ -0,0 +1,127 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "telemetry_encoder.hpp"
+
+#include <nlohmann/json.hpp>
+
+#include <tf2/utils.h>
+
+#include <string>
+
+namespace autoware::mqtt_telemetry_uplink
+{
+namespace
+{
+double to_seconds(const builtin_interfaces::msg::Time & stamp)
+{
+  return static_cast<double>(stamp.sec) + static_cast<double>(stamp.nanosec) * 1e-9;
+}
+
+nlohmann::json create_base(const builtin_interfaces::msg::Time & stamp, const std::string & id)
+{
+  nlohmann::json j;
+  j["vehicle_id"] = id;
+  j["stamp"] = to_seconds(stamp);
+  return j;
+}
+}  // namespace
+
+std::string encode_pose(const Odometry & odometry, const std::string & vehicle_id)
+{
+  auto j = create_base(odometry.header.stamp, vehicle_id);
+  const auto & pose = odometry.pose.pose;
+  j["frame_id"] = odometry.header.frame_id;
+  j["position"] = {{"x", pose.position.x}, {"y", pose.position.y}, {"z", pose.position.z}};
+  j["yaw"] = tf2::getYaw(pose.orientation);
+  return j.dump();
+}
+
+std::string encode_speed(const Odometry & odometry, const std::string & vehicle_id)
+{
+  auto j = create_base(odometry.header.stamp, vehicle_id);
+  const auto & twist = odometry.twist.twist;
+  j["longitudinal_velocity"] = twist.linear.x;
+  j["lateral_velocity"] = twist.linear.y;
+  j["yaw_rate"] = twist.angular.z;
+  return j.dump();
+}
+
+std::string encode_battery(const BatteryStatus & battery, const std::string & vehicle_id)
+{
+  auto j = create_base(battery.stamp, vehicle_id);
+  j["energy_level"] = battery.energy_level;
+  return j.dump();
+}
+
+std::string encode_health(
+  const MrmState & mrm_state, const OperationModeState & operation_mode,
+  const std::string & vehicle_id)
+{
+  auto j = create_base(mrm_state.stamp, vehicle_id);
+  j["mrm_state"] = to_string(mrm_state);
+  j["mrm_behavior"] = to_behavior_string(mrm_state);
+  j["operation_mode"] = to_string(operation_mode);
+  j["is_autoware_control_enabled"] = operation_mode.is_autoware_control_enabled;
+  return j.dump();
+}
+
+std::string to_string(const MrmState & mrm_state)
+{
+  switch (mrm_state.state) {
+    case MrmState::NORMAL:
+      return "NORMAL";
+    case MrmState::MRM_OPERATING:
+      return "MRM_OPERATING";
+    case MrmState::MRM_SUCCEEDED:
+      return "MRM_SUCCEEDED";
+    case MrmState::MRM_FAILED:
+      return "MRM_FAILED";
+    default:
+      return "UNKNOWN";
+  }
+}
+
+std::string to_behavior_string(const MrmState & mrm_state)
+{
+  switch (mrm_state.behavior) {
+    case MrmState::NONE:
+      return "NONE";
+    case MrmState::EMERGENCY_STOP:
+      return "EMERGENCY_STOP";
+    case MrmState::COMFORTABLE_STOP:
+      return "COMFORTABLE_STOP";
+    case MrmState::PULL_OVER:
+      return "PULL_OVER";
+    default:
+      return "UNKNOWN";
+  }
+}
+
+std::string to_string(const OperationModeState & operation_mode)
+{
+  switch (operation_mode.mode) {
+    case OperationModeState::STOP:
+      return "STOP";
+    case OperationModeState::AUTONOMOUS:
+      return "AUTONOMOUS";
+    case OperationModeState::LOCAL:
+      return "LOCAL";
+    case OperationModeState::REMOTE:
+      return "REMOTE";
+    default:
+      return "UNKNOWN";
+  }
+}
+}  // namespace autoware::mqtt_telemetry_uplink
@@ -0,0 +1,44 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef TELEMETRY_ENCODER_HPP_
+#define TELEMETRY_ENCODER_HPP_
+
+#include <autoware_adapi_v1_msgs/msg/mrm_state.hpp>
+#include <autoware_adapi_v1_msgs/msg/operation_mode_state.hpp>
+#include <nav_msgs/msg/odometry.hpp>
+#include <tier4_vehicle_msgs/msg/battery_status.hpp>
+
+#include <string>
+
+namespace autoware::mqtt_telemetry_uplink
+{
+using autoware_adapi_v1_msgs::msg::MrmState;
+using autoware_adapi_v1_msgs::msg::OperationModeState;
+using nav_msgs::msg::Odometry;
+using tier4_vehicle_msgs::msg::BatteryStatus;
+
+std::string encode_pose(const Odometry & odometry, const std::string & vehicle_id);
+std::string encode_speed(const Odometry & odometry, const std::string & vehicle_id);
+std::string encode_battery(const BatteryStatus & battery, const std::string & vehicle_id);
+std::string encode_health(
+  const MrmState & mrm_state, const OperationModeState & operation_mode,
+  const std::string & vehicle_id);
+
+std::string to_string(const MrmState & mrm_state);
+std::string to_behavior_string(const MrmState & mrm_state);
+std::string to_string(const OperationModeState & operation_mode);
+}  // namespace autoware::mqtt_telemetry_uplink
+
+#endif  // TELEMETRY_ENCODER_HPP_
@@ -0,0 +1,79 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "offline_buffer.hpp"
+
+#include <gtest/gtest.h>
+
+using autoware::mqtt_telemetry_uplink::OfflineBuffer;
+
+TEST(OfflineBuffer, KeepsOrder)
+{
+  OfflineBuffer<int> buffer(3);
+  buffer.push(1);
+  buffer.push(2);
+  buffer.push(3);
+
+  EXPECT_EQ(buffer.size(), 3u);
+  EXPECT_EQ(buffer.pop().value(), 1);
+  EXPECT_EQ(buffer.pop().value(), 2);
+  EXPECT_EQ(buffer.pop().value(), 3);
+  EXPECT_FALSE(buffer.pop().has_value());
+  EXPECT_EQ(buffer.dropped_count(), 0u);
+}
+
+TEST(OfflineBuffer, DropsOldestWhenFull)
+{
+  OfflineBuffer<int> buffer(2);
+  buffer.push(1);
+  buffer.push(2);
+  buffer.push(3);
+
+  EXPECT_EQ(buffer.size(), 2u);
+  EXPECT_EQ(buffer.dropped_count(), 1u);
+  EXPECT_EQ(buffer.pop().value(), 2);
+  EXPECT_EQ(buffer.pop().value(), 3);
+}
+
+TEST(OfflineBuffer, PushFrontReturnsElementToHead)
+{
+  OfflineBuffer<int> buffer(3);
+  buffer.push(1);
+  buffer.push(2);
+
+  const auto value = buffer.pop();
+  buffer.push_front(*value);
+  EXPECT_EQ(buffer.pop().value(), 1);
+  EXPECT_EQ(buffer.pop().value(), 2);
+}
+
+TEST(OfflineBuffer, PushFrontOnFullBufferDropsReturnedElement)
+{
+  OfflineBuffer<int> buffer(2);
+  buffer.push(1);
+  buffer.push(2);
+  buffer.push_front(0);
+
+  EXPECT_EQ(buffer.dropped_count(), 1u);
+  EXPECT_EQ(buffer.pop().value(), 1);
+}
+
+TEST(OfflineBuffer, ZeroCapacityDropsEverything)
+{
+  OfflineBuffer<int> buffer(0);
+  buffer.push(1);
+
+  EXPECT_TRUE(buffer.empty());
+  EXPECT_EQ(buffer.dropped_count(), 1u);
+}
@@ -0,0 +1,86 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "telemetry_encoder.hpp"
+
+#include <nlohmann/json.hpp>
+
+#include <gtest/gtest.h>
+
+using autoware::mqtt_telemetry_uplink::BatteryStatus;
+using autoware::mqtt_telemetry_uplink::MrmState;
+using autoware::mqtt_telemetry_uplink::Odometry;
+using autoware::mqtt_telemetry_uplink::OperationModeState;
+
+TEST(TelemetryEncoder, EncodePose)
+{
+  Odometry odometry;
+  odometry.header.stamp.sec = 10;
+  odometry.header.stamp.nanosec = 500000000;
+  odometry.header.frame_id = "map";
+  odometry.pose.pose.position.x = 1.0;
+  odometry.pose.pose.position.y = 2.0;
+  odometry.pose.pose.position.z = 3.0;
+  odometry.pose.pose.orientation.w = 1.0;
+
+  const auto j =
+    nlohmann::json::parse(autoware::mqtt_telemetry_uplink::encode_pose(odometry, "vehicle"));
+  EXPECT_EQ(j["vehicle_id"], "vehicle");
+  EXPECT_DOUBLE_EQ(j["stamp"].get<double>(), 10.5);
+  EXPECT_EQ(j["frame_id"], "map");
+  EXPECT_DOUBLE_EQ(j["position"]["x"].get<double>(), 1.0);
+  EXPECT_DOUBLE_EQ(j["position"]["y"].get<double>(), 2.0);
+  EXPECT_DOUBLE_EQ(j["position"]["z"].get<double>(), 3.0);
+  EXPECT_DOUBLE_EQ(j["yaw"].get<double>(), 0.0);
+}
+
+TEST(TelemetryEncoder, EncodeSpeed)
+{
+  Odometry odometry;
+  odometry.twist.twist.linear.x = 5.0;
+  odometry.twist.twist.angular.z = 0.1;
+
+  const auto j =
+    nlohmann::json::parse(autoware::mqtt_telemetry_uplink::encode_speed(odometry, "vehicle"));
+  EXPECT_DOUBLE_EQ(j["longitudinal_velocity"].get<double>(), 5.0);
+  EXPECT_DOUBLE_EQ(j["lateral_velocity"].get<double>(), 0.0);
+  EXPECT_DOUBLE_EQ(j["yaw_rate"].get<double>(), 0.1);
+}
+
+TEST(TelemetryEncoder, EncodeBattery)
+{
+  BatteryStatus battery;
+  battery.energy_level = 42.0;
+
+  const auto j =
+    nlohmann::json::parse(autoware::mqtt_telemetry_uplink::encode_battery(battery, "vehicle"));
+  EXPECT_FLOAT_EQ(j["energy_level"].get<float>(), 42.0f);
+}
+
+TEST(TelemetryEncoder, EncodeHealth)
+{
+  MrmState mrm_state;
+  mrm_state.state = MrmState::MRM_OPERATING;
+  mrm_state.behavior = MrmState::EMERGENCY_STOP;
+  OperationModeState operation_mode;
+  operation_mode.mode = OperationModeState::AUTONOMOUS;
+  operation_mode.is_autoware_control_enabled = true;
+
+  const auto j = nlohmann::json::parse(
+    autoware::mqtt_telemetry_uplink::encode_health(mrm_state, operation_mode, "vehicle"));
+  EXPECT_EQ(j["mrm_state"], "MRM_OPERATING");
+  EXPECT_EQ(j["mrm_behavior"], "EMERGENCY_STOP");
+  EXPECT_EQ(j["operation_mode"], "AUTONOMOUS");
+  EXPECT_TRUE(j["is_autoware_control_enabled"].get<bool>());
+}
@@ -0,0 +1,140 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "odd_definition.hpp"
+
+#include <boost/geometry/algorithms/correct.hpp>
+
+#include <nlohmann/json.hpp>
+
+#include <cstdio>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::odd_monitor
+{
+namespace
+{
+ZoneType to_zone_type(const std::string & type)
+{
+  if (type == "operational_area") {
+    return ZoneType::OPERATIONAL_AREA;
+  }
+  if (type == "speed_zone") {
+    return ZoneType::SPEED_ZONE;
+  }
+  if (type == "keep_out") {
+    return ZoneType::KEEP_OUT;
+  }
+  throw std::invalid_argument("unknown zone type: " + type);
+}
+
+Polygon2d to_polygon(const nlohmann::json & ring, const Projector & projector)
+{
+  Polygon2d polygon;
+  for (const auto & position : ring) {
+    if (!position.is_array() || position.size() < 2) {
+      throw std::invalid_argument("a position must have longitude and latitude");
+    }
+    // the position of GeoJSON is in the order of longitude and latitude
+    const auto point = projector(position[1].get<double>(), position[0].get<double>());
+    polygon.outer().emplace_back(point.x, point.y);
+  }
+  if (polygon.outer().size() < 3) {
+    throw std::invalid_argument("a zone must have three or more vertices");
+  }
+  // fixes the orientation and the closure, since GeoJSON is counterclockwise while boost is not
+  boost::geometry::correct(polygon);
+  return polygon;
+}
+}  // namespace
+
+bool is_active(const Zone & zone, const double seconds_of_day)
+{
+  if (zone.time_windows.empty()) {
+    return true;
+  }
+  for (const auto & window : zone.time_windows) {
+    const bool is_inside = window.begin <= window.end
+                             ? window.begin <= seconds_of_day && seconds_of_day < window.end
+                             : window.begin <= seconds_of_day || seconds_of_day < window.end;
+    if (is_inside) {
+      return true;
+    }
+  }
+  return false;
+}
+
+double parse_time_of_day(const std::string & time)
+{
+  int hour = -1;
+  int minute = -1;
+  int second = 0;
+  int length = 0;
+  // %n stores the number of the consumed characters, so that the trailing characters are rejected
+  const int num_fields =
+    std::sscanf(time.c_str(), "%d:%d%n:%d%n", &hour, &minute, &length, &second, &length);
+  const double seconds_of_day = hour * 3600.0 + minute * 60.0 + second;
+  if (
+    num_fields < 2 || length != static_cast<int>(time.size()) || hour < 0 || minute < 0 ||
+    minute >= 60 || second < 0 || second >= 60 || seconds_of_day > 24.0 * 3600.0) {
+    throw std::invalid_argument("invalid time of day: " + time);
+  }
+  return seconds_of_day;
+}
+
+std::vector<Zone> parse_odd_definition(const std::string & geojson, const Projector & projector)
+{
+  nlohmann::json j;
+  try {
+    j = nlohmann::json::parse(geojson);
+  } catch (const nlohmann::json::parse_error & e) {
+    throw std::invalid_argument(std::string("invalid GeoJSON: ") + e.what());
+  }
+  if (j.value("type", "") != "FeatureCollection" || !j.contains("features")) {
+    throw std::invalid_argument("GeoJSON must be a FeatureCollection");
+  }
+
+  std::vector<Zone> zones;
+  try {
+    for (const auto & feature : j.at("features")) {
+      const auto & geometry = feature.at("geometry");
+      if (geometry.value("type", "") != "Polygon") {
+        throw std::invalid_argument("a zone must be a Polygon");
+      }
+      const auto & properties = feature.at("properties");
+
+      Zone zone;
+      zone.name = properties.value("name", "zone_" + std::to_string(zones.size()));
+      zone.type = to_zone_type(properties.at("type").get<std::string>());
+      // holes are ignored
+      zone.polygon = to_polygon(geometry.at("coordinates").at(0), projector);
+      if (zone.type == ZoneType::SPEED_ZONE) {
+        // the speed is given in km/h as the speed limit of lanelet2
+        zone.max_speed = properties.at("max_speed").get<double>() / 3.6;
+      }
+      for (const auto & window : properties.value("time_windows", nlohmann::json::array())) {
+        zone.time_windows.push_back(TimeWindow{
+          parse_time_of_day(window.at("begin").get<std::string>()),
+          parse_time_of_day(window.at("end").get<std::string>())});
+      }
+      zones.push_back(zone);
+    }
+  } catch (const nlohmann::json::exception & e) {
+    throw std::invalid_argument(std::string("invalid ODD definition: ") + e.what());
+  }
+  return zones;
+}
+}  // namespace autoware::odd_monitor
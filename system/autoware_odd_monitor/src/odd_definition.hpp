@@ -0,0 +1,73 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef ODD_DEFINITION_HPP_
+#define ODD_DEFINITION_HPP_
+
+#include <autoware/universe_utils/geometry/boost_geometry.hpp>
+
+#include <geometry_msgs/msg/point.hpp>
+
+#include <functional>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::odd_monitor
+{
+using autoware::universe_utils::Point2d;
+using autoware::universe_utils::Polygon2d;
+
+enum class ZoneType {
+  // the vehicle must stay inside one of the operational areas
+  OPERATIONAL_AREA,
+  // the speed is limited inside the zone
+  SPEED_ZONE,
+  // the vehicle must not enter the zone
+  KEEP_OUT,
+};
+
+// [begin, end) in seconds of the day, which wraps at midnight when end is smaller than begin
+struct TimeWindow
+{
+  double begin;
+  double end;
+};
+
+struct Zone
+{
+  std::string name;
+  ZoneType type;
+  Polygon2d polygon;
+  // [m/s], only for the speed zones
+  std::optional<double> max_speed;
+  // the zone is always active when no window is given
+  std::vector<TimeWindow> time_windows;
+};
+
+bool is_active(const Zone & zone, double seconds_of_day);
+
+// converts "HH:MM" or "HH:MM:SS" into seconds of the day
+// throws std::invalid_argument when the string is not a valid time
+double parse_time_of_day(const std::string & time);
+
+// converts latitude and longitude into a point in the map frame
+using Projector = std::function<geometry_msgs::msg::Point(double latitude, double longitude)>;
+
+// reads the zones from the Polygon features of a GeoJSON string
+// throws std::invalid_argument when the string is not a valid definition
+std::vector<Zone> parse_odd_definition(const std::string & geojson, const Projector & projector);
+}  // namespace autoware::odd_monitor
+
+#endif  // ODD_DEFINITION_HPP_
@@ -0,0 +1,82 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "odd_evaluator.hpp"
+
+#include <boost/geometry/algorithms/covered_by.hpp>
+
+#include <algorithm>
+#include <vector>
+
+namespace autoware::odd_monitor
+{
+namespace
+{
+bool is_covered_by_any(const std::vector<const Zone *> & zones, const Point2d & point)
+{
+  return std::any_of(zones.begin(), zones.end(), [&](const auto * zone) {
+    return boost::geometry::covered_by(point, zone->polygon);
+  });
+}
+}  // namespace
+
+OddStatus evaluate_odd(
+  const std::vector<Zone> & zones, const Point2d & ego, const std::vector<Point2d> & lookahead,
+  const double seconds_of_day)
+{
+  OddStatus status;
+
+  // an operational area which is out of its time windows is treated as absent, so the ego is
+  // outside the ODD when no operational area is active
+  bool has_operational_area = false;
+  std::vector<const Zone *> operational_areas;
+  for (const auto & zone : zones) {
+    if (zone.type == ZoneType::OPERATIONAL_AREA) {
+      has_operational_area = true;
+      if (is_active(zone, seconds_of_day)) {
+        operational_areas.push_back(&zone);
+      }
+    }
+  }
+  if (has_operational_area) {
+    status.is_in_operational_area = is_covered_by_any(operational_areas, ego);
+    status.will_exit_operational_area =
+      std::any_of(lookahead.begin(), lookahead.end(), [&](const auto & point) {
+        return !is_covered_by_any(operational_areas, point);
+      });
+  }
+
+  for (const auto & zone : zones) {
+    if (zone.type == ZoneType::OPERATIONAL_AREA || !is_active(zone, seconds_of_day)) {
+      continue;
+    }
+    const bool contains_ego = boost::geometry::covered_by(ego, zone.polygon);
+    const bool contains_lookahead =
+      std::any_of(lookahead.begin(), lookahead.end(), [&](const auto & point) {
+        return boost::geometry::covered_by(point, zone.polygon);
+      });
+
+    if (zone.type == ZoneType::KEEP_OUT) {
+      if (contains_ego) {
+        status.entered_keep_out_zones.push_back(zone.name);
+      } else if (contains_lookahead) {
+        status.approaching_keep_out_zones.push_back(zone.name);
+      }
+    } else if (zone.type == ZoneType::SPEED_ZONE && (contains_ego || contains_lookahead)) {
+      status.max_speed = std::min(status.max_speed.value_or(*zone.max_speed), *zone.max_speed);
+    }
+  }
+  return status;
+}
+}  // namespace autoware::odd_monitor
@@ -0,0 +1,49 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef ODD_EVALUATOR_HPP_
+#define ODD_EVALUATOR_HPP_
+
+#include "odd_definition.hpp"
+
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::odd_monitor
+{
+struct OddStatus
+{
+  // true when no operational area is defined
+  bool is_in_operational_area{true};
+  // a point of the lookahead is outside the operational areas
+  bool will_exit_operational_area{false};
+  // the active keep-out zones which contain the ego
+  std::vector<std::string> entered_keep_out_zones{};
+  // the active keep-out zones which contain a point of the lookahead
+  std::vector<std::string> approaching_keep_out_zones{};
+  // [m/s] the lowest speed of the active speed zones which contain the ego or the lookahead
+  std::optional<double> max_speed{};
+};
+
+/**
+ * @brief evaluates the ODD at the ego position and the points which the ego will pass soon
+ * @param seconds_of_day local time to select the active zones
+ */
+OddStatus evaluate_odd(
+  const std::vector<Zone> & zones, const Point2d & ego, const std::vector<Point2d> & lookahead,
+  double seconds_of_day);
+}  // namespace autoware::odd_monitor
+
+#endif  // ODD_EVALUATOR_HPP_
@@ -0,0 +1,247 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "odd_monitor_node.hpp"
+
+#include <autoware/geography_utils/projection.hpp>
+#include <autoware/motion_utils/trajectory/trajectory.hpp>
+
+#include <algorithm>
+#include <cmath>
+#include <fstream>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::odd_monitor
+{
+namespace
+{
+std::string join(const std::vector<std::string> & names)
+{
+  std::string joined;
+  for (const auto & name : names) {
+    joined += (joined.empty() ? "" : ", ") + name;
+  }
+  return joined;
+}
+}  // namespace
+
+OddMonitor::OddMonitor(const rclcpp::NodeOptions & node_options)
+: Node("odd_monitor", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.update_rate = declare_parameter<double>("update_rate");
+  node_param_.utc_offset = declare_parameter<double>("utc_offset");
+  node_param_.lookahead_margin = declare_parameter<double>("lookahead_margin");
+  node_param_.boundary_max_speed = declare_parameter<double>("boundary_max_speed");
+  node_param_.min_acceleration = declare_parameter<double>("velocity_limit.min_acceleration");
+  node_param_.max_jerk = declare_parameter<double>("velocity_limit.max_jerk");
+  node_param_.min_jerk = declare_parameter<double>("velocity_limit.min_jerk");
+  node_param_.data_timeout = declare_parameter<double>("data_timeout");
+
+  const auto odd_definition_file = declare_parameter<std::string>("odd_definition_file");
+  std::ifstream ifs(odd_definition_file);
+  if (!ifs) {
+    throw std::runtime_error("failed to open the ODD definition file: " + odd_definition_file);
+  }
+  std::stringstream ss;
+  ss << ifs.rdbuf();
+  odd_definition_ = ss.str();
+
+  // validates the definition before the map projector info is received
+  const auto zones = parse_odd_definition(
+    odd_definition_, [](const double latitude, const double longitude) {
+      geometry_msgs::msg::Point point;
+      point.x = longitude;
+      point.y = latitude;
+      return point;
+    });
+  RCLCPP_INFO(
+    get_logger(), "%zu zones are defined in %s", zones.size(), odd_definition_file.c_str());
+
+  // Subscriber
+  sub_kinematic_state_ = create_subscription<Odometry>(
+    "input/kinematic_state", rclcpp::QoS{1},
+    [this](const Odometry::ConstSharedPtr msg) { kinematic_state_ = msg; });
+  sub_trajectory_ = create_subscription<Trajectory>(
+    "input/trajectory", rclcpp::QoS{1},
+    [this](const Trajectory::ConstSharedPtr msg) { trajectory_ = msg; });
+  const auto adaptor = autoware::component_interface_utils::NodeAdaptor(this);
+  adaptor.init_sub(
+    sub_map_projector_info_,
+    [this](const MapProjectorInfo::Message::ConstSharedPtr msg) { on_map_projector_info(msg); });
+
+  // Publisher
+  pub_velocity_limit_ = create_publisher<VelocityLimit>(
+    "output/velocity_limit", rclcpp::QoS{1}.transient_local());
+  pub_velocity_limit_clear_command_ = create_publisher<VelocityLimitClearCommand>(
+    "output/velocity_limit_clear_command", rclcpp::QoS{1}.transient_local());
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.update_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&OddMonitor::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("odd_monitor");
+  updater_.add("odd_state", this, &OddMonitor::check_odd_state);
+}
+
+void OddMonitor::on_map_projector_info(const MapProjectorInfo::Message::ConstSharedPtr msg)
+{
+  // the zones are given in WGS84, which cannot be converted into the local coordinates
+  if (msg->projector_type == MapProjectorInfo::Message::LOCAL) {
+    RCLCPP_ERROR(get_logger(), "the ODD definition cannot be used with the local projector");
+    return;
+  }
+
+  const auto projector = [msg](const double latitude, const double longitude) {
+    geographic_msgs::msg::GeoPoint gps_point;
+    gps_point.latitude = latitude;
+    gps_point.longitude = longitude;
+    gps_point.altitude = 0.0;
+    return autoware::geography_utils::project_forward(gps_point, *msg);
+  };
+  zones_ = parse_odd_definition(odd_definition_, projector);
+  RCLCPP_INFO(get_logger(), "the zones are projected onto the map");
+}
+
+void OddMonitor::on_timer()
+{
+  if (
+    !zones_ || !kinematic_state_ ||
+    (now() - kinematic_state_->header.stamp).seconds() > node_param_.data_timeout) {
+    odd_status_.reset();
+    return;
+  }
+
+  const auto & position = kinematic_state_->pose.pose.position;
+  odd_status_ = evaluate_odd(
+    *zones_, Point2d{position.x, position.y}, get_lookahead_points(), get_seconds_of_day());
+
+  // the ego slows down before leaving the ODD, and the speed limit of the zones is applied
+  std::optional<double> max_speed = odd_status_->max_speed;
+  if (odd_status_->will_exit_operational_area || !odd_status_->approaching_keep_out_zones.empty()) {
+    max_speed = std::min(
+      max_speed.value_or(node_param_.boundary_max_speed), node_param_.boundary_max_speed);
+  }
+  publish_velocity_limit(max_speed);
+}
+
+std::vector<Point2d> OddMonitor::get_lookahead_points() const
+{
+  if (!trajectory_ || trajectory_->points.empty()) {
+    return {};
+  }
+
+  // the stopping distance with the deceleration of the velocity limit
+  const double velocity = std::abs(kinematic_state_->twist.twist.linear.x);
+  const double lookahead_distance =
+    velocity * velocity / (2.0 * std::abs(node_param_.min_acceleration)) +
+    node_param_.lookahead_margin;
+
+  const auto & points = trajectory_->points;
+  const auto & ego_position = kinematic_state_->pose.pose.position;
+  const size_t nearest_index = autoware::motion_utils::findNearestIndex(points, ego_position);
+
+  std::vector<Point2d> lookahead;
+  for (size_t i = nearest_index; i < points.size(); ++i) {
+    const double distance = autoware::motion_utils::calcSignedArcLength(points, ego_position, i);
+    if (distance > lookahead_distance) {
+      break;
+    }
+    const auto & p = points.at(i).pose.position;
+    lookahead.emplace_back(p.x, p.y);
+  }
+  return lookahead;
+}
+
+double OddMonitor::get_seconds_of_day() const
+{
+  constexpr double seconds_per_day = 24.0 * 3600.0;
+  const double seconds =
+    std::fmod(now().seconds() + node_param_.utc_offset * 3600.0, seconds_per_day);
+  return seconds < 0.0 ? seconds + seconds_per_day : seconds;
+}
+
+void OddMonitor::publish_velocity_limit(const std::optional<double> & max_speed)
+{
+  if (max_speed == published_max_speed_) {
+    return;
+  }
+  published_max_speed_ = max_speed;
+
+  if (!max_speed) {
+    VelocityLimitClearCommand clear_command;
+    clear_command.stamp = now();
+    clear_command.command = true;
+    clear_command.sender = "odd_monitor";
+    pub_velocity_limit_clear_command_->publish(clear_command);
+    RCLCPP_INFO(get_logger(), "reset velocity limit");
+    return;
+  }
+
+  VelocityLimit velocity_limit;
+  velocity_limit.stamp = now();
+  velocity_limit.max_velocity = static_cast<float>(*max_speed);
+  velocity_limit.use_constraints = true;
+  velocity_limit.constraints.min_acceleration = static_cast<float>(node_param_.min_acceleration);
+  velocity_limit.constraints.max_jerk = static_cast<float>(node_param_.max_jerk);
+  velocity_limit.constraints.min_jerk = static_cast<float>(node_param_.min_jerk);
+  velocity_limit.sender = "odd_monitor";
+  pub_velocity_limit_->publish(velocity_limit);
+  RCLCPP_INFO(get_logger(), "set velocity limit: %.2f [m/s]", *max_speed);
+}
+
+void OddMonitor::check_odd_state(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  if (!zones_) {
+    stat.summary(DiagnosticStatus::STALE, "the ODD definition is not loaded");
+    return;
+  }
+  if (!odd_status_) {
+    stat.summary(DiagnosticStatus::STALE, "no ego pose");
+    return;
+  }
+
+  const auto & status = *odd_status_;
+  stat.add("is_in_operational_area", status.is_in_operational_area);
+  stat.add("will_exit_operational_area", status.will_exit_operational_area);
+  stat.add("entered_keep_out_zones", join(status.entered_keep_out_zones));
+  stat.add("approaching_keep_out_zones", join(status.approaching_keep_out_zones));
+  if (published_max_speed_) {
+    stat.addf("max_speed", "%.2f [m/s]", *published_max_speed_);
+  }
+
+  // ERROR triggers the MRM through the diagnostic graph
+  if (!status.is_in_operational_area) {
+    stat.summary(DiagnosticStatus::ERROR, "outside the operational area");
+  } else if (!status.entered_keep_out_zones.empty()) {
+    stat.summary(DiagnosticStatus::ERROR, "inside the keep-out zones");
+  } else if (status.will_exit_operational_area) {
+    stat.summary(DiagnosticStatus::WARN, "approaching the boundary of the operational area");
+  } else if (!status.approaching_keep_out_zones.empty()) {
+    stat.summary(DiagnosticStatus::WARN, "approaching the keep-out zones");
+  } else {
+    stat.summary(DiagnosticStatus::OK, "OK");
+  }
+}
+}  // namespace autoware::odd_monitor
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::odd_monitor::OddMonitor)
@@ -0,0 +1,101 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef ODD_MONITOR_NODE_HPP_
+#define ODD_MONITOR_NODE_HPP_
+
+#include "odd_definition.hpp"
+#include "odd_evaluator.hpp"
+
+#include <autoware/component_interface_specs_universe/map.hpp>
+#include <autoware/component_interface_utils/rclcpp.hpp>
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_planning_msgs/msg/trajectory.hpp>
+#include <nav_msgs/msg/odometry.hpp>
+#include <tier4_planning_msgs/msg/velocity_limit.hpp>
+#include <tier4_planning_msgs/msg/velocity_limit_clear_command.hpp>
+
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::odd_monitor
+{
+using autoware::component_interface_specs_universe::map::MapProjectorInfo;
+using autoware_planning_msgs::msg::Trajectory;
+using nav_msgs::msg::Odometry;
+using tier4_planning_msgs::msg::VelocityLimit;
+using tier4_planning_msgs::msg::VelocityLimitClearCommand;
+
+struct NodeParam
+{
+  double update_rate{};
+  double utc_offset{};
+  double lookahead_margin{};
+  double boundary_max_speed{};
+  double min_acceleration{};
+  double max_jerk{};
+  double min_jerk{};
+  double data_timeout{};
+};
+
+class OddMonitor : public rclcpp::Node
+{
+public:
+  explicit OddMonitor(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+  std::string odd_definition_;
+
+  // Subscriber
+  rclcpp::Subscription<Odometry>::SharedPtr sub_kinematic_state_;
+  rclcpp::Subscription<Trajectory>::SharedPtr sub_trajectory_;
+  autoware::component_interface_utils::Subscription<MapProjectorInfo>::SharedPtr
+    sub_map_projector_info_;
+
+  void on_map_projector_info(const MapProjectorInfo::Message::ConstSharedPtr msg);
+
+  // Publisher
+  rclcpp::Publisher<VelocityLimit>::SharedPtr pub_velocity_limit_;
+  rclcpp::Publisher<VelocityLimitClearCommand>::SharedPtr pub_velocity_limit_clear_command_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_odd_state(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  Odometry::ConstSharedPtr kinematic_state_;
+  Trajectory::ConstSharedPtr trajectory_;
+  std::optional<std::vector<Zone>> zones_;
+  std::optional<OddStatus> odd_status_;
+  std::optional<double> published_max_speed_;
+
+  // Function
+  std::vector<Point2d> get_lookahead_points() const;
+  double get_seconds_of_day() const;
+  void publish_velocity_limit(const std::optional<double> & max_speed);
+};
+}  // namespace autoware::odd_monitor
+
+#endif  // ODD_MONITOR_NODE_HPP_
@@ -0,0 +1,190 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "odd_definition.hpp"
+#include "odd_evaluator.hpp"
+
+#include <gtest/gtest.h>
+
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+using autoware::odd_monitor::Point2d;
+using autoware::odd_monitor::Zone;
+
+namespace
+{
+// one degree is mapped to 1000 m, so that the zones can be written in degrees
+geometry_msgs::msg::Point project(const double latitude, const double longitude)
+{
+  geometry_msgs::msg::Point point;
+  point.x = longitude * 1000.0;
+  point.y = latitude * 1000.0;
+  return point;
+}
+
+std::string create_feature(
+  const std::string & properties, const double min_degree, const double max_degree)
+{
+  const auto min = std::to_string(min_degree);
+  const auto max = std::to_string(max_degree);
+  return R"({"type": "Feature", "properties": )" + properties +
+         R"(, "geometry": {"type": "Polygon", "coordinates": [[)" + "[" + min + ", " + min +
+         "], [" + max + ", " + min + "], [" + max + ", " + max + "], [" + min + ", " + max +
+         "], [" + min + ", " + min + "]]]}}";
+}
+
+std::string create_definition(const std::vector<std::string> & features)
+{
+  std::string joined;
+  for (const auto & feature : features) {
+    joined += (joined.empty() ? "" : ", ") + feature;
+  }
+  return R"({"type": "FeatureCollection", "features": [)" + joined + "]}";
+}
+
+std::vector<Zone> create_zones()
+{
+  // the operational area is [0, 100] m, the speed zone is [40, 60] m and the keep-out zone is
+  // [80, 90] m in both axes
+  return autoware::odd_monitor::parse_odd_definition(
+    create_definition(
+      {create_feature(R"({"name": "area", "type": "operational_area"})", 0.0, 0.1),
+       create_feature(R"({"name": "school", "type": "speed_zone", "max_speed": 18.0})", 0.04, 0.06),
+       create_feature(R"({"name": "depot", "type": "keep_out"})", 0.08, 0.09)}),
+    project);
+}
+}  // namespace
+
+TEST(OddDefinition, ParseTimeOfDay)
+{
+  using autoware::odd_monitor::parse_time_of_day;
+  EXPECT_DOUBLE_EQ(parse_time_of_day("07:30"), 7.0 * 3600.0 + 30.0 * 60.0);
+  EXPECT_DOUBLE_EQ(parse_time_of_day("23:59:30"), 23.0 * 3600.0 + 59.0 * 60.0 + 30.0);
+  EXPECT_DOUBLE_EQ(parse_time_of_day("24:00"), 24.0 * 3600.0);
+  EXPECT_THROW(parse_time_of_day("7"), std::invalid_argument);
+  EXPECT_THROW(parse_time_of_day("07:60"), std::invalid_argument);
+  EXPECT_THROW(parse_time_of_day("24:01"), std::invalid_argument);
+  EXPECT_THROW(parse_time_of_day("07:30 "), std::invalid_argument);
+}
+
+TEST(OddDefinition, Parse)
+{
+  const auto zones = create_zones();
+  ASSERT_EQ(zones.size(), 3u);
+  EXPECT_EQ(zones.at(0).name, "area");
+  EXPECT_EQ(zones.at(0).type, autoware::odd_monitor::ZoneType::OPERATIONAL_AREA);
+  EXPECT_EQ(zones.at(1).type, autoware::odd_monitor::ZoneType::SPEED_ZONE);
+  ASSERT_TRUE(zones.at(1).max_speed.has_value());
+  EXPECT_DOUBLE_EQ(*zones.at(1).max_speed, 5.0);
+  EXPECT_EQ(zones.at(2).type, autoware::odd_monitor::ZoneType::KEEP_OUT);
+  EXPECT_FALSE(zones.at(2).max_speed.has_value());
+}
+
+TEST(OddDefinition, ParseInvalid)
+{
+  using autoware::odd_monitor::parse_odd_definition;
+  EXPECT_THROW(parse_odd_definition("{", project), std::invalid_argument);
+  EXPECT_THROW(
+    parse_odd_definition(R"({"type": "Feature", "features": []})", project),
+    std::invalid_argument);
+  EXPECT_THROW(
+    parse_odd_definition(
+      create_definition({create_feature(R"({"type": "parking"})", 0.0, 0.1)}), project),
+    std::invalid_argument);
+  // the speed zone requires max_speed
+  EXPECT_THROW(
+    parse_odd_definition(
+      create_definition({create_feature(R"({"type": "speed_zone"})", 0.0, 0.1)}), project),
+    std::invalid_argument);
+}
+
+TEST(OddDefinition, TimeWindow)
+{
+  using autoware::odd_monitor::is_active;
+  Zone zone;
+  EXPECT_TRUE(is_active(zone, 0.0));
+
+  zone.time_windows = {{7.0 * 3600.0, 19.0 * 3600.0}};
+  EXPECT_TRUE(is_active(zone, 12.0 * 3600.0));
+  EXPECT_FALSE(is_active(zone, 20.0 * 3600.0));
+
+  // wraps at midnight
+  zone.time_windows = {{22.0 * 3600.0, 5.0 * 3600.0}};
+  EXPECT_TRUE(is_active(zone, 23.0 * 3600.0));
+  EXPECT_TRUE(is_active(zone, 1.0 * 3600.0));
+  EXPECT_FALSE(is_active(zone, 12.0 * 3600.0));
+}
+
+TEST(OddEvaluator, OperationalArea)
+{
+  using autoware::odd_monitor::evaluate_odd;
+  const auto zones = create_zones();
+
+  const auto inside = evaluate_odd(zones, Point2d{10.0, 10.0}, {Point2d{20.0, 10.0}}, 0.0);
+  EXPECT_TRUE(inside.is_in_operational_area);
+  EXPECT_FALSE(inside.will_exit_operational_area);
+  EXPECT_FALSE(inside.max_speed.has_value());
+
+  const auto exiting = evaluate_odd(zones, Point2d{95.0, 10.0}, {Point2d{105.0, 10.0}}, 0.0);
+  EXPECT_TRUE(exiting.is_in_operational_area);
+  EXPECT_TRUE(exiting.will_exit_operational_area);
+
+  const auto outside = evaluate_odd(zones, Point2d{110.0, 10.0}, {}, 0.0);
+  EXPECT_FALSE(outside.is_in_operational_area);
+
+  // no operational area means no restriction
+  EXPECT_TRUE(evaluate_odd({}, Point2d{110.0, 10.0}, {}, 0.0).is_in_operational_area);
+}
+
+TEST(OddEvaluator, InactiveOperationalArea)
+{
+  using autoware::odd_monitor::evaluate_odd;
+  auto zones = create_zones();
+  zones.at(0).time_windows = {{7.0 * 3600.0, 19.0 * 3600.0}};
+
+  EXPECT_TRUE(evaluate_odd(zones, Point2d{10.0, 10.0}, {}, 12.0 * 3600.0).is_in_operational_area);
+  EXPECT_FALSE(evaluate_odd(zones, Point2d{10.0, 10.0}, {}, 20.0 * 3600.0).is_in_operational_area);
+}
+
+TEST(OddEvaluator, SpeedZone)
+{
+  using autoware::odd_monitor::evaluate_odd;
+  const auto zones = create_zones();
+
+  // the limit is applied before entering the zone
+  const auto approaching = evaluate_odd(zones, Point2d{35.0, 50.0}, {Point2d{45.0, 50.0}}, 0.0);
+  ASSERT_TRUE(approaching.max_speed.has_value());
+  EXPECT_DOUBLE_EQ(*approaching.max_speed, 5.0);
+
+  const auto inside = evaluate_odd(zones, Point2d{50.0, 50.0}, {}, 0.0);
+  ASSERT_TRUE(inside.max_speed.has_value());
+  EXPECT_DOUBLE_EQ(*inside.max_speed, 5.0);
+}
+
+TEST(OddEvaluator, KeepOut)
+{
+  using autoware::odd_monitor::evaluate_odd;
+  const auto zones = create_zones();
+
+  const auto approaching = evaluate_odd(zones, Point2d{75.0, 85.0}, {Point2d{82.0, 85.0}}, 0.0);
+  ASSERT_EQ(approaching.approaching_keep_out_zones.size(), 1u);
+  EXPECT_EQ(approaching.approaching_keep_out_zones.front(), "depot");
+  EXPECT_TRUE(approaching.entered_keep_out_zones.empty());
+
+  const auto entered = evaluate_odd(zones, Point2d{85.0, 85.0}, {}, 0.0);
+  ASSERT_EQ(entered.entered_keep_out_zones.size(), 1u);
+  EXPECT_TRUE(entered.approaching_keep_out_zones.empty());
+}
@@ -0,0 +1,125 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "road_condition.hpp"
+
+#include <algorithm>
+#include <cmath>
+
+namespace autoware::road_condition_estimator
+{
+RoadCondition evaluate_road_condition(const Cues & cues, const ConditionParam & param)
+{
+  RoadCondition condition;
+  const bool is_wiping = cues.wiper_level && *cues.wiper_level >= param.wet_wiper_level;
+  const bool is_raining = cues.rain_intensity && *cues.rain_intensity >= param.wet_rain_intensity;
+  condition.is_wet = is_wiping || is_raining;
+  condition.is_rough = cues.vibration_rms && *cues.vibration_rms >= param.rough_vibration_rms;
+
+  // heavy rain reduces the visibility even when the camera is not available
+  const bool is_fast_wiping =
+    cues.wiper_level && *cues.wiper_level >= param.poor_visibility_wiper_level;
+  const bool is_heavy_rain =
+    cues.rain_intensity && *cues.rain_intensity >= param.heavy_rain_intensity;
+  if (
+    is_fast_wiping ||
+    (cues.image_contrast && *cues.image_contrast < param.poor_visibility_contrast)) {
+    condition.visibility = Visibility::POOR;
+  } else if (
+    is_heavy_rain ||
+    (cues.image_contrast && *cues.image_contrast < param.reduced_visibility_contrast)) {
+    condition.visibility = Visibility::REDUCED;
+  }
+  return condition;
+}
+
+double calc_band_rms(
+  const std::vector<double> & samples, const double sampling_rate, const double low_frequency,
+  const double high_frequency)
+{
+  const size_t n = samples.size();
+  if (n < 2) {
+    return 0.0;
+  }
+
+  double mean = 0.0;
+  for (const auto sample : samples) {
+    mean += sample;
+  }
+  mean /= static_cast<double>(n);
+
+  // the one-sided power spectrum, where the power of a bin is 2 |X_k|^2 / N^2 by Parseval
+  double power = 0.0;
+  const double resolution = sampling_rate / static_cast<double>(n);
+  for (size_t k = 1; k < (n + 1) / 2; ++k) {
+    const double frequency = static_cast<double>(k) * resolution;
+    if (frequency < low_frequency || frequency > high_frequency) {
+      continue;
+    }
+    double re = 0.0;
+    double im = 0.0;
+    for (size_t i = 0; i < n; ++i) {
+      const double phase = 2.0 * M_PI * static_cast<double>(k * i) / static_cast<double>(n);
+      re += (samples.at(i) - mean) * std::cos(phase);
+      im -= (samples.at(i) - mean) * std::sin(phase);
+    }
+    power += 2.0 * (re * re + im * im) / static_cast<double>(n * n);
+  }
+  return std::sqrt(power);
+}
+
+std::optional<double> calc_image_contrast(
+  const std::vector<uint8_t> & data, const uint32_t width, const uint32_t height,
+  const uint32_t step, const std::string & encoding, const uint32_t pixel_stride)
+{
+  size_t channels = 0;
+  if (encoding == "mono8") {
+    channels = 1;
+  } else if (encoding == "rgb8" || encoding == "bgr8") {
+    channels = 3;
+  } else {
+    return std::nullopt;
+  }
+  const bool is_bgr = encoding == "bgr8";
+  const uint32_t stride = std::max<uint32_t>(pixel_stride, 1);
+
+  double sum = 0.0;
+  double square_sum = 0.0;
+  size_t count = 0;
+  for (uint32_t v = 0; v < height; v += stride) {
+    for (uint32_t u = 0; u < width; u += stride) {
+      const size_t index = static_cast<size_t>(v) * step + static_cast<size_t>(u) * channels;
+      if (index + channels > data.size()) {
+        return std::nullopt;
+      }
+      double luminance = data.at(index);
+      if (channels == 3) {
+        const double r = data.at(index + (is_bgr ? 2 : 0));
+        const double g = data.at(index + 1);
+        const double b = data.at(index + (is_bgr ? 0 : 2));
+        luminance = 0.299 * r + 0.587 * g + 0.114 * b;
+      }
+      luminance /= 255.0;
+      sum += luminance;
+      square_sum += luminance * luminance;
+      ++count;
+    }
+  }
+  if (count == 0) {
+    return std::nullopt;
+  }
+  const double mean = sum / static_cast<double>(count);
+  return std::sqrt(std::max(square_sum / static_cast<double>(count) - mean * mean, 0.0));
+}
+}  // namespace autoware::road_condition_estimator
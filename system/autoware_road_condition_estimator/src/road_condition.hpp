@@ -0,0 +1,73 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef ROAD_CONDITION_HPP_
+#define ROAD_CONDITION_HPP_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::road_condition_estimator
+{
+enum class Visibility { GOOD = 0, REDUCED = 1, POOR = 2 };
+
+// the cues which are not available are nullopt
+struct Cues
+{
+  std::optional<int> wiper_level;
+  // [0, 1]
+  std::optional<double> rain_intensity;
+  // RMS of the vertical acceleration in the band [m/ss]
+  std::optional<double> vibration_rms;
+  // RMS contrast of the luminance in [0, 1]
+  std::optional<double> image_contrast;
+};
+
+struct ConditionParam
+{
+  int wet_wiper_level{};
+  int poor_visibility_wiper_level{};
+  double wet_rain_intensity{};
+  double heavy_rain_intensity{};
+  double rough_vibration_rms{};
+  double reduced_visibility_contrast{};
+  double poor_visibility_contrast{};
+};
+
+struct RoadCondition
+{
+  bool is_wet{false};
+  bool is_rough{false};
+  Visibility visibility{Visibility::GOOD};
+};
+
+RoadCondition evaluate_road_condition(const Cues & cues, const ConditionParam & param);
+
+// Returns the RMS of the samples in the frequency band [low, high] by the discrete Fourier
+// transform, where the mean is removed.
+double calc_band_rms(
+  const std::vector<double> & samples, double sampling_rate, double low_frequency,
+  double high_frequency);
+
+// Returns the RMS contrast of the luminance of every `pixel_stride` pixels, or nullopt when the
+// encoding is not mono8, rgb8 or bgr8.
+std::optional<double> calc_image_contrast(
+  const std::vector<uint8_t> & data, uint32_t width, uint32_t height, uint32_t step,
+  const std::string & encoding, uint32_t pixel_stride);
+}  // namespace autoware::road_condition_estimator
+
+#endif  // ROAD_CONDITION_HPP_
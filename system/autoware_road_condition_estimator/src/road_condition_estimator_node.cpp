@@ -0,0 +1,345 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "road_condition_estimator_node.hpp"
+
+#include <algorithm>
+#include <cmath>
+#include <string>
+#include <vector>
+
+namespace autoware::road_condition_estimator
+{
+namespace
+{
+std::string join(const std::vector<std::string> & names)
+{
+  std::string joined;
+  for (const auto & name : names) {
+    joined += (joined.empty() ? "" : ", ") + name;
+  }
+  return joined;
+}
+
+const char * to_string(const Visibility visibility)
+{
+  switch (visibility) {
+    case Visibility::POOR:
+      return "POOR";
+    case Visibility::REDUCED:
+      return "REDUCED";
+    default:
+      return "GOOD";
+  }
+}
+
+CanSignal declare_signal(rclcpp::Node & node, const std::string & prefix)
+{
+  CanSignal signal;
+  signal.id = static_cast<uint32_t>(node.declare_parameter<int>(prefix + "id"));
+  signal.start_bit = static_cast<uint32_t>(node.declare_parameter<int>(prefix + "start_bit"));
+  signal.length = static_cast<uint32_t>(node.declare_parameter<int>(prefix + "length"));
+  signal.is_little_endian = node.declare_parameter<bool>(prefix + "little_endian");
+  signal.is_signed = node.declare_parameter<bool>(prefix + "signed");
+  signal.scale = node.declare_parameter<double>(prefix + "scale");
+  signal.offset = node.declare_parameter<double>(prefix + "offset");
+  return signal;
+}
+}  // namespace
+
+RoadConditionEstimator::RoadConditionEstimator(const rclcpp::NodeOptions & node_options)
+: Node("road_condition_estimator", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.update_rate = declare_parameter<double>("update_rate");
+  node_param_.use_wiper = declare_parameter<bool>("wiper.enable");
+  node_param_.wiper_signal = declare_signal(*this, "wiper.signal.");
+  node_param_.use_rain_sensor = declare_parameter<bool>("rain_sensor.enable");
+  node_param_.rain_sensor_signal = declare_signal(*this, "rain_sensor.signal.");
+  node_param_.use_vibration = declare_parameter<bool>("vibration.enable");
+  node_param_.vibration_window_size =
+    static_cast<size_t>(declare_parameter<int>("vibration.window_size"));
+  node_param_.vibration_low_frequency = declare_parameter<double>("vibration.low_frequency");
+  node_param_.vibration_high_frequency = declare_parameter<double>("vibration.high_frequency");
+  node_param_.vibration_min_speed = declare_parameter<double>("vibration.min_speed");
+  node_param_.use_camera = declare_parameter<bool>("camera.enable");
+  node_param_.camera_pixel_stride =
+    static_cast<uint32_t>(declare_parameter<int>("camera.pixel_stride"));
+  auto & condition = node_param_.condition;
+  condition.wet_wiper_level = static_cast<int>(declare_parameter<int>("wet.wiper_level"));
+  condition.wet_rain_intensity = declare_parameter<double>("wet.rain_intensity");
+  condition.rough_vibration_rms = declare_parameter<double>("rough.vibration_rms");
+  condition.poor_visibility_wiper_level =
+    static_cast<int>(declare_parameter<int>("visibility.poor_wiper_level"));
+  condition.heavy_rain_intensity = declare_parameter<double>("visibility.heavy_rain_intensity");
+  condition.reduced_visibility_contrast = declare_parameter<double>("visibility.reduced_contrast");
+  condition.poor_visibility_contrast = declare_parameter<double>("visibility.poor_contrast");
+  node_param_.recovery_time = declare_parameter<double>("recovery_time");
+  node_param_.wet_max_speed = declare_parameter<double>("max_speed.wet");
+  node_param_.rough_max_speed = declare_parameter<double>("max_speed.rough");
+  node_param_.reduced_visibility_max_speed =
+    declare_parameter<double>("max_speed.reduced_visibility");
+  node_param_.poor_visibility_max_speed = declare_parameter<double>("max_speed.poor_visibility");
+  node_param_.dry_friction = declare_parameter<double>("friction_coefficient.dry");
+  node_param_.wet_friction = declare_parameter<double>("friction_coefficient.wet");
+  node_param_.rough_friction = declare_parameter<double>("friction_coefficient.rough");
+  node_param_.min_acceleration = declare_parameter<double>("velocity_limit.min_acceleration");
+  node_param_.max_jerk = declare_parameter<double>("velocity_limit.max_jerk");
+  node_param_.min_jerk = declare_parameter<double>("velocity_limit.min_jerk");
+  node_param_.data_timeout = declare_parameter<double>("data_timeout");
+
+  // Subscriber
+  sub_can_frame_ = create_subscription<can_msgs::msg::Frame>(
+    "input/can_frame", rclcpp::QoS{100},
+    std::bind(&RoadConditionEstimator::on_can_frame, this, std::placeholders::_1));
+  sub_imu_ = create_subscription<Imu>(
+    "input/imu", rclcpp::QoS{100},
+    std::bind(&RoadConditionEstimator::on_imu, this, std::placeholders::_1));
+  sub_image_ = create_subscription<Image>(
+    "input/image", rclcpp::SensorDataQoS(),
+    [this](const Image::ConstSharedPtr msg) { image_ = msg; });
+  sub_kinematic_state_ = create_subscription<Odometry>(
+    "input/kinematic_state", rclcpp::QoS{1},
+    [this](const Odometry::ConstSharedPtr msg) { kinematic_state_ = msg; });
+
+  // Publisher
+  pub_friction_coefficient_ =
+    create_publisher<Float64Stamped>("output/friction_coefficient", rclcpp::QoS{1});
+  pub_velocity_limit_ = create_publisher<VelocityLimit>(
+    "output/velocity_limit", rclcpp::QoS{1}.transient_local());
+  pub_velocity_limit_clear_command_ = create_publisher<VelocityLimitClearCommand>(
+    "output/velocity_limit_clear_command", rclcpp::QoS{1}.transient_local());
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.update_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&RoadConditionEstimator::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("road_condition_estimator");
+  updater_.add("road_condition", this, &RoadConditionEstimator::check_road_condition);
+}
+
+void RoadConditionEstimator::on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg)
+{
+  if (msg->is_rtr || msg->is_error) {
+    return;
+  }
+
+  const size_t size = std::min<size_t>(msg->dlc, 8);
+  if (node_param_.use_wiper && msg->id == node_param_.wiper_signal.id) {
+    if (const auto value = decode_signal(node_param_.wiper_signal, msg->data.data(), size)) {
+      wiper_level_ = std::make_pair(*value, now());
+    }
+  }
+  if (node_param_.use_rain_sensor && msg->id == node_param_.rain_sensor_signal.id) {
+    if (const auto value = decode_signal(node_param_.rain_sensor_signal, msg->data.data(), size)) {
+      rain_intensity_ = std::make_pair(*value, now());
+    }
+  }
+}
+
+void RoadConditionEstimator::on_imu(const Imu::ConstSharedPtr msg)
+{
+  accelerations_.emplace_back(msg->header.stamp, msg->linear_acceleration.z);
+  while (accelerations_.size() > node_param_.vibration_window_size) {
+    accelerations_.pop_front();
+  }
+}
+
+void RoadConditionEstimator::on_timer()
+{
+  const auto current_time = now();
+  cues_ = collect_cues(current_time);
+  condition_ =
+    hold_road_condition(evaluate_road_condition(cues_, node_param_.condition), current_time);
+
+  friction_coefficient_ = node_param_.dry_friction;
+  std::optional<double> max_speed;
+  const auto limit = [&max_speed](const double speed) {
+    max_speed = std::min(max_speed.value_or(speed), speed);
+  };
+  if (condition_.is_wet) {
+    friction_coefficient_ = std::min(friction_coefficient_, node_param_.wet_friction);
+    limit(node_param_.wet_max_speed);
+  }
+  if (condition_.is_rough) {
+    friction_coefficient_ = std::min(friction_coefficient_, node_param_.rough_friction);
+    limit(node_param_.rough_max_speed);
+  }
+  if (condition_.visibility == Visibility::REDUCED) {
+    limit(node_param_.reduced_visibility_max_speed);
+  } else if (condition_.visibility == Visibility::POOR) {
+    limit(node_param_.poor_visibility_max_speed);
+  }
+
+  Float64Stamped friction_coefficient;
+  friction_coefficient.stamp = current_time;
+  friction_coefficient.data = friction_coefficient_;
+  pub_friction_coefficient_->publish(friction_coefficient);
+  publish_velocity_limit(max_speed);
+}
+
+Cues RoadConditionEstimator::collect_cues(const rclcpp::Time & current_time)
+{
+  const auto is_fresh = [&](const rclcpp::Time & stamp) {
+    return (current_time - stamp).seconds() <= node_param_.data_timeout;
+  };
+
+  Cues cues;
+  lost_cues_.clear();
+  if (node_param_.use_wiper) {
+    if (wiper_level_ && is_fresh(wiper_level_->second)) {
+      cues.wiper_level = static_cast<int>(std::lround(wiper_level_->first));
+    } else {
+      lost_cues_.push_back("wiper");
+    }
+  }
+  if (node_param_.use_rain_sensor) {
+    if (rain_intensity_ && is_fresh(rain_intensity_->second)) {
+      cues.rain_intensity = rain_intensity_->first;
+    } else {
+      lost_cues_.push_back("rain_sensor");
+    }
+  }
+
+  if (node_param_.use_vibration) {
+    if (
+      accelerations_.size() < node_param_.vibration_window_size ||
+      !is_fresh(accelerations_.back().first)) {
+      lost_cues_.push_back("vibration");
+    } else if (
+      kinematic_state_ &&
+      std::abs(kinematic_state_->twist.twist.linear.x) >= node_param_.vibration_min_speed) {
+      // the sampling rate is taken from the stamps, since the IMU drivers drop some samples
+      const double duration =
+        (accelerations_.back().first - accelerations_.front().first).seconds();
+      if (duration > 0.0) {
+        std::vector<double> samples;
+        for (const auto & acceleration : accelerations_) {
+          samples.push_back(acceleration.second);
+        }
+        const double sampling_rate = static_cast<double>(samples.size() - 1) / duration;
+        cues.vibration_rms = calc_band_rms(
+          samples, sampling_rate, node_param_.vibration_low_frequency,
+          node_param_.vibration_high_frequency);
+      }
+    }
+  }
+
+  if (node_param_.use_camera) {
+    if (image_ && is_fresh(image_->header.stamp)) {
+      cues.image_contrast = calc_image_contrast(
+        image_->data, image_->width, image_->height, image_->step, image_->encoding,
+        node_param_.camera_pixel_stride);
+    } else {
+      lost_cues_.push_back("camera");
+    }
+  }
+  return cues;
+}
+
+RoadCondition RoadConditionEstimator::hold_road_condition(
+  const RoadCondition & condition, const rclcpp::Time & current_time)
+{
+  // the road stays wet for a while after the rain stops, and the other conditions are held too
+  // to prevent chattering
+  if (condition.is_wet) {
+    wet_time_ = current_time;
+  }
+  if (condition.is_rough) {
+    rough_time_ = current_time;
+  }
+  if (condition.visibility == Visibility::POOR) {
+    poor_visibility_time_ = current_time;
+  }
+  if (condition.visibility != Visibility::GOOD) {
+    reduced_visibility_time_ = current_time;
+  }
+
+  const auto is_held = [&](const std::optional<rclcpp::Time> & time) {
+    return time && (current_time - *time).seconds() <= node_param_.recovery_time;
+  };
+  RoadCondition held_condition;
+  held_condition.is_wet = is_held(wet_time_);
+  held_condition.is_rough = is_held(rough_time_);
+  if (is_held(poor_visibility_time_)) {
+    held_condition.visibility = Visibility::POOR;
+  } else if (is_held(reduced_visibility_time_)) {
+    held_condition.visibility = Visibility::REDUCED;
+  }
+  return held_condition;
+}
+
+void RoadConditionEstimator::publish_velocity_limit(const std::optional<double> & max_speed)
+{
+  if (max_speed == published_max_speed_) {
+    return;
+  }
+  published_max_speed_ = max_speed;
+
+  if (!max_speed) {
+    VelocityLimitClearCommand clear_command;
+    clear_command.stamp = now();
+    clear_command.command = true;
+    clear_command.sender = "road_condition_estimator";
+    pub_velocity_limit_clear_command_->publish(clear_command);
+    RCLCPP_INFO(get_logger(), "reset velocity limit");
+    return;
+  }
+
+  VelocityLimit velocity_limit;
+  velocity_limit.stamp = now();
+  velocity_limit.max_velocity = static_cast<float>(*max_speed);
+  velocity_limit.use_constraints = true;
+  velocity_limit.constraints.min_acceleration = static_cast<float>(node_param_.min_acceleration);
+  velocity_limit.constraints.max_jerk = static_cast<float>(node_param_.max_jerk);
+  velocity_limit.constraints.min_jerk = static_cast<float>(node_param_.min_jerk);
+  velocity_limit.sender = "road_condition_estimator";
+  pub_velocity_limit_->publish(velocity_limit);
+  RCLCPP_INFO(get_logger(), "set velocity limit: %.2f [m/s]", *max_speed);
+}
+
+void RoadConditionEstimator::check_road_condition(
+  diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  stat.add("is_wet", condition_.is_wet);
+  stat.add("is_rough", condition_.is_rough);
+  stat.add("visibility", to_string(condition_.visibility));
+  stat.addf("friction_coefficient", "%.2f", friction_coefficient_);
+  if (cues_.vibration_rms) {
+    stat.addf("vibration_rms", "%.3f [m/ss]", *cues_.vibration_rms);
+  }
+  if (cues_.image_contrast) {
+    stat.addf("image_contrast", "%.3f", *cues_.image_contrast);
+  }
+  if (published_max_speed_) {
+    stat.addf("max_speed", "%.2f [m/s]", *published_max_speed_);
+  }
+
+  if (!lost_cues_.empty()) {
+    stat.summary(DiagnosticStatus::WARN, "no data from " + join(lost_cues_));
+  } else if (
+    condition_.is_wet || condition_.is_rough || condition_.visibility != Visibility::GOOD) {
+    stat.summary(DiagnosticStatus::WARN, "the road condition is degraded");
+  } else {
+    stat.summary(DiagnosticStatus::OK, "OK");
+  }
+}
+}  // namespace autoware::road_condition_estimator
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::road_condition_estimator::RoadConditionEstimator)
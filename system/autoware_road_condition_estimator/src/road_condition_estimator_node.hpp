@@ -0,0 +1,137 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef ROAD_CONDITION_ESTIMATOR_NODE_HPP_
+#define ROAD_CONDITION_ESTIMATOR_NODE_HPP_
+
+#include "road_condition.hpp"
+
+#include <autoware/can_signal_utils/can_signal.hpp>
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_internal_debug_msgs/msg/float64_stamped.hpp>
+#include <can_msgs/msg/frame.hpp>
+#include <nav_msgs/msg/odometry.hpp>
+#include <sensor_msgs/msg/image.hpp>
+#include <sensor_msgs/msg/imu.hpp>
+#include <tier4_planning_msgs/msg/velocity_limit.hpp>
+#include <tier4_planning_msgs/msg/velocity_limit_clear_command.hpp>
+
+#include <deque>
+#include <optional>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace autoware::road_condition_estimator
+{
+using autoware::can_signal_utils::CanSignal;
+using autoware::can_signal_utils::decode_signal;
+using autoware_internal_debug_msgs::msg::Float64Stamped;
+using nav_msgs::msg::Odometry;
+using sensor_msgs::msg::Image;
+using sensor_msgs::msg::Imu;
+using tier4_planning_msgs::msg::VelocityLimit;
+using tier4_planning_msgs::msg::VelocityLimitClearCommand;
+
+struct NodeParam
+{
+  double update_rate{};
+  bool use_wiper{};
+  CanSignal wiper_signal{};
+  bool use_rain_sensor{};
+  CanSignal rain_sensor_signal{};
+  bool use_vibration{};
+  size_t vibration_window_size{};
+  double vibration_low_frequency{};
+  double vibration_high_frequency{};
+  double vibration_min_speed{};
+  bool use_camera{};
+  uint32_t camera_pixel_stride{};
+  ConditionParam condition{};
+  double recovery_time{};
+  double wet_max_speed{};
+  double rough_max_speed{};
+  double reduced_visibility_max_speed{};
+  double poor_visibility_max_speed{};
+  double dry_friction{};
+  double wet_friction{};
+  double rough_friction{};
+  double min_acceleration{};
+  double max_jerk{};
+  double min_jerk{};
+  double data_timeout{};
+};
+
+class RoadConditionEstimator : public rclcpp::Node
+{
+public:
+  explicit RoadConditionEstimator(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<can_msgs::msg::Frame>::SharedPtr sub_can_frame_;
+  rclcpp::Subscription<Imu>::SharedPtr sub_imu_;
+  rclcpp::Subscription<Image>::SharedPtr sub_image_;
+  rclcpp::Subscription<Odometry>::SharedPtr sub_kinematic_state_;
+
+  void on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg);
+  void on_imu(const Imu::ConstSharedPtr msg);
+
+  // Publisher
+  rclcpp::Publisher<Float64Stamped>::SharedPtr pub_friction_coefficient_;
+  rclcpp::Publisher<VelocityLimit>::SharedPtr pub_velocity_limit_;
+  rclcpp::Publisher<VelocityLimitClearCommand>::SharedPtr pub_velocity_limit_clear_command_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_road_condition(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  std::optional<std::pair<double, rclcpp::Time>> wiper_level_;
+  std::optional<std::pair<double, rclcpp::Time>> rain_intensity_;
+  // the stamp and the vertical acceleration
+  std::deque<std::pair<rclcpp::Time, double>> accelerations_;
+  Image::ConstSharedPtr image_;
+  Odometry::ConstSharedPtr kinematic_state_;
+  Cues cues_;
+  RoadCondition condition_;
+  double friction_coefficient_{};
+  std::vector<std::string> lost_cues_;
+  // the last time when each condition is detected, which is kept for recovery_time
+  std::optional<rclcpp::Time> wet_time_;
+  std::optional<rclcpp::Time> rough_time_;
+  std::optional<rclcpp::Time> reduced_visibility_time_;
+  std::optional<rclcpp::Time> poor_visibility_time_;
+  std::optional<double> published_max_speed_;
+
+  // Function
+  Cues collect_cues(const rclcpp::Time & current_time);
+  RoadCondition hold_road_condition(
+    const RoadCondition & condition, const rclcpp::Time & current_time);
+  void publish_velocity_limit(const std::optional<double> & max_speed);
+};
+}  // namespace autoware::road_condition_estimator
+
+#endif  // ROAD_CONDITION_ESTIMATOR_NODE_HPP_
@@ -0,0 +1,108 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "road_condition.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+#include <cstdint>
+#include <vector>
+
+using autoware::road_condition_estimator::ConditionParam;
+using autoware::road_condition_estimator::Cues;
+using autoware::road_condition_estimator::Visibility;
+
+namespace
+{
+ConditionParam create_param()
+{
+  ConditionParam param;
+  param.wet_wiper_level = 1;
+  param.poor_visibility_wiper_level = 3;
+  param.wet_rain_intensity = 0.2;
+  param.heavy_rain_intensity = 0.7;
+  param.rough_vibration_rms = 1.0;
+  param.reduced_visibility_contrast = 0.1;
+  param.poor_visibility_contrast = 0.05;
+  return param;
+}
+}  // namespace
+
+TEST(RoadCondition, EvaluateRoadCondition)
+{
+  using autoware::road_condition_estimator::evaluate_road_condition;
+  const auto param = create_param();
+
+  const auto unknown = evaluate_road_condition(Cues{}, param);
+  EXPECT_FALSE(unknown.is_wet);
+  EXPECT_FALSE(unknown.is_rough);
+  EXPECT_EQ(unknown.visibility, Visibility::GOOD);
+
+  Cues drizzle;
+  drizzle.wiper_level = 1;
+  drizzle.rain_intensity = 0.1;
+  drizzle.vibration_rms = 0.3;
+  drizzle.image_contrast = 0.2;
+  const auto wet = evaluate_road_condition(drizzle, param);
+  EXPECT_TRUE(wet.is_wet);
+  EXPECT_FALSE(wet.is_rough);
+  EXPECT_EQ(wet.visibility, Visibility::GOOD);
+
+  Cues heavy_rain;
+  heavy_rain.rain_intensity = 0.8;
+  heavy_rain.vibration_rms = 1.5;
+  const auto heavy = evaluate_road_condition(heavy_rain, param);
+  EXPECT_TRUE(heavy.is_wet);
+  EXPECT_TRUE(heavy.is_rough);
+  EXPECT_EQ(heavy.visibility, Visibility::REDUCED);
+
+  Cues fog;
+  fog.wiper_level = 0;
+  fog.image_contrast = 0.03;
+  const auto foggy = evaluate_road_condition(fog, param);
+  EXPECT_FALSE(foggy.is_wet);
+  EXPECT_EQ(foggy.visibility, Visibility::POOR);
+}
+
+TEST(RoadCondition, CalcBandRms)
+{
+  using autoware::road_condition_estimator::calc_band_rms;
+  // 20 [Hz] of the amplitude 2 on the gravity, sampled at 200 [Hz]
+  std::vector<double> samples;
+  for (int i = 0; i < 200; ++i) {
+    samples.push_back(9.8 + 2.0 * std::sin(2.0 * M_PI * 20.0 * i / 200.0));
+  }
+  EXPECT_NEAR(calc_band_rms(samples, 200.0, 10.0, 30.0), std::sqrt(2.0), 1e-6);
+  EXPECT_NEAR(calc_band_rms(samples, 200.0, 40.0, 60.0), 0.0, 1e-6);
+  EXPECT_DOUBLE_EQ(calc_band_rms({1.0}, 200.0, 10.0, 30.0), 0.0);
+}
+
+TEST(RoadCondition, CalcImageContrast)
+{
+  using autoware::road_condition_estimator::calc_image_contrast;
+  // a checkerboard of black and white has the contrast of 0.5
+  const std::vector<uint8_t> checker{0, 255, 255, 0};
+  const auto contrast = calc_image_contrast(checker, 2, 2, 2, "mono8", 1);
+  ASSERT_TRUE(contrast.has_value());
+  EXPECT_NEAR(*contrast, 0.5, 1e-9);
+
+  const std::vector<uint8_t> gray(2 * 2 * 3, 128);
+  const auto gray_contrast = calc_image_contrast(gray, 2, 2, 6, "rgb8", 1);
+  ASSERT_TRUE(gray_contrast.has_value());
+  EXPECT_NEAR(*gray_contrast, 0.0, 1e-9);
+
+  EXPECT_FALSE(calc_image_contrast(gray, 2, 2, 6, "yuv422", 1).has_value());
+  EXPECT_FALSE(calc_image_contrast(checker, 2, 2, 6, "rgb8", 1).has_value());
+}
@@ -0,0 +1,92 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef SNAPSHOT_BUFFER_HPP_
+#define SNAPSHOT_BUFFER_HPP_
+
+#include <cstddef>
+#include <cstdint>
+#include <deque>
+#include <utility>
+#include <vector>
+
+namespace autoware::snapshot_recorder
+{
+/**
+ * @brief ring buffer of the recent entries, limited by both the time span and the total size
+ */
+template <typename T>
+class SnapshotBuffer
+{
+public:
+  struct Entry
+  {
+    int64_t stamp_ns;
+    size_t size;
+    T item;
+  };
+
+  SnapshotBuffer(const int64_t duration_ns, const size_t max_size)
+  : duration_ns_(duration_ns), max_size_(max_size)
+  {
+  }
+
+  // the stamps are expected to be pushed in the order of the time
+  void push(const int64_t stamp_ns, const size_t size, T item)
+  {
+    entries_.push_back(Entry{stamp_ns, size, std::move(item)});
+    total_size_ += size;
+    trim(stamp_ns);
+  }
+
+  // removes the entries older than the duration from the given time
+  void trim(const int64_t now_ns)
+  {
+    while (!entries_.empty() &&
+           (entries_.front().stamp_ns < now_ns - duration_ns_ || total_size_ > max_size_)) {
+      if (entries_.front().stamp_ns >= now_ns - duration_ns_) {
+        ++dropped_count_;
+      }
+      total_size_ -= entries_.front().size;
+      entries_.pop_front();
+    }
+  }
+
+  // returns the entries in [from_ns, to_ns]
+  [[nodiscard]] std::vector<Entry> snapshot(const int64_t from_ns, const int64_t to_ns) const
+  {
+    std::vector<Entry> result;
+    for (const auto & entry : entries_) {
+      if (from_ns <= entry.stamp_ns && entry.stamp_ns <= to_ns) {
+        result.push_back(entry);
+      }
+    }
+    return result;
+  }
+
+  [[nodiscard]] size_t size() const { return entries_.size(); }
+  [[nodiscard]] size_t total_size() const { return total_size_; }
+  // number of the entries removed by the size limit before they expire
+  [[nodiscard]] size_t dropped_count() const { return dropped_count_; }
+
+private:
+  int64_t duration_ns_;
+  size_t max_size_;
+  std::deque<Entry> entries_;
+  size_t total_size_{0};
+  size_t dropped_count_{0};
+};
+}  // namespace autoware::snapshot_recorder
+
+#endif  // SNAPSHOT_BUFFER_HPP_
@@ -0,0 +1,294 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "snapshot_recorder_node.hpp"
+
+#include <rosbag2_cpp/writer.hpp>
+#include <rosbag2_storage/storage_options.hpp>
+
+#include <algorithm>
+#include <chrono>
+#include <ctime>
+#include <filesystem>
+#include <functional>
+#include <iomanip>
+#include <memory>
+#include <sstream>
+#include <string>
+#include <utility>
+#include <vector>
+
+namespace autoware::snapshot_recorder
+{
+namespace
+{
+int64_t to_nanoseconds(const double seconds)
+{
+  return static_cast<int64_t>(seconds * 1e9);
+}
+
+std::string create_directory_name(const std::string & reason)
+{
+  const auto now = std::chrono::system_clock::to_time_t(std::chrono::system_clock::now());
+  std::tm local_time{};
+  localtime_r(&now, &local_time);
+  std::stringstream ss;
+  ss << "snapshot_" << std::put_time(&local_time, "%Y%m%d-%H%M%S") << "_" << reason;
+  return ss.str();
+}
+}  // namespace
+
+SnapshotRecorder::SnapshotRecorder(const rclcpp::NodeOptions & options)
+: Node("snapshot_recorder", options),
+  node_param_(load_node_param()),
+  buffer_(
+    to_nanoseconds(node_param_.pre_trigger_duration + node_param_.post_trigger_duration),
+    static_cast<size_t>(declare_parameter<double>("max_buffer_size_mb") * 1024.0 * 1024.0))
+{
+  // Subscriber
+  if (node_param_.trigger_on_mrm) {
+    sub_mrm_state_ = create_subscription<MrmState>(
+      "input/mrm_state", rclcpp::QoS{1},
+      [this](const MrmState::ConstSharedPtr msg) { on_mrm_state(msg); });
+  }
+  if (node_param_.trigger_on_disengagement) {
+    sub_operation_mode_ = create_subscription<OperationModeState>(
+      "input/operation_mode", rclcpp::QoS{1}.transient_local(),
+      [this](const OperationModeState::ConstSharedPtr msg) { on_operation_mode(msg); });
+  }
+
+  // Service
+  using std::placeholders::_1;
+  using std::placeholders::_2;
+  srv_trigger_ =
+    create_service<Trigger>("~/trigger", std::bind(&SnapshotRecorder::on_trigger, this, _1, _2));
+
+  // the publishers of the recorded topics may start later than this node
+  subscribe_topics();
+  const auto period_ns = rclcpp::Rate(10.0).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&SnapshotRecorder::on_timer, this));
+}
+
+SnapshotRecorder::~SnapshotRecorder()
+{
+  if (writer_thread_.joinable()) {
+    writer_thread_.join();
+  }
+}
+
+NodeParam SnapshotRecorder::load_node_param()
+{
+  NodeParam p;
+  p.topics = declare_parameter<std::vector<std::string>>("topics");
+  p.pre_trigger_duration = declare_parameter<double>("pre_trigger_duration");
+  p.post_trigger_duration = declare_parameter<double>("post_trigger_duration");
+  p.min_trigger_interval = declare_parameter<double>("min_trigger_interval");
+  p.output_directory = declare_parameter<std::string>("output_directory");
+  p.storage_id = declare_parameter<std::string>("storage_id");
+  p.trigger_on_mrm = declare_parameter<bool>("trigger.on_mrm");
+  p.trigger_on_disengagement = declare_parameter<bool>("trigger.on_disengagement");
+  return p;
+}
+
+void SnapshotRecorder::on_timer()
+{
+  subscribe_topics();
+
+  const auto now = this->now();
+  std::vector<SnapshotBuffer<RecordedMessage>::Entry> entries;
+  {
+    std::lock_guard<std::mutex> lock(buffer_mutex_);
+    buffer_.trim(now.nanoseconds());
+
+    const bool is_post_trigger_finished =
+      pending_ && (now - pending_->trigger_time).seconds() >= node_param_.post_trigger_duration;
+    if (!is_post_trigger_finished || is_writing_) {
+      return;
+    }
+    const auto from_ns =
+      pending_->trigger_time.nanoseconds() - to_nanoseconds(node_param_.pre_trigger_duration);
+    // the latched messages received before the snapshot come first, as they were on the topics
+    for (const auto & [topic_name, latched_entries] : latched_messages_) {
+      for (const auto & entry : latched_entries) {
+        if (entry.stamp_ns < from_ns) {
+          entries.push_back(entry);
+        }
+      }
+    }
+    std::sort(entries.begin(), entries.end(), [](const auto & a, const auto & b) {
+      return a.stamp_ns < b.stamp_ns;
+    });
+    const auto recent_entries = buffer_.snapshot(from_ns, now.nanoseconds());
+    entries.insert(entries.end(), recent_entries.begin(), recent_entries.end());
+  }
+
+  if (writer_thread_.joinable()) {
+    writer_thread_.join();
+  }
+  is_writing_ = true;
+  writer_thread_ = std::thread([this, snapshot = *pending_, entries = std::move(entries)]() {
+    write(snapshot, entries);
+    is_writing_ = false;
+  });
+  pending_.reset();
+}
+
+void SnapshotRecorder::subscribe_topics()
+{
+  if (sub_topics_.size() == node_param_.topics.size()) {
+    return;
+  }
+
+  const auto topic_names_and_types = get_topic_names_and_types();
+  for (const auto & topic_name : node_param_.topics) {
+    if (sub_topics_.count(topic_name) != 0) {
+      continue;
+    }
+    const auto it = topic_names_and_types.find(topic_name);
+    if (it == topic_names_and_types.end() || it->second.empty()) {
+      continue;
+    }
+
+    const auto publishers = get_publishers_info_by_topic(topic_name);
+    if (publishers.empty()) {
+      continue;
+    }
+
+    // a transient local subscription receives the latched message but matches only transient
+    // local publishers, while best effort is compatible with both reliable and best effort ones
+    const bool is_latched =
+      std::all_of(publishers.begin(), publishers.end(), [](const rclcpp::TopicEndpointInfo & info) {
+        const auto & qos = info.qos_profile();
+        return qos.durability() == rclcpp::DurabilityPolicy::TransientLocal &&
+               qos.reliability() == rclcpp::ReliabilityPolicy::Reliable;
+      });
+    const auto qos =
+      is_latched ? rclcpp::QoS{10}.reliable().transient_local() : rclcpp::QoS{10}.best_effort();
+
+    const auto topic_type = it->second.front();
+    sub_topics_[topic_name] = create_generic_subscription(
+      topic_name, topic_type, qos,
+      [this, topic_name, topic_type, is_latched](std::shared_ptr<rclcpp::SerializedMessage> msg) {
+        on_message(topic_name, topic_type, is_latched, msg);
+      });
+    RCLCPP_INFO(
+      get_logger(), "recording %s [%s]%s", topic_name.c_str(), topic_type.c_str(),
+      is_latched ? " as a latched topic" : "");
+  }
+}
+
+void SnapshotRecorder::on_message(
+  const std::string & topic_name, const std::string & topic_type, const bool is_latched,
+  std::shared_ptr<rclcpp::SerializedMessage> msg)
+{
+  const auto stamp_ns = now().nanoseconds();
+  const auto size = msg->size();
+  // each publisher of a latched topic, e.g. a static transform broadcaster on /tf_static, latches
+  // its own message, so as many latest messages as the publishers are kept
+  const auto num_publishers = is_latched ? std::max<size_t>(count_publishers(topic_name), 1) : 0;
+  RecordedMessage message{topic_name, topic_type, std::move(msg)};
+  std::lock_guard<std::mutex> lock(buffer_mutex_);
+  // the latest messages of a latched topic are kept even after they expire from the buffer
+  if (is_latched) {
+    auto & latched_entries = latched_messages_[topic_name];
+    latched_entries.push_back(SnapshotBuffer<RecordedMessage>::Entry{stamp_ns, size, message});
+    while (latched_entries.size() > num_publishers) {
+      latched_entries.pop_front();
+    }
+  }
+  buffer_.push(stamp_ns, size, std::move(message));
+}
+
+void SnapshotRecorder::on_mrm_state(const MrmState::ConstSharedPtr msg)
+{
+  const bool was_operating = mrm_state_ && mrm_state_->state == MrmState::MRM_OPERATING;
+  if (!was_operating && msg->state == MrmState::MRM_OPERATING) {
+    trigger("mrm");
+  }
+  mrm_state_ = *msg;
+}
+
+void SnapshotRecorder::on_operation_mode(const OperationModeState::ConstSharedPtr msg)
+{
+  const auto is_autonomous = [](const OperationModeState & state) {
+    return state.mode == OperationModeState::AUTONOMOUS && state.is_autoware_control_enabled;
+  };
+  if (operation_mode_ && is_autonomous(*operation_mode_) && !is_autonomous(*msg)) {
+    trigger("disengagement");
+  }
+  operation_mode_ = *msg;
+}
+
+void SnapshotRecorder::on_trigger(
+  [[maybe_unused]] const Trigger::Request::SharedPtr request,
+  const Trigger::Response::SharedPtr response)
+{
+  const auto output_path = trigger("service");
+  response->success = output_path.has_value();
+  response->message = output_path ? *output_path : "the trigger is ignored by the interval limit";
+}
+
+std::optional<std::string> SnapshotRecorder::trigger(const std::string & reason)
+{
+  // a trigger during the post trigger duration is covered by the pending snapshot
+  if (pending_) {
+    return pending_->output_path;
+  }
+
+  const auto now = this->now();
+  if (
+    last_trigger_time_ &&
+    (now - *last_trigger_time_).seconds() < node_param_.min_trigger_interval) {
+    RCLCPP_WARN(get_logger(), "ignore the trigger by %s within the interval", reason.c_str());
+    return std::nullopt;
+  }
+  last_trigger_time_ = now;
+
+  const auto output_path =
+    (std::filesystem::path(node_param_.output_directory) / create_directory_name(reason)).string();
+  pending_ = PendingSnapshot{reason, output_path, now};
+  RCLCPP_INFO(get_logger(), "triggered by %s, writing to %s", reason.c_str(), output_path.c_str());
+  return output_path;
+}
+
+void SnapshotRecorder::write(
+  const PendingSnapshot & snapshot,
+  const std::vector<SnapshotBuffer<RecordedMessage>::Entry> & entries)
+{
+  try {
+    std::filesystem::create_directories(node_param_.output_directory);
+
+    rosbag2_storage::StorageOptions storage_options;
+    storage_options.uri = snapshot.output_path;
+    storage_options.storage_id = node_param_.storage_id;
+
+    rosbag2_cpp::Writer writer;
+    writer.open(storage_options);
+    const auto clock_type = get_clock()->get_clock_type();
+    for (const auto & entry : entries) {
+      writer.write(
+        entry.item.message, entry.item.topic_name, entry.item.topic_type,
+        rclcpp::Time(entry.stamp_ns, clock_type));
+    }
+    RCLCPP_INFO(
+      get_logger(), "%zu messages are written to %s", entries.size(), snapshot.output_path.c_str());
+  } catch (const std::exception & e) {
+    RCLCPP_ERROR(get_logger(), "failed to write %s: %s", snapshot.output_path.c_str(), e.what());
+  }
+}
+}  // namespace autoware::snapshot_recorder
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::snapshot_recorder::SnapshotRecorder)
@@ -0,0 +1,122 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef SNAPSHOT_RECORDER_NODE_HPP_
+#define SNAPSHOT_RECORDER_NODE_HPP_
+
+#include "snapshot_buffer.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_adapi_v1_msgs/msg/mrm_state.hpp>
+#include <autoware_adapi_v1_msgs/msg/operation_mode_state.hpp>
+#include <std_srvs/srv/trigger.hpp>
+
+#include <atomic>
+#include <deque>
+#include <map>
+#include <memory>
+#include <mutex>
+#include <optional>
+#include <string>
+#include <thread>
+#include <vector>
+
+namespace autoware::snapshot_recorder
+{
+using autoware_adapi_v1_msgs::msg::MrmState;
+using autoware_adapi_v1_msgs::msg::OperationModeState;
+using std_srvs::srv::Trigger;
+
+struct RecordedMessage
+{
+  std::string topic_name;
+  std::string topic_type;
+  // non-const since rosbag2_cpp::Writer::write of Humble takes a non-const pointer
+  std::shared_ptr<rclcpp::SerializedMessage> message;
+};
+
+struct NodeParam
+{
+  std::vector<std::string> topics;
+  double pre_trigger_duration;
+  double post_trigger_duration;
+  double min_trigger_interval;
+  std::string output_directory;
+  std::string storage_id;
+  bool trigger_on_mrm;
+  bool trigger_on_disengagement;
+};
+
+struct PendingSnapshot
+{
+  std::string reason;
+  std::string output_path;
+  rclcpp::Time trigger_time;
+};
+
+class SnapshotRecorder : public rclcpp::Node
+{
+public:
+  explicit SnapshotRecorder(const rclcpp::NodeOptions & options);
+  ~SnapshotRecorder() override;
+
+private:
+  // Parameter
+  NodeParam node_param_;
+
+  // Subscriber
+  std::map<std::string, rclcpp::GenericSubscription::SharedPtr> sub_topics_;
+  rclcpp::Subscription<MrmState>::SharedPtr sub_mrm_state_;
+  rclcpp::Subscription<OperationModeState>::SharedPtr sub_operation_mode_;
+
+  // Service
+  rclcpp::Service<Trigger>::SharedPtr srv_trigger_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  // Buffer
+  std::mutex buffer_mutex_;
+  SnapshotBuffer<RecordedMessage> buffer_;
+  std::map<std::string, std::deque<SnapshotBuffer<RecordedMessage>::Entry>> latched_messages_;
+
+  // State
+  std::optional<PendingSnapshot> pending_;
+  std::optional<rclcpp::Time> last_trigger_time_;
+  std::optional<MrmState> mrm_state_;
+  std::optional<OperationModeState> operation_mode_;
+  std::thread writer_thread_;
+  std::atomic<bool> is_writing_{false};
+
+  NodeParam load_node_param();
+  void on_timer();
+  void subscribe_topics();
+  void on_message(
+    const std::string & topic_name, const std::string & topic_type, bool is_latched,
+    std::shared_ptr<rclcpp::SerializedMessage> msg);
+  void on_mrm_state(const MrmState::ConstSharedPtr msg);
+  void on_operation_mode(const OperationModeState::ConstSharedPtr msg);
+  void on_trigger(
+    const Trigger::Request::SharedPtr request, const Trigger::Response::SharedPtr response);
+
+  // returns the path of the snapshot, or empty when the trigger is ignored
+  std::optional<std::string> trigger(const std::string & reason);
+  void write(
+    const PendingSnapshot & snapshot,
+    const std::vector<SnapshotBuffer<RecordedMessage>::Entry> & entries);
+};
+}  // namespace autoware::snapshot_recorder
+
+#endif  // SNAPSHOT_RECORDER_NODE_HPP_
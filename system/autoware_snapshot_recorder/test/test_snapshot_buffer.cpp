@@ -0,0 +1,68 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "snapshot_buffer.hpp"
+
+#include <gtest/gtest.h>
+
+using autoware::snapshot_recorder::SnapshotBuffer;
+
+TEST(SnapshotBuffer, RemovesExpiredEntries)
+{
+  SnapshotBuffer<int> buffer(10, 1000);
+  buffer.push(0, 1, 0);
+  buffer.push(5, 1, 1);
+  buffer.push(10, 1, 2);
+  EXPECT_EQ(buffer.size(), 3u);
+
+  buffer.push(11, 1, 3);
+  EXPECT_EQ(buffer.size(), 3u);
+  EXPECT_EQ(buffer.total_size(), 3u);
+
+  buffer.trim(20);
+  EXPECT_EQ(buffer.size(), 2u);
+  EXPECT_EQ(buffer.dropped_count(), 0u);
+}
+
+TEST(SnapshotBuffer, RemovesOldestEntriesWhenFull)
+{
+  SnapshotBuffer<int> buffer(100, 10);
+  buffer.push(0, 4, 0);
+  buffer.push(1, 4, 1);
+  buffer.push(2, 4, 2);
+  EXPECT_EQ(buffer.size(), 2u);
+  EXPECT_EQ(buffer.total_size(), 8u);
+  EXPECT_EQ(buffer.dropped_count(), 1u);
+
+  const auto entries = buffer.snapshot(0, 100);
+  ASSERT_EQ(entries.size(), 2u);
+  EXPECT_EQ(entries.at(0).item, 1);
+  EXPECT_EQ(entries.at(1).item, 2);
+}
+
+TEST(SnapshotBuffer, Snapshot)
+{
+  SnapshotBuffer<int> buffer(100, 1000);
+  for (int i = 0; i < 10; ++i) {
+    buffer.push(i * 10, 1, i);
+  }
+
+  const auto entries = buffer.snapshot(20, 50);
+  ASSERT_EQ(entries.size(), 4u);
+  EXPECT_EQ(entries.front().stamp_ns, 20);
+  EXPECT_EQ(entries.back().stamp_ns, 50);
+  EXPECT_EQ(buffer.size(), 10u);
+
+  EXPECT_TRUE(buffer.snapshot(91, 200).empty());
+}
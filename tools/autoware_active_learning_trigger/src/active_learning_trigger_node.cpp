@@ -0,0 +1,210 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "active_learning_trigger_node.hpp"
+
+#include <autoware/object_recognition_utils/object_classification.hpp>
+
+#include <geometry_msgs/msg/point_stamped.hpp>
+
+#include <tf2_geometry_msgs/tf2_geometry_msgs.hpp>
+
+#include <cmath>
+#include <cstdlib>
+#include <string>
+#include <vector>
+
+namespace autoware::active_learning_trigger
+{
+ActiveLearningTrigger::ActiveLearningTrigger(const rclcpp::NodeOptions & node_options)
+: Node("active_learning_trigger", node_options),
+  low_confidence_condition_(declare_parameter<double>("low_confidence.min_duration")),
+  disagreement_condition_(declare_parameter<double>("disagreement.min_duration")),
+  hard_braking_condition_(declare_parameter<double>("hard_braking.min_duration"))
+{
+  // Parameter
+  node_param_.base_frame = declare_parameter<std::string>("base_frame");
+  node_param_.min_trigger_interval = declare_parameter<double>("min_trigger_interval");
+  node_param_.enable_low_confidence = declare_parameter<bool>("low_confidence.enable");
+  node_param_.low_confidence_min_score = declare_parameter<double>("low_confidence.min_score");
+  node_param_.low_confidence_max_score = declare_parameter<double>("low_confidence.max_score");
+  node_param_.low_confidence_min_object_count =
+    declare_parameter<int64_t>("low_confidence.min_object_count");
+  node_param_.enable_disagreement = declare_parameter<bool>("disagreement.enable");
+  node_param_.disagreement_distance_threshold =
+    declare_parameter<double>("disagreement.distance_threshold");
+  node_param_.disagreement_max_range = declare_parameter<double>("disagreement.max_range");
+  node_param_.disagreement_stamp_tolerance =
+    declare_parameter<double>("disagreement.stamp_tolerance");
+  node_param_.disagreement_min_mismatch_count =
+    declare_parameter<int64_t>("disagreement.min_mismatch_count");
+  node_param_.enable_hard_braking = declare_parameter<bool>("hard_braking.enable");
+  node_param_.hard_braking_acceleration_threshold =
+    declare_parameter<double>("hard_braking.acceleration_threshold");
+
+  tf_buffer_ = std::make_unique<tf2_ros::Buffer>(get_clock());
+  tf_listener_ = std::make_unique<tf2_ros::TransformListener>(*tf_buffer_);
+
+  // Subscriber
+  if (node_param_.enable_low_confidence || node_param_.enable_disagreement) {
+    sub_objects_ = create_subscription<DetectedObjects>(
+      "input/objects", rclcpp::QoS{1},
+      [this](const DetectedObjects::ConstSharedPtr msg) { on_objects(msg); });
+  }
+  if (node_param_.enable_disagreement) {
+    sub_tracked_objects_ = create_subscription<TrackedObjects>(
+      "input/tracked_objects", rclcpp::QoS{1},
+      [this](const TrackedObjects::ConstSharedPtr msg) { on_tracked_objects(msg); });
+  }
+  if (node_param_.enable_hard_braking) {
+    sub_control_cmd_ = create_subscription<Control>(
+      "input/control_cmd", rclcpp::QoS{1},
+      [this](const Control::ConstSharedPtr msg) { on_control_cmd(msg); });
+  }
+
+  // Publisher
+  pub_reason_ = create_publisher<StringStamped>("~/output/reason", rclcpp::QoS{1});
+
+  // Client
+  cli_trigger_ = create_client<Trigger>("output/trigger");
+}
+
+void ActiveLearningTrigger::on_objects(const DetectedObjects::ConstSharedPtr msg)
+{
+  const double time = rclcpp::Time(msg->header.stamp).seconds();
+
+  if (node_param_.enable_low_confidence) {
+    std::vector<double> scores;
+    for (const auto & object : msg->objects) {
+      scores.push_back(object.existence_probability);
+    }
+    const auto count = count_low_confidence(
+      scores, node_param_.low_confidence_min_score, node_param_.low_confidence_max_score);
+    const bool is_low_confidence =
+      static_cast<int64_t>(count) >= node_param_.low_confidence_min_object_count;
+    if (low_confidence_condition_.update(is_low_confidence, time)) {
+      trigger("low_confidence");
+    }
+  }
+
+  if (node_param_.enable_disagreement) {
+    // the tracker publishes the objects of the same stamp later than the detector
+    const auto points = to_object_points(*msg);
+    if (points) {
+      const auto stamp = rclcpp::Time(msg->header.stamp).nanoseconds();
+      detections_[stamp] = *points;
+      const auto tolerance = static_cast<int64_t>(node_param_.disagreement_stamp_tolerance * 1e9);
+      detections_.erase(detections_.begin(), detections_.lower_bound(stamp - 10 * tolerance));
+    }
+  }
+}
+
+void ActiveLearningTrigger::on_tracked_objects(const TrackedObjects::ConstSharedPtr msg)
+{
+  const auto stamp = rclcpp::Time(msg->header.stamp).nanoseconds();
+  const auto tolerance = static_cast<int64_t>(node_param_.disagreement_stamp_tolerance * 1e9);
+  const auto detections = detections_.lower_bound(stamp - tolerance);
+  if (detections == detections_.end() || tolerance < std::llabs(detections->first - stamp)) {
+    return;
+  }
+  const auto tracks = to_object_points(*msg);
+  if (!tracks) {
+    return;
+  }
+
+  const auto count = count_disagreement(
+    detections->second, *tracks, node_param_.disagreement_distance_threshold);
+  const bool is_disagreed =
+    static_cast<int64_t>(count) >= node_param_.disagreement_min_mismatch_count;
+  if (disagreement_condition_.update(is_disagreed, rclcpp::Time(msg->header.stamp).seconds())) {
+    trigger("disagreement");
+  }
+}
+
+void ActiveLearningTrigger::on_control_cmd(const Control::ConstSharedPtr msg)
+{
+  const bool is_hard_braking =
+    msg->longitudinal.acceleration <= node_param_.hard_braking_acceleration_threshold;
+  if (hard_braking_condition_.update(is_hard_braking, rclcpp::Time(msg->stamp).seconds())) {
+    trigger("hard_braking");
+  }
+}
+
+template <class ObjectsT>
+std::optional<std::vector<ObjectPoint>> ActiveLearningTrigger::to_object_points(
+  const ObjectsT & objects)
+{
+  geometry_msgs::msg::TransformStamped transform;
+  try {
+    transform = tf_buffer_->lookupTransform(
+      node_param_.base_frame, objects.header.frame_id, objects.header.stamp,
+      rclcpp::Duration::from_seconds(0.1));
+  } catch (const tf2::TransformException & e) {
+    RCLCPP_WARN_THROTTLE(get_logger(), *get_clock(), 1000, "%s", e.what());
+    return std::nullopt;
+  }
+
+  // the far objects are excluded, since they are often out of the range of the tracker
+  std::vector<ObjectPoint> points;
+  for (const auto & object : objects.objects) {
+    geometry_msgs::msg::PointStamped position;
+    position.header = objects.header;
+    position.point = object.kinematics.pose_with_covariance.pose.position;
+    tf2::doTransform(position, position, transform);
+    if (node_param_.disagreement_max_range < std::hypot(position.point.x, position.point.y)) {
+      continue;
+    }
+    points.push_back(ObjectPoint{
+      position.point.x, position.point.y,
+      autoware::object_recognition_utils::getHighestProbLabel(object.classification)});
+  }
+  return points;
+}
+
+void ActiveLearningTrigger::trigger(const std::string & reason)
+{
+  // the recorder also ignores the triggers within its interval, but they are not sent at all
+  const auto current_time = now();
+  if (
+    last_trigger_time_ &&
+    (current_time - *last_trigger_time_).seconds() < node_param_.min_trigger_interval) {
+    return;
+  }
+  if (!cli_trigger_->service_is_ready()) {
+    RCLCPP_WARN_THROTTLE(get_logger(), *get_clock(), 5000, "the recorder is not ready");
+    return;
+  }
+  last_trigger_time_ = current_time;
+
+  StringStamped reason_msg;
+  reason_msg.stamp = current_time;
+  reason_msg.data = reason;
+  pub_reason_->publish(reason_msg);
+
+  RCLCPP_INFO(get_logger(), "trigger a snapshot by %s", reason.c_str());
+  cli_trigger_->async_send_request(
+    std::make_shared<Trigger::Request>(),
+    [this](rclcpp::Client<Trigger>::SharedFuture future) {
+      const auto response = future.get();
+      if (response->success) {
+        RCLCPP_INFO(get_logger(), "the snapshot will be saved in %s", response->message.c_str());
+      } else {
+        RCLCPP_WARN(get_logger(), "the snapshot is rejected: %s", response->message.c_str());
+      }
+    });
+}
+}  // namespace autoware::active_learning_trigger
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::active_learning_trigger::ActiveLearningTrigger)
@@ -0,0 +1,109 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef ACTIVE_LEARNING_TRIGGER_NODE_HPP_
+#define ACTIVE_LEARNING_TRIGGER_NODE_HPP_
+
+#include "trigger_condition.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_control_msgs/msg/control.hpp>
+#include <autoware_internal_debug_msgs/msg/string_stamped.hpp>
+#include <autoware_perception_msgs/msg/detected_objects.hpp>
+#include <autoware_perception_msgs/msg/tracked_objects.hpp>
+#include <std_srvs/srv/trigger.hpp>
+
+#include <tf2_ros/buffer.h>
+#include <tf2_ros/transform_listener.h>
+
+#include <cstdint>
+#include <map>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::active_learning_trigger
+{
+using autoware_control_msgs::msg::Control;
+using autoware_internal_debug_msgs::msg::StringStamped;
+using autoware_perception_msgs::msg::DetectedObjects;
+using autoware_perception_msgs::msg::TrackedObjects;
+using std_srvs::srv::Trigger;
+
+struct NodeParam
+{
+  std::string base_frame;
+  double min_trigger_interval{};
+
+  bool enable_low_confidence{};
+  double low_confidence_min_score{};
+  double low_confidence_max_score{};
+  int64_t low_confidence_min_object_count{};
+  double low_confidence_min_duration{};
+
+  bool enable_disagreement{};
+  double disagreement_distance_threshold{};
+  double disagreement_max_range{};
+  double disagreement_stamp_tolerance{};
+  int64_t disagreement_min_mismatch_count{};
+  double disagreement_min_duration{};
+
+  bool enable_hard_braking{};
+  double hard_braking_acceleration_threshold{};
+  double hard_braking_min_duration{};
+};
+
+class ActiveLearningTrigger : public rclcpp::Node
+{
+public:
+  explicit ActiveLearningTrigger(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<DetectedObjects>::SharedPtr sub_objects_;
+  rclcpp::Subscription<TrackedObjects>::SharedPtr sub_tracked_objects_;
+  rclcpp::Subscription<Control>::SharedPtr sub_control_cmd_;
+  std::unique_ptr<tf2_ros::Buffer> tf_buffer_;
+  std::unique_ptr<tf2_ros::TransformListener> tf_listener_;
+
+  // Publisher
+  rclcpp::Publisher<StringStamped>::SharedPtr pub_reason_;
+
+  // Client
+  rclcpp::Client<Trigger>::SharedPtr cli_trigger_;
+
+  // State
+  PersistentCondition low_confidence_condition_;
+  PersistentCondition disagreement_condition_;
+  PersistentCondition hard_braking_condition_;
+  // the detections in base_link, keyed by the stamp in nanoseconds
+  std::map<int64_t, std::vector<ObjectPoint>> detections_;
+  std::optional<rclcpp::Time> last_trigger_time_;
+
+  // Function
+  void on_objects(const DetectedObjects::ConstSharedPtr msg);
+  void on_tracked_objects(const TrackedObjects::ConstSharedPtr msg);
+  void on_control_cmd(const Control::ConstSharedPtr msg);
+  template <class ObjectsT>
+  std::optional<std::vector<ObjectPoint>> to_object_points(const ObjectsT & objects);
+  void trigger(const std::string & reason);
+};
+}  // namespace autoware::active_learning_trigger
+
+#endif  // ACTIVE_LEARNING_TRIGGER_NODE_HPP_
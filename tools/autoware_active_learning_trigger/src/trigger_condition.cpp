@@ -0,0 +1,82 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "trigger_condition.hpp"
+
+#include <algorithm>
+#include <cmath>
+#include <tuple>
+#include <vector>
+
+namespace autoware::active_learning_trigger
+{
+size_t count_low_confidence(
+  const std::vector<double> & scores, const double min_score, const double max_score)
+{
+  return static_cast<size_t>(std::count_if(scores.begin(), scores.end(), [&](const double score) {
+    return min_score <= score && score <= max_score;
+  }));
+}
+
+size_t count_disagreement(
+  const std::vector<ObjectPoint> & detections, const std::vector<ObjectPoint> & tracks,
+  const double distance_threshold)
+{
+  std::vector<std::tuple<double, size_t, size_t>> pairs;
+  for (size_t i = 0; i < detections.size(); ++i) {
+    for (size_t j = 0; j < tracks.size(); ++j) {
+      const double distance =
+        std::hypot(detections[i].x - tracks[j].x, detections[i].y - tracks[j].y);
+      if (distance <= distance_threshold) {
+        pairs.emplace_back(distance, i, j);
+      }
+    }
+  }
+  std::sort(pairs.begin(), pairs.end());
+
+  std::vector<bool> is_detection_matched(detections.size(), false);
+  std::vector<bool> is_track_matched(tracks.size(), false);
+  size_t matched_count = 0;
+  size_t label_mismatch_count = 0;
+  for (const auto & [distance, i, j] : pairs) {
+    if (is_detection_matched[i] || is_track_matched[j]) {
+      continue;
+    }
+    is_detection_matched[i] = true;
+    is_track_matched[j] = true;
+    ++matched_count;
+    if (detections[i].label != tracks[j].label) {
+      ++label_mismatch_count;
+    }
+  }
+  return (detections.size() - matched_count) + (tracks.size() - matched_count) +
+         label_mismatch_count;
+}
+
+PersistentCondition::PersistentCondition(const double min_duration) : min_duration_(min_duration)
+{
+}
+
+bool PersistentCondition::update(const bool condition, const double time)
+{
+  if (!condition) {
+    start_time_ = std::nullopt;
+    return false;
+  }
+  if (!start_time_) {
+    start_time_ = time;
+  }
+  return min_duration_ <= time - *start_time_;
+}
+}  // namespace autoware::active_learning_trigger
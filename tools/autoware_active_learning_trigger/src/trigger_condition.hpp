@@ -0,0 +1,53 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef TRIGGER_CONDITION_HPP_
+#define TRIGGER_CONDITION_HPP_
+
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+namespace autoware::active_learning_trigger
+{
+struct ObjectPoint
+{
+  double x{};
+  double y{};
+  uint8_t label{};
+};
+
+// the number of the scores in [min_score, max_score]
+size_t count_low_confidence(const std::vector<double> & scores, double min_score, double max_score);
+
+// Matches the detections and the tracks greedily from the nearest pair within the threshold,
+// and returns the number of the unmatched objects and the matched pairs of different labels.
+size_t count_disagreement(
+  const std::vector<ObjectPoint> & detections, const std::vector<ObjectPoint> & tracks,
+  double distance_threshold);
+
+// a condition which is satisfied only when it continues for the duration
+class PersistentCondition
+{
+public:
+  explicit PersistentCondition(double min_duration);
+  bool update(bool condition, double time);
+
+private:
+  double min_duration_;
+  std::optional<double> start_time_;
+};
+}  // namespace autoware::active_learning_trigger
+
+#endif  // TRIGGER_CONDITION_HPP_
@@ -0,0 +1,61 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "trigger_condition.hpp"
+
+#include <gtest/gtest.h>
+
+#include <vector>
+
+namespace autoware::active_learning_trigger
+{
+TEST(TriggerCondition, CountLowConfidence)
+{
+  EXPECT_EQ(count_low_confidence({0.1, 0.3, 0.4, 0.5, 0.9}, 0.3, 0.5), 3u);
+  EXPECT_EQ(count_low_confidence({}, 0.3, 0.5), 0u);
+}
+
+TEST(TriggerCondition, CountDisagreement)
+{
+  const std::vector<ObjectPoint> detections = {{0.0, 0.0, 1}, {10.0, 0.0, 1}, {20.0, 0.0, 7}};
+  const std::vector<ObjectPoint> tracks = {{0.5, 0.0, 1}, {20.5, 0.0, 1}, {40.0, 0.0, 1}};
+
+  // the detection at 10 and the track at 40 are unmatched, and the labels at 20 differ
+  EXPECT_EQ(count_disagreement(detections, tracks, 2.0), 3u);
+  EXPECT_EQ(count_disagreement(detections, detections, 2.0), 0u);
+  EXPECT_EQ(count_disagreement(detections, {}, 2.0), 3u);
+}
+
+TEST(TriggerCondition, CountDisagreementMatchesNearestFirst)
+{
+  // the track at 1.5 is matched with the detection at 2.0, not with the one at 0.0
+  const std::vector<ObjectPoint> detections = {{0.0, 0.0, 1}, {2.0, 0.0, 1}};
+  const std::vector<ObjectPoint> tracks = {{1.5, 0.0, 1}, {-0.5, 0.0, 1}};
+  EXPECT_EQ(count_disagreement(detections, tracks, 2.0), 0u);
+}
+
+TEST(TriggerCondition, PersistentCondition)
+{
+  PersistentCondition condition(1.0);
+  EXPECT_FALSE(condition.update(true, 0.0));
+  EXPECT_FALSE(condition.update(true, 0.5));
+  EXPECT_TRUE(condition.update(true, 1.0));
+  EXPECT_FALSE(condition.update(false, 1.1));
+  EXPECT_FALSE(condition.update(true, 1.2));
+  EXPECT_TRUE(condition.update(true, 2.2));
+
+  PersistentCondition immediate(0.0);
+  EXPECT_TRUE(immediate.update(true, 0.0));
+}
+}  // namespace autoware::active_learning_trigger
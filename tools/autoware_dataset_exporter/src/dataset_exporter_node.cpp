@@ -0,0 +1,373 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "dataset_exporter_node.hpp"
+
+#include <autoware/object_recognition_utils/object_classification.hpp>
+#include <autoware/universe_utils/ros/uuid_helper.hpp>
+#include <opencv2/imgcodecs.hpp>
+
+#include <tf2_geometry_msgs/tf2_geometry_msgs.hpp>
+
+#if __has_include(<cv_bridge/cv_bridge.hpp>)
+#include <cv_bridge/cv_bridge.hpp>
+#else
+#include <cv_bridge/cv_bridge.h>
+#endif
+
+#include <algorithm>
+#include <cstring>
+#include <exception>
+#include <filesystem>
+#include <fstream>
+#include <functional>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_exporter
+{
+namespace
+{
+Pose to_pose(const geometry_msgs::msg::Pose & msg)
+{
+  Pose pose;
+  pose.translation = {msg.position.x, msg.position.y, msg.position.z};
+  pose.rotation = {msg.orientation.w, msg.orientation.x, msg.orientation.y, msg.orientation.z};
+  return pose;
+}
+
+// the intensity of Autoware is uint8, while the one of the other drivers is float32
+std::vector<std::array<float, 4>> to_points(const PointCloud2 & pointcloud)
+{
+  const sensor_msgs::msg::PointField * fields[4] = {};
+  const char * names[4] = {"x", "y", "z", "intensity"};
+  for (const auto & field : pointcloud.fields) {
+    for (size_t i = 0; i < 4; ++i) {
+      if (field.name == names[i]) {
+        fields[i] = &field;
+      }
+    }
+  }
+  for (size_t i = 0; i < 3; ++i) {
+    if (!fields[i] || fields[i]->datatype != sensor_msgs::msg::PointField::FLOAT32) {
+      throw std::runtime_error(std::string("the point cloud does not have float32 ") + names[i]);
+    }
+  }
+
+  const size_t count = static_cast<size_t>(pointcloud.width) * pointcloud.height;
+  std::vector<std::array<float, 4>> points(count);
+  for (size_t n = 0; n < count; ++n) {
+    const uint8_t * point = pointcloud.data.data() + n * pointcloud.point_step;
+    for (size_t i = 0; i < 3; ++i) {
+      std::memcpy(&points[n][i], point + fields[i]->offset, sizeof(float));
+    }
+    points[n][3] = 0.0f;
+    if (fields[3] && fields[3]->datatype == sensor_msgs::msg::PointField::FLOAT32) {
+      std::memcpy(&points[n][3], point + fields[3]->offset, sizeof(float));
+    } else if (fields[3] && fields[3]->datatype == sensor_msgs::msg::PointField::UINT8) {
+      points[n][3] = static_cast<float>(point[fields[3]->offset]);
+    }
+  }
+  return points;
+}
+
+template <class T>
+void remove_older(std::map<int64_t, T> & records, const int64_t stamp)
+{
+  records.erase(records.begin(), records.lower_bound(stamp));
+}
+}  // namespace
+
+DatasetExporter::DatasetExporter(const rclcpp::NodeOptions & node_options)
+: Node("dataset_exporter", node_options)
+{
+  // Parameter
+  node_param_.output_directory = declare_parameter<std::string>("output_directory");
+  node_param_.map_frame = declare_parameter<std::string>("map_frame");
+  node_param_.base_frame = declare_parameter<std::string>("base_frame");
+  node_param_.camera_names = declare_parameter<std::vector<std::string>>("camera_names");
+  node_param_.image_format = declare_parameter<std::string>("image_format");
+  node_param_.export_objects = declare_parameter<bool>("export_objects");
+  node_param_.sync_tolerance = declare_parameter<double>("sync_tolerance");
+  node_param_.max_wait = declare_parameter<double>("max_wait");
+  node_param_.frame_interval = declare_parameter<double>("frame_interval");
+  if (node_param_.image_format != "png" && node_param_.image_format != "jpg") {
+    throw std::invalid_argument("image_format must be png or jpg");
+  }
+
+  const std::filesystem::path output_directory(node_param_.output_directory);
+  std::filesystem::create_directories(output_directory / "lidar");
+  std::filesystem::create_directories(output_directory / "labels");
+  for (const auto & camera_name : node_param_.camera_names) {
+    std::filesystem::create_directories(output_directory / camera_name);
+  }
+
+  tf_buffer_ = std::make_unique<tf2_ros::Buffer>(get_clock());
+  tf_listener_ = std::make_unique<tf2_ros::TransformListener>(*tf_buffer_);
+
+  // Subscriber
+  sub_pointcloud_ = create_subscription<PointCloud2>(
+    "input/pointcloud", rclcpp::SensorDataQoS(),
+    [this](const PointCloud2::ConstSharedPtr msg) { pending_pointclouds_.push_back(msg); });
+  for (const auto & camera_name : node_param_.camera_names) {
+    sub_images_.push_back(create_subscription<Image>(
+      "input/" + camera_name + "/image", rclcpp::SensorDataQoS(),
+      [this, camera_name](const Image::ConstSharedPtr msg) {
+        images_[camera_name][rclcpp::Time(msg->header.stamp).nanoseconds()] = msg;
+      }));
+    sub_camera_infos_.push_back(create_subscription<CameraInfo>(
+      "input/" + camera_name + "/camera_info", rclcpp::SensorDataQoS(),
+      [this, camera_name](const CameraInfo::ConstSharedPtr msg) {
+        camera_infos_[camera_name] = msg;
+      }));
+  }
+  if (node_param_.export_objects) {
+    sub_objects_ = create_subscription<TrackedObjects>(
+      "input/objects", rclcpp::QoS{10}, [this](const TrackedObjects::ConstSharedPtr msg) {
+        objects_[rclcpp::Time(msg->header.stamp).nanoseconds()] = msg;
+      });
+  }
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(10.0).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&DatasetExporter::on_timer, this));
+}
+
+DatasetExporter::~DatasetExporter()
+{
+  try {
+    save_index();
+  } catch (const std::exception & e) {
+    RCLCPP_ERROR(get_logger(), "%s", e.what());
+  }
+}
+
+void DatasetExporter::on_timer()
+{
+  // a point cloud waits for the images and the objects which arrive later than it
+  const auto current_time = now();
+  const auto max_wait = rclcpp::Duration::from_seconds(node_param_.max_wait);
+  while (!pending_pointclouds_.empty() &&
+         rclcpp::Time(pending_pointclouds_.front()->header.stamp) + max_wait <= current_time) {
+    try {
+      export_frame(*pending_pointclouds_.front());
+    } catch (const std::exception & e) {
+      RCLCPP_ERROR(get_logger(), "%s", e.what());
+    }
+    pending_pointclouds_.pop_front();
+  }
+
+  // the records which no longer match any point cloud
+  const auto oldest = pending_pointclouds_.empty()
+                        ? current_time - max_wait
+                        : rclcpp::Time(pending_pointclouds_.front()->header.stamp);
+  const auto keep_from = oldest - rclcpp::Duration::from_seconds(node_param_.sync_tolerance);
+  for (auto & [camera_name, images] : images_) {
+    remove_older(images, keep_from.nanoseconds());
+  }
+  remove_older(objects_, keep_from.nanoseconds());
+}
+
+void DatasetExporter::export_frame(const PointCloud2 & pointcloud)
+{
+  const rclcpp::Time stamp(pointcloud.header.stamp);
+  const int64_t stamp_ns = stamp.nanoseconds();
+  const auto interval = static_cast<int64_t>(node_param_.frame_interval * 1e9);
+  if (last_frame_stamp_ && stamp_ns - *last_frame_stamp_ < interval) {
+    return;
+  }
+
+  // a frame is exported only when all the data are synchronized
+  const auto tolerance = static_cast<int64_t>(node_param_.sync_tolerance * 1e9);
+  std::map<std::string, Image::ConstSharedPtr> images;
+  for (const auto & camera_name : node_param_.camera_names) {
+    const auto image = find_nearest(images_[camera_name], stamp_ns, tolerance);
+    if (!image) {
+      RCLCPP_WARN_THROTTLE(
+        get_logger(), *get_clock(), 1000, "no image of %s is synchronized", camera_name.c_str());
+      return;
+    }
+    images[camera_name] = *image;
+  }
+
+  std::optional<std::vector<ExportObject>> objects;
+  if (node_param_.export_objects) {
+    const auto objects_msg = find_nearest(objects_, stamp_ns, tolerance);
+    if (!objects_msg) {
+      RCLCPP_WARN_THROTTLE(get_logger(), *get_clock(), 1000, "no objects are synchronized");
+      return;
+    }
+    objects = create_objects(**objects_msg);
+    if (!objects) {
+      return;
+    }
+  }
+
+  const auto ego_pose = lookup_pose(node_param_.map_frame, node_param_.base_frame, stamp);
+  if (!ego_pose) {
+    return;
+  }
+
+  if (sensors_.empty()) {
+    const auto sensors = create_sensors(pointcloud);
+    if (!sensors) {
+      return;
+    }
+    sensors_ = *sensors;
+    std::ofstream(std::filesystem::path(node_param_.output_directory) / "calibration.json")
+      << encode_calibration(sensors_).dump(2);
+  }
+
+  ExportFrame frame;
+  frame.index = frames_.size();
+  frame.stamp = stamp_ns;
+  frame.ego_pose = *ego_pose;
+  const auto name = to_frame_name(frame.index);
+  const std::filesystem::path output_directory(node_param_.output_directory);
+
+  frame.lidar_path = "lidar/" + name + ".bin";
+  write_point_cloud((output_directory / frame.lidar_path).string(), to_points(pointcloud));
+
+  for (const auto & [camera_name, image] : images) {
+    const auto path = camera_name + "/" + name + "." + node_param_.image_format;
+    const auto cv_image = cv_bridge::toCvShare(image, "bgr8");
+    if (!cv::imwrite((output_directory / path).string(), cv_image->image)) {
+      throw std::runtime_error("failed to write " + path);
+    }
+    frame.image_paths[camera_name] = path;
+  }
+
+  if (objects) {
+    frame.label_path = "labels/" + name + ".json";
+    std::ofstream(output_directory / frame.label_path) << encode_labels(*objects).dump(2);
+  }
+
+  frames_.push_back(frame);
+  last_frame_stamp_ = stamp_ns;
+}
+
+std::optional<std::vector<ExportSensor>> DatasetExporter::create_sensors(
+  const PointCloud2 & pointcloud)
+{
+  const rclcpp::Time stamp(pointcloud.header.stamp);
+  std::vector<ExportSensor> sensors;
+
+  ExportSensor lidar;
+  lidar.name = "lidar";
+  lidar.modality = "lidar";
+  lidar.frame_id = pointcloud.header.frame_id;
+  const auto lidar_pose = lookup_pose(node_param_.base_frame, lidar.frame_id, stamp);
+  if (!lidar_pose) {
+    return std::nullopt;
+  }
+  lidar.pose = *lidar_pose;
+  sensors.push_back(lidar);
+
+  for (const auto & camera_name : node_param_.camera_names) {
+    const auto it = camera_infos_.find(camera_name);
+    if (it == camera_infos_.end()) {
+      RCLCPP_WARN_THROTTLE(
+        get_logger(), *get_clock(), 1000, "no camera info of %s", camera_name.c_str());
+      return std::nullopt;
+    }
+    const auto & camera_info = *it->second;
+
+    ExportSensor camera;
+    camera.name = camera_name;
+    camera.modality = "camera";
+    camera.frame_id = camera_info.header.frame_id;
+    const auto camera_pose = lookup_pose(node_param_.base_frame, camera.frame_id, stamp);
+    if (!camera_pose) {
+      return std::nullopt;
+    }
+    camera.pose = *camera_pose;
+    camera.width = camera_info.width;
+    camera.height = camera_info.height;
+    std::copy(camera_info.k.begin(), camera_info.k.end(), camera.camera_matrix.begin());
+    camera.distortion_model = camera_info.distortion_model;
+    camera.distortion = camera_info.d;
+    sensors.push_back(camera);
+  }
+  return sensors;
+}
+
+std::optional<std::vector<ExportObject>> DatasetExporter::create_objects(
+  const TrackedObjects & objects)
+{
+  geometry_msgs::msg::TransformStamped transform;
+  try {
+    transform = tf_buffer_->lookupTransform(
+      node_param_.base_frame, objects.header.frame_id, objects.header.stamp,
+      rclcpp::Duration::from_seconds(0.1));
+  } catch (const tf2::TransformException & e) {
+    RCLCPP_WARN_THROTTLE(get_logger(), *get_clock(), 1000, "%s", e.what());
+    return std::nullopt;
+  }
+
+  std::vector<ExportObject> export_objects;
+  for (const auto & object : objects.objects) {
+    geometry_msgs::msg::Pose pose;
+    tf2::doTransform(object.kinematics.pose_with_covariance.pose, pose, transform);
+
+    ExportObject export_object;
+    export_object.id = autoware::universe_utils::toHexString(object.object_id);
+    export_object.label = autoware::object_recognition_utils::convertLabelToString(
+      autoware::object_recognition_utils::getHighestProbLabel(object.classification));
+    export_object.existence_probability = object.existence_probability;
+    export_object.pose = to_pose(pose);
+    export_object.size = {
+      object.shape.dimensions.x, object.shape.dimensions.y, object.shape.dimensions.z};
+    export_object.velocity = object.kinematics.twist_with_covariance.twist.linear.x;
+    export_objects.push_back(export_object);
+  }
+  return export_objects;
+}
+
+std::optional<Pose> DatasetExporter::lookup_pose(
+  const std::string & frame_id, const std::string & child_frame_id,
+  const rclcpp::Time & stamp) const
+{
+  try {
+    const auto transform = tf_buffer_->lookupTransform(
+      frame_id, child_frame_id, stamp, rclcpp::Duration::from_seconds(0.1));
+    geometry_msgs::msg::Pose pose;
+    pose.position.x = transform.transform.translation.x;
+    pose.position.y = transform.transform.translation.y;
+    pose.position.z = transform.transform.translation.z;
+    pose.orientation = transform.transform.rotation;
+    return to_pose(pose);
+  } catch (const tf2::TransformException & e) {
+    RCLCPP_WARN_THROTTLE(get_logger(), *get_clock(), 1000, "%s", e.what());
+    return std::nullopt;
+  }
+}
+
+void DatasetExporter::save_index() const
+{
+  const auto path = std::filesystem::path(node_param_.output_directory) / "frames.json";
+  std::ofstream file(path);
+  if (!file) {
+    throw std::runtime_error("failed to open " + path.string());
+  }
+  file << encode_frames(frames_).dump(2);
+  RCLCPP_INFO(
+    get_logger(), "exported %zu frames in %s", frames_.size(),
+    node_param_.output_directory.c_str());
+}
+}  // namespace autoware::dataset_exporter
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::dataset_exporter::DatasetExporter)
@@ -0,0 +1,100 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef DATASET_EXPORTER_NODE_HPP_
+#define DATASET_EXPORTER_NODE_HPP_
+
+#include "dataset_layout.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_perception_msgs/msg/tracked_objects.hpp>
+#include <sensor_msgs/msg/camera_info.hpp>
+#include <sensor_msgs/msg/image.hpp>
+#include <sensor_msgs/msg/point_cloud2.hpp>
+
+#include <tf2_ros/buffer.h>
+#include <tf2_ros/transform_listener.h>
+
+#include <cstdint>
+#include <deque>
+#include <map>
+#include <memory>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_exporter
+{
+using autoware_perception_msgs::msg::TrackedObjects;
+using sensor_msgs::msg::CameraInfo;
+using sensor_msgs::msg::Image;
+using sensor_msgs::msg::PointCloud2;
+
+struct NodeParam
+{
+  std::string output_directory;
+  std::string map_frame;
+  std::string base_frame;
+  std::vector<std::string> camera_names;
+  std::string image_format;
+  bool export_objects{};
+  double sync_tolerance{};
+  double max_wait{};
+  double frame_interval{};
+};
+
+class DatasetExporter : public rclcpp::Node
+{
+public:
+  explicit DatasetExporter(const rclcpp::NodeOptions & node_options);
+  ~DatasetExporter() override;
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<PointCloud2>::SharedPtr sub_pointcloud_;
+  std::vector<rclcpp::Subscription<Image>::SharedPtr> sub_images_;
+  std::vector<rclcpp::Subscription<CameraInfo>::SharedPtr> sub_camera_infos_;
+  rclcpp::Subscription<TrackedObjects>::SharedPtr sub_objects_;
+  std::unique_ptr<tf2_ros::Buffer> tf_buffer_;
+  std::unique_ptr<tf2_ros::TransformListener> tf_listener_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  // State
+  std::deque<PointCloud2::ConstSharedPtr> pending_pointclouds_;
+  std::map<std::string, std::map<int64_t, Image::ConstSharedPtr>> images_;
+  std::map<std::string, CameraInfo::ConstSharedPtr> camera_infos_;
+  std::map<int64_t, TrackedObjects::ConstSharedPtr> objects_;
+  std::optional<int64_t> last_frame_stamp_;
+  std::vector<ExportSensor> sensors_;
+  std::vector<ExportFrame> frames_;
+
+  // Function
+  void on_timer();
+  void export_frame(const PointCloud2 & pointcloud);
+  std::optional<std::vector<ExportSensor>> create_sensors(const PointCloud2 & pointcloud);
+  std::optional<std::vector<ExportObject>> create_objects(const TrackedObjects & objects);
+  std::optional<Pose> lookup_pose(
+    const std::string & frame_id, const std::string & child_frame_id,
+    const rclcpp::Time & stamp) const;
+  void save_index() const;
+};
+}  // namespace autoware::dataset_exporter
+
+#endif  // DATASET_EXPORTER_NODE_HPP_
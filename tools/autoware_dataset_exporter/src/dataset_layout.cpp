@@ -0,0 +1,116 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "dataset_layout.hpp"
+
+#include <cstdio>
+#include <fstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_exporter
+{
+namespace
+{
+nlohmann::json encode_pose(const Pose & pose)
+{
+  nlohmann::json j;
+  j["translation"] = pose.translation;
+  j["rotation"] = pose.rotation;
+  return j;
+}
+}  // namespace
+
+std::string to_frame_name(const size_t index)
+{
+  char name[32];
+  std::snprintf(name, sizeof(name), "%06zu", index);
+  return name;
+}
+
+void write_point_cloud(const std::string & path, const std::vector<std::array<float, 4>> & points)
+{
+  std::ofstream file(path, std::ios::binary);
+  if (!file) {
+    throw std::runtime_error("failed to open " + path);
+  }
+  file.write(
+    reinterpret_cast<const char *>(points.data()),
+    static_cast<std::streamsize>(points.size() * sizeof(points.front())));
+  if (!file) {
+    throw std::runtime_error("failed to write " + path);
+  }
+}
+
+nlohmann::json encode_calibration(const std::vector<ExportSensor> & sensors)
+{
+  nlohmann::json j;
+  j["sensors"] = nlohmann::json::array();
+  for (const auto & sensor : sensors) {
+    nlohmann::json s = encode_pose(sensor.pose);
+    s["name"] = sensor.name;
+    s["modality"] = sensor.modality;
+    s["frame_id"] = sensor.frame_id;
+    if (sensor.modality == "camera") {
+      s["width"] = sensor.width;
+      s["height"] = sensor.height;
+      s["camera_intrinsic"] = nlohmann::json::array();
+      for (size_t i = 0; i < 3; ++i) {
+        s["camera_intrinsic"].push_back(
+          {sensor.camera_matrix[i * 3], sensor.camera_matrix[i * 3 + 1],
+           sensor.camera_matrix[i * 3 + 2]});
+      }
+      s["distortion_model"] = sensor.distortion_model;
+      s["distortion"] = sensor.distortion;
+    }
+    j["sensors"].push_back(s);
+  }
+  return j;
+}
+
+nlohmann::json encode_frames(const std::vector<ExportFrame> & frames)
+{
+  nlohmann::json j;
+  j["frames"] = nlohmann::json::array();
+  for (const auto & frame : frames) {
+    nlohmann::json f;
+    f["index"] = frame.index;
+    f["timestamp"] = frame.stamp;
+    f["ego_pose"] = encode_pose(frame.ego_pose);
+    f["lidar"] = frame.lidar_path;
+    f["cameras"] = frame.image_paths;
+    // the label is null when the objects are not exported
+    f["label"] = frame.label_path.empty() ? nlohmann::json() : nlohmann::json(frame.label_path);
+    j["frames"].push_back(f);
+  }
+  return j;
+}
+
+nlohmann::json encode_labels(const std::vector<ExportObject> & objects)
+{
+  nlohmann::json j;
+  j["objects"] = nlohmann::json::array();
+  for (const auto & object : objects) {
+    nlohmann::json o = encode_pose(object.pose);
+    o["id"] = object.id;
+    o["label"] = object.label;
+    o["existence_probability"] = object.existence_probability;
+    o["size"] = object.size;
+    o["velocity"] = object.velocity;
+    j["objects"].push_back(o);
+  }
+  return j;
+}
+}  // namespace autoware::dataset_exporter
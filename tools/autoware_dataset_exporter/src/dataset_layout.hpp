@@ -0,0 +1,108 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef DATASET_LAYOUT_HPP_
+#define DATASET_LAYOUT_HPP_
+
+#include <nlohmann/json.hpp>
+
+#include <array>
+#include <cstdint>
+#include <cstdlib>
+#include <map>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_exporter
+{
+struct Pose
+{
+  std::array<double, 3> translation{};
+  // w, x, y, z
+  std::array<double, 4> rotation{1.0, 0.0, 0.0, 0.0};
+};
+
+struct ExportSensor
+{
+  std::string name;
+  // lidar or camera
+  std::string modality;
+  std::string frame_id;
+  // the pose of the sensor in base_link
+  Pose pose;
+  // the intrinsics of the camera
+  uint32_t width{};
+  uint32_t height{};
+  std::array<double, 9> camera_matrix{};
+  std::string distortion_model;
+  std::vector<double> distortion;
+};
+
+struct ExportObject
+{
+  std::string id;
+  std::string label;
+  double existence_probability{};
+  // the pose of the center of the box in base_link
+  Pose pose;
+  // length, width and height
+  std::array<double, 3> size{};
+  // the velocity along the x axis of the object
+  double velocity{};
+};
+
+struct ExportFrame
+{
+  size_t index{};
+  int64_t stamp{};
+  // the pose of base_link in the map
+  Pose ego_pose;
+  // the paths relative to the output directory
+  std::string lidar_path;
+  std::map<std::string, std::string> image_paths;
+  std::string label_path;
+};
+
+// the file name of the frame without the extension, e.g. 000042
+std::string to_frame_name(size_t index);
+
+// Writes the points as float32 x, y, z and intensity in the binary format of KITTI.
+// Throws std::runtime_error on failure.
+void write_point_cloud(const std::string & path, const std::vector<std::array<float, 4>> & points);
+
+nlohmann::json encode_calibration(const std::vector<ExportSensor> & sensors);
+nlohmann::json encode_frames(const std::vector<ExportFrame> & frames);
+nlohmann::json encode_labels(const std::vector<ExportObject> & objects);
+
+// Finds the record of the stamp nearest to the given stamp within the tolerance [ns].
+template <class T>
+std::optional<T> find_nearest(
+  const std::map<int64_t, T> & records, const int64_t stamp, const int64_t tolerance)
+{
+  std::optional<T> nearest;
+  int64_t min_difference = tolerance;
+  for (auto it = records.lower_bound(stamp - tolerance);
+       it != records.end() && it->first <= stamp + tolerance; ++it) {
+    const int64_t difference = std::llabs(it->first - stamp);
+    if (difference <= min_difference) {
+      min_difference = difference;
+      nearest = it->second;
+    }
+  }
+  return nearest;
+}
+}  // namespace autoware::dataset_exporter
+
+#endif  // DATASET_LAYOUT_HPP_
@@ -0,0 +1,120 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "dataset_layout.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cstdio>
+#include <filesystem>
+#include <fstream>
+#include <iterator>
+#include <map>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_exporter
+{
+TEST(DatasetLayout, FrameName)
+{
+  EXPECT_EQ(to_frame_name(0), "000000");
+  EXPECT_EQ(to_frame_name(42), "000042");
+}
+
+TEST(DatasetLayout, FindNearest)
+{
+  const std::map<int64_t, int> records = {{100, 1}, {200, 2}, {260, 3}};
+  EXPECT_EQ(find_nearest(records, 210, 50).value_or(0), 2);
+  EXPECT_EQ(find_nearest(records, 240, 50).value_or(0), 3);
+  EXPECT_EQ(find_nearest(records, 90, 50).value_or(0), 1);
+  EXPECT_FALSE(find_nearest(records, 150, 40).has_value());
+  EXPECT_FALSE(find_nearest(std::map<int64_t, int>{}, 0, 40).has_value());
+}
+
+TEST(DatasetLayout, WritePointCloud)
+{
+  const auto path = (std::filesystem::temp_directory_path() / "test_dataset_exporter.bin").string();
+  write_point_cloud(path, {{1.0f, 2.0f, 3.0f, 4.0f}, {5.0f, 6.0f, 7.0f, 8.0f}});
+
+  std::ifstream file(path, std::ios::binary);
+  const std::vector<char> bytes(
+    (std::istreambuf_iterator<char>(file)), std::istreambuf_iterator<char>());
+  ASSERT_EQ(bytes.size(), 8 * sizeof(float));
+  const auto * values = reinterpret_cast<const float *>(bytes.data());
+  EXPECT_FLOAT_EQ(values[4], 5.0f);
+  EXPECT_FLOAT_EQ(values[7], 8.0f);
+  std::remove(path.c_str());
+}
+
+TEST(DatasetLayout, EncodeCalibration)
+{
+  ExportSensor lidar;
+  lidar.name = "lidar";
+  lidar.modality = "lidar";
+  lidar.frame_id = "base_link";
+
+  ExportSensor camera;
+  camera.name = "camera0";
+  camera.modality = "camera";
+  camera.frame_id = "camera0/camera_optical_link";
+  camera.pose.translation = {1.0, 0.0, 1.5};
+  camera.width = 1920;
+  camera.height = 1080;
+  camera.camera_matrix = {1000, 0, 960, 0, 1000, 540, 0, 0, 1};
+  camera.distortion_model = "plumb_bob";
+  camera.distortion = {0.1, 0.0, 0.0, 0.0, 0.0};
+
+  const auto j = encode_calibration({lidar, camera});
+  ASSERT_EQ(j.at("sensors").size(), 2u);
+  EXPECT_FALSE(j.at("sensors")[0].contains("camera_intrinsic"));
+  const auto & c = j.at("sensors")[1];
+  EXPECT_EQ(c.at("frame_id"), "camera0/camera_optical_link");
+  EXPECT_DOUBLE_EQ(c.at("translation")[2].get<double>(), 1.5);
+  EXPECT_DOUBLE_EQ(c.at("rotation")[0].get<double>(), 1.0);
+  EXPECT_DOUBLE_EQ(c.at("camera_intrinsic")[1][2].get<double>(), 540.0);
+  EXPECT_EQ(c.at("width"), 1920);
+  EXPECT_EQ(c.at("distortion").size(), 5u);
+}
+
+TEST(DatasetLayout, EncodeFramesAndLabels)
+{
+  ExportFrame frame;
+  frame.index = 3;
+  frame.stamp = 1700000000123456789;
+  frame.lidar_path = "lidar/000003.bin";
+  frame.image_paths["camera0"] = "camera0/000003.jpg";
+
+  ExportFrame unlabeled = frame;
+  frame.label_path = "labels/000003.json";
+
+  const auto frames = encode_frames({frame, unlabeled}).at("frames");
+  ASSERT_EQ(frames.size(), 2u);
+  EXPECT_EQ(frames[0].at("timestamp").get<int64_t>(), 1700000000123456789);
+  EXPECT_EQ(frames[0].at("cameras").at("camera0"), "camera0/000003.jpg");
+  EXPECT_EQ(frames[0].at("label"), "labels/000003.json");
+  EXPECT_TRUE(frames[1].at("label").is_null());
+
+  ExportObject object;
+  object.id = "0123";
+  object.label = "CAR";
+  object.existence_probability = 0.9;
+  object.size = {4.5, 1.8, 1.5};
+  object.velocity = 3.0;
+  const auto objects = encode_labels({object}).at("objects");
+  ASSERT_EQ(objects.size(), 1u);
+  EXPECT_EQ(objects[0].at("label"), "CAR");
+  EXPECT_DOUBLE_EQ(objects[0].at("size")[0].get<double>(), 4.5);
+  EXPECT_DOUBLE_EQ(objects[0].at("velocity").get<double>(), 3.0);
+}
+}  // namespace autoware::dataset_exporter
@@ -0,0 +1,310 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "dataset_reader_node.hpp"
+
+#include "kitti_loader.hpp"
+#include "nuscenes_loader.hpp"
+
+#include <autoware/object_recognition_utils/object_classification.hpp>
+#include <autoware/universe_utils/ros/uuid_helper.hpp>
+#include <opencv2/imgcodecs.hpp>
+
+#include <sensor_msgs/point_cloud2_iterator.hpp>
+
+#if __has_include(<cv_bridge/cv_bridge.hpp>)
+#include <cv_bridge/cv_bridge.hpp>
+#else
+#include <cv_bridge/cv_bridge.h>
+#endif
+
+#include <algorithm>
+#include <cctype>
+#include <cstring>
+#include <exception>
+#include <functional>
+#include <limits>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_reader
+{
+namespace
+{
+geometry_msgs::msg::Pose to_pose(const Transform & transform)
+{
+  const auto q = to_quaternion(transform);
+  geometry_msgs::msg::Pose pose;
+  pose.position.x = transform.translation[0];
+  pose.position.y = transform.translation[1];
+  pose.position.z = transform.translation[2];
+  pose.orientation.w = q[0];
+  pose.orientation.x = q[1];
+  pose.orientation.y = q[2];
+  pose.orientation.z = q[3];
+  return pose;
+}
+
+geometry_msgs::msg::TransformStamped to_transform_stamped(
+  const Transform & transform, const rclcpp::Time & stamp, const std::string & frame_id,
+  const std::string & child_frame_id)
+{
+  const auto pose = to_pose(transform);
+  geometry_msgs::msg::TransformStamped msg;
+  msg.header.stamp = stamp;
+  msg.header.frame_id = frame_id;
+  msg.child_frame_id = child_frame_id;
+  msg.transform.translation.x = pose.position.x;
+  msg.transform.translation.y = pose.position.y;
+  msg.transform.translation.z = pose.position.z;
+  msg.transform.rotation = pose.orientation;
+  return msg;
+}
+
+std::string to_topic_name(std::string name)
+{
+  std::transform(name.begin(), name.end(), name.begin(), [](const unsigned char c) {
+    return static_cast<char>(std::tolower(c));
+  });
+  return name;
+}
+}  // namespace
+
+DatasetReader::DatasetReader(const rclcpp::NodeOptions & node_options)
+: Node("dataset_reader", node_options)
+{
+  // Parameter
+  node_param_.update_rate = declare_parameter<double>("update_rate");
+  node_param_.playback_rate = declare_parameter<double>("playback_rate");
+  node_param_.loop = declare_parameter<bool>("loop");
+  const auto format = declare_parameter<std::string>("format");
+  if (format == "kitti") {
+    const auto root = declare_parameter<std::string>("kitti.root");
+    const auto split = declare_parameter<std::string>("kitti.split");
+    const auto sequence = declare_parameter<std::string>("kitti.sequence");
+    sequence_ = load_kitti_tracking(root, split, sequence);
+  } else if (format == "nuscenes") {
+    const auto dataroot = declare_parameter<std::string>("nuscenes.dataroot");
+    const auto version = declare_parameter<std::string>("nuscenes.version");
+    const auto scene = declare_parameter<std::string>("nuscenes.scene");
+    const auto channels = declare_parameter<std::vector<std::string>>("nuscenes.channels");
+    sequence_ = load_nuscenes_scene(dataroot, version, scene, channels);
+  } else {
+    throw std::invalid_argument("unknown format: " + format);
+  }
+  if (node_param_.playback_rate <= 0.0) {
+    throw std::invalid_argument("playback_rate must be positive");
+  }
+
+  // the playback starts from the earliest record
+  start_time_ = std::numeric_limits<double>::max();
+  if (!sequence_.data.empty()) {
+    start_time_ = std::min(start_time_, sequence_.data.front().time);
+  }
+  if (!sequence_.annotations.empty()) {
+    start_time_ = std::min(start_time_, sequence_.annotations.front().time);
+  }
+  if (!sequence_.ego_poses.empty()) {
+    start_time_ = std::min(start_time_, sequence_.ego_poses.front().time);
+  }
+  RCLCPP_INFO(
+    get_logger(), "loaded %zu sensors, %zu data, %zu annotation frames and %zu ego poses",
+    sequence_.sensors.size(), sequence_.data.size(), sequence_.annotations.size(),
+    sequence_.ego_poses.size());
+
+  // Publisher
+  for (const auto & sensor : sequence_.sensors) {
+    sensors_[sensor.name] = sensor;
+    const auto prefix = "~/output/" + to_topic_name(sensor.name);
+    if (sensor.type == SensorType::LIDAR) {
+      pub_pointclouds_[sensor.name] =
+        create_publisher<PointCloud2>(prefix + "/pointcloud", rclcpp::SensorDataQoS());
+    } else {
+      pub_images_[sensor.name] =
+        create_publisher<Image>(prefix + "/image_raw", rclcpp::SensorDataQoS());
+      pub_camera_infos_[sensor.name] =
+        create_publisher<CameraInfo>(prefix + "/camera_info", rclcpp::SensorDataQoS());
+    }
+  }
+  pub_objects_ = create_publisher<TrackedObjects>("~/output/ground_truth/objects", rclcpp::QoS{1});
+  pub_pose_ = create_publisher<PoseWithCovarianceStamped>(
+    "~/output/ground_truth/pose_with_covariance", rclcpp::QoS{1});
+  tf_broadcaster_ = std::make_unique<tf2_ros::TransformBroadcaster>(*this);
+  static_tf_broadcaster_ = std::make_unique<tf2_ros::StaticTransformBroadcaster>(*this);
+  publish_static_transforms();
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.update_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&DatasetReader::on_timer, this));
+}
+
+rclcpp::Time DatasetReader::to_stamp(const double time) const
+{
+  return *start_stamp_ +
+         rclcpp::Duration::from_seconds((time - start_time_) / node_param_.playback_rate);
+}
+
+void DatasetReader::on_timer()
+{
+  // the clock starts with zero until /clock is received when use_sim_time is true
+  const auto current_time = now();
+  if (current_time.nanoseconds() == 0) {
+    return;
+  }
+  if (!start_stamp_) {
+    start_stamp_ = current_time;
+  }
+
+  const double time =
+    start_time_ + (current_time - *start_stamp_).seconds() * node_param_.playback_rate;
+  for (; data_index_ < sequence_.data.size(); ++data_index_) {
+    if (time < sequence_.data[data_index_].time) {
+      break;
+    }
+    publish_data(sequence_.data[data_index_]);
+  }
+  for (; annotation_index_ < sequence_.annotations.size(); ++annotation_index_) {
+    if (time < sequence_.annotations[annotation_index_].time) {
+      break;
+    }
+    publish_annotation(sequence_.annotations[annotation_index_]);
+  }
+  for (; ego_pose_index_ < sequence_.ego_poses.size(); ++ego_pose_index_) {
+    if (time < sequence_.ego_poses[ego_pose_index_].time) {
+      break;
+    }
+    publish_ego_pose(sequence_.ego_poses[ego_pose_index_]);
+  }
+
+  const bool finished = data_index_ == sequence_.data.size() &&
+                        annotation_index_ == sequence_.annotations.size() &&
+                        ego_pose_index_ == sequence_.ego_poses.size();
+  if (!finished) {
+    return;
+  }
+  if (!node_param_.loop) {
+    RCLCPP_INFO(get_logger(), "finished the playback");
+    timer_->cancel();
+    return;
+  }
+
+  // the stamps of the next loop continue from the current time
+  start_stamp_ = std::nullopt;
+  data_index_ = 0;
+  annotation_index_ = 0;
+  ego_pose_index_ = 0;
+}
+
+void DatasetReader::publish_data(const SensorData & data)
+{
+  const auto & sensor = sensors_.at(data.sensor);
+  std_msgs::msg::Header header;
+  header.stamp = to_stamp(data.time);
+  header.frame_id = sensor.frame_id;
+
+  try {
+    if (sensor.type == SensorType::LIDAR) {
+      const auto points = read_point_cloud(data.path, sensor.point_stride);
+      PointCloud2 msg;
+      msg.header = header;
+      sensor_msgs::PointCloud2Modifier modifier(msg);
+      modifier.setPointCloud2Fields(
+        4, "x", 1, sensor_msgs::msg::PointField::FLOAT32, "y", 1,
+        sensor_msgs::msg::PointField::FLOAT32, "z", 1, sensor_msgs::msg::PointField::FLOAT32,
+        "intensity", 1, sensor_msgs::msg::PointField::FLOAT32);
+      modifier.resize(points.size());
+      std::memcpy(msg.data.data(), points.data(), points.size() * sizeof(Point));
+      pub_pointclouds_.at(data.sensor)->publish(msg);
+      return;
+    }
+
+    const auto image = cv::imread(data.path, cv::IMREAD_COLOR);
+    if (image.empty()) {
+      RCLCPP_WARN(get_logger(), "failed to read %s", data.path.c_str());
+      return;
+    }
+    pub_images_.at(data.sensor)->publish(*cv_bridge::CvImage(header, "bgr8", image).toImageMsg());
+
+    // the images of the datasets are already rectified
+    CameraInfo camera_info;
+    camera_info.header = header;
+    camera_info.width = image.cols;
+    camera_info.height = image.rows;
+    camera_info.distortion_model = "plumb_bob";
+    camera_info.d = {0.0, 0.0, 0.0, 0.0, 0.0};
+    camera_info.r = {1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0};
+    std::copy(sensor.camera_matrix.begin(), sensor.camera_matrix.end(), camera_info.k.begin());
+    std::copy(
+      sensor.projection_matrix.begin(), sensor.projection_matrix.end(), camera_info.p.begin());
+    pub_camera_infos_.at(data.sensor)->publish(camera_info);
+  } catch (const std::exception & e) {
+    RCLCPP_WARN(get_logger(), "%s", e.what());
+  }
+}
+
+void DatasetReader::publish_annotation(const AnnotationFrame & frame)
+{
+  TrackedObjects msg;
+  msg.header.stamp = to_stamp(frame.time);
+  msg.header.frame_id = frame.frame_id;
+  for (const auto & annotation : frame.objects) {
+    // the same UUID is used for the annotations of an object across the frames
+    if (uuids_.count(annotation.id) == 0) {
+      uuids_[annotation.id] = autoware::universe_utils::generateUUID();
+    }
+
+    autoware_perception_msgs::msg::TrackedObject object;
+    object.object_id = uuids_.at(annotation.id);
+    object.existence_probability = 1.0;
+    object.classification =
+      autoware::object_recognition_utils::toObjectClassifications(annotation.label, 1.0);
+    object.kinematics.pose_with_covariance.pose = to_pose(annotation.pose);
+    object.kinematics.orientation_availability =
+      autoware_perception_msgs::msg::TrackedObjectKinematics::AVAILABLE;
+    object.shape.type = autoware_perception_msgs::msg::Shape::BOUNDING_BOX;
+    object.shape.dimensions.x = annotation.size[0];
+    object.shape.dimensions.y = annotation.size[1];
+    object.shape.dimensions.z = annotation.size[2];
+    msg.objects.push_back(object);
+  }
+  pub_objects_->publish(msg);
+}
+
+void DatasetReader::publish_ego_pose(const EgoPose & ego_pose)
+{
+  const auto stamp = to_stamp(ego_pose.time);
+  tf_broadcaster_->sendTransform(to_transform_stamped(ego_pose.pose, stamp, "map", "base_link"));
+
+  PoseWithCovarianceStamped msg;
+  msg.header.stamp = stamp;
+  msg.header.frame_id = "map";
+  msg.pose.pose = to_pose(ego_pose.pose);
+  pub_pose_->publish(msg);
+}
+
+void DatasetReader::publish_static_transforms()
+{
+  std::vector<geometry_msgs::msg::TransformStamped> transforms;
+  for (const auto & sensor : sequence_.sensors) {
+    transforms.push_back(
+      to_transform_stamped(sensor.base_to_sensor, now(), "base_link", sensor.frame_id));
+  }
+  static_tf_broadcaster_->sendTransform(transforms);
+}
+}  // namespace autoware::dataset_reader
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::dataset_reader::DatasetReader)
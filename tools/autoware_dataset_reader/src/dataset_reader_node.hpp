@@ -0,0 +1,94 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef DATASET_READER_NODE_HPP_
+#define DATASET_READER_NODE_HPP_
+
+#include "sequence.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_perception_msgs/msg/tracked_objects.hpp>
+#include <geometry_msgs/msg/pose_with_covariance_stamped.hpp>
+#include <sensor_msgs/msg/camera_info.hpp>
+#include <sensor_msgs/msg/image.hpp>
+#include <sensor_msgs/msg/point_cloud2.hpp>
+#include <unique_identifier_msgs/msg/uuid.hpp>
+
+#include <tf2_ros/static_transform_broadcaster.h>
+#include <tf2_ros/transform_broadcaster.h>
+
+#include <map>
+#include <memory>
+#include <optional>
+#include <string>
+#include <unordered_map>
+
+namespace autoware::dataset_reader
+{
+using autoware_perception_msgs::msg::TrackedObjects;
+using geometry_msgs::msg::PoseWithCovarianceStamped;
+using sensor_msgs::msg::CameraInfo;
+using sensor_msgs::msg::Image;
+using sensor_msgs::msg::PointCloud2;
+
+struct NodeParam
+{
+  double update_rate{};
+  double playback_rate{};
+  bool loop{};
+};
+
+class DatasetReader : public rclcpp::Node
+{
+public:
+  explicit DatasetReader(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+
+  // Publisher
+  std::map<std::string, rclcpp::Publisher<PointCloud2>::SharedPtr> pub_pointclouds_;
+  std::map<std::string, rclcpp::Publisher<Image>::SharedPtr> pub_images_;
+  std::map<std::string, rclcpp::Publisher<CameraInfo>::SharedPtr> pub_camera_infos_;
+  rclcpp::Publisher<TrackedObjects>::SharedPtr pub_objects_;
+  rclcpp::Publisher<PoseWithCovarianceStamped>::SharedPtr pub_pose_;
+  std::unique_ptr<tf2_ros::TransformBroadcaster> tf_broadcaster_;
+  std::unique_ptr<tf2_ros::StaticTransformBroadcaster> static_tf_broadcaster_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  // State
+  Sequence sequence_;
+  std::map<std::string, Sensor> sensors_;
+  std::unordered_map<std::string, unique_identifier_msgs::msg::UUID> uuids_;
+  double start_time_{};
+  std::optional<rclcpp::Time> start_stamp_;
+  size_t data_index_{};
+  size_t annotation_index_{};
+  size_t ego_pose_index_{};
+
+  // Function
+  void on_timer();
+  rclcpp::Time to_stamp(double time) const;
+  void publish_data(const SensorData & data);
+  void publish_annotation(const AnnotationFrame & frame);
+  void publish_ego_pose(const EgoPose & ego_pose);
+  void publish_static_transforms();
+};
+}  // namespace autoware::dataset_reader
+
+#endif  // DATASET_READER_NODE_HPP_
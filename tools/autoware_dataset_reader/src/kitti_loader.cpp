@@ -0,0 +1,269 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "kitti_loader.hpp"
+
+#include <array>
+#include <cmath>
+#include <cstdio>
+#include <filesystem>
+#include <fstream>
+#include <optional>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_reader
+{
+namespace
+{
+constexpr double earth_radius = 6378137.0;
+
+const std::vector<double> & find_calibration(
+  const std::map<std::string, std::vector<double>> & calibration,
+  const std::vector<std::string> & keys, const size_t size)
+{
+  // the tracking benchmark and the object benchmark name the matrices differently
+  for (const auto & key : keys) {
+    const auto it = calibration.find(key);
+    if (it != calibration.end() && size <= it->second.size()) {
+      return it->second;
+    }
+  }
+  throw std::runtime_error("the calibration does not have " + keys.front());
+}
+
+Transform rotation_from_rpy(const double roll, const double pitch, const double yaw)
+{
+  const double cr = std::cos(roll);
+  const double sr = std::sin(roll);
+  const double cp = std::cos(pitch);
+  const double sp = std::sin(pitch);
+  const double cy = std::cos(yaw);
+  const double sy = std::sin(yaw);
+  // Rz(yaw) * Ry(pitch) * Rx(roll)
+  Transform transform;
+  transform.rotation = {cy * cp, cy * sp * sr - sy * cr, cy * sp * cr + sy * sr,
+                        sy * cp, sy * sp * sr + cy * cr, sy * sp * cr - cy * sr,
+                        -sp,     cp * sr,                cp * cr};
+  return transform;
+}
+
+std::string frame_name(const size_t frame, const std::string & extension)
+{
+  char name[32];
+  std::snprintf(name, sizeof(name), "%06zu", frame);
+  return name + extension;
+}
+}  // namespace
+
+std::map<std::string, std::vector<double>> parse_kitti_calibration(std::istream & stream)
+{
+  std::map<std::string, std::vector<double>> calibration;
+  std::string line;
+  while (std::getline(stream, line)) {
+    std::istringstream iss(line);
+    std::string key;
+    if (!(iss >> key)) {
+      continue;
+    }
+    if (key.back() == ':') {
+      key.pop_back();
+    }
+    std::vector<double> values;
+    double value;
+    while (iss >> value) {
+      values.push_back(value);
+    }
+    calibration[key] = values;
+  }
+  return calibration;
+}
+
+std::string to_kitti_label(const std::string & type)
+{
+  if (type == "Car" || type == "Van") {
+    return "CAR";
+  }
+  if (type == "Truck") {
+    return "TRUCK";
+  }
+  if (type == "Tram") {
+    return "BUS";
+  }
+  if (type == "Pedestrian" || type == "Person_sitting" || type == "Person") {
+    return "PEDESTRIAN";
+  }
+  if (type == "Cyclist") {
+    return "BICYCLE";
+  }
+  if (type == "Misc") {
+    return "UNKNOWN";
+  }
+  // DontCare
+  return "";
+}
+
+std::vector<AnnotationFrame> parse_kitti_labels(
+  std::istream & stream, const Transform & base_to_camera, const size_t frame_count)
+{
+  std::vector<AnnotationFrame> frames(frame_count);
+  for (size_t i = 0; i < frame_count; ++i) {
+    frames[i].time = static_cast<double>(i) * kitti_frame_period;
+    frames[i].frame_id = "base_link";
+  }
+
+  std::string line;
+  while (std::getline(stream, line)) {
+    std::istringstream iss(line);
+    size_t frame;
+    std::string track_id, type;
+    double truncated, occluded, alpha, left, top, right, bottom;
+    double height, width, length, x, y, z, rotation_y;
+    if (!(iss >> frame >> track_id >> type >> truncated >> occluded >> alpha >> left >> top >>
+          right >> bottom >> height >> width >> length >> x >> y >> z >> rotation_y)) {
+      continue;
+    }
+    const auto label = to_kitti_label(type);
+    if (label.empty() || frame_count <= frame) {
+      continue;
+    }
+
+    // the location is the bottom center, and the y axis of the camera points down
+    const double c = std::cos(rotation_y);
+    const double s = std::sin(rotation_y);
+    Transform box;
+    box.rotation = {c, s, 0.0, 0.0, 0.0, -1.0, -s, c, 0.0};
+    box.translation = {x, y - height / 2.0, z};
+
+    Annotation annotation;
+    annotation.id = track_id;
+    annotation.label = label;
+    annotation.pose = compose(base_to_camera, box);
+    annotation.size = {length, width, height};
+    frames[frame].objects.push_back(annotation);
+  }
+  return frames;
+}
+
+std::vector<EgoPose> parse_kitti_oxts(std::istream & stream)
+{
+  std::vector<EgoPose> poses;
+  std::optional<double> scale;
+  std::array<double, 3> origin{};
+  std::string line;
+  while (std::getline(stream, line)) {
+    std::istringstream iss(line);
+    double lat, lon, alt, roll, pitch, yaw;
+    if (!(iss >> lat >> lon >> alt >> roll >> pitch >> yaw)) {
+      continue;
+    }
+    if (!scale) {
+      scale = std::cos(lat * M_PI / 180.0);
+    }
+    const double mx = *scale * lon * M_PI * earth_radius / 180.0;
+    const double my = *scale * earth_radius * std::log(std::tan((90.0 + lat) * M_PI / 360.0));
+    if (poses.empty()) {
+      origin = {mx, my, alt};
+    }
+
+    EgoPose pose;
+    pose.time = static_cast<double>(poses.size()) * kitti_frame_period;
+    pose.pose = rotation_from_rpy(roll, pitch, yaw);
+    pose.pose.translation = {mx - origin[0], my - origin[1], alt - origin[2]};
+    poses.push_back(pose);
+  }
+  return poses;
+}
+
+Sequence load_kitti_tracking(
+  const std::string & root, const std::string & split, const std::string & sequence)
+{
+  const auto directory = std::filesystem::path(root) / split;
+  const auto open = [](const std::filesystem::path & path) {
+    std::ifstream file(path);
+    if (!file) {
+      throw std::runtime_error("failed to open " + path.string());
+    }
+    return file;
+  };
+
+  auto calibration_file = open(directory / "calib" / (sequence + ".txt"));
+  const auto calibration = parse_kitti_calibration(calibration_file);
+  const auto & p2 = find_calibration(calibration, {"P2"}, 12);
+  const auto & r_rect = find_calibration(calibration, {"R_rect", "R0_rect"}, 9);
+  const auto velo_to_camera =
+    from_matrix(find_calibration(calibration, {"Tr_velo_cam", "Tr_velo_to_cam"}, 12));
+  const auto imu_to_velo =
+    from_matrix(find_calibration(calibration, {"Tr_imu_velo", "Tr_imu_to_velo"}, 12));
+
+  Transform rectification;
+  for (size_t i = 0; i < 9; ++i) {
+    rectification.rotation[i] = r_rect[i];
+  }
+  // the points in base_link (IMU) into the rectified camera 0
+  const auto base_to_camera0_inv = compose(rectification, compose(velo_to_camera, imu_to_velo));
+
+  // P2 = K [I | b] where b is the offset of the camera 2 from the rectified camera 0
+  Transform camera0_to_camera2;
+  const double bz = p2[11] / p2[10];
+  const double by = (p2[7] - p2[6] * bz) / p2[5];
+  const double bx = (p2[3] - p2[1] * by - p2[2] * bz) / p2[0];
+  camera0_to_camera2.translation = {bx, by, bz};
+
+  Sequence result;
+  Sensor lidar;
+  lidar.name = "velodyne";
+  lidar.type = SensorType::LIDAR;
+  lidar.frame_id = "velodyne";
+  lidar.base_to_sensor = inverse(imu_to_velo);
+  lidar.point_stride = 4;
+  result.sensors.push_back(lidar);
+
+  Sensor camera;
+  camera.name = "camera2";
+  camera.type = SensorType::CAMERA;
+  camera.frame_id = "camera2";
+  camera.base_to_sensor = inverse(compose(camera0_to_camera2, base_to_camera0_inv));
+  camera.camera_matrix = {p2[0], p2[1], p2[2], p2[4], p2[5], p2[6], p2[8], p2[9], p2[10]};
+  camera.projection_matrix = {p2[0], p2[1], p2[2], 0.0,   p2[4], p2[5],
+                              p2[6], 0.0,   p2[8], p2[9], p2[10], 0.0};
+  result.sensors.push_back(camera);
+
+  auto oxts_file = open(directory / "oxts" / (sequence + ".txt"));
+  result.ego_poses = parse_kitti_oxts(oxts_file);
+  const size_t frame_count = result.ego_poses.size();
+
+  // the labels are not provided for the testing split
+  const auto label_path = directory / "label_02" / (sequence + ".txt");
+  if (std::filesystem::exists(label_path)) {
+    auto label_file = open(label_path);
+    result.annotations = parse_kitti_labels(label_file, inverse(base_to_camera0_inv), frame_count);
+  }
+
+  for (size_t i = 0; i < frame_count; ++i) {
+    const double time = static_cast<double>(i) * kitti_frame_period;
+    const auto lidar_path = directory / "velodyne" / sequence / frame_name(i, ".bin");
+    if (std::filesystem::exists(lidar_path)) {
+      result.data.push_back(SensorData{time, lidar.name, lidar_path.string()});
+    }
+    const auto image_path = directory / "image_02" / sequence / frame_name(i, ".png");
+    if (std::filesystem::exists(image_path)) {
+      result.data.push_back(SensorData{time, camera.name, image_path.string()});
+    }
+  }
+  return result;
+}
+}  // namespace autoware::dataset_reader
@@ -0,0 +1,51 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef KITTI_LOADER_HPP_
+#define KITTI_LOADER_HPP_
+
+#include "sequence.hpp"
+
+#include <istream>
+#include <map>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_reader
+{
+// the frame rate of the KITTI tracking benchmark
+constexpr double kitti_frame_period = 0.1;
+
+// Parses the lines of `<key>: <values>` or `<key> <values>`.
+std::map<std::string, std::vector<double>> parse_kitti_calibration(std::istream & stream);
+
+// Parses the tracking labels in the rectified camera 0, and converts them into base_link.
+// A frame is created for every frame up to frame_count, including the frames without objects.
+std::vector<AnnotationFrame> parse_kitti_labels(
+  std::istream & stream, const Transform & base_to_camera, size_t frame_count);
+
+// Parses the OXTS data into the poses of the IMU, which is regarded as base_link, in the ENU
+// frame of the Mercator projection whose origin is at the first pose.
+std::vector<EgoPose> parse_kitti_oxts(std::istream & stream);
+
+// the KITTI class name into the class name of Autoware, or empty to ignore
+std::string to_kitti_label(const std::string & type);
+
+// Loads `<root>/<split>/{calib,oxts,label_02,velodyne,image_02}` of the sequence of the tracking
+// benchmark. Throws std::runtime_error on failure.
+Sequence load_kitti_tracking(
+  const std::string & root, const std::string & split, const std::string & sequence);
+}  // namespace autoware::dataset_reader
+
+#endif  // KITTI_LOADER_HPP_
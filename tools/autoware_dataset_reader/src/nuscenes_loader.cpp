@@ -0,0 +1,221 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "nuscenes_loader.hpp"
+
+#include <nlohmann/json.hpp>
+
+#include <algorithm>
+#include <filesystem>
+#include <fstream>
+#include <map>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <unordered_map>
+#include <vector>
+
+namespace autoware::dataset_reader
+{
+namespace
+{
+using Table = std::unordered_map<std::string, nlohmann::json>;
+
+Table load_table(const std::filesystem::path & directory, const std::string & name)
+{
+  const auto path = directory / (name + ".json");
+  std::ifstream file(path);
+  if (!file) {
+    throw std::runtime_error("failed to open " + path.string());
+  }
+
+  Table table;
+  try {
+    for (auto & record : nlohmann::json::parse(file)) {
+      const auto token = record.at("token").get<std::string>();
+      table.emplace(token, std::move(record));
+    }
+  } catch (const nlohmann::json::exception & e) {
+    throw std::runtime_error("failed to parse " + path.string() + ": " + e.what());
+  }
+  return table;
+}
+
+const nlohmann::json & find(const Table & table, const std::string & token)
+{
+  const auto it = table.find(token);
+  if (it == table.end()) {
+    throw std::runtime_error("unknown token " + token);
+  }
+  return it->second;
+}
+
+// nuScenes stores the rotation as w, x, y, z
+Transform to_transform(const nlohmann::json & record)
+{
+  const auto & q = record.at("rotation");
+  const auto & t = record.at("translation");
+  return from_quaternion(
+    q.at(0).get<double>(), q.at(1).get<double>(), q.at(2).get<double>(), q.at(3).get<double>(),
+    {t.at(0).get<double>(), t.at(1).get<double>(), t.at(2).get<double>()});
+}
+
+double to_seconds(const nlohmann::json & timestamp)
+{
+  return static_cast<double>(timestamp.get<int64_t>()) * 1e-6;
+}
+}  // namespace
+
+std::string to_nuscenes_label(const std::string & category)
+{
+  static const std::vector<std::pair<std::string, std::string>> prefixes = {
+    {"vehicle.car", "CAR"},
+    {"vehicle.emergency", "CAR"},
+    {"vehicle.truck", "TRUCK"},
+    {"vehicle.construction", "TRUCK"},
+    {"vehicle.trailer", "TRAILER"},
+    {"vehicle.bus", "BUS"},
+    {"vehicle.motorcycle", "MOTORCYCLE"},
+    {"vehicle.bicycle", "BICYCLE"},
+    {"human.pedestrian", "PEDESTRIAN"},
+  };
+  for (const auto & [prefix, label] : prefixes) {
+    if (category.rfind(prefix, 0) == 0) {
+      return label;
+    }
+  }
+  return "UNKNOWN";
+}
+
+Sequence load_nuscenes_scene(
+  const std::string & dataroot, const std::string & version, const std::string & scene,
+  const std::vector<std::string> & channels)
+{
+  const auto directory = std::filesystem::path(dataroot) / version;
+  const auto scenes = load_table(directory, "scene");
+  const auto samples = load_table(directory, "sample");
+  const auto sample_data = load_table(directory, "sample_data");
+  const auto calibrated_sensors = load_table(directory, "calibrated_sensor");
+  const auto sensors = load_table(directory, "sensor");
+  const auto ego_poses = load_table(directory, "ego_pose");
+  const auto sample_annotations = load_table(directory, "sample_annotation");
+  const auto instances = load_table(directory, "instance");
+  const auto categories = load_table(directory, "category");
+
+  std::string scene_token;
+  for (const auto & [token, record] : scenes) {
+    if (record.at("name").get<std::string>() == scene) {
+      scene_token = token;
+    }
+  }
+  if (scene_token.empty()) {
+    throw std::runtime_error("unknown scene " + scene);
+  }
+
+  Sequence result;
+  std::map<std::string, double> sample_times;
+  for (const auto & [token, record] : samples) {
+    if (record.at("scene_token").get<std::string>() == scene_token) {
+      sample_times[token] = to_seconds(record.at("timestamp"));
+    }
+  }
+
+  // the sensors are found from the sample data, since the calibration may differ between the logs
+  std::set<std::string> ego_pose_tokens;
+  std::map<std::string, Sensor> sensor_map;
+  for (const auto & [token, record] : sample_data) {
+    if (sample_times.count(record.at("sample_token").get<std::string>()) == 0) {
+      continue;
+    }
+    const auto & calibration =
+      find(calibrated_sensors, record.at("calibrated_sensor_token").get<std::string>());
+    const auto & sensor = find(sensors, calibration.at("sensor_token").get<std::string>());
+    const auto channel = sensor.at("channel").get<std::string>();
+    if (std::find(channels.begin(), channels.end(), channel) == channels.end()) {
+      continue;
+    }
+
+    if (sensor_map.count(channel) == 0) {
+      const auto modality = sensor.at("modality").get<std::string>();
+      if (modality != "lidar" && modality != "camera") {
+        throw std::runtime_error("unsupported modality " + modality + " of " + channel);
+      }
+      Sensor & s = sensor_map[channel];
+      s.name = channel;
+      s.frame_id = channel;
+      s.base_to_sensor = to_transform(calibration);
+      if (modality == "lidar") {
+        s.type = SensorType::LIDAR;
+        s.point_stride = 5;
+      } else {
+        s.type = SensorType::CAMERA;
+        const auto & k = calibration.at("camera_intrinsic");
+        for (size_t i = 0; i < 9; ++i) {
+          s.camera_matrix[i] = k.at(i / 3).at(i % 3).get<double>();
+          s.projection_matrix[i / 3 * 4 + i % 3] = s.camera_matrix[i];
+        }
+      }
+    }
+
+    const auto filename = record.at("filename").get<std::string>();
+    const auto path = std::filesystem::path(dataroot) / filename;
+    result.data.push_back(SensorData{to_seconds(record.at("timestamp")), channel, path.string()});
+    ego_pose_tokens.insert(record.at("ego_pose_token").get<std::string>());
+  }
+  for (const auto & channel : channels) {
+    if (sensor_map.count(channel) == 0) {
+      throw std::runtime_error("no data of " + channel + " in " + scene);
+    }
+    result.sensors.push_back(sensor_map.at(channel));
+  }
+
+  for (const auto & token : ego_pose_tokens) {
+    const auto & record = find(ego_poses, token);
+    result.ego_poses.push_back(EgoPose{to_seconds(record.at("timestamp")), to_transform(record)});
+  }
+
+  std::map<std::string, AnnotationFrame> frames;
+  for (const auto & [token, time] : sample_times) {
+    frames[token] = AnnotationFrame{time, "map", {}};
+  }
+  for (const auto & [token, record] : sample_annotations) {
+    const auto it = frames.find(record.at("sample_token").get<std::string>());
+    if (it == frames.end()) {
+      continue;
+    }
+    const auto instance_token = record.at("instance_token").get<std::string>();
+    const auto & instance = find(instances, instance_token);
+    const auto & category = find(categories, instance.at("category_token").get<std::string>());
+
+    // the size of nuScenes is width, length and height
+    const auto & size = record.at("size");
+    Annotation annotation;
+    annotation.id = instance_token;
+    annotation.label = to_nuscenes_label(category.at("name").get<std::string>());
+    annotation.pose = to_transform(record);
+    annotation.size = {
+      size.at(1).get<double>(), size.at(0).get<double>(), size.at(2).get<double>()};
+    it->second.objects.push_back(annotation);
+  }
+  for (auto & [token, frame] : frames) {
+    result.annotations.push_back(std::move(frame));
+  }
+
+  const auto by_time = [](const auto & a, const auto & b) { return a.time < b.time; };
+  std::stable_sort(result.data.begin(), result.data.end(), by_time);
+  std::stable_sort(result.ego_poses.begin(), result.ego_poses.end(), by_time);
+  std::stable_sort(result.annotations.begin(), result.annotations.end(), by_time);
+  return result;
+}
+}  // namespace autoware::dataset_reader
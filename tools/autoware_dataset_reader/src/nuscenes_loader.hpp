@@ -0,0 +1,36 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef NUSCENES_LOADER_HPP_
+#define NUSCENES_LOADER_HPP_
+
+#include "sequence.hpp"
+
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_reader
+{
+// the category name of nuScenes into the class name of Autoware
+std::string to_nuscenes_label(const std::string & category);
+
+// Loads the scene from the tables in `<dataroot>/<version>`. All the sweeps of the channels are
+// loaded, and the annotations of the key frames are in the map frame.
+// Throws std::runtime_error on failure.
+Sequence load_nuscenes_scene(
+  const std::string & dataroot, const std::string & version, const std::string & scene,
+  const std::vector<std::string> & channels);
+}  // namespace autoware::dataset_reader
+
+#endif  // NUSCENES_LOADER_HPP_
@@ -0,0 +1,175 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "sequence.hpp"
+
+#include <cmath>
+#include <cstring>
+#include <fstream>
+#include <iterator>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_reader
+{
+Transform compose(const Transform & a, const Transform & b)
+{
+  Transform transform;
+  for (size_t i = 0; i < 3; ++i) {
+    for (size_t j = 0; j < 3; ++j) {
+      double sum = 0.0;
+      for (size_t k = 0; k < 3; ++k) {
+        sum += a.rotation[i * 3 + k] * b.rotation[k * 3 + j];
+      }
+      transform.rotation[i * 3 + j] = sum;
+    }
+  }
+  transform.translation = transform_point(a, b.translation);
+  return transform;
+}
+
+Transform inverse(const Transform & transform)
+{
+  Transform inverted;
+  for (size_t i = 0; i < 3; ++i) {
+    for (size_t j = 0; j < 3; ++j) {
+      inverted.rotation[i * 3 + j] = transform.rotation[j * 3 + i];
+    }
+  }
+  for (size_t i = 0; i < 3; ++i) {
+    double sum = 0.0;
+    for (size_t k = 0; k < 3; ++k) {
+      sum += inverted.rotation[i * 3 + k] * transform.translation[k];
+    }
+    inverted.translation[i] = -sum;
+  }
+  return inverted;
+}
+
+std::array<double, 3> transform_point(
+  const Transform & transform, const std::array<double, 3> & point)
+{
+  std::array<double, 3> result{};
+  for (size_t i = 0; i < 3; ++i) {
+    result[i] = transform.translation[i];
+    for (size_t k = 0; k < 3; ++k) {
+      result[i] += transform.rotation[i * 3 + k] * point[k];
+    }
+  }
+  return result;
+}
+
+Transform from_matrix(const std::vector<double> & matrix)
+{
+  if (matrix.size() < 12) {
+    throw std::invalid_argument("the matrix must have 12 elements at least");
+  }
+  Transform transform;
+  for (size_t i = 0; i < 3; ++i) {
+    for (size_t j = 0; j < 3; ++j) {
+      transform.rotation[i * 3 + j] = matrix[i * 4 + j];
+    }
+    transform.translation[i] = matrix[i * 4 + 3];
+  }
+  return transform;
+}
+
+Transform from_quaternion(
+  const double w, const double x, const double y, const double z,
+  const std::array<double, 3> & translation)
+{
+  const double norm = std::sqrt(w * w + x * x + y * y + z * z);
+  const double qw = w / norm;
+  const double qx = x / norm;
+  const double qy = y / norm;
+  const double qz = z / norm;
+  Transform transform;
+  auto & r = transform.rotation;
+  r[0] = 1.0 - 2.0 * (qy * qy + qz * qz);
+  r[1] = 2.0 * (qx * qy - qz * qw);
+  r[2] = 2.0 * (qx * qz + qy * qw);
+  r[3] = 2.0 * (qx * qy + qz * qw);
+  r[4] = 1.0 - 2.0 * (qx * qx + qz * qz);
+  r[5] = 2.0 * (qy * qz - qx * qw);
+  r[6] = 2.0 * (qx * qz - qy * qw);
+  r[7] = 2.0 * (qy * qz + qx * qw);
+  r[8] = 1.0 - 2.0 * (qx * qx + qy * qy);
+  transform.translation = translation;
+  return transform;
+}
+
+std::array<double, 4> to_quaternion(const Transform & transform)
+{
+  const auto & r = transform.rotation;
+  const double trace = r[0] + r[4] + r[8];
+  double w, x, y, z;
+  if (0.0 < trace) {
+    const double s = std::sqrt(trace + 1.0) * 2.0;
+    w = 0.25 * s;
+    x = (r[7] - r[5]) / s;
+    y = (r[2] - r[6]) / s;
+    z = (r[3] - r[1]) / s;
+  } else if (r[4] < r[0] && r[8] < r[0]) {
+    const double s = std::sqrt(1.0 + r[0] - r[4] - r[8]) * 2.0;
+    w = (r[7] - r[5]) / s;
+    x = 0.25 * s;
+    y = (r[1] + r[3]) / s;
+    z = (r[2] + r[6]) / s;
+  } else if (r[8] < r[4]) {
+    const double s = std::sqrt(1.0 + r[4] - r[0] - r[8]) * 2.0;
+    w = (r[2] - r[6]) / s;
+    x = (r[1] + r[3]) / s;
+    y = 0.25 * s;
+    z = (r[5] + r[7]) / s;
+  } else {
+    const double s = std::sqrt(1.0 + r[8] - r[0] - r[4]) * 2.0;
+    w = (r[3] - r[1]) / s;
+    x = (r[2] + r[6]) / s;
+    y = (r[5] + r[7]) / s;
+    z = 0.25 * s;
+  }
+  return {w, x, y, z};
+}
+
+double to_yaw(const Transform & transform)
+{
+  return std::atan2(transform.rotation[3], transform.rotation[0]);
+}
+
+std::vector<Point> read_point_cloud(const std::string & path, const size_t stride)
+{
+  if (stride < 4) {
+    throw std::invalid_argument("the stride of the point cloud must be 4 or more");
+  }
+  std::ifstream file(path, std::ios::binary);
+  if (!file) {
+    throw std::runtime_error("failed to open " + path);
+  }
+  const std::vector<char> bytes(
+    (std::istreambuf_iterator<char>(file)), std::istreambuf_iterator<char>());
+  const size_t point_size = stride * sizeof(float);
+  if (bytes.size() % point_size != 0) {
+    throw std::runtime_error("the size is not a multiple of the stride: " + path);
+  }
+
+  std::vector<Point> points(bytes.size() / point_size);
+  std::vector<float> values(stride);
+  for (size_t i = 0; i < points.size(); ++i) {
+    std::memcpy(values.data(), bytes.data() + i * point_size, point_size);
+    points[i] = Point{values[0], values[1], values[2], values[3]};
+  }
+  return points;
+}
+}  // namespace autoware::dataset_reader
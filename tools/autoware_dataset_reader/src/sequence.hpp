@@ -0,0 +1,118 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef SEQUENCE_HPP_
+#define SEQUENCE_HPP_
+
+#include <array>
+#include <cstddef>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_reader
+{
+// a rigid transform which maps the points in the child frame into the parent frame
+struct Transform
+{
+  // row-major
+  std::array<double, 9> rotation{1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0};
+  std::array<double, 3> translation{};
+};
+
+Transform compose(const Transform & a, const Transform & b);
+Transform inverse(const Transform & transform);
+std::array<double, 3> transform_point(
+  const Transform & transform, const std::array<double, 3> & point);
+// from the upper 3x4 of a row-major homogeneous matrix
+Transform from_matrix(const std::vector<double> & matrix);
+Transform from_quaternion(
+  double w, double x, double y, double z, const std::array<double, 3> & translation);
+// w, x, y, z
+std::array<double, 4> to_quaternion(const Transform & transform);
+// yaw of the x axis projected on the xy plane
+double to_yaw(const Transform & transform);
+
+enum class SensorType { LIDAR, CAMERA };
+
+struct Sensor
+{
+  std::string name;
+  SensorType type{};
+  std::string frame_id;
+  // the pose of the sensor in base_link
+  Transform base_to_sensor;
+  // the number of the float32 values per point of the lidar
+  size_t point_stride{};
+  // the intrinsics of the camera, row-major
+  std::array<double, 9> camera_matrix{};
+  std::array<double, 12> projection_matrix{};
+};
+
+// a file of a sensor
+struct SensorData
+{
+  double time{};
+  std::string sensor;
+  std::string path;
+};
+
+struct Annotation
+{
+  std::string id;
+  // the class name of Autoware, e.g. CAR
+  std::string label;
+  // the pose of the center of the box
+  Transform pose;
+  // length, width and height
+  std::array<double, 3> size{};
+};
+
+struct AnnotationFrame
+{
+  double time{};
+  std::string frame_id;
+  std::vector<Annotation> objects;
+};
+
+struct EgoPose
+{
+  double time{};
+  // the pose of base_link in the map
+  Transform pose;
+};
+
+// everything in a sequence, sorted by the time [s]
+struct Sequence
+{
+  std::vector<Sensor> sensors;
+  std::vector<SensorData> data;
+  std::vector<AnnotationFrame> annotations;
+  std::vector<EgoPose> ego_poses;
+};
+
+struct Point
+{
+  float x;
+  float y;
+  float z;
+  float intensity;
+};
+
+// Reads the binary point cloud of KITTI and nuScenes, which consists of `stride` float32 values
+// per point starting with x, y, z and intensity. Throws std::runtime_error on failure.
+std::vector<Point> read_point_cloud(const std::string & path, size_t stride);
+}  // namespace autoware::dataset_reader
+
+#endif  // SEQUENCE_HPP_
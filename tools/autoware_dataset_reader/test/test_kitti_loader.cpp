@@ -0,0 +1,96 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "kitti_loader.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+#include <sstream>
+
+namespace autoware::dataset_reader
+{
+TEST(KittiLoader, ParseCalibration)
+{
+  std::istringstream stream(
+    "P2: 1 0 2 3 0 1 4 5 0 0 1 6\n"
+    "R_rect 1 0 0 0 1 0 0 0 1\n"
+    "\n");
+  const auto calibration = parse_kitti_calibration(stream);
+  ASSERT_EQ(calibration.size(), 2u);
+  ASSERT_EQ(calibration.at("P2").size(), 12u);
+  EXPECT_DOUBLE_EQ(calibration.at("P2")[3], 3.0);
+  EXPECT_EQ(calibration.at("R_rect").size(), 9u);
+}
+
+TEST(KittiLoader, Label)
+{
+  EXPECT_EQ(to_kitti_label("Van"), "CAR");
+  EXPECT_EQ(to_kitti_label("Tram"), "BUS");
+  EXPECT_EQ(to_kitti_label("Person_sitting"), "PEDESTRIAN");
+  EXPECT_EQ(to_kitti_label("Cyclist"), "BICYCLE");
+  EXPECT_EQ(to_kitti_label("DontCare"), "");
+}
+
+TEST(KittiLoader, ParseLabels)
+{
+  // the camera looks forward with the x axis to the right and the y axis down
+  Transform base_to_camera;
+  base_to_camera.rotation = {0, 0, 1, -1, 0, 0, 0, -1, 0};
+  base_to_camera.translation = {0.0, 0.0, 1.5};
+
+  std::istringstream stream(
+    "0 3 Car 0 0 -1.5 100 100 200 200 1.6 1.8 4.0 2.0 1.5 10.0 0.0\n"
+    "0 -1 DontCare -1 -1 -10 0 0 10 10 -1 -1 -1 -1000 -1000 -1000 -10\n"
+    "2 3 Car 0 0 -1.5 100 100 200 200 1.6 1.8 4.0 2.0 1.5 12.0 -1.5707963267948966\n"
+    "5 4 Car 0 0 -1.5 100 100 200 200 1.6 1.8 4.0 2.0 1.5 12.0 0.0\n");
+  const auto frames = parse_kitti_labels(stream, base_to_camera, 3);
+  ASSERT_EQ(frames.size(), 3u);
+  EXPECT_NEAR(frames[2].time, 0.2, 1e-9);
+  EXPECT_EQ(frames[1].objects.size(), 0u);
+
+  ASSERT_EQ(frames[0].objects.size(), 1u);
+  const auto & object = frames[0].objects.front();
+  EXPECT_EQ(object.id, "3");
+  EXPECT_EQ(object.label, "CAR");
+  EXPECT_NEAR(object.pose.translation[0], 10.0, 1e-9);
+  EXPECT_NEAR(object.pose.translation[1], -2.0, 1e-9);
+  EXPECT_NEAR(object.pose.translation[2], 0.8, 1e-9);
+  EXPECT_NEAR(object.size[0], 4.0, 1e-9);
+  EXPECT_NEAR(object.size[1], 1.8, 1e-9);
+  EXPECT_NEAR(object.size[2], 1.6, 1e-9);
+  // rotation_y of zero points to the x axis of the camera, i.e. the right
+  EXPECT_NEAR(to_yaw(object.pose), -M_PI / 2.0, 1e-9);
+
+  ASSERT_EQ(frames[2].objects.size(), 1u);
+  EXPECT_NEAR(to_yaw(frames[2].objects.front().pose), 0.0, 1e-9);
+}
+
+TEST(KittiLoader, ParseOxts)
+{
+  // 1e-5 degree of the latitude is about 1.1 m
+  std::istringstream stream(
+    "49.0 8.4 110.0 0.0 0.0 0.5\n"
+    "49.00001 8.4 111.0 0.0 0.0 1.0\n");
+  const auto poses = parse_kitti_oxts(stream);
+  ASSERT_EQ(poses.size(), 2u);
+  EXPECT_NEAR(poses[0].pose.translation[0], 0.0, 1e-6);
+  EXPECT_NEAR(poses[0].pose.translation[1], 0.0, 1e-6);
+  EXPECT_NEAR(to_yaw(poses[0].pose), 0.5, 1e-9);
+  EXPECT_NEAR(poses[1].time, 0.1, 1e-9);
+  EXPECT_NEAR(poses[1].pose.translation[0], 0.0, 1e-6);
+  EXPECT_NEAR(poses[1].pose.translation[1], 1.11, 0.01);
+  EXPECT_NEAR(poses[1].pose.translation[2], 1.0, 1e-9);
+}
+}  // namespace autoware::dataset_reader
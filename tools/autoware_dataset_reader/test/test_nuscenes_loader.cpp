@@ -0,0 +1,152 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "nuscenes_loader.hpp"
+
+#include <gtest/gtest.h>
+
+#include <filesystem>
+#include <fstream>
+#include <stdexcept>
+#include <string>
+
+namespace autoware::dataset_reader
+{
+class NuscenesLoader : public testing::Test
+{
+protected:
+  void SetUp() override
+  {
+    dataroot_ = std::filesystem::temp_directory_path() / "test_autoware_dataset_reader";
+    const auto directory = dataroot_ / "v1.0-mini";
+    std::filesystem::create_directories(directory);
+    const auto write = [&](const std::string & name, const std::string & content) {
+      std::ofstream(directory / (name + ".json")) << content;
+    };
+
+    write("scene", R"([{"token": "s0", "name": "scene-0061"}, {"token": "s1", "name": "other"}])");
+    write(
+      "sample",
+      R"([{"token": "k0", "timestamp": 1000000, "scene_token": "s0"},
+          {"token": "k1", "timestamp": 1500000, "scene_token": "s0"},
+          {"token": "k2", "timestamp": 9000000, "scene_token": "s1"}])");
+    write(
+      "sensor",
+      R"([{"token": "n0", "channel": "LIDAR_TOP", "modality": "lidar"},
+          {"token": "n1", "channel": "CAM_FRONT", "modality": "camera"}])");
+    write(
+      "calibrated_sensor",
+      R"([{"token": "c0", "sensor_token": "n0", "translation": [1, 0, 2], "rotation": [1, 0, 0, 0],
+           "camera_intrinsic": []},
+          {"token": "c1", "sensor_token": "n1", "translation": [2, 0, 1], "rotation": [1, 0, 0, 0],
+           "camera_intrinsic": [[1000, 0, 800], [0, 1000, 450], [0, 0, 1]]}])");
+    write(
+      "ego_pose",
+      R"([{"token": "e0", "timestamp": 1000000, "translation": [10, 20, 0],
+           "rotation": [1, 0, 0, 0]},
+          {"token": "e1", "timestamp": 1050000, "translation": [10.5, 20, 0],
+           "rotation": [1, 0, 0, 0]},
+          {"token": "e2", "timestamp": 9000000, "translation": [0, 0, 0],
+           "rotation": [1, 0, 0, 0]}])");
+    write(
+      "sample_data",
+      R"([{"token": "d0", "sample_token": "k0", "ego_pose_token": "e0",
+           "calibrated_sensor_token": "c0", "timestamp": 1000000,
+           "filename": "samples/LIDAR_TOP/0.pcd.bin"},
+          {"token": "d1", "sample_token": "k0", "ego_pose_token": "e1",
+           "calibrated_sensor_token": "c1", "timestamp": 1050000,
+           "filename": "samples/CAM_FRONT/0.jpg"},
+          {"token": "d2", "sample_token": "k2", "ego_pose_token": "e2",
+           "calibrated_sensor_token": "c0", "timestamp": 9000000,
+           "filename": "samples/LIDAR_TOP/1.pcd.bin"}])");
+    write(
+      "category",
+      R"([{"token": "g0", "name": "vehicle.car"},
+          {"token": "g1", "name": "movable_object.barrier"}])");
+    write(
+      "instance",
+      R"([{"token": "i0", "category_token": "g0"}, {"token": "i1", "category_token": "g1"}])");
+    write(
+      "sample_annotation",
+      R"([{"token": "a0", "sample_token": "k1", "instance_token": "i0",
+           "translation": [15, 20, 1], "size": [1.8, 4.5, 1.5], "rotation": [1, 0, 0, 0]},
+          {"token": "a1", "sample_token": "k1", "instance_token": "i1",
+           "translation": [16, 22, 1], "size": [0.5, 2.0, 1.0], "rotation": [1, 0, 0, 0]}])");
+  }
+
+  void TearDown() override { std::filesystem::remove_all(dataroot_); }
+
+  std::filesystem::path dataroot_;
+};
+
+TEST(NuscenesLabel, Label)
+{
+  EXPECT_EQ(to_nuscenes_label("vehicle.car"), "CAR");
+  EXPECT_EQ(to_nuscenes_label("vehicle.bus.rigid"), "BUS");
+  EXPECT_EQ(to_nuscenes_label("human.pedestrian.adult"), "PEDESTRIAN");
+  EXPECT_EQ(to_nuscenes_label("movable_object.trafficcone"), "UNKNOWN");
+}
+
+TEST_F(NuscenesLoader, LoadScene)
+{
+  const auto sequence =
+    load_nuscenes_scene(dataroot_.string(), "v1.0-mini", "scene-0061", {"LIDAR_TOP", "CAM_FRONT"});
+
+  ASSERT_EQ(sequence.sensors.size(), 2u);
+  EXPECT_EQ(sequence.sensors[0].name, "LIDAR_TOP");
+  EXPECT_TRUE(sequence.sensors[0].type == SensorType::LIDAR);
+  EXPECT_EQ(sequence.sensors[0].point_stride, 5u);
+  EXPECT_DOUBLE_EQ(sequence.sensors[0].base_to_sensor.translation[2], 2.0);
+  EXPECT_TRUE(sequence.sensors[1].type == SensorType::CAMERA);
+  EXPECT_DOUBLE_EQ(sequence.sensors[1].camera_matrix[2], 800.0);
+  EXPECT_DOUBLE_EQ(sequence.sensors[1].projection_matrix[6], 450.0);
+
+  // the data of the other scene is not loaded
+  ASSERT_EQ(sequence.data.size(), 2u);
+  EXPECT_DOUBLE_EQ(sequence.data[0].time, 1.0);
+  EXPECT_EQ(sequence.data[1].sensor, "CAM_FRONT");
+  EXPECT_EQ(sequence.data[1].path, (dataroot_ / "samples/CAM_FRONT/0.jpg").string());
+
+  ASSERT_EQ(sequence.ego_poses.size(), 2u);
+  EXPECT_DOUBLE_EQ(sequence.ego_poses[1].time, 1.05);
+  EXPECT_DOUBLE_EQ(sequence.ego_poses[1].pose.translation[0], 10.5);
+
+  ASSERT_EQ(sequence.annotations.size(), 2u);
+  EXPECT_EQ(sequence.annotations[0].objects.size(), 0u);
+  ASSERT_EQ(sequence.annotations[1].objects.size(), 2u);
+  EXPECT_EQ(sequence.annotations[1].frame_id, "map");
+  for (const auto & object : sequence.annotations[1].objects) {
+    if (object.id == "i0") {
+      EXPECT_EQ(object.label, "CAR");
+      EXPECT_DOUBLE_EQ(object.size[0], 4.5);
+      EXPECT_DOUBLE_EQ(object.size[1], 1.8);
+    } else {
+      EXPECT_EQ(object.label, "UNKNOWN");
+    }
+  }
+}
+
+TEST_F(NuscenesLoader, Error)
+{
+  EXPECT_THROW(
+    load_nuscenes_scene(dataroot_.string(), "v1.0-mini", "unknown", {"LIDAR_TOP"}),
+    std::runtime_error);
+  EXPECT_THROW(
+    load_nuscenes_scene(dataroot_.string(), "v1.0-mini", "scene-0061", {"RADAR_FRONT"}),
+    std::runtime_error);
+  EXPECT_THROW(
+    load_nuscenes_scene(dataroot_.string(), "v1.0-trainval", "scene-0061", {"LIDAR_TOP"}),
+    std::runtime_error);
+}
+}  // namespace autoware::dataset_reader
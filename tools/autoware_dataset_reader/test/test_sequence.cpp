@@ -0,0 +1,88 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "sequence.hpp"
+
+#include <gtest/gtest.h>
+
+#include <cmath>
+#include <cstdio>
+#include <fstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::dataset_reader
+{
+TEST(Sequence, ComposeAndInverse)
+{
+  const auto a = from_quaternion(std::cos(0.25), 0.0, 0.0, std::sin(0.25), {1.0, 2.0, 3.0});
+  const auto b = from_quaternion(std::cos(0.1), std::sin(0.1), 0.0, 0.0, {-1.0, 0.5, 0.0});
+  const auto identity = compose(compose(a, b), inverse(compose(a, b)));
+  for (size_t i = 0; i < 9; ++i) {
+    EXPECT_NEAR(identity.rotation[i], i % 4 == 0 ? 1.0 : 0.0, 1e-9);
+  }
+  for (size_t i = 0; i < 3; ++i) {
+    EXPECT_NEAR(identity.translation[i], 0.0, 1e-9);
+  }
+
+  const auto point = transform_point(compose(a, b), {1.0, 0.0, 0.0});
+  const auto expected = transform_point(a, transform_point(b, {1.0, 0.0, 0.0}));
+  for (size_t i = 0; i < 3; ++i) {
+    EXPECT_NEAR(point[i], expected[i], 1e-9);
+  }
+}
+
+TEST(Sequence, Quaternion)
+{
+  const auto transform = from_quaternion(std::cos(0.4), 0.0, 0.0, std::sin(0.4), {});
+  EXPECT_NEAR(to_yaw(transform), 0.8, 1e-9);
+
+  const auto q = to_quaternion(transform);
+  EXPECT_NEAR(q[0], std::cos(0.4), 1e-9);
+  EXPECT_NEAR(q[1], 0.0, 1e-9);
+  EXPECT_NEAR(q[2], 0.0, 1e-9);
+  EXPECT_NEAR(q[3], std::sin(0.4), 1e-9);
+}
+
+TEST(Sequence, FromMatrix)
+{
+  const auto transform = from_matrix({0, -1, 0, 1, 1, 0, 0, 2, 0, 0, 1, 3});
+  const auto point = transform_point(transform, {1.0, 0.0, 0.0});
+  EXPECT_NEAR(point[0], 1.0, 1e-9);
+  EXPECT_NEAR(point[1], 3.0, 1e-9);
+  EXPECT_NEAR(point[2], 3.0, 1e-9);
+  EXPECT_THROW(from_matrix({1.0, 0.0}), std::invalid_argument);
+}
+
+TEST(Sequence, ReadPointCloud)
+{
+  const std::string path = "/tmp/test_autoware_dataset_reader.bin";
+  const std::vector<float> values = {1, 2, 3, 4, 0, 5, 6, 7, 8, 0};
+  {
+    std::ofstream file(path, std::ios::binary);
+    file.write(reinterpret_cast<const char *>(values.data()), values.size() * sizeof(float));
+  }
+
+  const auto points = read_point_cloud(path, 5);
+  ASSERT_EQ(points.size(), 2u);
+  EXPECT_FLOAT_EQ(points[1].x, 5.0f);
+  EXPECT_FLOAT_EQ(points[1].intensity, 8.0f);
+  EXPECT_THROW(read_point_cloud(path, 3), std::invalid_argument);
+  EXPECT_THROW(read_point_cloud(path, 4), std::runtime_error);
+  std::remove(path.c_str());
+
+  EXPECT_THROW(read_point_cloud(path, 4), std::runtime_error);
+}
+}  // namespace autoware::dataset_reader
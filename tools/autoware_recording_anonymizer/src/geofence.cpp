@@ -0,0 +1,120 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "geofence.hpp"
+
+#include <nlohmann/json.hpp>
+
+#include <cmath>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::recording_anonymizer
+{
+namespace
+{
+GeoZone to_zone(const nlohmann::json & ring)
+{
+  GeoZone zone;
+  for (const auto & position : ring) {
+    if (!position.is_array() || position.size() < 2) {
+      throw std::invalid_argument("a position must have longitude and latitude");
+    }
+    // the position of GeoJSON is in the order of longitude and latitude
+    zone.push_back(GeoVertex{position[1].get<double>(), position[0].get<double>()});
+  }
+  if (zone.size() < 3) {
+    throw std::invalid_argument("a zone must have three or more vertices");
+  }
+  return zone;
+}
+}  // namespace
+
+bool is_inside(const GeoZone & zone, const double latitude, const double longitude)
+{
+  // ray casting along the longitude axis
+  bool inside = false;
+  for (size_t i = 0, j = zone.size() - 1; i < zone.size(); j = i++) {
+    const auto & a = zone.at(i);
+    const auto & b = zone.at(j);
+    if ((a.latitude > latitude) == (b.latitude > latitude)) {
+      continue;
+    }
+    const double crossing = a.longitude + (latitude - a.latitude) * (b.longitude - a.longitude) /
+                                            (b.latitude - a.latitude);
+    if (longitude < crossing) {
+      inside = !inside;
+    }
+  }
+  return inside;
+}
+
+bool is_inside_any(
+  const std::vector<GeoZone> & zones, const double latitude, const double longitude)
+{
+  for (const auto & zone : zones) {
+    if (is_inside(zone, latitude, longitude)) {
+      return true;
+    }
+  }
+  return false;
+}
+
+double coarsen(const double degree, const double resolution)
+{
+  return (std::floor(degree / resolution) + 0.5) * resolution;
+}
+
+CellVariance calc_cell_variance(const double latitude, const double resolution)
+{
+  // 1 degree of the latitude is about 111 km
+  constexpr double meter_per_degree = 111000.0;
+  const double north_size = resolution * meter_per_degree;
+  const double east_size = north_size * std::cos(latitude * M_PI / 180.0);
+  return CellVariance{east_size * east_size / 12.0, north_size * north_size / 12.0};
+}
+
+std::vector<GeoZone> parse_geojson_zones(const std::string & geojson)
+{
+  nlohmann::json j;
+  try {
+    j = nlohmann::json::parse(geojson);
+  } catch (const nlohmann::json::parse_error & e) {
+    throw std::invalid_argument(std::string("invalid GeoJSON: ") + e.what());
+  }
+  if (j.value("type", "") != "FeatureCollection" || !j.contains("features")) {
+    throw std::invalid_argument("GeoJSON must be a FeatureCollection");
+  }
+
+  std::vector<GeoZone> zones;
+  for (const auto & feature : j.at("features")) {
+    if (!feature.contains("geometry") || feature.at("geometry").is_null()) {
+      continue;
+    }
+    const auto & geometry = feature.at("geometry");
+    const auto type = geometry.value("type", "");
+    const auto & coordinates = geometry.at("coordinates");
+    // holes are ignored, so a hole is treated as a part of the zone
+    if (type == "Polygon") {
+      zones.push_back(to_zone(coordinates.at(0)));
+    } else if (type == "MultiPolygon") {
+      for (const auto & polygon : coordinates) {
+        zones.push_back(to_zone(polygon.at(0)));
+      }
+    }
+  }
+  return zones;
+}
+}  // namespace autoware::recording_anonymizer
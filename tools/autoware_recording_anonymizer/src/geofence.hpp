@@ -0,0 +1,54 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef GEOFENCE_HPP_
+#define GEOFENCE_HPP_
+
+#include <string>
+#include <vector>
+
+namespace autoware::recording_anonymizer
+{
+struct GeoVertex
+{
+  double latitude;
+  double longitude;
+};
+
+// exterior ring of a zone, the last vertex may or may not be equal to the first one
+using GeoZone = std::vector<GeoVertex>;
+
+// Zones are small enough to treat latitude and longitude as planar coordinates.
+bool is_inside(const GeoZone & zone, double latitude, double longitude);
+bool is_inside_any(const std::vector<GeoZone> & zones, double latitude, double longitude);
+
+// snaps a coordinate to the center of the grid cell of the given resolution
+double coarsen(double degree, double resolution);
+
+struct CellVariance
+{
+  double east;   // [m^2]
+  double north;  // [m^2]
+};
+
+// the variance of a uniform distribution over the grid cell at the latitude, whose east-west size
+// shrinks by the cosine of the latitude
+CellVariance calc_cell_variance(double latitude, double resolution);
+
+// reads the exterior rings of the Polygon and MultiPolygon features of a GeoJSON string
+// throws std::invalid_argument when the string is not a valid FeatureCollection
+std::vector<GeoZone> parse_geojson_zones(const std::string & geojson);
+}  // namespace autoware::recording_anonymizer
+
+#endif  // GEOFENCE_HPP_
@@ -0,0 +1,76 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "gnss_geofence_filter_node.hpp"
+
+#include <fstream>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+
+namespace autoware::recording_anonymizer
+{
+GnssGeofenceFilter::GnssGeofenceFilter(const rclcpp::NodeOptions & options)
+: Node("gnss_geofence_filter", options)
+{
+  // Parameter
+  const auto mode = declare_parameter<std::string>("outside_zone_mode");
+  if (mode != "drop" && mode != "coarsen") {
+    throw std::invalid_argument("outside_zone_mode must be drop or coarsen: " + mode);
+  }
+  param_.drop_outside = mode == "drop";
+  param_.coarse_resolution = declare_parameter<double>("coarse_resolution");
+
+  const auto geofence_file = declare_parameter<std::string>("geofence_file");
+  std::ifstream ifs(geofence_file);
+  if (!ifs) {
+    throw std::runtime_error("failed to open the geofence file: " + geofence_file);
+  }
+  std::stringstream ss;
+  ss << ifs.rdbuf();
+  zones_ = parse_geojson_zones(ss.str());
+  RCLCPP_INFO(get_logger(), "%zu zones are loaded from %s", zones_.size(), geofence_file.c_str());
+
+  // Subscriber
+  sub_fix_ = create_subscription<NavSatFix>(
+    "input/fix", rclcpp::QoS{1}, [this](const NavSatFix::ConstSharedPtr msg) { on_fix(msg); });
+
+  // Publisher
+  pub_fix_ = create_publisher<NavSatFix>("output/fix", rclcpp::QoS{1});
+}
+
+void GnssGeofenceFilter::on_fix(const NavSatFix::ConstSharedPtr msg)
+{
+  if (is_inside_any(zones_, msg->latitude, msg->longitude)) {
+    pub_fix_->publish(*msg);
+    return;
+  }
+  if (param_.drop_outside) {
+    return;
+  }
+
+  NavSatFix coarse = *msg;
+  coarse.latitude = coarsen(msg->latitude, param_.coarse_resolution);
+  coarse.longitude = coarsen(msg->longitude, param_.coarse_resolution);
+  // the covariance is in ENU, so the first one is of the longitude
+  const auto variance = calc_cell_variance(coarse.latitude, param_.coarse_resolution);
+  coarse.position_covariance = {variance.east, 0.0, 0.0, 0.0, variance.north, 0.0, 0.0, 0.0,
+                                msg->position_covariance[8]};
+  coarse.position_covariance_type = NavSatFix::COVARIANCE_TYPE_APPROXIMATED;
+  pub_fix_->publish(coarse);
+}
+}  // namespace autoware::recording_anonymizer
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::recording_anonymizer::GnssGeofenceFilter)
@@ -0,0 +1,57 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef GNSS_GEOFENCE_FILTER_NODE_HPP_
+#define GNSS_GEOFENCE_FILTER_NODE_HPP_
+
+#include "geofence.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <sensor_msgs/msg/nav_sat_fix.hpp>
+
+#include <vector>
+
+namespace autoware::recording_anonymizer
+{
+using sensor_msgs::msg::NavSatFix;
+
+struct GnssGeofenceFilterParam
+{
+  // drop the fix or coarsen it when the fix is outside the zones
+  bool drop_outside;
+  double coarse_resolution;
+};
+
+class GnssGeofenceFilter : public rclcpp::Node
+{
+public:
+  explicit GnssGeofenceFilter(const rclcpp::NodeOptions & options);
+
+private:
+  // Parameter
+  GnssGeofenceFilterParam param_;
+  std::vector<GeoZone> zones_;
+
+  // Subscriber
+  rclcpp::Subscription<NavSatFix>::SharedPtr sub_fix_;
+
+  // Publisher
+  rclcpp::Publisher<NavSatFix>::SharedPtr pub_fix_;
+
+  void on_fix(const NavSatFix::ConstSharedPtr msg);
+};
+}  // namespace autoware::recording_anonymizer
+
+#endif  // GNSS_GEOFENCE_FILTER_NODE_HPP_
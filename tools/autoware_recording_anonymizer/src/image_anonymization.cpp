@@ -0,0 +1,84 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "image_anonymization.hpp"
+
+#include <opencv2/imgproc.hpp>
+
+#include <algorithm>
+#include <cmath>
+#include <stdexcept>
+#include <string>
+
+namespace autoware::recording_anonymizer
+{
+AnonymizationMethod to_anonymization_method(const std::string & name)
+{
+  if (name == "gaussian_blur") {
+    return AnonymizationMethod::GAUSSIAN_BLUR;
+  }
+  if (name == "pixelate") {
+    return AnonymizationMethod::PIXELATE;
+  }
+  if (name == "fill") {
+    return AnonymizationMethod::FILL;
+  }
+  throw std::invalid_argument("unknown anonymization method: " + name);
+}
+
+cv::Rect expand_region(const cv::Rect & region, const double ratio, const cv::Size & image_size)
+{
+  const double width = region.width * ratio;
+  const double height = region.height * ratio;
+  const double center_x = region.x + region.width / 2.0;
+  const double center_y = region.y + region.height / 2.0;
+  const cv::Rect expanded(
+    static_cast<int>(std::floor(center_x - width / 2.0)),
+    static_cast<int>(std::floor(center_y - height / 2.0)), static_cast<int>(std::ceil(width)),
+    static_cast<int>(std::ceil(height)));
+  return expanded & cv::Rect(cv::Point(0, 0), image_size);
+}
+
+void anonymize_region(
+  cv::Mat & image, const cv::Rect & region, const AnonymizationMethod method, const int strength)
+{
+  const auto clipped = region & cv::Rect(0, 0, image.cols, image.rows);
+  if (clipped.empty()) {
+    return;
+  }
+  cv::Mat roi = image(clipped);
+
+  switch (method) {
+    case AnonymizationMethod::GAUSSIAN_BLUR: {
+      // the kernel size of cv::GaussianBlur must be odd
+      const int kernel_size = std::max(1, strength) | 1;
+      cv::GaussianBlur(roi, roi, cv::Size(kernel_size, kernel_size), 0.0);
+      break;
+    }
+    case AnonymizationMethod::PIXELATE: {
+      const int cell_size = std::max(1, strength);
+      cv::Mat small;
+      cv::resize(
+        roi, small,
+        cv::Size(std::max(1, roi.cols / cell_size), std::max(1, roi.rows / cell_size)), 0.0, 0.0,
+        cv::INTER_AREA);
+      cv::resize(small, roi, roi.size(), 0.0, 0.0, cv::INTER_NEAREST);
+      break;
+    }
+    case AnonymizationMethod::FILL:
+      roi.setTo(cv::Scalar::all(0));
+      break;
+  }
+}
+}  // namespace autoware::recording_anonymizer
@@ -0,0 +1,37 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef IMAGE_ANONYMIZATION_HPP_
+#define IMAGE_ANONYMIZATION_HPP_
+
+#include <opencv2/core.hpp>
+
+#include <string>
+
+namespace autoware::recording_anonymizer
+{
+enum class AnonymizationMethod { GAUSSIAN_BLUR, PIXELATE, FILL };
+
+// throws std::invalid_argument for an unknown name
+AnonymizationMethod to_anonymization_method(const std::string & name);
+
+// enlarges the region by the ratio around its center and clips it to the image
+cv::Rect expand_region(const cv::Rect & region, double ratio, const cv::Size & image_size);
+
+// strength is the kernel size of the blur or the cell size of the pixelation in pixels
+void anonymize_region(
+  cv::Mat & image, const cv::Rect & region, AnonymizationMethod method, int strength);
+}  // namespace autoware::recording_anonymizer
+
+#endif  // IMAGE_ANONYMIZATION_HPP_
@@ -0,0 +1,82 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "image_anonymizer_node.hpp"
+
+#if __has_include(<cv_bridge/cv_bridge.hpp>)
+#include <cv_bridge/cv_bridge.hpp>  // for ROS 2 Jazzy or newer
+#else
+#include <cv_bridge/cv_bridge.h>  // for ROS 2 Humble or older
+#endif
+
+#include <functional>
+#include <memory>
+#include <string>
+
+namespace autoware::recording_anonymizer
+{
+ImageAnonymizer::ImageAnonymizer(const rclcpp::NodeOptions & options)
+: Node("image_anonymizer", options)
+{
+  using std::placeholders::_1;
+  using std::placeholders::_2;
+
+  // Parameter
+  param_.method = to_anonymization_method(declare_parameter<std::string>("method"));
+  param_.strength = static_cast<int>(declare_parameter<int>("strength"));
+  param_.expansion_ratio = declare_parameter<double>("expansion_ratio");
+  const auto image_transport = declare_parameter<std::string>("image_transport");
+  const auto sync_queue_size = static_cast<uint32_t>(declare_parameter<int>("sync_queue_size"));
+
+  // Subscriber
+  // images without the detection result are never published, so that nothing leaks out when the
+  // detector is late or down
+  sub_image_.subscribe(this, "input/image", image_transport, rmw_qos_profile_sensor_data);
+  sub_rois_.subscribe(this, "input/rois", rclcpp::QoS{10}.get_rmw_qos_profile());
+  sync_ = std::make_shared<Sync>(SyncPolicy(sync_queue_size), sub_image_, sub_rois_);
+  sync_->registerCallback(std::bind(&ImageAnonymizer::on_image, this, _1, _2));
+
+  // Publisher
+  pub_image_ =
+    image_transport::create_publisher(this, "output/image", rclcpp::QoS{1}.get_rmw_qos_profile());
+}
+
+void ImageAnonymizer::on_image(
+  const Image::ConstSharedPtr image_msg, const DetectedObjectsWithFeature::ConstSharedPtr rois_msg)
+{
+  cv_bridge::CvImagePtr cv_ptr;
+  try {
+    cv_ptr = cv_bridge::toCvCopy(image_msg, image_msg->encoding);
+  } catch (const cv_bridge::Exception & e) {
+    RCLCPP_ERROR_THROTTLE(
+      get_logger(), *get_clock(), 5000, "failed to convert the image: %s", e.what());
+    return;
+  }
+
+  for (const auto & object : rois_msg->feature_objects) {
+    const auto & roi = object.feature.roi;
+    const cv::Rect region(
+      static_cast<int>(roi.x_offset), static_cast<int>(roi.y_offset), static_cast<int>(roi.width),
+      static_cast<int>(roi.height));
+    anonymize_region(
+      cv_ptr->image, expand_region(region, param_.expansion_ratio, cv_ptr->image.size()),
+      param_.method, param_.strength);
+  }
+
+  pub_image_.publish(cv_ptr->toImageMsg());
+}
+}  // namespace autoware::recording_anonymizer
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::recording_anonymizer::ImageAnonymizer)
@@ -0,0 +1,71 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef IMAGE_ANONYMIZER_NODE_HPP_
+#define IMAGE_ANONYMIZER_NODE_HPP_
+
+#include "image_anonymization.hpp"
+
+#include <image_transport/image_transport.hpp>
+#include <image_transport/subscriber_filter.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <sensor_msgs/msg/image.hpp>
+#include <tier4_perception_msgs/msg/detected_objects_with_feature.hpp>
+
+#include <message_filters/subscriber.h>
+#include <message_filters/sync_policies/exact_time.h>
+#include <message_filters/synchronizer.h>
+
+#include <memory>
+
+namespace autoware::recording_anonymizer
+{
+using sensor_msgs::msg::Image;
+using tier4_perception_msgs::msg::DetectedObjectsWithFeature;
+
+struct ImageAnonymizerParam
+{
+  AnonymizationMethod method;
+  int strength;
+  double expansion_ratio;
+};
+
+class ImageAnonymizer : public rclcpp::Node
+{
+public:
+  explicit ImageAnonymizer(const rclcpp::NodeOptions & options);
+
+private:
+  using SyncPolicy = message_filters::sync_policies::ExactTime<Image, DetectedObjectsWithFeature>;
+  using Sync = message_filters::Synchronizer<SyncPolicy>;
+
+  // Parameter
+  ImageAnonymizerParam param_;
+
+  // Subscriber
+  image_transport::SubscriberFilter sub_image_;
+  message_filters::Subscriber<DetectedObjectsWithFeature> sub_rois_;
+  std::shared_ptr<Sync> sync_;
+
+  // Publisher
+  image_transport::Publisher pub_image_;
+
+  void on_image(
+    const Image::ConstSharedPtr image_msg,
+    const DetectedObjectsWithFeature::ConstSharedPtr rois_msg);
+};
+}  // namespace autoware::recording_anonymizer
+
+#endif  // IMAGE_ANONYMIZER_NODE_HPP_
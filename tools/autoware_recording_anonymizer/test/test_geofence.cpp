@@ -0,0 +1,131 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "geofence.hpp"
+
+#include <gtest/gtest.h>
+
+#include <stdexcept>
+#include <string>
+
+using autoware::recording_anonymizer::GeoZone;
+
+namespace
+{
+GeoZone create_square_zone()
+{
+  return GeoZone{{35.0, 139.0}, {35.0, 139.1}, {35.1, 139.1}, {35.1, 139.0}};
+}
+}  // namespace
+
+TEST(Geofence, IsInside)
+{
+  using autoware::recording_anonymizer::is_inside;
+  const auto zone = create_square_zone();
+  EXPECT_TRUE(is_inside(zone, 35.05, 139.05));
+  EXPECT_FALSE(is_inside(zone, 35.05, 139.15));
+  EXPECT_FALSE(is_inside(zone, 34.95, 139.05));
+}
+
+TEST(Geofence, IsInsideConcaveZone)
+{
+  using autoware::recording_anonymizer::is_inside;
+  // L shape, the upper right quarter is outside
+  const GeoZone zone{{0.0, 0.0}, {0.0, 2.0}, {1.0, 2.0}, {1.0, 1.0}, {2.0, 1.0}, {2.0, 0.0}};
+  EXPECT_TRUE(is_inside(zone, 0.5, 1.5));
+  EXPECT_TRUE(is_inside(zone, 1.5, 0.5));
+  EXPECT_FALSE(is_inside(zone, 1.5, 1.5));
+}
+
+TEST(Geofence, IsInsideAny)
+{
+  using autoware::recording_anonymizer::is_inside_any;
+  EXPECT_FALSE(is_inside_any({}, 35.05, 139.05));
+  EXPECT_TRUE(is_inside_any({create_square_zone()}, 35.05, 139.05));
+}
+
+TEST(Geofence, Coarsen)
+{
+  using autoware::recording_anonymizer::coarsen;
+  EXPECT_NEAR(coarsen(35.6789, 0.01), 35.675, 1e-9);
+  EXPECT_NEAR(coarsen(-35.6789, 0.01), -35.675, 1e-9);
+  EXPECT_NEAR(coarsen(139.7001, 0.1), 139.75, 1e-9);
+}
+
+TEST(Geofence, CalcCellVariance)
+{
+  using autoware::recording_anonymizer::calc_cell_variance;
+  // the cell of 0.01 degree is 1110 m in the north-south
+  const auto equator = calc_cell_variance(0.0, 0.01);
+  EXPECT_NEAR(equator.north, 1110.0 * 1110.0 / 12.0, 1e-6);
+  EXPECT_NEAR(equator.east, equator.north, 1e-6);
+
+  // the east-west size is a half at 60 degrees
+  const auto north = calc_cell_variance(60.0, 0.01);
+  EXPECT_NEAR(north.north, equator.north, 1e-6);
+  EXPECT_NEAR(north.east, 555.0 * 555.0 / 12.0, 1e-6);
+  EXPECT_NEAR(calc_cell_variance(-60.0, 0.01).east, north.east, 1e-6);
+}
+
+TEST(Geofence, ParseGeoJsonZones)
+{
+  const std::string geojson = R"({
+    "type": "FeatureCollection",
+    "features": [
+      {
+        "type": "Feature",
+        "properties": {"name": "depot"},
+        "geometry": {
+          "type": "Polygon",
+          "coordinates": [
+            [[139.0, 35.0], [139.1, 35.0], [139.1, 35.1], [139.0, 35.1], [139.0, 35.0]]
+          ]
+        }
+      },
+      {
+        "type": "Feature",
+        "properties": {},
+        "geometry": {
+          "type": "MultiPolygon",
+          "coordinates": [
+            [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]],
+            [[[2.0, 2.0], [3.0, 2.0], [3.0, 3.0], [2.0, 2.0]]]
+          ]
+        }
+      },
+      {
+        "type": "Feature",
+        "properties": {},
+        "geometry": {"type": "Point", "coordinates": [0.0, 0.0]}
+      }
+    ]
+  })";
+
+  const auto zones = autoware::recording_anonymizer::parse_geojson_zones(geojson);
+  ASSERT_EQ(zones.size(), 3u);
+  EXPECT_DOUBLE_EQ(zones.at(0).at(1).latitude, 35.0);
+  EXPECT_DOUBLE_EQ(zones.at(0).at(1).longitude, 139.1);
+  EXPECT_TRUE(autoware::recording_anonymizer::is_inside(zones.at(0), 35.05, 139.05));
+}
+
+TEST(Geofence, ParseInvalidGeoJson)
+{
+  using autoware::recording_anonymizer::parse_geojson_zones;
+  EXPECT_THROW(parse_geojson_zones("{"), std::invalid_argument);
+  EXPECT_THROW(parse_geojson_zones(R"({"type": "Feature"})"), std::invalid_argument);
+  EXPECT_THROW(
+    parse_geojson_zones(R"({"type": "FeatureCollection", "features": [{"geometry":
+      {"type": "Polygon", "coordinates": [[[0.0, 0.0], [1.0, 1.0]]]}}]})"),
+    std::invalid_argument);
+}
@@ -0,0 +1,112 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "image_anonymization.hpp"
+
+#include <gtest/gtest.h>
+
+#include <stdexcept>
+
+using autoware::recording_anonymizer::AnonymizationMethod;
+using autoware::recording_anonymizer::anonymize_region;
+using autoware::recording_anonymizer::expand_region;
+
+TEST(ImageAnonymization, ToAnonymizationMethod)
+{
+  using autoware::recording_anonymizer::to_anonymization_method;
+  EXPECT_EQ(to_anonymization_method("gaussian_blur"), AnonymizationMethod::GAUSSIAN_BLUR);
+  EXPECT_EQ(to_anonymization_method("pixelate"), AnonymizationMethod::PIXELATE);
+  EXPECT_EQ(to_anonymization_method("fill"), AnonymizationMethod::FILL);
+  EXPECT_THROW(to_anonymization_method("mosaic"), std::invalid_argument);
+}
+
+TEST(ImageAnonymization, ExpandRegion)
+{
+  const cv::Size image_size(100, 50);
+  EXPECT_EQ(expand_region(cv::Rect(10, 10, 20, 20), 1.5, image_size), cv::Rect(5, 5, 30, 30));
+  EXPECT_EQ(expand_region(cv::Rect(10, 10, 20, 20), 1.0, image_size), cv::Rect(10, 10, 20, 20));
+}
+
+TEST(ImageAnonymization, ExpandRegionClipsAtImageEdges)
+{
+  const cv::Size image_size(100, 50);
+  // top left corner
+  EXPECT_EQ(expand_region(cv::Rect(0, 0, 20, 10), 2.0, image_size), cv::Rect(0, 0, 30, 15));
+  // bottom right corner
+  EXPECT_EQ(expand_region(cv::Rect(90, 40, 10, 10), 2.0, image_size), cv::Rect(85, 35, 15, 15));
+  // outside of the image
+  EXPECT_TRUE(expand_region(cv::Rect(200, 100, 10, 10), 1.5, image_size).empty());
+}
+
+TEST(ImageAnonymization, Fill)
+{
+  cv::Mat image(20, 20, CV_8UC1, cv::Scalar::all(255));
+  anonymize_region(image, cv::Rect(5, 5, 5, 5), AnonymizationMethod::FILL, 0);
+  EXPECT_EQ(image.at<uint8_t>(5, 5), 0);
+  EXPECT_EQ(image.at<uint8_t>(9, 9), 0);
+  EXPECT_EQ(image.at<uint8_t>(4, 4), 255);
+  EXPECT_EQ(image.at<uint8_t>(10, 10), 255);
+  EXPECT_EQ(cv::countNonZero(image), 20 * 20 - 5 * 5);
+}
+
+TEST(ImageAnonymization, ClipsRegionAtImageEdges)
+{
+  cv::Mat image(20, 20, CV_8UC1, cv::Scalar::all(255));
+  anonymize_region(image, cv::Rect(-5, -5, 10, 10), AnonymizationMethod::FILL, 0);
+  EXPECT_EQ(image.at<uint8_t>(0, 0), 0);
+  EXPECT_EQ(image.at<uint8_t>(4, 4), 0);
+  EXPECT_EQ(image.at<uint8_t>(5, 5), 255);
+
+  anonymize_region(image, cv::Rect(15, 15, 10, 10), AnonymizationMethod::FILL, 0);
+  EXPECT_EQ(image.at<uint8_t>(19, 19), 0);
+  EXPECT_EQ(cv::countNonZero(image), 20 * 20 - 2 * 5 * 5);
+
+  // a region outside of the image is ignored
+  EXPECT_NO_THROW(anonymize_region(image, cv::Rect(30, 30, 5, 5), AnonymizationMethod::FILL, 0));
+  EXPECT_EQ(cv::countNonZero(image), 20 * 20 - 2 * 5 * 5);
+}
+
+TEST(ImageAnonymization, Pixelate)
+{
+  // a horizontal gradient
+  cv::Mat image(8, 12, CV_8UC1);
+  for (int col = 0; col < image.cols; ++col) {
+    image.col(col).setTo(cv::Scalar::all(col * 20));
+  }
+  anonymize_region(image, cv::Rect(0, 0, 8, 8), AnonymizationMethod::PIXELATE, 4);
+
+  // every cell of 4x4 pixels has a single value
+  EXPECT_EQ(image.at<uint8_t>(0, 0), image.at<uint8_t>(3, 3));
+  EXPECT_EQ(image.at<uint8_t>(4, 4), image.at<uint8_t>(7, 7));
+  EXPECT_NE(image.at<uint8_t>(0, 3), image.at<uint8_t>(0, 4));
+  // outside of the region
+  EXPECT_EQ(image.at<uint8_t>(0, 8), 160);
+  EXPECT_EQ(image.at<uint8_t>(0, 11), 220);
+}
+
+TEST(ImageAnonymization, GaussianBlur)
+{
+  cv::Mat image(21, 21, CV_8UC1, cv::Scalar::all(0));
+  image.at<uint8_t>(10, 10) = 255;
+  image.at<uint8_t>(0, 0) = 255;
+  // an even strength is rounded up to an odd kernel size
+  anonymize_region(image, cv::Rect(5, 5, 11, 11), AnonymizationMethod::GAUSSIAN_BLUR, 4);
+
+  EXPECT_LT(image.at<uint8_t>(10, 10), 255);
+  EXPECT_GT(image.at<uint8_t>(10, 11), 0);
+  EXPECT_GT(image.at<uint8_t>(11, 10), 0);
+  // outside of the region
+  EXPECT_EQ(image.at<uint8_t>(0, 0), 255);
+  EXPECT_EQ(image.at<uint8_t>(10, 16), 0);
+}
@@ -0,0 +1,251 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "battery_monitor_node.hpp"
+
+#include <algorithm>
+#include <chrono>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::battery_monitor
+{
+namespace
+{
+const std::vector<std::string> signal_names = {
+  "soc", "voltage", "current", "max_cell_temperature", "min_cell_temperature"};
+
+// the serial port is polled faster than the BMS sends the frames so that the buffer never overflows
+constexpr std::chrono::milliseconds serial_period{10};
+}  // namespace
+
+BatteryMonitor::BatteryMonitor(const rclcpp::NodeOptions & node_options)
+: Node("battery_monitor", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.update_rate = declare_parameter<double>("update_rate");
+  node_param_.use_serial = declare_parameter<bool>("serial.enable");
+  node_param_.serial_device = declare_parameter<std::string>("serial.device");
+  node_param_.serial_baud_rate = declare_parameter<int>("serial.baud_rate");
+  node_param_.serial_can_bitrate = declare_parameter<int>("serial.can_bitrate");
+  for (const auto & name : signal_names) {
+    const auto prefix = "signals." + name + ".";
+    CanSignal signal;
+    signal.id = static_cast<uint32_t>(declare_parameter<int>(prefix + "id"));
+    signal.start_bit = static_cast<uint32_t>(declare_parameter<int>(prefix + "start_bit"));
+    signal.length = static_cast<uint32_t>(declare_parameter<int>(prefix + "length"));
+    signal.is_little_endian = declare_parameter<bool>(prefix + "little_endian");
+    signal.is_signed = declare_parameter<bool>(prefix + "signed");
+    signal.scale = declare_parameter<double>(prefix + "scale");
+    signal.offset = declare_parameter<double>(prefix + "offset");
+    node_param_.signals.emplace(name, signal);
+  }
+  node_param_.battery_capacity = declare_parameter<double>("battery_capacity");
+  node_param_.range_window_distance = declare_parameter<double>("range_estimation.window_distance");
+  node_param_.range_min_distance = declare_parameter<double>("range_estimation.min_distance");
+  node_param_.data_timeout = declare_parameter<double>("data_timeout");
+  node_param_.soc_warn = declare_parameter<double>("soc.warn");
+  node_param_.soc_error = declare_parameter<double>("soc.error");
+  node_param_.max_cell_temperature_warn = declare_parameter<double>("cell_temperature.max_warn");
+  node_param_.max_cell_temperature_error = declare_parameter<double>("cell_temperature.max_error");
+  node_param_.min_cell_temperature_warn = declare_parameter<double>("cell_temperature.min_warn");
+
+  // Subscriber
+  if (!node_param_.use_serial) {
+    sub_can_frame_ = create_subscription<can_msgs::msg::Frame>(
+      "input/can_frame", rclcpp::QoS{100},
+      std::bind(&BatteryMonitor::on_can_frame, this, std::placeholders::_1));
+  }
+  sub_velocity_ = create_subscription<autoware_vehicle_msgs::msg::VelocityReport>(
+    "input/velocity_status", rclcpp::QoS{1},
+    std::bind(&BatteryMonitor::on_velocity, this, std::placeholders::_1));
+
+  // Publisher
+  pub_battery_status_ =
+    create_publisher<tier4_vehicle_msgs::msg::BatteryStatus>("output/battery_status", 1);
+  pub_remaining_range_ = create_publisher<autoware_internal_debug_msgs::msg::Float64Stamped>(
+    "output/remaining_range", 1);
+
+  // Serial port
+  if (node_param_.use_serial) {
+    const auto open_command =
+      create_slcan_open_command(static_cast<uint32_t>(node_param_.serial_can_bitrate));
+    if (!open_command) {
+      throw std::invalid_argument(
+        "unsupported CAN bitrate: " + std::to_string(node_param_.serial_can_bitrate));
+    }
+    serial_port_ =
+      std::make_unique<SerialPort>(node_param_.serial_device, node_param_.serial_baud_rate);
+    serial_port_->write(*open_command);
+    serial_timer_ = rclcpp::create_timer(
+      this, get_clock(), serial_period, std::bind(&BatteryMonitor::on_serial_timer, this));
+  }
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.update_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&BatteryMonitor::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("battery_monitor");
+  updater_.add("battery_state", this, &BatteryMonitor::check_battery_state);
+
+  // State
+  range_estimator_ = std::make_unique<RangeEstimator>(
+    node_param_.range_window_distance, node_param_.range_min_distance);
+}
+
+void BatteryMonitor::on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg)
+{
+  if (msg->is_rtr || msg->is_error) {
+    return;
+  }
+  on_frame(msg->id, msg->data.data(), std::min<size_t>(msg->dlc, 8));
+}
+
+void BatteryMonitor::on_serial_timer()
+{
+  const auto bytes = serial_port_->read();
+  for (const auto & frame : slcan_parser_.feed(bytes.data(), bytes.size())) {
+    on_frame(frame.id, frame.data.data(), frame.dlc);
+  }
+}
+
+void BatteryMonitor::on_frame(const uint32_t id, const uint8_t * data, const size_t size)
+{
+  const auto stamp = now();
+  for (const auto & [name, signal] : node_param_.signals) {
+    if (signal.id != id) {
+      continue;
+    }
+    if (const auto value = decode_signal(signal, data, size)) {
+      signal_values_[name] = {*value, stamp};
+    }
+  }
+}
+
+void BatteryMonitor::on_velocity(
+  const autoware_vehicle_msgs::msg::VelocityReport::ConstSharedPtr msg)
+{
+  speed_ = SignalValue{msg->longitudinal_velocity, now()};
+}
+
+void BatteryMonitor::on_timer()
+{
+  const auto stamp = now();
+
+  const auto voltage = get_signal("voltage");
+  const auto current = get_signal("current");
+  const bool has_speed = speed_ && (stamp - speed_->stamp).seconds() <= node_param_.data_timeout;
+  if (voltage && current && has_speed) {
+    range_estimator_->update(stamp.seconds(), *voltage * *current, speed_->value);
+  }
+
+  const auto soc = get_signal("soc");
+  if (!soc) {
+    return;
+  }
+
+  tier4_vehicle_msgs::msg::BatteryStatus battery_status;
+  battery_status.stamp = stamp;
+  battery_status.energy_level = static_cast<float>(*soc);
+  pub_battery_status_->publish(battery_status);
+
+  // the capacity is in kWh, while the estimator works in Wh
+  const double remaining_energy = *soc / 100.0 * node_param_.battery_capacity * 1000.0;
+  if (const auto range = range_estimator_->remaining_range(remaining_energy)) {
+    autoware_internal_debug_msgs::msg::Float64Stamped remaining_range;
+    remaining_range.stamp = stamp;
+    remaining_range.data = *range;
+    pub_remaining_range_->publish(remaining_range);
+  }
+}
+
+std::optional<double> BatteryMonitor::get_signal(const std::string & name) const
+{
+  const auto it = signal_values_.find(name);
+  if (it == signal_values_.end()) {
+    return std::nullopt;
+  }
+  if ((now() - it->second.stamp).seconds() > node_param_.data_timeout) {
+    return std::nullopt;
+  }
+  return it->second.value;
+}
+
+void BatteryMonitor::check_battery_state(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  const auto soc = get_signal("soc");
+  if (!soc) {
+    stat.summary(DiagnosticStatus::STALE, "no data from the BMS");
+    return;
+  }
+
+  stat.addf("soc", "%.1f [%%]", *soc);
+  if (const auto voltage = get_signal("voltage")) {
+    stat.addf("voltage", "%.1f [V]", *voltage);
+  }
+  if (const auto current = get_signal("current")) {
+    stat.addf("current", "%.1f [A]", *current);
+  }
+  if (const auto consumption = range_estimator_->consumption()) {
+    // Wh/m is equal to kWh/km
+    stat.addf("consumption", "%.3f [kWh/km]", *consumption);
+  }
+
+  auto level = DiagnosticStatus::OK;
+  std::vector<std::string> messages;
+  const auto add_message = [&](const auto new_level, const std::string & message) {
+    level = std::max(level, new_level);
+    messages.push_back(message);
+  };
+
+  if (*soc < node_param_.soc_error) {
+    add_message(DiagnosticStatus::ERROR, "critically low state of charge");
+  } else if (*soc < node_param_.soc_warn) {
+    add_message(DiagnosticStatus::WARN, "low state of charge");
+  }
+
+  if (const auto max_temperature = get_signal("max_cell_temperature")) {
+    stat.addf("max_cell_temperature", "%.1f [degC]", *max_temperature);
+    if (*max_temperature > node_param_.max_cell_temperature_error) {
+      add_message(DiagnosticStatus::ERROR, "critically high cell temperature");
+    } else if (*max_temperature > node_param_.max_cell_temperature_warn) {
+      add_message(DiagnosticStatus::WARN, "high cell temperature");
+    }
+  }
+  if (const auto min_temperature = get_signal("min_cell_temperature")) {
+    stat.addf("min_cell_temperature", "%.1f [degC]", *min_temperature);
+    if (*min_temperature < node_param_.min_cell_temperature_warn) {
+      add_message(DiagnosticStatus::WARN, "low cell temperature");
+    }
+  }
+
+  if (messages.empty()) {
+    stat.summary(DiagnosticStatus::OK, "OK");
+    return;
+  }
+  std::string message;
+  for (const auto & m : messages) {
+    message += (message.empty() ? "" : ", ") + m;
+  }
+  stat.summary(level, message);
+}
+}  // namespace autoware::battery_monitor
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::battery_monitor::BatteryMonitor)
@@ -0,0 +1,115 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef BATTERY_MONITOR_NODE_HPP_
+#define BATTERY_MONITOR_NODE_HPP_
+
+#include "range_estimator.hpp"
+#include "serial_port.hpp"
+#include "slcan.hpp"
+
+#include <autoware/can_signal_utils/can_signal.hpp>
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_internal_debug_msgs/msg/float64_stamped.hpp>
+#include <autoware_vehicle_msgs/msg/velocity_report.hpp>
+#include <can_msgs/msg/frame.hpp>
+#include <tier4_vehicle_msgs/msg/battery_status.hpp>
+
+#include <map>
+#include <memory>
+#include <optional>
+#include <string>
+
+namespace autoware::battery_monitor
+{
+using autoware::can_signal_utils::CanSignal;
+using autoware::can_signal_utils::decode_signal;
+
+struct NodeParam
+{
+  double update_rate{};
+  bool use_serial{};
+  std::string serial_device{};
+  int serial_baud_rate{};
+  int serial_can_bitrate{};
+  std::map<std::string, CanSignal> signals{};
+  double battery_capacity{};
+  double range_window_distance{};
+  double range_min_distance{};
+  double data_timeout{};
+  double soc_warn{};
+  double soc_error{};
+  double max_cell_temperature_warn{};
+  double max_cell_temperature_error{};
+  double min_cell_temperature_warn{};
+};
+
+class BatteryMonitor : public rclcpp::Node
+{
+public:
+  explicit BatteryMonitor(const rclcpp::NodeOptions & node_options);
+
+private:
+  struct SignalValue
+  {
+    double value;
+    rclcpp::Time stamp;
+  };
+
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<can_msgs::msg::Frame>::SharedPtr sub_can_frame_;
+  rclcpp::Subscription<autoware_vehicle_msgs::msg::VelocityReport>::SharedPtr sub_velocity_;
+
+  void on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg);
+  void on_frame(uint32_t id, const uint8_t * data, size_t size);
+  void on_velocity(const autoware_vehicle_msgs::msg::VelocityReport::ConstSharedPtr msg);
+
+  // Publisher
+  rclcpp::Publisher<tier4_vehicle_msgs::msg::BatteryStatus>::SharedPtr pub_battery_status_;
+  rclcpp::Publisher<autoware_internal_debug_msgs::msg::Float64Stamped>::SharedPtr
+    pub_remaining_range_;
+
+  // Serial port
+  std::unique_ptr<SerialPort> serial_port_;
+  SlcanParser slcan_parser_;
+  rclcpp::TimerBase::SharedPtr serial_timer_;
+
+  void on_serial_timer();
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_battery_state(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  std::map<std::string, SignalValue> signal_values_;
+  std::optional<SignalValue> speed_;
+  std::unique_ptr<RangeEstimator> range_estimator_;
+
+  // Function
+  std::optional<double> get_signal(const std::string & name) const;
+};
+}  // namespace autoware::battery_monitor
+
+#endif  // BATTERY_MONITOR_NODE_HPP_
@@ -0,0 +1,59 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "range_estimator.hpp"
+
+#include <cmath>
+
+namespace autoware::battery_monitor
+{
+void RangeEstimator::update(const double stamp, const double power, const double speed)
+{
+  const double dt = last_stamp_ ? stamp - *last_stamp_ : 0.0;
+  last_stamp_ = stamp;
+  if (dt <= 0.0) {
+    return;
+  }
+
+  // the energy consumed while stopping is also counted, which is charged to the next distance
+  const Sample sample{power * dt / 3600.0, std::abs(speed) * dt};
+  samples_.push_back(sample);
+  total_energy_ += sample.energy;
+  total_distance_ += sample.distance;
+
+  while (samples_.size() > 1 && total_distance_ - samples_.front().distance >= window_distance_) {
+    total_energy_ -= samples_.front().energy;
+    total_distance_ -= samples_.front().distance;
+    samples_.pop_front();
+  }
+}
+
+std::optional<double> RangeEstimator::consumption() const
+{
+  if (total_distance_ < min_distance_ || total_distance_ <= 0.0) {
+    return std::nullopt;
+  }
+  return total_energy_ / total_distance_;
+}
+
+std::optional<double> RangeEstimator::remaining_range(const double remaining_energy) const
+{
+  const auto wh_per_m = consumption();
+  // the regeneration exceeds the consumption, so the range cannot be estimated
+  if (!wh_per_m || *wh_per_m <= 0.0) {
+    return std::nullopt;
+  }
+  return remaining_energy / *wh_per_m;
+}
+}  // namespace autoware::battery_monitor
@@ -0,0 +1,58 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef RANGE_ESTIMATOR_HPP_
+#define RANGE_ESTIMATOR_HPP_
+
+#include <deque>
+#include <optional>
+
+namespace autoware::battery_monitor
+{
+/**
+ * @brief estimates the energy consumption per distance from the recent driving
+ */
+class RangeEstimator
+{
+public:
+  RangeEstimator(const double window_distance, const double min_distance)
+  : window_distance_(window_distance), min_distance_(min_distance)
+  {
+  }
+
+  // power is positive when discharging
+  void update(double stamp, double power, double speed);
+
+  // [Wh/m], nullopt until the vehicle drives min_distance
+  [[nodiscard]] std::optional<double> consumption() const;
+  // [m]
+  [[nodiscard]] std::optional<double> remaining_range(double remaining_energy) const;
+
+private:
+  struct Sample
+  {
+    double energy;
+    double distance;
+  };
+
+  double window_distance_;
+  double min_distance_;
+  std::optional<double> last_stamp_;
+  std::deque<Sample> samples_;
+  double total_energy_{0.0};
+  double total_distance_{0.0};
+};
+}  // namespace autoware::battery_monitor
+
+#endif  // RANGE_ESTIMATOR_HPP_
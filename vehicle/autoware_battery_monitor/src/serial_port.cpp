@@ -0,0 +1,102 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "serial_port.hpp"
+
+#include <fcntl.h>
+#include <termios.h>
+#include <unistd.h>
+
+#include <array>
+#include <cerrno>
+#include <cstring>
+#include <map>
+#include <stdexcept>
+
+namespace autoware::battery_monitor
+{
+namespace
+{
+speed_t to_speed(const int baud_rate)
+{
+  static const std::map<int, speed_t> speeds = {
+    {9600, B9600},     {19200, B19200},   {38400, B38400},   {57600, B57600},
+    {115200, B115200}, {230400, B230400}, {460800, B460800}, {921600, B921600}};
+  const auto it = speeds.find(baud_rate);
+  if (it == speeds.end()) {
+    throw std::runtime_error("unsupported baud rate: " + std::to_string(baud_rate));
+  }
+  return it->second;
+}
+}  // namespace
+
+SerialPort::SerialPort(const std::string & device, const int baud_rate)
+{
+  const auto speed = to_speed(baud_rate);
+  fd_ = ::open(device.c_str(), O_RDWR | O_NOCTTY | O_NONBLOCK);
+  if (fd_ < 0) {
+    throw std::runtime_error("failed to open " + device + ": " + std::strerror(errno));
+  }
+
+  termios tty{};
+  if (tcgetattr(fd_, &tty) != 0) {
+    ::close(fd_);
+    throw std::runtime_error("failed to get the attributes of " + device);
+  }
+  cfmakeraw(&tty);
+  cfsetispeed(&tty, speed);
+  cfsetospeed(&tty, speed);
+  tty.c_cflag |= CLOCAL | CREAD;
+  tty.c_cflag &= ~(CSTOPB | CRTSCTS);
+  if (tcsetattr(fd_, TCSANOW, &tty) != 0) {
+    ::close(fd_);
+    throw std::runtime_error("failed to set the attributes of " + device);
+  }
+  tcflush(fd_, TCIOFLUSH);
+}
+
+SerialPort::~SerialPort()
+{
+  if (fd_ >= 0) {
+    ::close(fd_);
+  }
+}
+
+std::vector<uint8_t> SerialPort::read()
+{
+  std::vector<uint8_t> data;
+  std::array<uint8_t, 256> buffer{};
+  while (true) {
+    const auto size = ::read(fd_, buffer.data(), buffer.size());
+    if (size <= 0) {
+      // EAGAIN when no more byte is available
+      return data;
+    }
+    data.insert(data.end(), buffer.begin(), buffer.begin() + size);
+  }
+}
+
+void SerialPort::write(const std::string & data)
+{
+  size_t written = 0;
+  while (written < data.size()) {
+    const auto size = ::write(fd_, data.data() + written, data.size() - written);
+    if (size < 0 && errno != EAGAIN) {
+      throw std::runtime_error(
+        std::string("failed to write to the serial port: ") + std::strerror(errno));
+    }
+    written += size > 0 ? static_cast<size_t>(size) : 0;
+  }
+}
+}  // namespace autoware::battery_monitor
@@ -0,0 +1,46 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef SERIAL_PORT_HPP_
+#define SERIAL_PORT_HPP_
+
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#include <vector>
+
+namespace autoware::battery_monitor
+{
+/**
+ * @brief non-blocking serial port in the raw mode of 8N1
+ */
+class SerialPort
+{
+public:
+  // throws std::runtime_error when the device cannot be opened or the baud rate is not supported
+  SerialPort(const std::string & device, int baud_rate);
+  ~SerialPort();
+  SerialPort(const SerialPort &) = delete;
+  SerialPort & operator=(const SerialPort &) = delete;
+
+  // returns the bytes received so far, which may be empty
+  std::vector<uint8_t> read();
+  void write(const std::string & data);
+
+private:
+  int fd_{-1};
+};
+}  // namespace autoware::battery_monitor
+
+#endif  // SERIAL_PORT_HPP_
@@ -0,0 +1,121 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "slcan.hpp"
+
+#include <map>
+
+namespace autoware::battery_monitor
+{
+namespace
+{
+// a line longer than this is broken, since the longest frame is "T" + 8 + 1 + 16 + 4 characters
+constexpr size_t max_line_length = 32;
+
+std::optional<uint32_t> parse_hex(
+  const std::string & text, const size_t position, const size_t size)
+{
+  if (position + size > text.size()) {
+    return std::nullopt;
+  }
+  uint32_t value = 0;
+  for (size_t i = position; i < position + size; ++i) {
+    const char c = text[i];
+    uint32_t digit = 0;
+    if ('0' <= c && c <= '9') {
+      digit = c - '0';
+    } else if ('A' <= c && c <= 'F') {
+      digit = c - 'A' + 10;
+    } else if ('a' <= c && c <= 'f') {
+      digit = c - 'a' + 10;
+    } else {
+      return std::nullopt;
+    }
+    value = (value << 4) | digit;
+  }
+  return value;
+}
+}  // namespace
+
+std::optional<SlcanFrame> parse_slcan_line(const std::string & line)
+{
+  if (line.empty() || (line[0] != 't' && line[0] != 'T')) {
+    return std::nullopt;
+  }
+
+  const size_t id_size = line[0] == 't' ? 3 : 8;
+  const auto id = parse_hex(line, 1, id_size);
+  const auto dlc = parse_hex(line, 1 + id_size, 1);
+  if (!id || !dlc || *dlc > 8) {
+    return std::nullopt;
+  }
+
+  // the timestamp of 4 characters may follow the data
+  const size_t data_position = 2 + id_size;
+  const size_t data_end = data_position + *dlc * 2;
+  if (line.size() != data_end && line.size() != data_end + 4) {
+    return std::nullopt;
+  }
+
+  SlcanFrame frame;
+  frame.id = *id;
+  frame.dlc = static_cast<uint8_t>(*dlc);
+  for (size_t i = 0; i < frame.dlc; ++i) {
+    const auto byte = parse_hex(line, data_position + i * 2, 2);
+    if (!byte) {
+      return std::nullopt;
+    }
+    frame.data[i] = static_cast<uint8_t>(*byte);
+  }
+  return frame;
+}
+
+std::optional<std::string> create_slcan_open_command(const uint32_t bitrate)
+{
+  static const std::map<uint32_t, char> bitrate_codes = {
+    {10000, '0'},  {20000, '1'},  {50000, '2'},  {100000, '3'}, {125000, '4'},
+    {250000, '5'}, {500000, '6'}, {800000, '7'}, {1000000, '8'}};
+  const auto it = bitrate_codes.find(bitrate);
+  if (it == bitrate_codes.end()) {
+    return std::nullopt;
+  }
+  // close the channel which may be left open, set the bitrate and open it
+  return std::string("C\rS") + it->second + "\rO\r";
+}
+
+std::vector<SlcanFrame> SlcanParser::feed(const uint8_t * data, size_t size)
+{
+  std::vector<SlcanFrame> frames;
+  for (size_t i = 0; i < size; ++i) {
+    const char c = static_cast<char>(data[i]);
+    // the error of a command is responded with BEL
+    if (c != '\r' && c != '\a') {
+      if (line_.size() < max_line_length) {
+        line_.push_back(c);
+      } else {
+        is_broken_ = true;
+      }
+      continue;
+    }
+    if (!is_broken_) {
+      if (const auto frame = parse_slcan_line(line_)) {
+        frames.push_back(*frame);
+      }
+    }
+    line_.clear();
+    is_broken_ = false;
+  }
+  return frames;
+}
+}  // namespace autoware::battery_monitor
@@ -0,0 +1,55 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef SLCAN_HPP_
+#define SLCAN_HPP_
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::battery_monitor
+{
+struct SlcanFrame
+{
+  uint32_t id{};
+  uint8_t dlc{};
+  std::array<uint8_t, 8> data{};
+};
+
+// parses a line of the SLCAN (Lawicel) protocol without the trailing CR, e.g. "t3E82E803",
+// returns nullopt for the remote frames, the responses to the commands and the broken lines
+std::optional<SlcanFrame> parse_slcan_line(const std::string & line);
+
+// returns the command to open the channel, nullopt when the bitrate is not supported by SLCAN
+std::optional<std::string> create_slcan_open_command(uint32_t bitrate);
+
+/**
+ * @brief splits the byte stream of a serial port into the SLCAN frames
+ */
+class SlcanParser
+{
+public:
+  std::vector<SlcanFrame> feed(const uint8_t * data, size_t size);
+
+private:
+  std::string line_;
+  bool is_broken_{false};
+};
+}  // namespace autoware::battery_monitor
+
+#endif  // SLCAN_HPP_
@@ -0,0 +1,55 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "range_estimator.hpp"
+
+#include <gtest/gtest.h>
+
+using autoware::battery_monitor::RangeEstimator;
+
+TEST(RangeEstimator, Consumption)
+{
+  RangeEstimator estimator(1000.0, 100.0);
+  EXPECT_FALSE(estimator.consumption());
+
+  // 36 kW at 20 m/s is 0.5 Wh/m
+  for (int i = 0; i <= 10; ++i) {
+    estimator.update(i * 1.0, 36000.0, 20.0);
+  }
+  ASSERT_TRUE(estimator.consumption());
+  EXPECT_NEAR(*estimator.consumption(), 0.5, 1e-9);
+  EXPECT_NEAR(*estimator.remaining_range(10000.0), 20000.0, 1e-6);
+}
+
+TEST(RangeEstimator, Window)
+{
+  RangeEstimator estimator(1000.0, 100.0);
+  for (int i = 0; i <= 100; ++i) {
+    estimator.update(i * 1.0, 36000.0, 20.0);
+  }
+  // only the recent window is used after the consumption changes
+  for (int i = 101; i <= 200; ++i) {
+    estimator.update(i * 1.0, 72000.0, 20.0);
+  }
+  EXPECT_NEAR(*estimator.consumption(), 1.0, 1e-9);
+}
+
+TEST(RangeEstimator, Regeneration)
+{
+  RangeEstimator estimator(1000.0, 100.0);
+  for (int i = 0; i <= 10; ++i) {
+    estimator.update(i * 1.0, -1000.0, 20.0);
+  }
+  EXPECT_FALSE(estimator.remaining_range(10000.0));
+}
@@ -0,0 +1,88 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "slcan.hpp"
+
+#include <gtest/gtest.h>
+
+#include <string>
+
+using autoware::battery_monitor::parse_slcan_line;
+using autoware::battery_monitor::SlcanParser;
+
+TEST(Slcan, ParseStandardFrame)
+{
+  const auto frame = parse_slcan_line("t3E83E8031F");
+  ASSERT_TRUE(frame.has_value());
+  EXPECT_EQ(frame->id, 0x3E8u);
+  EXPECT_EQ(frame->dlc, 3u);
+  EXPECT_EQ(frame->data[0], 0xE8);
+  EXPECT_EQ(frame->data[1], 0x03);
+  EXPECT_EQ(frame->data[2], 0x1F);
+}
+
+TEST(Slcan, ParseExtendedFrameWithTimestamp)
+{
+  const auto frame = parse_slcan_line("T18FF50E52ab12EA60");
+  ASSERT_TRUE(frame.has_value());
+  EXPECT_EQ(frame->id, 0x18FF50E5u);
+  EXPECT_EQ(frame->dlc, 2u);
+  EXPECT_EQ(frame->data[0], 0xAB);
+  EXPECT_EQ(frame->data[1], 0x12);
+}
+
+TEST(Slcan, RejectInvalidLine)
+{
+  // remote frame
+  EXPECT_FALSE(parse_slcan_line("r3E80").has_value());
+  // response to a command
+  EXPECT_FALSE(parse_slcan_line("z").has_value());
+  // short data
+  EXPECT_FALSE(parse_slcan_line("t3E82E8").has_value());
+  // DLC over 8
+  EXPECT_FALSE(parse_slcan_line("t3E89").has_value());
+  // not a hex digit
+  EXPECT_FALSE(parse_slcan_line("t3E81G0").has_value());
+}
+
+TEST(Slcan, OpenCommand)
+{
+  using autoware::battery_monitor::create_slcan_open_command;
+  EXPECT_EQ(create_slcan_open_command(500000).value_or(""), "C\rS6\rO\r");
+  EXPECT_FALSE(create_slcan_open_command(333000).has_value());
+}
+
+TEST(Slcan, SplitStream)
+{
+  SlcanParser parser;
+  const std::string first = "\at3E8101\rt3E";
+  const std::string second = "8102\rz\r";
+
+  const auto frames = parser.feed(reinterpret_cast<const uint8_t *>(first.data()), first.size());
+  ASSERT_EQ(frames.size(), 1u);
+  EXPECT_EQ(frames.front().data[0], 0x01);
+
+  // the frame is split into two reads
+  const auto next_frames =
+    parser.feed(reinterpret_cast<const uint8_t *>(second.data()), second.size());
+  ASSERT_EQ(next_frames.size(), 1u);
+  EXPECT_EQ(next_frames.front().data[0], 0x02);
+
+  // a line which is too long is dropped
+  const std::string broken = std::string(40, '0') + "\rt3E8103\r";
+  const auto broken_frames =
+    parser.feed(reinterpret_cast<const uint8_t *>(broken.data()), broken.size());
+  ASSERT_EQ(broken_frames.size(), 1u);
+  EXPECT_EQ(broken_frames.front().data[0], 0x03);
+}
@@ -0,0 +1,69 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "body_control_frame.hpp"
+
+namespace autoware::body_control_interface
+{
+namespace
+{
+void set_bits(CanData & data, const uint32_t start_bit, const uint32_t length, const uint32_t value)
+{
+  for (uint32_t i = 0; i < length; ++i) {
+    const uint32_t bit = start_bit + i;
+    if (bit >= data.size() * 8) {
+      return;
+    }
+    if ((value >> i) & 1U) {
+      data.at(bit / 8) |= static_cast<uint8_t>(1U << (bit % 8));
+    } else {
+      data.at(bit / 8) &= static_cast<uint8_t>(~(1U << (bit % 8)));
+    }
+  }
+}
+
+bool get_bit(const uint8_t * data, const uint32_t bit)
+{
+  return ((data[bit / 8] >> (bit % 8)) & 1U) != 0;
+}
+}  // namespace
+
+CanData encode_command(const BodyCommand & command, const CommandLayout & layout)
+{
+  CanData data{};
+  set_bits(data, layout.turn_signal_start_bit, 2, static_cast<uint32_t>(command.turn_signal));
+  set_bits(data, layout.hazard_bit, 1, command.hazard ? 1 : 0);
+  set_bits(data, layout.horn_bit, 1, command.horn ? 1 : 0);
+  set_bits(data, layout.counter_start_bit, 4, command.counter);
+  return data;
+}
+
+std::optional<BodyStatus> decode_status(
+  const uint8_t * data, const size_t size, const StatusLayout & layout)
+{
+  for (const auto bit :
+       {layout.turn_left_bit, layout.turn_right_bit, layout.hazard_bit, layout.horn_bit}) {
+    if (bit >= size * 8) {
+      return std::nullopt;
+    }
+  }
+
+  BodyStatus status;
+  status.turn_left = get_bit(data, layout.turn_left_bit);
+  status.turn_right = get_bit(data, layout.turn_right_bit);
+  status.hazard = get_bit(data, layout.hazard_bit);
+  status.horn = get_bit(data, layout.horn_bit);
+  return status;
+}
+}  // namespace autoware::body_control_interface
@@ -0,0 +1,71 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef BODY_CONTROL_FRAME_HPP_
+#define BODY_CONTROL_FRAME_HPP_
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+
+namespace autoware::body_control_interface
+{
+using CanData = std::array<uint8_t, 8>;
+
+enum class TurnSignal : uint8_t { NONE = 0, LEFT = 1, RIGHT = 2 };
+
+struct BodyCommand
+{
+  TurnSignal turn_signal{TurnSignal::NONE};
+  bool hazard{false};
+  bool horn{false};
+  // rolling counter of 4 bits, which lets the BCM detect a frozen sender
+  uint8_t counter{0};
+};
+
+struct BodyStatus
+{
+  bool turn_left{false};
+  bool turn_right{false};
+  bool hazard{false};
+  bool horn{false};
+};
+
+// The bits are numbered from the LSB of the first byte, as the little endian signals of DBC.
+struct CommandLayout
+{
+  // 2 bits of TurnSignal
+  uint32_t turn_signal_start_bit{};
+  uint32_t hazard_bit{};
+  uint32_t horn_bit{};
+  uint32_t counter_start_bit{};
+};
+
+struct StatusLayout
+{
+  uint32_t turn_left_bit{};
+  uint32_t turn_right_bit{};
+  uint32_t hazard_bit{};
+  uint32_t horn_bit{};
+};
+
+CanData encode_command(const BodyCommand & command, const CommandLayout & layout);
+
+// returns nullopt when a bit of the layout is beyond the frame
+std::optional<BodyStatus> decode_status(
+  const uint8_t * data, size_t size, const StatusLayout & layout);
+}  // namespace autoware::body_control_interface
+
+#endif  // BODY_CONTROL_FRAME_HPP_
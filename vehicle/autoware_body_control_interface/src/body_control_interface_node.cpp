@@ -0,0 +1,228 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "body_control_interface_node.hpp"
+
+#include <algorithm>
+
+namespace autoware::body_control_interface
+{
+BodyControlInterface::BodyControlInterface(const rclcpp::NodeOptions & node_options)
+: Node("body_control_interface", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.command_rate = declare_parameter<double>("command_rate");
+  node_param_.command_id = static_cast<uint32_t>(declare_parameter<int>("command.id"));
+  node_param_.command_layout.turn_signal_start_bit =
+    static_cast<uint32_t>(declare_parameter<int>("command.turn_signal_start_bit"));
+  node_param_.command_layout.hazard_bit =
+    static_cast<uint32_t>(declare_parameter<int>("command.hazard_bit"));
+  node_param_.command_layout.horn_bit =
+    static_cast<uint32_t>(declare_parameter<int>("command.horn_bit"));
+  node_param_.command_layout.counter_start_bit =
+    static_cast<uint32_t>(declare_parameter<int>("command.counter_start_bit"));
+  node_param_.status_id = static_cast<uint32_t>(declare_parameter<int>("status.id"));
+  node_param_.status_layout.turn_left_bit =
+    static_cast<uint32_t>(declare_parameter<int>("status.turn_left_bit"));
+  node_param_.status_layout.turn_right_bit =
+    static_cast<uint32_t>(declare_parameter<int>("status.turn_right_bit"));
+  node_param_.status_layout.hazard_bit =
+    static_cast<uint32_t>(declare_parameter<int>("status.hazard_bit"));
+  node_param_.status_layout.horn_bit =
+    static_cast<uint32_t>(declare_parameter<int>("status.horn_bit"));
+  node_param_.horn_max_duration = declare_parameter<double>("horn_max_duration");
+  node_param_.echo_timeout = declare_parameter<double>("echo_timeout");
+  node_param_.status_timeout = declare_parameter<double>("status_timeout");
+
+  // Subscriber
+  sub_turn_indicators_cmd_ = create_subscription<TurnIndicatorsCommand>(
+    "input/turn_indicators_cmd", rclcpp::QoS{1},
+    std::bind(&BodyControlInterface::on_turn_indicators_cmd, this, std::placeholders::_1));
+  sub_hazard_lights_cmd_ = create_subscription<HazardLightsCommand>(
+    "input/hazard_lights_cmd", rclcpp::QoS{1},
+    std::bind(&BodyControlInterface::on_hazard_lights_cmd, this, std::placeholders::_1));
+  sub_horn_cmd_ = create_subscription<BoolStamped>(
+    "input/horn_cmd", rclcpp::QoS{1},
+    std::bind(&BodyControlInterface::on_horn_cmd, this, std::placeholders::_1));
+  sub_can_frame_ = create_subscription<can_msgs::msg::Frame>(
+    "input/can_frame", rclcpp::QoS{100},
+    std::bind(&BodyControlInterface::on_can_frame, this, std::placeholders::_1));
+
+  // Publisher
+  pub_can_frame_ = create_publisher<can_msgs::msg::Frame>("output/can_frame", rclcpp::QoS{10});
+  pub_turn_indicators_status_ =
+    create_publisher<TurnIndicatorsReport>("output/turn_indicators_status", rclcpp::QoS{1});
+  pub_hazard_lights_status_ =
+    create_publisher<HazardLightsReport>("output/hazard_lights_status", rclcpp::QoS{1});
+  pub_horn_status_ = create_publisher<BoolStamped>("output/horn_status", rclcpp::QoS{1});
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.command_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&BodyControlInterface::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("body_control_interface");
+  updater_.add("body_control", this, &BodyControlInterface::check_body_control);
+}
+
+void BodyControlInterface::on_turn_indicators_cmd(const TurnIndicatorsCommand::ConstSharedPtr msg)
+{
+  switch (msg->command) {
+    case TurnIndicatorsCommand::DISABLE:
+      command_.turn_signal = TurnSignal::NONE;
+      break;
+    case TurnIndicatorsCommand::ENABLE_LEFT:
+      command_.turn_signal = TurnSignal::LEFT;
+      break;
+    case TurnIndicatorsCommand::ENABLE_RIGHT:
+      command_.turn_signal = TurnSignal::RIGHT;
+      break;
+    default:
+      // NO_COMMAND keeps the current state
+      break;
+  }
+}
+
+void BodyControlInterface::on_hazard_lights_cmd(const HazardLightsCommand::ConstSharedPtr msg)
+{
+  if (msg->command == HazardLightsCommand::DISABLE) {
+    command_.hazard = false;
+  } else if (msg->command == HazardLightsCommand::ENABLE) {
+    command_.hazard = true;
+  }
+}
+
+void BodyControlInterface::on_horn_cmd(const BoolStamped::ConstSharedPtr msg)
+{
+  if (!msg->data) {
+    command_.horn = false;
+    horn_start_time_.reset();
+    return;
+  }
+  // the horn is not restarted after horn_max_duration until it is released
+  if (!horn_start_time_) {
+    command_.horn = true;
+    horn_start_time_ = now();
+  }
+}
+
+void BodyControlInterface::on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg)
+{
+  if (msg->id != node_param_.status_id || msg->is_rtr || msg->is_error) {
+    return;
+  }
+  const auto status =
+    decode_status(msg->data.data(), std::min<size_t>(msg->dlc, 8), node_param_.status_layout);
+  if (!status) {
+    return;
+  }
+  status_ = *status;
+  status_time_ = now();
+
+  TurnIndicatorsReport turn_indicators_status;
+  turn_indicators_status.stamp = *status_time_;
+  if (status->turn_left && !status->turn_right) {
+    turn_indicators_status.report = TurnIndicatorsReport::ENABLE_LEFT;
+  } else if (status->turn_right && !status->turn_left) {
+    turn_indicators_status.report = TurnIndicatorsReport::ENABLE_RIGHT;
+  } else {
+    turn_indicators_status.report = TurnIndicatorsReport::DISABLE;
+  }
+  pub_turn_indicators_status_->publish(turn_indicators_status);
+
+  HazardLightsReport hazard_lights_status;
+  hazard_lights_status.stamp = *status_time_;
+  hazard_lights_status.report =
+    status->hazard ? HazardLightsReport::ENABLE : HazardLightsReport::DISABLE;
+  pub_hazard_lights_status_->publish(hazard_lights_status);
+
+  BoolStamped horn_status;
+  horn_status.stamp = *status_time_;
+  horn_status.data = status->horn;
+  pub_horn_status_->publish(horn_status);
+}
+
+void BodyControlInterface::on_timer()
+{
+  const auto current_time = now();
+
+  // a stuck horn command is released
+  if (
+    command_.horn && horn_start_time_ &&
+    (current_time - *horn_start_time_).seconds() > node_param_.horn_max_duration) {
+    command_.horn = false;
+    RCLCPP_WARN(get_logger(), "the horn is released after %.1f [s]", node_param_.horn_max_duration);
+  }
+
+  const auto data = encode_command(command_, node_param_.command_layout);
+  can_msgs::msg::Frame frame;
+  frame.header.stamp = current_time;
+  frame.id = node_param_.command_id;
+  frame.is_extended = node_param_.command_id > 0x7FF;
+  frame.dlc = static_cast<uint8_t>(data.size());
+  std::copy(data.begin(), data.end(), frame.data.begin());
+  pub_can_frame_->publish(frame);
+  command_.counter = static_cast<uint8_t>((command_.counter + 1) & 0x0F);
+
+  if (!status_ || is_echoed()) {
+    mismatch_start_time_.reset();
+  } else if (!mismatch_start_time_) {
+    mismatch_start_time_ = current_time;
+  }
+}
+
+bool BodyControlInterface::is_echoed() const
+{
+  const bool is_turn_signal_echoed =
+    status_->turn_left == (command_.turn_signal == TurnSignal::LEFT) &&
+    status_->turn_right == (command_.turn_signal == TurnSignal::RIGHT);
+  // a BCM may report both turn lamps lit while the hazard lights flash them
+  const bool is_lit_by_hazard =
+    command_.hazard && status_->hazard && status_->turn_left && status_->turn_right;
+  return (is_turn_signal_echoed || is_lit_by_hazard) && status_->hazard == command_.hazard &&
+         status_->horn == command_.horn;
+}
+
+void BodyControlInterface::check_body_control(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  if (!status_time_) {
+    stat.summary(DiagnosticStatus::STALE, "no status from the BCM");
+    return;
+  }
+
+  const auto current_time = now();
+  stat.add("turn_left", status_->turn_left);
+  stat.add("turn_right", status_->turn_right);
+  stat.add("hazard", status_->hazard);
+  stat.add("horn", status_->horn);
+
+  if ((current_time - *status_time_).seconds() > node_param_.status_timeout) {
+    stat.summary(DiagnosticStatus::ERROR, "the status from the BCM is lost");
+    return;
+  }
+  if (
+    mismatch_start_time_ &&
+    (current_time - *mismatch_start_time_).seconds() > node_param_.echo_timeout) {
+    stat.summary(DiagnosticStatus::WARN, "the command is not echoed by the BCM");
+    return;
+  }
+  stat.summary(DiagnosticStatus::OK, "OK");
+}
+}  // namespace autoware::body_control_interface
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::body_control_interface::BodyControlInterface)
@@ -0,0 +1,101 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef BODY_CONTROL_INTERFACE_NODE_HPP_
+#define BODY_CONTROL_INTERFACE_NODE_HPP_
+
+#include "body_control_frame.hpp"
+
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <autoware_internal_debug_msgs/msg/bool_stamped.hpp>
+#include <autoware_vehicle_msgs/msg/hazard_lights_command.hpp>
+#include <autoware_vehicle_msgs/msg/hazard_lights_report.hpp>
+#include <autoware_vehicle_msgs/msg/turn_indicators_command.hpp>
+#include <autoware_vehicle_msgs/msg/turn_indicators_report.hpp>
+#include <can_msgs/msg/frame.hpp>
+
+#include <optional>
+
+namespace autoware::body_control_interface
+{
+using autoware_internal_debug_msgs::msg::BoolStamped;
+using autoware_vehicle_msgs::msg::HazardLightsCommand;
+using autoware_vehicle_msgs::msg::HazardLightsReport;
+using autoware_vehicle_msgs::msg::TurnIndicatorsCommand;
+using autoware_vehicle_msgs::msg::TurnIndicatorsReport;
+
+struct NodeParam
+{
+  double command_rate{};
+  uint32_t command_id{};
+  uint32_t status_id{};
+  CommandLayout command_layout{};
+  StatusLayout status_layout{};
+  double horn_max_duration{};
+  double echo_timeout{};
+  double status_timeout{};
+};
+
+class BodyControlInterface : public rclcpp::Node
+{
+public:
+  explicit BodyControlInterface(const rclcpp::NodeOptions & node_options);
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<TurnIndicatorsCommand>::SharedPtr sub_turn_indicators_cmd_;
+  rclcpp::Subscription<HazardLightsCommand>::SharedPtr sub_hazard_lights_cmd_;
+  rclcpp::Subscription<BoolStamped>::SharedPtr sub_horn_cmd_;
+  rclcpp::Subscription<can_msgs::msg::Frame>::SharedPtr sub_can_frame_;
+
+  void on_turn_indicators_cmd(const TurnIndicatorsCommand::ConstSharedPtr msg);
+  void on_hazard_lights_cmd(const HazardLightsCommand::ConstSharedPtr msg);
+  void on_horn_cmd(const BoolStamped::ConstSharedPtr msg);
+  void on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg);
+
+  // Publisher
+  rclcpp::Publisher<can_msgs::msg::Frame>::SharedPtr pub_can_frame_;
+  rclcpp::Publisher<TurnIndicatorsReport>::SharedPtr pub_turn_indicators_status_;
+  rclcpp::Publisher<HazardLightsReport>::SharedPtr pub_hazard_lights_status_;
+  rclcpp::Publisher<BoolStamped>::SharedPtr pub_horn_status_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_body_control(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  BodyCommand command_{};
+  std::optional<rclcpp::Time> horn_start_time_;
+  std::optional<BodyStatus> status_;
+  std::optional<rclcpp::Time> status_time_;
+  // the time since when the status differs from the command
+  std::optional<rclcpp::Time> mismatch_start_time_;
+
+  // Function
+  bool is_echoed() const;
+};
+}  // namespace autoware::body_control_interface
+
+#endif  // BODY_CONTROL_INTERFACE_NODE_HPP_
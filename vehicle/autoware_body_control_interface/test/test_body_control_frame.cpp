@@ -0,0 +1,84 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "body_control_frame.hpp"
+
+#include <gtest/gtest.h>
+
+using autoware::body_control_interface::BodyCommand;
+using autoware::body_control_interface::CommandLayout;
+using autoware::body_control_interface::StatusLayout;
+using autoware::body_control_interface::TurnSignal;
+
+namespace
+{
+CommandLayout create_command_layout()
+{
+  CommandLayout layout;
+  layout.turn_signal_start_bit = 0;
+  layout.hazard_bit = 2;
+  layout.horn_bit = 3;
+  layout.counter_start_bit = 60;
+  return layout;
+}
+
+StatusLayout create_status_layout()
+{
+  StatusLayout layout;
+  layout.turn_left_bit = 0;
+  layout.turn_right_bit = 1;
+  layout.hazard_bit = 2;
+  layout.horn_bit = 8;
+  return layout;
+}
+}  // namespace
+
+TEST(BodyControlFrame, EncodeCommand)
+{
+  using autoware::body_control_interface::encode_command;
+  BodyCommand command;
+  command.turn_signal = TurnSignal::RIGHT;
+  command.horn = true;
+  command.counter = 0x0B;
+
+  const auto data = encode_command(command, create_command_layout());
+  EXPECT_EQ(data.at(0), 0x0A);
+  EXPECT_EQ(data.at(7), 0xB0);
+  for (size_t i = 1; i < 7; ++i) {
+    EXPECT_EQ(data.at(i), 0x00);
+  }
+
+  command.turn_signal = TurnSignal::NONE;
+  command.hazard = true;
+  command.horn = false;
+  command.counter = 0x1F;  // only the lower 4 bits are sent
+  const auto hazard_data = encode_command(command, create_command_layout());
+  EXPECT_EQ(hazard_data.at(0), 0x04);
+  EXPECT_EQ(hazard_data.at(7), 0xF0);
+}
+
+TEST(BodyControlFrame, DecodeStatus)
+{
+  using autoware::body_control_interface::decode_status;
+  const uint8_t data[] = {0x05, 0x01};
+  const auto status = decode_status(data, sizeof(data), create_status_layout());
+  ASSERT_TRUE(status.has_value());
+  EXPECT_TRUE(status->turn_left);
+  EXPECT_FALSE(status->turn_right);
+  EXPECT_TRUE(status->hazard);
+  EXPECT_TRUE(status->horn);
+
+  // the horn bit is beyond the frame
+  EXPECT_FALSE(decode_status(data, 1, create_status_layout()).has_value());
+}
@@ -0,0 +1,148 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "can_log.hpp"
+
+#include <algorithm>
+#include <cctype>
+#include <cstdio>
+#include <sstream>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::can_replay_tester
+{
+namespace
+{
+std::optional<uint32_t> parse_hex(const std::string & text)
+{
+  if (text.empty() || text.size() > 8) {
+    return std::nullopt;
+  }
+  for (const char c : text) {
+    if (!std::isxdigit(static_cast<unsigned char>(c))) {
+      return std::nullopt;
+    }
+  }
+  return static_cast<uint32_t>(std::stoul(text, nullptr, 16));
+}
+}  // namespace
+
+std::optional<CanFrame> parse_frame(const std::string & text)
+{
+  const auto separator = text.find('#');
+  if (separator == std::string::npos) {
+    return std::nullopt;
+  }
+  const auto id_text = text.substr(0, separator);
+  const auto data_text = text.substr(separator + 1);
+
+  // "##" is CAN FD and "#R" is a remote frame
+  if (!data_text.empty() && (data_text.front() == '#' || data_text.front() == 'R')) {
+    return std::nullopt;
+  }
+  // the standard ID has 3 digits and the extended ID has 8 digits
+  if (id_text.size() != 3 && id_text.size() != 8) {
+    return std::nullopt;
+  }
+  const auto id = parse_hex(id_text);
+  if (!id || data_text.size() % 2 != 0 || data_text.size() > 16) {
+    return std::nullopt;
+  }
+
+  CanFrame frame;
+  frame.id = *id;
+  frame.is_extended = id_text.size() == 8;
+  for (size_t i = 0; i < data_text.size(); i += 2) {
+    const auto byte = parse_hex(data_text.substr(i, 2));
+    if (!byte) {
+      return std::nullopt;
+    }
+    frame.data.push_back(static_cast<uint8_t>(*byte));
+  }
+  return frame;
+}
+
+std::optional<CanFrame> parse_candump_line(const std::string & line)
+{
+  std::istringstream stream(line);
+  std::string time_text;
+  std::string interface;
+  std::string frame_text;
+  if (!(stream >> time_text >> interface >> frame_text)) {
+    return std::nullopt;
+  }
+  if (time_text.size() < 3 || time_text.front() != '(' || time_text.back() != ')') {
+    return std::nullopt;
+  }
+
+  auto frame = parse_frame(frame_text);
+  if (!frame) {
+    return std::nullopt;
+  }
+  try {
+    size_t pos = 0;
+    const auto time_value = time_text.substr(1, time_text.size() - 2);
+    frame->time = std::stod(time_value, &pos);
+    if (pos != time_value.size()) {
+      return std::nullopt;
+    }
+  } catch (const std::exception &) {
+    return std::nullopt;
+  }
+  return frame;
+}
+
+std::vector<CanFrame> load_candump(std::istream & stream)
+{
+  std::vector<CanFrame> frames;
+  std::string line;
+  size_t line_number = 0;
+  while (std::getline(stream, line)) {
+    ++line_number;
+    const auto first = line.find_first_not_of(" \t\r");
+    if (first == std::string::npos || line[first] == '#') {
+      continue;
+    }
+    const auto frame = parse_candump_line(line);
+    if (!frame) {
+      // the remote frames and the CAN FD frames are skipped silently
+      if (line.find("#R") != std::string::npos || line.find("##") != std::string::npos) {
+        continue;
+      }
+      throw std::runtime_error("malformed candump line " + std::to_string(line_number));
+    }
+    frames.push_back(*frame);
+  }
+  std::stable_sort(frames.begin(), frames.end(), [](const auto & a, const auto & b) {
+    return a.time < b.time;
+  });
+  return frames;
+}
+
+std::string to_candump_line(const CanFrame & frame, const std::string & interface)
+{
+  char buffer[64];
+  std::snprintf(buffer, sizeof(buffer), "(%.6f) %s ", frame.time, interface.c_str());
+  std::string line = buffer;
+  std::snprintf(buffer, sizeof(buffer), frame.is_extended ? "%08X#" : "%03X#", frame.id);
+  line += buffer;
+  for (const auto byte : frame.data) {
+    std::snprintf(buffer, sizeof(buffer), "%02X", byte);
+    line += buffer;
+  }
+  return line;
+}
+}  // namespace autoware::can_replay_tester
@@ -0,0 +1,49 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef CAN_LOG_HPP_
+#define CAN_LOG_HPP_
+
+#include <cstdint>
+#include <istream>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::can_replay_tester
+{
+struct CanFrame
+{
+  // the time in the log [s]
+  double time{};
+  uint32_t id{};
+  bool is_extended{};
+  std::vector<uint8_t> data;
+};
+
+// Parses a line of `candump -l`, e.g. "(1700000000.123456) can0 123#DEADBEEF".
+// Returns std::nullopt for the comments, the remote frames and the CAN FD frames.
+std::optional<CanFrame> parse_candump_line(const std::string & line);
+
+// Reads the frames of a candump log, sorted by the time.
+// Throws std::runtime_error with the line number on a malformed line.
+std::vector<CanFrame> load_candump(std::istream & stream);
+
+std::string to_candump_line(const CanFrame & frame, const std::string & interface);
+
+// Parses the data of "ID#HEX" into the frame, which is also used for the comparison masks.
+std::optional<CanFrame> parse_frame(const std::string & text);
+}  // namespace autoware::can_replay_tester
+
+#endif  // CAN_LOG_HPP_
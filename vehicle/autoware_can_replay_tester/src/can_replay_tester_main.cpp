@@ -0,0 +1,39 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "can_replay_tester_node.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <chrono>
+#include <cstdlib>
+#include <memory>
+
+// The component cannot return the result to a test script, so the standalone executable exits
+// with the result of the comparison instead of spinning forever.
+int main(int argc, char ** argv)
+{
+  rclcpp::init(argc, argv);
+  rclcpp::executors::SingleThreadedExecutor executor;
+  auto node =
+    std::make_shared<autoware::can_replay_tester::CanReplayTester>(rclcpp::NodeOptions{});
+  executor.add_node(node);
+  while (rclcpp::ok() && !node->is_finished()) {
+    executor.spin_once(std::chrono::milliseconds(100));
+  }
+  executor.remove_node(node);
+  const bool is_passed = node->is_passed();
+  rclcpp::shutdown();
+  return is_passed ? EXIT_SUCCESS : EXIT_FAILURE;
+}
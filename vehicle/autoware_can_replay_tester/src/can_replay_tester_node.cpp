@@ -0,0 +1,224 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "can_replay_tester_node.hpp"
+
+#include <nlohmann/json.hpp>
+
+#include <algorithm>
+#include <filesystem>
+#include <fstream>
+#include <functional>
+#include <set>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::can_replay_tester
+{
+namespace
+{
+std::set<uint32_t> to_id_set(const std::vector<int64_t> & ids)
+{
+  std::set<uint32_t> id_set;
+  for (const auto id : ids) {
+    id_set.insert(static_cast<uint32_t>(id));
+  }
+  return id_set;
+}
+
+std::vector<CanFrame> load_file(const std::string & path)
+{
+  std::ifstream file(path);
+  if (!file) {
+    throw std::runtime_error("failed to open " + path);
+  }
+  return load_candump(file);
+}
+
+nlohmann::json to_json(const CanFrame & frame)
+{
+  return {{"time", frame.time}, {"frame", to_candump_line(frame, "can0")}};
+}
+}  // namespace
+
+CanReplayTester::CanReplayTester(const rclcpp::NodeOptions & node_options)
+: Node("can_replay_tester", node_options)
+{
+  // Parameter
+  node_param_.update_rate = declare_parameter<double>("update_rate");
+  node_param_.start_delay = declare_parameter<double>("start_delay");
+  node_param_.time_tolerance = declare_parameter<double>("time_tolerance");
+  node_param_.output_directory = declare_parameter<std::string>("output_directory");
+
+  const auto log_file = declare_parameter<std::string>("log_file");
+  auto expected_file = declare_parameter<std::string>("expected_file");
+  const auto replay_ids = to_id_set(declare_parameter<std::vector<int64_t>>("replay_ids"));
+  const auto expected_ids = to_id_set(declare_parameter<std::vector<int64_t>>("expected_ids"));
+  for (const auto & text : declare_parameter<std::vector<std::string>>("masks")) {
+    const auto mask = parse_frame(text);
+    if (!mask) {
+      throw std::invalid_argument("invalid mask: " + text);
+    }
+    masks_[mask->id] = mask->data;
+  }
+  if (expected_file.empty()) {
+    expected_file = log_file;
+  }
+
+  // the expected frames are never replayed, even if replay_ids is empty
+  for (const auto & frame : load_file(log_file)) {
+    const bool is_replayed =
+      replay_ids.empty() ? expected_ids.count(frame.id) == 0 : replay_ids.count(frame.id) > 0;
+    if (is_replayed) {
+      replay_frames_.push_back(frame);
+    }
+  }
+  for (const auto & frame : load_file(expected_file)) {
+    if (expected_ids.count(frame.id) > 0) {
+      expected_frames_.push_back(frame);
+    }
+  }
+  if (replay_frames_.empty() && expected_frames_.empty()) {
+    throw std::runtime_error("no frames to replay or to expect");
+  }
+
+  // both logs are aligned on the first frame of the replay log
+  log_start_time_ = replay_frames_.empty() ? expected_frames_.front().time
+                                           : replay_frames_.front().time;
+  log_end_time_ = std::max(
+    replay_frames_.empty() ? log_start_time_ : replay_frames_.back().time,
+    expected_frames_.empty() ? log_start_time_ : expected_frames_.back().time);
+  RCLCPP_INFO(
+    get_logger(), "replaying %zu frames and expecting %zu frames over %.1f s",
+    replay_frames_.size(), expected_frames_.size(), log_end_time_ - log_start_time_);
+
+  // Subscriber
+  sub_can_frame_ = create_subscription<can_msgs::msg::Frame>(
+    "input/can_frame", rclcpp::QoS{500},
+    std::bind(&CanReplayTester::on_can_frame, this, std::placeholders::_1));
+
+  // Publisher
+  pub_can_frame_ = create_publisher<can_msgs::msg::Frame>("output/can_frame", rclcpp::QoS{500});
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(node_param_.update_rate).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&CanReplayTester::on_timer, this));
+}
+
+double CanReplayTester::to_log_time(const rclcpp::Time & time) const
+{
+  return log_start_time_ + (time - *start_time_).seconds() - node_param_.start_delay;
+}
+
+void CanReplayTester::on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg)
+{
+  if (!start_time_ || finished_ || msg->is_rtr || msg->is_error) {
+    return;
+  }
+  CanFrame frame;
+  frame.time = to_log_time(now());
+  frame.id = msg->id;
+  frame.is_extended = msg->is_extended;
+  frame.data.assign(msg->data.begin(), msg->data.begin() + std::min<size_t>(msg->dlc, 8));
+  captured_frames_.push_back(frame);
+}
+
+void CanReplayTester::on_timer()
+{
+  if (finished_) {
+    return;
+  }
+  if (!start_time_) {
+    start_time_ = now();
+    return;
+  }
+
+  const auto log_time = to_log_time(now());
+  while (next_index_ < replay_frames_.size() && replay_frames_.at(next_index_).time <= log_time) {
+    const auto & frame = replay_frames_.at(next_index_);
+    can_msgs::msg::Frame msg;
+    msg.header.stamp = now();
+    msg.id = frame.id;
+    msg.is_extended = frame.is_extended;
+    msg.dlc = static_cast<uint8_t>(frame.data.size());
+    std::copy(frame.data.begin(), frame.data.end(), msg.data.begin());
+    pub_can_frame_->publish(msg);
+    ++next_index_;
+  }
+
+  if (log_time > log_end_time_ + node_param_.time_tolerance) {
+    finish();
+  }
+}
+
+void CanReplayTester::finish()
+{
+  timer_->cancel();
+
+  const auto result =
+    match_frames(expected_frames_, captured_frames_, node_param_.time_tolerance, masks_);
+  passed_ = result.passed();
+  finished_ = true;
+  if (result.passed()) {
+    RCLCPP_INFO(get_logger(), "PASS: %zu frames matched", result.matched.size());
+  } else {
+    RCLCPP_ERROR(
+      get_logger(), "FAIL: %zu frames matched, %zu missing and %zu unexpected",
+      result.matched.size(), result.missing.size(), result.unexpected.size());
+    for (const auto & frame : result.missing) {
+      RCLCPP_ERROR(get_logger(), "missing %s", to_candump_line(frame, "can0").c_str());
+    }
+    for (const auto & frame : result.unexpected) {
+      RCLCPP_ERROR(get_logger(), "unexpected %s", to_candump_line(frame, "can0").c_str());
+    }
+  }
+
+  if (!node_param_.output_directory.empty()) {
+    const std::filesystem::path directory(node_param_.output_directory);
+    std::filesystem::create_directories(directory);
+
+    nlohmann::json report;
+    report["passed"] = result.passed();
+    report["time_tolerance"] = node_param_.time_tolerance;
+    report["matched"] = nlohmann::json::array();
+    for (const auto & [expected, actual] : result.matched) {
+      report["matched"].push_back(
+        {{"expected", to_json(expected)},
+         {"actual", to_json(actual)},
+         {"delay", actual.time - expected.time}});
+    }
+    report["missing"] = nlohmann::json::array();
+    for (const auto & frame : result.missing) {
+      report["missing"].push_back(to_json(frame));
+    }
+    report["unexpected"] = nlohmann::json::array();
+    for (const auto & frame : result.unexpected) {
+      report["unexpected"].push_back(to_json(frame));
+    }
+    std::ofstream(directory / "report.json") << report.dump(2) << std::endl;
+
+    // the captured frames on the time axis of the log, which can be used as the next expectation
+    std::ofstream captured(directory / "captured.log");
+    for (const auto & frame : captured_frames_) {
+      captured << to_candump_line(frame, "can0") << "\n";
+    }
+    RCLCPP_INFO(get_logger(), "wrote the report to %s", directory.c_str());
+  }
+}
+}  // namespace autoware::can_replay_tester
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::can_replay_tester::CanReplayTester)
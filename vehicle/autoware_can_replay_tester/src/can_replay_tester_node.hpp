@@ -0,0 +1,82 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef CAN_REPLAY_TESTER_NODE_HPP_
+#define CAN_REPLAY_TESTER_NODE_HPP_
+
+#include "can_log.hpp"
+#include "frame_matcher.hpp"
+
+#include <rclcpp/rclcpp.hpp>
+
+#include <can_msgs/msg/frame.hpp>
+
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::can_replay_tester
+{
+struct NodeParam
+{
+  double update_rate{};
+  double start_delay{};
+  double time_tolerance{};
+  std::string output_directory;
+};
+
+class CanReplayTester : public rclcpp::Node
+{
+public:
+  explicit CanReplayTester(const rclcpp::NodeOptions & node_options);
+
+  // the node does nothing after the comparison, and the standalone executable exits with it
+  bool is_finished() const { return finished_; }
+  bool is_passed() const { return passed_; }
+
+private:
+  // Parameter
+  NodeParam node_param_{};
+  Masks masks_;
+
+  // Subscriber
+  rclcpp::Subscription<can_msgs::msg::Frame>::SharedPtr sub_can_frame_;
+
+  // Publisher
+  rclcpp::Publisher<can_msgs::msg::Frame>::SharedPtr pub_can_frame_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  // State
+  std::vector<CanFrame> replay_frames_;
+  std::vector<CanFrame> expected_frames_;
+  std::vector<CanFrame> captured_frames_;
+  size_t next_index_{0};
+  // the time of the first frame in the log, which is replayed at start_time_
+  double log_start_time_{};
+  double log_end_time_{};
+  std::optional<rclcpp::Time> start_time_;
+  bool finished_{false};
+  bool passed_{false};
+
+  // Function
+  void on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg);
+  void on_timer();
+  void finish();
+  double to_log_time(const rclcpp::Time & time) const;
+};
+}  // namespace autoware::can_replay_tester
+
+#endif  // CAN_REPLAY_TESTER_NODE_HPP_
@@ -0,0 +1,75 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "frame_matcher.hpp"
+
+#include <cmath>
+#include <set>
+#include <vector>
+
+namespace autoware::can_replay_tester
+{
+bool equals(const CanFrame & expected, const CanFrame & actual, const Masks & masks)
+{
+  if (expected.id != actual.id || expected.data.size() != actual.data.size()) {
+    return false;
+  }
+  const auto mask = masks.find(expected.id);
+  for (size_t i = 0; i < expected.data.size(); ++i) {
+    const uint8_t byte_mask =
+      mask != masks.end() && i < mask->second.size() ? mask->second.at(i) : 0xFF;
+    if ((expected.data.at(i) & byte_mask) != (actual.data.at(i) & byte_mask)) {
+      return false;
+    }
+  }
+  return true;
+}
+
+MatchResult match_frames(
+  const std::vector<CanFrame> & expected, const std::vector<CanFrame> & actual,
+  const double time_tolerance, const Masks & masks)
+{
+  std::set<uint32_t> expected_ids;
+  for (const auto & frame : expected) {
+    expected_ids.insert(frame.id);
+  }
+
+  MatchResult result;
+  std::vector<bool> used(actual.size(), false);
+  for (const auto & expected_frame : expected) {
+    bool found = false;
+    for (size_t i = 0; i < actual.size(); ++i) {
+      if (used.at(i) || std::abs(actual.at(i).time - expected_frame.time) > time_tolerance) {
+        continue;
+      }
+      if (equals(expected_frame, actual.at(i), masks)) {
+        used.at(i) = true;
+        result.matched.emplace_back(expected_frame, actual.at(i));
+        found = true;
+        break;
+      }
+    }
+    if (!found) {
+      result.missing.push_back(expected_frame);
+    }
+  }
+
+  for (size_t i = 0; i < actual.size(); ++i) {
+    if (!used.at(i) && expected_ids.count(actual.at(i).id) > 0) {
+      result.unexpected.push_back(actual.at(i));
+    }
+  }
+  return result;
+}
+}  // namespace autoware::can_replay_tester
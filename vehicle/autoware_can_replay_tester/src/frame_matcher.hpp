@@ -0,0 +1,51 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef FRAME_MATCHER_HPP_
+#define FRAME_MATCHER_HPP_
+
+#include "can_log.hpp"
+
+#include <cstdint>
+#include <map>
+#include <vector>
+
+namespace autoware::can_replay_tester
+{
+// the bits to compare per ID, e.g. to ignore the rolling counters and the checksums
+using Masks = std::map<uint32_t, std::vector<uint8_t>>;
+
+struct MatchResult
+{
+  // pairs of the expected frame and the captured frame
+  std::vector<std::pair<CanFrame, CanFrame>> matched;
+  // the expected frames which were not captured within the tolerance
+  std::vector<CanFrame> missing;
+  // the captured frames of the expected IDs which matched no expected frame
+  std::vector<CanFrame> unexpected;
+
+  bool passed() const { return missing.empty() && unexpected.empty(); }
+};
+
+bool equals(const CanFrame & expected, const CanFrame & actual, const Masks & masks);
+
+// Matches each expected frame with the earliest unmatched captured frame of the same ID and
+// data within the time tolerance. Both frames are on the same time axis, and the captured
+// frames of the IDs which are not expected are ignored.
+MatchResult match_frames(
+  const std::vector<CanFrame> & expected, const std::vector<CanFrame> & actual,
+  double time_tolerance, const Masks & masks);
+}  // namespace autoware::can_replay_tester
+
+#endif  // FRAME_MATCHER_HPP_
@@ -0,0 +1,140 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "can_log.hpp"
+#include "frame_matcher.hpp"
+
+#include <gtest/gtest.h>
+
+#include <sstream>
+#include <stdexcept>
+#include <vector>
+
+namespace autoware::can_replay_tester
+{
+namespace
+{
+CanFrame make_frame(const double time, const uint32_t id, const std::vector<uint8_t> & data)
+{
+  CanFrame frame;
+  frame.time = time;
+  frame.id = id;
+  frame.data = data;
+  return frame;
+}
+}  // namespace
+
+TEST(CanLog, ParseCandumpLine)
+{
+  const auto frame = parse_candump_line("(1700000000.123456) can0 123#DEADBEEF");
+  ASSERT_TRUE(frame.has_value());
+  EXPECT_NEAR(frame->time, 1700000000.123456, 1e-6);
+  EXPECT_EQ(frame->id, 0x123u);
+  EXPECT_FALSE(frame->is_extended);
+  EXPECT_EQ(frame->data, (std::vector<uint8_t>{0xDE, 0xAD, 0xBE, 0xEF}));
+
+  const auto extended = parse_candump_line("(1.000000) vcan0 18FF0001#");
+  ASSERT_TRUE(extended.has_value());
+  EXPECT_EQ(extended->id, 0x18FF0001u);
+  EXPECT_TRUE(extended->is_extended);
+  EXPECT_TRUE(extended->data.empty());
+}
+
+TEST(CanLog, RejectUnsupportedLine)
+{
+  EXPECT_FALSE(parse_candump_line("(1.0) can0 123#R").has_value());
+  EXPECT_FALSE(parse_candump_line("(1.0) can0 123##1DEAD").has_value());
+  EXPECT_FALSE(parse_candump_line("(1.0) can0 123#ABC").has_value());
+  EXPECT_FALSE(parse_candump_line("(1.0) can0 1234#00").has_value());
+  EXPECT_FALSE(parse_candump_line("(1.0) can0 123#000000000000000000").has_value());
+  EXPECT_FALSE(parse_candump_line("1.0 can0 123#00").has_value());
+  EXPECT_FALSE(parse_candump_line("(1.0x) can0 123#00").has_value());
+}
+
+TEST(CanLog, RoundTrip)
+{
+  const auto frame = make_frame(12.5, 0x500, {0x01, 0x00, 0xAB});
+  const auto line = to_candump_line(frame, "can0");
+  EXPECT_EQ(line, "(12.500000) can0 500#0100AB");
+
+  const auto parsed = parse_candump_line(line);
+  ASSERT_TRUE(parsed.has_value());
+  EXPECT_EQ(parsed->id, frame.id);
+  EXPECT_EQ(parsed->data, frame.data);
+}
+
+TEST(CanLog, LoadCandump)
+{
+  std::istringstream stream(
+    "# comment\n"
+    "(2.0) can0 101#02\n"
+    "\n"
+    "(1.0) can0 100#01\n"
+    "(1.5) can0 100#R\n");
+  const auto frames = load_candump(stream);
+  ASSERT_EQ(frames.size(), 2u);
+  EXPECT_EQ(frames.at(0).id, 0x100u);
+  EXPECT_EQ(frames.at(1).id, 0x101u);
+
+  std::istringstream malformed("(1.0) can0 100#01\ngarbage\n");
+  EXPECT_THROW(load_candump(malformed), std::runtime_error);
+}
+
+TEST(FrameMatcher, MatchWithinTolerance)
+{
+  const std::vector<CanFrame> expected{
+    make_frame(1.0, 0x500, {0x01}), make_frame(2.0, 0x500, {0x02})};
+  const std::vector<CanFrame> actual{
+    make_frame(1.03, 0x500, {0x01}), make_frame(1.5, 0x600, {0xFF}),
+    make_frame(2.2, 0x500, {0x02})};
+
+  const auto result = match_frames(expected, actual, 0.05, {});
+  EXPECT_FALSE(result.passed());
+  ASSERT_EQ(result.matched.size(), 1u);
+  EXPECT_DOUBLE_EQ(result.matched.front().second.time, 1.03);
+  ASSERT_EQ(result.missing.size(), 1u);
+  EXPECT_DOUBLE_EQ(result.missing.front().time, 2.0);
+  // the frame of 0x600 is not expected and ignored
+  ASSERT_EQ(result.unexpected.size(), 1u);
+  EXPECT_DOUBLE_EQ(result.unexpected.front().time, 2.2);
+
+  EXPECT_TRUE(match_frames(expected, actual, 0.3, {}).passed());
+}
+
+TEST(FrameMatcher, MatchEachFrameOnce)
+{
+  const std::vector<CanFrame> expected{
+    make_frame(1.0, 0x500, {0x01}), make_frame(1.01, 0x500, {0x01})};
+  const std::vector<CanFrame> actual{make_frame(1.0, 0x500, {0x01})};
+
+  const auto result = match_frames(expected, actual, 0.1, {});
+  EXPECT_EQ(result.matched.size(), 1u);
+  EXPECT_EQ(result.missing.size(), 1u);
+  EXPECT_TRUE(result.unexpected.empty());
+}
+
+TEST(FrameMatcher, ApplyMask)
+{
+  const Masks masks{{0x500, {0xFF, 0x0F}}};
+  const auto expected = make_frame(1.0, 0x500, {0x01, 0x12, 0x33});
+
+  // the upper nibble of the second byte is the rolling counter
+  EXPECT_TRUE(equals(expected, make_frame(1.0, 0x500, {0x01, 0xA2, 0x33}), masks));
+  EXPECT_FALSE(equals(expected, make_frame(1.0, 0x500, {0x01, 0x13, 0x33}), masks));
+  // the bytes beyond the mask are compared
+  EXPECT_FALSE(equals(expected, make_frame(1.0, 0x500, {0x01, 0x12, 0x34}), masks));
+  EXPECT_FALSE(equals(expected, make_frame(1.0, 0x500, {0x01, 0x12}), masks));
+  EXPECT_FALSE(equals(expected, make_frame(1.0, 0x501, {0x01, 0x12, 0x33}), masks));
+}
+}  // namespace autoware::can_replay_tester
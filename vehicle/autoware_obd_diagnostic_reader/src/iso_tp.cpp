@@ -0,0 +1,103 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "iso_tp.hpp"
+
+#include <algorithm>
+#include <utility>
+
+namespace autoware::obd_diagnostic_reader
+{
+namespace
+{
+enum class FrameType : uint8_t { SINGLE = 0, FIRST = 1, CONSECUTIVE = 2, FLOW_CONTROL = 3 };
+}  // namespace
+
+std::optional<CanData> create_single_frame(const std::vector<uint8_t> & payload)
+{
+  if (payload.empty() || payload.size() > 7) {
+    return std::nullopt;
+  }
+  CanData data;
+  data.fill(padding_byte);
+  data[0] = static_cast<uint8_t>(payload.size());
+  std::copy(payload.begin(), payload.end(), data.begin() + 1);
+  return data;
+}
+
+CanData create_flow_control_frame()
+{
+  CanData data;
+  data.fill(padding_byte);
+  // continue to send, no block size limit and no separation time
+  data[0] = 0x30;
+  data[1] = 0x00;
+  data[2] = 0x00;
+  return data;
+}
+
+IsoTpReceiver::Result IsoTpReceiver::on_frame(const uint8_t * data, const std::size_t size)
+{
+  Result result;
+  if (size == 0) {
+    return result;
+  }
+
+  switch (static_cast<FrameType>(data[0] >> 4)) {
+    case FrameType::SINGLE: {
+      const std::size_t length = data[0] & 0x0F;
+      reset();
+      if (length > 0 && length < size) {
+        result.payload = std::vector<uint8_t>(data + 1, data + 1 + length);
+      }
+      return result;
+    }
+    case FrameType::FIRST: {
+      if (size < 2) {
+        return result;
+      }
+      reset();
+      expected_size_ = (static_cast<std::size_t>(data[0] & 0x0F) << 8) | data[1];
+      buffer_.assign(data + 2, data + size);
+      next_sequence_number_ = 1;
+      result.send_flow_control = true;
+      return result;
+    }
+    case FrameType::CONSECUTIVE: {
+      // a frame out of sequence aborts the message
+      if (expected_size_ == 0 || (data[0] & 0x0F) != next_sequence_number_) {
+        reset();
+        return result;
+      }
+      buffer_.insert(buffer_.end(), data + 1, data + size);
+      next_sequence_number_ = (next_sequence_number_ + 1) & 0x0F;
+      if (buffer_.size() >= expected_size_) {
+        buffer_.resize(expected_size_);
+        result.payload = std::move(buffer_);
+        reset();
+      }
+      return result;
+    }
+    default:
+      return result;
+  }
+}
+
+void IsoTpReceiver::reset()
+{
+  buffer_.clear();
+  expected_size_ = 0;
+  next_sequence_number_ = 0;
+}
+}  // namespace autoware::obd_diagnostic_reader
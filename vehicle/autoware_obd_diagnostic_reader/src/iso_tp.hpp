@@ -0,0 +1,61 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef ISO_TP_HPP_
+#define ISO_TP_HPP_
+
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <vector>
+
+namespace autoware::obd_diagnostic_reader
+{
+using CanData = std::array<uint8_t, 8>;
+
+// the unused bytes of a frame are padded as recommended by ISO 15765-4
+constexpr uint8_t padding_byte = 0x55;
+
+// returns nullopt when the payload does not fit in a single frame
+std::optional<CanData> create_single_frame(const std::vector<uint8_t> & payload);
+
+// flow control which lets the sender send all the consecutive frames without delay
+CanData create_flow_control_frame();
+
+/**
+ * @brief reassembles a payload of ISO 15765-2 from the single, first and consecutive frames
+ */
+class IsoTpReceiver
+{
+public:
+  struct Result
+  {
+    // the reassembled payload, set when the message is complete
+    std::optional<std::vector<uint8_t>> payload;
+    // true when the frame was a first frame and a flow control frame has to be sent
+    bool send_flow_control{false};
+  };
+
+  Result on_frame(const uint8_t * data, std::size_t size);
+  void reset();
+
+private:
+  std::vector<uint8_t> buffer_;
+  std::size_t expected_size_{0};
+  uint8_t next_sequence_number_{0};
+};
+}  // namespace autoware::obd_diagnostic_reader
+
+#endif  // ISO_TP_HPP_
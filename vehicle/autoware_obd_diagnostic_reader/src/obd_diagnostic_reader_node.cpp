@@ -0,0 +1,234 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "obd_diagnostic_reader_node.hpp"
+
+#include <algorithm>
+#include <stdexcept>
+#include <string>
+#include <vector>
+
+namespace autoware::obd_diagnostic_reader
+{
+namespace
+{
+// the bit of the DTC status which indicates a confirmed fault
+constexpr uint8_t confirmed_dtc = 0x08;
+}  // namespace
+
+ObdDiagnosticReader::ObdDiagnosticReader(const rclcpp::NodeOptions & node_options)
+: Node("obd_diagnostic_reader", node_options), updater_(this)
+{
+  // Parameter
+  node_param_.request_id = static_cast<uint32_t>(declare_parameter<int>("request_id"));
+  node_param_.response_id = static_cast<uint32_t>(declare_parameter<int>("response_id"));
+  for (const auto & name : declare_parameter<std::vector<std::string>>("pids")) {
+    const auto pid = find_pid(name);
+    if (!pid) {
+      throw std::invalid_argument("unsupported PID: " + name);
+    }
+    node_param_.pids.push_back(*pid);
+  }
+  node_param_.pid_query_period = declare_parameter<double>("pid_query_period");
+  node_param_.dtc_query_period = declare_parameter<double>("dtc_query_period");
+  node_param_.dtc_status_mask = static_cast<uint8_t>(declare_parameter<int>("dtc_status_mask"));
+  node_param_.response_timeout = declare_parameter<double>("response_timeout");
+  node_param_.data_timeout = declare_parameter<double>("data_timeout");
+  node_param_.max_coolant_temperature = declare_parameter<double>("max_coolant_temperature");
+  node_param_.min_control_module_voltage =
+    declare_parameter<double>("min_control_module_voltage");
+  node_param_.max_control_module_voltage =
+    declare_parameter<double>("max_control_module_voltage");
+
+  // Subscriber
+  sub_can_frame_ = create_subscription<can_msgs::msg::Frame>(
+    "input/can_frame", rclcpp::QoS{100},
+    std::bind(&ObdDiagnosticReader::on_can_frame, this, std::placeholders::_1));
+
+  // Publisher
+  pub_can_frame_ = create_publisher<can_msgs::msg::Frame>("output/can_frame", rclcpp::QoS{10});
+
+  // Timer
+  const auto period_ns = rclcpp::Rate(20.0).period();
+  timer_ = rclcpp::create_timer(
+    this, get_clock(), period_ns, std::bind(&ObdDiagnosticReader::on_timer, this));
+
+  // Diagnostic Updater
+  updater_.setHardwareID("obd_diagnostic_reader");
+  updater_.add("obd_vehicle_health", this, &ObdDiagnosticReader::check_vehicle_health);
+
+  // State
+  for (const auto & pid : node_param_.pids) {
+    requests_.push_back({create_pid_request(pid.pid), pid, node_param_.pid_query_period, now()});
+  }
+  requests_.push_back(
+    {create_dtc_request(node_param_.dtc_status_mask), std::nullopt, node_param_.dtc_query_period,
+     now()});
+}
+
+void ObdDiagnosticReader::on_timer()
+{
+  const auto current_time = now();
+
+  // only one request is sent at a time, since the responses are not tagged
+  if (pending_) {
+    if ((current_time - pending_->sent_time).seconds() < node_param_.response_timeout) {
+      return;
+    }
+    ++num_timeouts_;
+    RCLCPP_DEBUG(get_logger(), "no response to the request %zu", pending_->index);
+    pending_.reset();
+    receiver_.reset();
+  }
+
+  for (size_t i = 0; i < requests_.size(); ++i) {
+    auto & request = requests_.at(i);
+    if (current_time < request.next_time) {
+      continue;
+    }
+    const auto frame = create_single_frame(request.payload);
+    if (!frame) {
+      continue;
+    }
+    send_frame(*frame);
+    pending_ = PendingRequest{i, current_time};
+    request.next_time = current_time + rclcpp::Duration::from_seconds(request.period);
+    return;
+  }
+}
+
+void ObdDiagnosticReader::on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg)
+{
+  if (msg->id != node_param_.response_id || msg->is_extended || msg->is_rtr || msg->is_error) {
+    return;
+  }
+
+  const auto result = receiver_.on_frame(msg->data.data(), std::min<size_t>(msg->dlc, 8));
+  if (result.send_flow_control) {
+    send_frame(create_flow_control_frame());
+  }
+  if (result.payload) {
+    on_response(*result.payload);
+  }
+}
+
+void ObdDiagnosticReader::on_response(const std::vector<uint8_t> & payload)
+{
+  if (!pending_) {
+    return;
+  }
+  const auto & request = requests_.at(pending_->index);
+  const auto current_time = now();
+
+  const auto negative_response_code = get_negative_response_code(payload, request.payload.front());
+  if (negative_response_code) {
+    // the ECU needs more time, so the timeout is extended
+    if (*negative_response_code == response_pending) {
+      pending_->sent_time = current_time;
+      return;
+    }
+    RCLCPP_WARN_THROTTLE(
+      get_logger(), *get_clock(), 10000, "negative response 0x%02X to the service 0x%02X",
+      *negative_response_code, request.payload.front());
+    pending_.reset();
+    return;
+  }
+
+  if (request.pid) {
+    if (const auto value = decode_pid_response(*request.pid, payload)) {
+      pid_values_[request.pid->name] = {*value, current_time};
+      last_response_time_ = current_time;
+    }
+  } else {
+    if (const auto dtcs = decode_dtc_response(payload)) {
+      dtcs_ = *dtcs;
+      last_response_time_ = current_time;
+    }
+  }
+  pending_.reset();
+}
+
+void ObdDiagnosticReader::send_frame(const CanData & data)
+{
+  can_msgs::msg::Frame frame;
+  frame.header.stamp = now();
+  frame.id = node_param_.request_id;
+  frame.dlc = static_cast<uint8_t>(data.size());
+  std::copy(data.begin(), data.end(), frame.data.begin());
+  pub_can_frame_->publish(frame);
+}
+
+void ObdDiagnosticReader::check_vehicle_health(diagnostic_updater::DiagnosticStatusWrapper & stat)
+{
+  using diagnostic_msgs::msg::DiagnosticStatus;
+
+  const auto current_time = now();
+  stat.add("num_timeouts", num_timeouts_);
+
+  if (
+    !last_response_time_ ||
+    (current_time - *last_response_time_).seconds() > node_param_.data_timeout) {
+    stat.summary(DiagnosticStatus::STALE, "no response from the ECU");
+    return;
+  }
+
+  std::vector<std::string> warnings;
+  for (const auto & pid : node_param_.pids) {
+    const auto it = pid_values_.find(pid.name);
+    if (it == pid_values_.end()) {
+      continue;
+    }
+    const auto & [value, stamp] = it->second;
+    if ((current_time - stamp).seconds() > node_param_.data_timeout) {
+      continue;
+    }
+    stat.addf(pid.name, "%.2f [%s]", value, pid.unit.c_str());
+
+    if (pid.name == "coolant_temperature" && value > node_param_.max_coolant_temperature) {
+      warnings.emplace_back("high coolant temperature");
+    }
+    if (
+      pid.name == "control_module_voltage" && (value < node_param_.min_control_module_voltage ||
+                                               value > node_param_.max_control_module_voltage)) {
+      warnings.emplace_back("abnormal battery voltage");
+    }
+  }
+
+  if (dtcs_) {
+    size_t num_confirmed = 0;
+    for (const auto & dtc : *dtcs_) {
+      const bool is_confirmed = (dtc.status & confirmed_dtc) != 0;
+      stat.addf(to_string(dtc), "status 0x%02X%s", dtc.status, is_confirmed ? " (confirmed)" : "");
+      num_confirmed += is_confirmed ? 1 : 0;
+    }
+    stat.add("num_dtcs", dtcs_->size());
+    if (num_confirmed > 0) {
+      warnings.emplace_back(std::to_string(num_confirmed) + " confirmed DTCs");
+    }
+  }
+
+  if (warnings.empty()) {
+    stat.summary(DiagnosticStatus::OK, "OK");
+    return;
+  }
+  std::string message;
+  for (const auto & warning : warnings) {
+    message += (message.empty() ? "" : ", ") + warning;
+  }
+  stat.summary(DiagnosticStatus::WARN, message);
+}
+}  // namespace autoware::obd_diagnostic_reader
+
+#include <rclcpp_components/register_node_macro.hpp>
+RCLCPP_COMPONENTS_REGISTER_NODE(autoware::obd_diagnostic_reader::ObdDiagnosticReader)
@@ -0,0 +1,111 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef OBD_DIAGNOSTIC_READER_NODE_HPP_
+#define OBD_DIAGNOSTIC_READER_NODE_HPP_
+
+#include "iso_tp.hpp"
+#include "obd_protocol.hpp"
+
+#include <diagnostic_updater/diagnostic_updater.hpp>
+#include <rclcpp/rclcpp.hpp>
+
+#include <can_msgs/msg/frame.hpp>
+
+#include <map>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::obd_diagnostic_reader
+{
+struct NodeParam
+{
+  uint32_t request_id{};
+  uint32_t response_id{};
+  std::vector<PidDefinition> pids{};
+  double pid_query_period{};
+  double dtc_query_period{};
+  uint8_t dtc_status_mask{};
+  double response_timeout{};
+  double data_timeout{};
+  double max_coolant_temperature{};
+  double min_control_module_voltage{};
+  double max_control_module_voltage{};
+};
+
+class ObdDiagnosticReader : public rclcpp::Node
+{
+public:
+  explicit ObdDiagnosticReader(const rclcpp::NodeOptions & node_options);
+
+private:
+  struct Request
+  {
+    std::vector<uint8_t> payload;
+    // the PID of the service 01, or nullopt for the DTC request
+    std::optional<PidDefinition> pid;
+    double period;
+    rclcpp::Time next_time;
+  };
+
+  struct PendingRequest
+  {
+    size_t index;
+    rclcpp::Time sent_time;
+  };
+
+  struct PidValue
+  {
+    double value;
+    rclcpp::Time stamp;
+  };
+
+  // Parameter
+  NodeParam node_param_{};
+
+  // Subscriber
+  rclcpp::Subscription<can_msgs::msg::Frame>::SharedPtr sub_can_frame_;
+
+  void on_can_frame(const can_msgs::msg::Frame::ConstSharedPtr msg);
+
+  // Publisher
+  rclcpp::Publisher<can_msgs::msg::Frame>::SharedPtr pub_can_frame_;
+
+  // Timer
+  rclcpp::TimerBase::SharedPtr timer_;
+
+  void on_timer();
+
+  // Diagnostic Updater
+  diagnostic_updater::Updater updater_;
+
+  void check_vehicle_health(diagnostic_updater::DiagnosticStatusWrapper & stat);
+
+  // State
+  std::vector<Request> requests_;
+  std::optional<PendingRequest> pending_;
+  IsoTpReceiver receiver_;
+  std::map<std::string, PidValue> pid_values_;
+  std::optional<std::vector<Dtc>> dtcs_;
+  std::optional<rclcpp::Time> last_response_time_;
+  size_t num_timeouts_{0};
+
+  // Function
+  void send_frame(const CanData & data);
+  void on_response(const std::vector<uint8_t> & payload);
+};
+}  // namespace autoware::obd_diagnostic_reader
+
+#endif  // OBD_DIAGNOSTIC_READER_NODE_HPP_
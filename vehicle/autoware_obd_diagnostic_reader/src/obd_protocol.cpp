@@ -0,0 +1,117 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "obd_protocol.hpp"
+
+#include <algorithm>
+#include <cstdio>
+
+namespace autoware::obd_diagnostic_reader
+{
+namespace
+{
+double to_uint16(const uint8_t * data)
+{
+  return static_cast<double>((static_cast<uint16_t>(data[0]) << 8) | data[1]);
+}
+}  // namespace
+
+const std::vector<PidDefinition> & supported_pids()
+{
+  // the formulas of SAE J1979 for the service 01
+  static const std::vector<PidDefinition> pids{
+    {0x05, "coolant_temperature", "degC", 1, [](const uint8_t * d) { return d[0] - 40.0; }},
+    {0x0C, "engine_speed", "rpm", 2, [](const uint8_t * d) { return to_uint16(d) / 4.0; }},
+    {0x0D, "vehicle_speed", "km/h", 1, [](const uint8_t * d) { return d[0] * 1.0; }},
+    {0x2F, "fuel_level", "%", 1, [](const uint8_t * d) { return d[0] * 100.0 / 255.0; }},
+    {0x42, "control_module_voltage", "V", 2,
+     [](const uint8_t * d) { return to_uint16(d) / 1000.0; }},
+    {0x46, "ambient_air_temperature", "degC", 1, [](const uint8_t * d) { return d[0] - 40.0; }},
+  };
+  return pids;
+}
+
+std::optional<PidDefinition> find_pid(const std::string & name)
+{
+  const auto & pids = supported_pids();
+  const auto it = std::find_if(
+    pids.begin(), pids.end(), [&](const auto & definition) { return definition.name == name; });
+  if (it == pids.end()) {
+    return std::nullopt;
+  }
+  return *it;
+}
+
+std::vector<uint8_t> create_pid_request(const uint8_t pid)
+{
+  return {show_current_data, pid};
+}
+
+std::optional<double> decode_pid_response(
+  const PidDefinition & definition, const std::vector<uint8_t> & payload)
+{
+  if (
+    payload.size() < 2 + definition.num_bytes ||
+    payload[0] != show_current_data + positive_response_offset || payload[1] != definition.pid) {
+    return std::nullopt;
+  }
+  return definition.decode(payload.data() + 2);
+}
+
+std::vector<uint8_t> create_dtc_request(const uint8_t status_mask)
+{
+  return {read_dtc_information, report_dtc_by_status_mask, status_mask};
+}
+
+std::optional<std::vector<Dtc>> decode_dtc_response(const std::vector<uint8_t> & payload)
+{
+  // the service, the sub-function and the status availability mask precede the records
+  if (
+    payload.size() < 3 || payload[0] != read_dtc_information + positive_response_offset ||
+    payload[1] != report_dtc_by_status_mask) {
+    return std::nullopt;
+  }
+
+  std::vector<Dtc> dtcs;
+  for (size_t i = 3; i + 4 <= payload.size(); i += 4) {
+    const uint32_t code = (static_cast<uint32_t>(payload[i]) << 16) |
+                          (static_cast<uint32_t>(payload[i + 1]) << 8) | payload[i + 2];
+    dtcs.push_back({code, payload[i + 3]});
+  }
+  return dtcs;
+}
+
+std::string to_string(const Dtc & dtc)
+{
+  static constexpr char systems[] = {'P', 'C', 'B', 'U'};
+  const auto high = static_cast<uint8_t>(dtc.code >> 16);
+  const auto low = static_cast<uint8_t>(dtc.code >> 8);
+  const auto failure_type = static_cast<uint8_t>(dtc.code);
+
+  char buffer[16];
+  std::snprintf(
+    buffer, sizeof(buffer), "%c%X%X%02X-%02X", systems[high >> 6], (high >> 4) & 0x03, high & 0x0F,
+    low, failure_type);
+  return buffer;
+}
+
+std::optional<uint8_t> get_negative_response_code(
+  const std::vector<uint8_t> & payload, const uint8_t service)
+{
+  if (payload.size() < 3 || payload[0] != negative_response || payload[1] != service) {
+    return std::nullopt;
+  }
+  return payload[2];
+}
+}  // namespace autoware::obd_diagnostic_reader
@@ -0,0 +1,70 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#ifndef OBD_PROTOCOL_HPP_
+#define OBD_PROTOCOL_HPP_
+
+#include <cstddef>
+#include <cstdint>
+#include <optional>
+#include <string>
+#include <vector>
+
+namespace autoware::obd_diagnostic_reader
+{
+// service identifiers of SAE J1979 and ISO 14229
+constexpr uint8_t show_current_data = 0x01;
+constexpr uint8_t read_dtc_information = 0x19;
+constexpr uint8_t report_dtc_by_status_mask = 0x02;
+constexpr uint8_t negative_response = 0x7F;
+constexpr uint8_t positive_response_offset = 0x40;
+constexpr uint8_t response_pending = 0x78;
+
+struct PidDefinition
+{
+  uint8_t pid;
+  std::string name;
+  std::string unit;
+  std::size_t num_bytes;
+  double (*decode)(const uint8_t * data);
+};
+
+// returns nullopt for an unsupported name
+std::optional<PidDefinition> find_pid(const std::string & name);
+const std::vector<PidDefinition> & supported_pids();
+
+std::vector<uint8_t> create_pid_request(uint8_t pid);
+// returns nullopt when the payload is not the positive response for the PID
+std::optional<double> decode_pid_response(
+  const PidDefinition & definition, const std::vector<uint8_t> & payload);
+
+struct Dtc
+{
+  // the 3 bytes of the DTC of ISO 14229, the last one is the failure type
+  uint32_t code;
+  uint8_t status;
+};
+
+std::vector<uint8_t> create_dtc_request(uint8_t status_mask);
+std::optional<std::vector<Dtc>> decode_dtc_response(const std::vector<uint8_t> & payload);
+
+// formats the DTC as SAE J2012, e.g. P0301-1C
+std::string to_string(const Dtc & dtc);
+
+// returns the negative response code when the payload is a negative response to the service
+std::optional<uint8_t> get_negative_response_code(
+  const std::vector<uint8_t> & payload, uint8_t service);
+}  // namespace autoware::obd_diagnostic_reader
+
+#endif  // OBD_PROTOCOL_HPP_
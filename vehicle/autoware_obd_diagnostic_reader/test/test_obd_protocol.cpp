@@ -0,0 +1,112 @@
+// Copyright 2025 TIER IV, Inc.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#include "iso_tp.hpp"
+#include "obd_protocol.hpp"
+
+#include <gtest/gtest.h>
+
+#include <vector>
+
+using autoware::obd_diagnostic_reader::CanData;
+using autoware::obd_diagnostic_reader::create_dtc_request;
+using autoware::obd_diagnostic_reader::create_pid_request;
+using autoware::obd_diagnostic_reader::create_single_frame;
+using autoware::obd_diagnostic_reader::decode_dtc_response;
+using autoware::obd_diagnostic_reader::decode_pid_response;
+using autoware::obd_diagnostic_reader::Dtc;
+using autoware::obd_diagnostic_reader::find_pid;
+using autoware::obd_diagnostic_reader::get_negative_response_code;
+using autoware::obd_diagnostic_reader::IsoTpReceiver;
+using autoware::obd_diagnostic_reader::to_string;
+
+TEST(IsoTp, SingleFrame)
+{
+  const auto frame = create_single_frame(create_pid_request(0x05));
+  ASSERT_TRUE(frame);
+  EXPECT_EQ(*frame, (CanData{0x02, 0x01, 0x05, 0x55, 0x55, 0x55, 0x55, 0x55}));
+  EXPECT_FALSE(create_single_frame(std::vector<uint8_t>(8, 0)));
+
+  IsoTpReceiver receiver;
+  const uint8_t response[] = {0x03, 0x41, 0x05, 0x7B, 0x55, 0x55, 0x55, 0x55};
+  const auto result = receiver.on_frame(response, sizeof(response));
+  ASSERT_TRUE(result.payload);
+  EXPECT_EQ(*result.payload, (std::vector<uint8_t>{0x41, 0x05, 0x7B}));
+  EXPECT_FALSE(result.send_flow_control);
+}
+
+TEST(IsoTp, MultiFrame)
+{
+  IsoTpReceiver receiver;
+  const uint8_t first[] = {0x10, 0x0A, 0x59, 0x02, 0xFF, 0x01, 0x23, 0x1C};
+  const uint8_t consecutive[] = {0x21, 0x2F, 0x03, 0x04, 0x55, 0x55, 0x55, 0x55};
+
+  auto result = receiver.on_frame(first, sizeof(first));
+  EXPECT_FALSE(result.payload);
+  EXPECT_TRUE(result.send_flow_control);
+
+  result = receiver.on_frame(consecutive, sizeof(consecutive));
+  ASSERT_TRUE(result.payload);
+  EXPECT_EQ(
+    *result.payload,
+    (std::vector<uint8_t>{0x59, 0x02, 0xFF, 0x01, 0x23, 0x1C, 0x2F, 0x03, 0x04, 0x55}));
+}
+
+TEST(IsoTp, OutOfSequence)
+{
+  IsoTpReceiver receiver;
+  const uint8_t first[] = {0x10, 0x0A, 0x59, 0x02, 0xFF, 0x01, 0x23, 0x1C};
+  const uint8_t wrong[] = {0x22, 0x2F, 0x03, 0x04, 0x55, 0x55, 0x55, 0x55};
+  receiver.on_frame(first, sizeof(first));
+  EXPECT_FALSE(receiver.on_frame(wrong, sizeof(wrong)).payload);
+}
+
+TEST(ObdProtocol, Pid)
+{
+  const auto coolant = find_pid("coolant_temperature");
+  ASSERT_TRUE(coolant);
+  EXPECT_DOUBLE_EQ(*decode_pid_response(*coolant, {0x41, 0x05, 0x7B}), 83.0);
+  // the response to another PID
+  EXPECT_FALSE(decode_pid_response(*coolant, {0x41, 0x0C, 0x7B}));
+
+  const auto voltage = find_pid("control_module_voltage");
+  ASSERT_TRUE(voltage);
+  EXPECT_DOUBLE_EQ(*decode_pid_response(*voltage, {0x41, 0x42, 0x36, 0xB0}), 14.0);
+  EXPECT_FALSE(decode_pid_response(*voltage, {0x41, 0x42, 0x36}));
+
+  EXPECT_FALSE(find_pid("unknown"));
+}
+
+TEST(ObdProtocol, Dtc)
+{
+  EXPECT_EQ(create_dtc_request(0x08), (std::vector<uint8_t>{0x19, 0x02, 0x08}));
+
+  const auto dtcs =
+    decode_dtc_response({0x59, 0x02, 0xFF, 0x03, 0x01, 0x1C, 0x08, 0xC1, 0x00, 0x00, 0x09});
+  ASSERT_TRUE(dtcs);
+  ASSERT_EQ(dtcs->size(), 2u);
+  EXPECT_EQ(to_string(dtcs->at(0)), "P0301-1C");
+  EXPECT_EQ(dtcs->at(0).status, 0x08);
+  EXPECT_EQ(to_string(dtcs->at(1)), "U0100-00");
+
+  EXPECT_TRUE(decode_dtc_response({0x59, 0x02, 0xFF})->empty());
+  EXPECT_FALSE(decode_dtc_response({0x41, 0x02, 0xFF}));
+}
+
+TEST(ObdProtocol, NegativeResponse)
+{
+  EXPECT_EQ(get_negative_response_code({0x7F, 0x19, 0x78}, 0x19), 0x78);
+  EXPECT_FALSE(get_negative_response_code({0x7F, 0x01, 0x31}, 0x19));
+  EXPECT_FALSE(get_negative_response_code({0x59, 0x02, 0xFF}, 0x19));
+}